@@ -0,0 +1,53 @@
+//
+//  BridgedTrait.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+/// A Swift class that conforms to the protocol that gets generated for the Rust `Animal` trait.
+class SwiftCat: Animal {
+    func name() -> RustString {
+        "Tom".intoRustString()
+    }
+
+    func speak(_ times: UInt8) -> RustString {
+        Array(repeating: "meow", count: Int(times)).joined(separator: " ").intoRustString()
+    }
+}
+
+/// A thread safe Swift implementation of the Rust `Storage` trait.
+final class SwiftInMemoryStorage: Storage, @unchecked Sendable {
+    private let lock = NSLock()
+    private var values: [String: String] = [:]
+
+    func get<GenericIntoRustString: IntoRustString>(_ key: GenericIntoRustString) -> Optional<RustString> {
+        let key = key.intoRustString().toString()
+
+        lock.lock()
+        defer { lock.unlock() }
+        return values[key]?.intoRustString()
+    }
+
+    func set<GenericIntoRustString: IntoRustString>(_ key: GenericIntoRustString, _ value: GenericIntoRustString) {
+        let key = key.intoRustString().toString()
+        let value = value.intoRustString().toString()
+
+        lock.lock()
+        defer { lock.unlock() }
+        values[key] = value
+    }
+}
+
+/// A Swift implementation of the Rust `Clock` trait that always returns the same time.
+final class SwiftFixedClock: Clock {
+    let millis: UInt64
+
+    init(millis: UInt64) {
+        self.millis = millis
+    }
+
+    func now_millis() -> UInt64 {
+        millis
+    }
+}
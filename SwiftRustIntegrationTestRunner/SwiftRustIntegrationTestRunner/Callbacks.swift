@@ -55,3 +55,33 @@ class SwiftMethodCallbackTester {
         callback(5)
     }
 }
+
+func swift_calls_rust_fn_callback_three_times(callback: (UInt8) -> UInt8) -> UInt8 {
+    callback(1) + callback(2) + callback(3)
+}
+
+func swift_calls_rust_fnmut_callback(callback: (UInt32) -> (), times: UInt32) {
+    for idx in 0..<times {
+        callback(idx)
+    }
+}
+
+var storedRustFnCallback: (() -> ())? = nil
+
+func swift_stores_rust_fn_callback(callback: @escaping () -> ()) {
+    callback()
+    storedRustFnCallback = callback
+}
+
+func swift_releases_stored_rust_fn_callback() {
+    storedRustFnCallback = nil
+}
+
+func swift_calls_sendable_rust_callback_concurrently(
+    callback: @escaping @Sendable (UInt32) -> (),
+    times: UInt32
+) {
+    DispatchQueue.concurrentPerform(iterations: Int(times)) { _ in
+        callback(1)
+    }
+}
@@ -0,0 +1,14 @@
+//
+//  Char.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+func swift_reflect_char(arg: Unicode.Scalar) -> Unicode.Scalar {
+    arg
+}
+
+func swift_reflect_option_char(arg: Optional<Unicode.Scalar>) -> Optional<Unicode.Scalar> {
+    arg
+}
@@ -0,0 +1,14 @@
+//
+//  FixedSizeArray.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+func swift_reflect_array_u8(arg: [UInt8]) -> [UInt8] {
+    arg
+}
+
+func swift_reflect_option_array(arg: Optional<[UInt8]>) -> Optional<[UInt8]> {
+    arg
+}
@@ -0,0 +1,21 @@
+//
+//  Int128.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+@available(macOS 15.0, iOS 18.0, watchOS 11.0, tvOS 18.0, visionOS 2.0, *)
+func swift_reflect_i128(arg: Int128) -> Int128 {
+    arg
+}
+
+@available(macOS 15.0, iOS 18.0, watchOS 11.0, tvOS 18.0, visionOS 2.0, *)
+func swift_reflect_u128(arg: UInt128) -> UInt128 {
+    arg
+}
+
+@available(macOS 15.0, iOS 18.0, watchOS 11.0, tvOS 18.0, visionOS 2.0, *)
+func swift_reflect_option_u128(arg: Optional<UInt128>) -> Optional<UInt128> {
+    arg
+}
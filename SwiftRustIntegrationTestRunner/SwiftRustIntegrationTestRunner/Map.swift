@@ -0,0 +1,14 @@
+//
+//  Map.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+func swift_reflect_hashmap_primitives(arg: RustMap<UInt32, Bool>) -> RustMap<UInt32, Bool> {
+    arg
+}
+
+func swift_reflect_hashmap_string_values(arg: RustMap<UInt8, RustString>) -> RustMap<UInt8, RustString> {
+    RustMap(arg.toDictionary())
+}
@@ -0,0 +1,14 @@
+//
+//  NestedContainers.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+func swift_reflect_option_option_u8(arg: Optional<Optional<UInt8>>) -> Optional<Optional<UInt8>> {
+    arg
+}
+
+func swift_reflect_vec_option_string(arg: [Optional<RustString>]) -> [Optional<RustString>] {
+    arg
+}
@@ -0,0 +1,14 @@
+//
+//  Set.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+func swift_reflect_hashset_primitives(arg: RustSet<UInt64>) -> RustSet<UInt64> {
+    arg
+}
+
+func swift_reflect_btreeset_strings(arg: RustSet<RustString>) -> RustSet<RustString> {
+    RustSet(arg.toSet())
+}
@@ -0,0 +1,10 @@
+//
+//  SwiftNaming.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+func camelCaseAddNumbers(firstNumber: UInt32, secondNumber: UInt32) -> UInt32 {
+    firstNumber + secondNumber
+}
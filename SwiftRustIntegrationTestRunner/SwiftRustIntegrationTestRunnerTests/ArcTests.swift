@@ -0,0 +1,72 @@
+//
+//  ArcTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for passing `Arc<T>` of an opaque Rust type between Rust and Swift.
+class ArcTests: XCTestCase {
+    /// Verify that every Swift instance of an `Arc<T>` holds its own strong count, which gets
+    /// released when the instance is deinitialized.
+    func testSwiftHoldsStrongCount() throws {
+        let cache = ArcSessionCache()
+        XCTAssertEqual(cache.session_strong_count(), 1)
+
+        var first: ArcSessionArc? = cache.session()
+        XCTAssertEqual(cache.session_strong_count(), 2)
+
+        var second: ArcSessionArc? = cache.session()
+        XCTAssertEqual(cache.session_strong_count(), 3)
+
+        first = nil
+        XCTAssertEqual(cache.session_strong_count(), 2)
+
+        second = nil
+        XCTAssertEqual(cache.session_strong_count(), 1)
+    }
+
+    /// Verify that returning the same `Arc<T>` twice gives two Swift instances that share one
+    /// allocation.
+    func testInstancesShareAllocation() throws {
+        let cache = ArcSessionCache()
+
+        let first = cache.session()
+        let second = cache.session()
+        XCTAssertEqual(first.ptr, second.ptr)
+
+        first.increment_requests()
+        first.increment_requests()
+        XCTAssertEqual(second.requests(), 2)
+    }
+
+    /// Verify that passing an `Arc<T>` to Rust gives Rust its own strong count, so the Swift
+    /// instance can still be used after the call.
+    func testPassArcToRust() throws {
+        let cache = ArcSessionCache()
+        let session = cache.session()
+
+        XCTAssertTrue(cache.is_cached_session(session))
+        XCTAssertTrue(cache.is_cached_session(session))
+        XCTAssertEqual(cache.session_strong_count(), 2)
+
+        let reflected = rust_reflect_arc_session(session)
+        XCTAssertEqual(reflected.ptr, session.ptr)
+        XCTAssertEqual(cache.session_strong_count(), 3)
+    }
+
+    /// Verify that we can pass and return an `Option<Arc<T>>`.
+    func testOptionArc() throws {
+        let cache = ArcSessionCache()
+
+        XCTAssertNil(cache.maybe_session(false))
+        XCTAssertEqual(cache.maybe_session(true)!.ptr, cache.session().ptr)
+
+        XCTAssertNil(rust_reflect_option_arc_session(nil))
+
+        let reflected = rust_reflect_option_arc_session(cache.session())
+        XCTAssertEqual(cache.session_strong_count(), 2)
+        XCTAssertTrue(cache.is_cached_session(reflected!))
+    }
+}
@@ -30,4 +30,26 @@ final class ArgumentAttributesTest: XCTestCase {
         XCTAssertEqual(rust_calls_swift_with_custom_labels(5, 3), 8)
     }
 
+    /// Verify that the `swift_bridge(default = "...")` attribute lets Swift leave out an argument.
+    func testArgumentDefault() throws {
+        XCTAssertEqual(test_argument_default(10), 19)
+        XCTAssertEqual(test_argument_default(10, times: 3), 29)
+        XCTAssertEqual(test_argument_default(10, times: 3, plus: 5), 35)
+    }
+
+    /// Verify that the `swift_bridge(default = None)` attribute gives an `Optional` argument a
+    /// default value of `nil`.
+    func testArgumentDefaultNone() throws {
+        XCTAssertEqual(test_argument_default_none(), 0)
+        XCTAssertEqual(test_argument_default_none(5), 5)
+    }
+
+    /// Verify that the `optional_args_default = None` module attribute gives every `Optional`
+    /// argument a default value of `nil`, unless the argument has its own default.
+    func testOptionalArgsDefault() throws {
+        XCTAssertEqual(test_optional_args_default(500), 100)
+        XCTAssertEqual(test_optional_args_default(5, 10), 10)
+        XCTAssertEqual(test_optional_args_default(500, nil, nil), 500)
+    }
+
 }
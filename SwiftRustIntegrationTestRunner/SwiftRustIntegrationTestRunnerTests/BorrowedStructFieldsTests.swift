@@ -0,0 +1,54 @@
+//
+//  BorrowedStructFieldsTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for shared structs that have `&str`, slice and pointer fields.
+/// crates/swift-integration-tests/src/shared_types/borrowed_struct_fields.rs
+class BorrowedStructFieldsTests: XCTestCase {
+    /// Verify that a struct's `&str` and `&[u8]` fields are views into the Rust data that the
+    /// struct borrows from.
+    func testStructWithBorrowedFields() throws {
+        let document = TokenizedDocument("hello world")
+
+        let token = document.first_token()!
+        XCTAssertEqual(token.text.toString(), "hello")
+        XCTAssertEqual(Array(token.bytes), Array("hello".utf8))
+        XCTAssertEqual(token.start, 0)
+
+        let tokens = document.tokens()
+        XCTAssertEqual(tokens.len(), 2)
+
+        let second = tokens.get(index: 1)!
+        XCTAssertEqual(second.text.toString(), "world")
+        XCTAssertEqual(second.start, 6)
+    }
+
+    /// Verify that we can pass a struct with borrowed fields to Rust and get it back.
+    func testRustReflectStructWithBorrowedFields() throws {
+        let document = TokenizedDocument("hello")
+        let token = document.first_token()!
+
+        let reflected = rust_reflect_borrowed_token(token)
+        XCTAssertEqual(reflected.text, token.text)
+        XCTAssertEqual(reflected.bytes.baseAddress, token.bytes.baseAddress)
+        XCTAssertEqual(reflected.bytes.count, 5)
+    }
+
+    /// Verify that we can pass a struct with pointer fields to Rust and get it back.
+    func testRustReflectStructWithPointerFields() throws {
+        let value = UnsafeMutablePointer<UInt8>.allocate(capacity: 1)
+        value.initialize(to: 5)
+        defer { value.deallocate() }
+
+        let reflected = rust_reflect_struct_with_pointer_fields(
+            StructWithPointerFields(const_ptr: UnsafePointer(value), mut_void_ptr: UnsafeMutableRawPointer(value))
+        )
+
+        XCTAssertEqual(reflected.const_ptr.pointee, 5)
+        XCTAssertEqual(reflected.mut_void_ptr, UnsafeMutableRawPointer(value))
+    }
+}
@@ -0,0 +1,68 @@
+//
+//  BridgedTraitTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for traits that get declared in the bridge module, which Swift sees as protocols.
+class BridgedTraitTests: XCTestCase {
+    /// Verify that an opaque Rust type that implements a trait conforms to the trait's protocol.
+    func testOpaqueRustTypeConformsToProtocol() throws {
+        let dog: any Animal = RustDog("Rex")
+
+        XCTAssertEqual(dog.name().toString(), "Rex")
+        XCTAssertEqual(dog.speak(2).toString(), "woof woof")
+    }
+
+    /// Verify that we can pass an opaque Rust type to a Rust function that takes a
+    /// `&dyn Animal` or a `Box<dyn Animal>`.
+    func testPassOpaqueRustTypeAsTraitObject() throws {
+        let dog = RustDog("Rex")
+
+        XCTAssertEqual(rust_animal_name(dog).toString(), "Rex")
+        XCTAssertEqual(rust_animal_speak(dog, 3).toString(), "Rex says woof woof woof")
+    }
+
+    /// Verify that we can pass a Swift class that conforms to a trait's protocol to a Rust
+    /// function that takes a `&dyn Animal` or a `Box<dyn Animal>`.
+    func testPassSwiftClassAsTraitObject() throws {
+        let cat = SwiftCat()
+
+        XCTAssertEqual(rust_animal_name(cat).toString(), "Tom")
+        XCTAssertEqual(rust_animal_speak(cat, 2).toString(), "Tom says meow meow")
+    }
+
+    /// Verify that Rust releases the Swift object when it drops the `Box<dyn Animal>`.
+    func testRustReleasesSwiftObject() throws {
+        weak var weakCat: SwiftCat?
+
+        do {
+            let cat = SwiftCat()
+            weakCat = cat
+            let _ = rust_animal_speak(cat, 1)
+        }
+
+        XCTAssertNil(weakCat)
+    }
+
+    /// Verify that Rust can use a Swift object that it received as an
+    /// `Arc<dyn Storage + Send + Sync>` from another thread.
+    func testPassSwiftClassAsArcTraitObject() throws {
+        let storage = SwiftInMemoryStorage()
+
+        let value = rust_storage_set_on_another_thread(storage, "language", "swift")
+
+        XCTAssertEqual(value?.toString(), "swift")
+        XCTAssertEqual(storage.get("language")?.toString(), "swift")
+    }
+
+    /// Verify that Rust can move a Swift object that it received as a `Box<dyn Clock + Send>` to
+    /// another thread.
+    func testPassSwiftClassAsSendTraitObject() throws {
+        let clock = SwiftFixedClock(millis: 1_500)
+
+        XCTAssertEqual(rust_clock_elapsed_millis(clock, 1_000), 500)
+    }
+}
@@ -14,4 +14,58 @@ class CallbackTests: XCTestCase {
     func testRustCallsSwift() throws {
         test_callbacks_rust_calls_swift()
     }
+
+    /// Verify that Rust can call a Swift closure that was passed as a `Box<dyn Fn>` more than once.
+    func testRustCallsSwiftFnCallbackMultipleTimes() throws {
+        var calls: [UInt8] = []
+        let sum = rust_calls_swift_fn_callback_twice({ num in
+            calls.append(num)
+            return num * 10
+        })
+
+        XCTAssertEqual(sum, 30)
+        XCTAssertEqual(calls, [1, 2])
+    }
+
+    /// Verify that Rust can call an `@Sendable` Swift closure from another thread.
+    func testRustCallsSendableSwiftCallbackOnAnotherThread() throws {
+        let doubled = rust_calls_sendable_swift_callback_on_another_thread({ num in
+            XCTAssertFalse(Thread.isMainThread)
+            return num * 2
+        })
+
+        XCTAssertEqual(doubled, 42)
+    }
+
+    /// Verify that Rust can store a Swift closure, call it whenever it wants, and release it when
+    /// the `Box<dyn FnMut>` is dropped.
+    func testRustStoresSwiftFnMutCallback() throws {
+        final class Received: @unchecked Sendable {
+            var values: [UInt32] = []
+        }
+        let received = Received()
+        weak var weakReceived: Received? = nil
+
+        let emitter = CallbackTestEventEmitter()
+        do {
+            let capturedReceived = Received()
+            weakReceived = capturedReceived
+
+            emitter.set_listener({ value in
+                received.values.append(value)
+                capturedReceived.values.append(value)
+            })
+        }
+
+        emitter.emit(1)
+        emitter.emit(2)
+        XCTAssertEqual(received.values, [1, 2])
+        XCTAssertNotNil(weakReceived)
+
+        emitter.remove_listener()
+        XCTAssertNil(weakReceived)
+
+        emitter.emit(3)
+        XCTAssertEqual(received.values, [1, 2])
+    }
 }
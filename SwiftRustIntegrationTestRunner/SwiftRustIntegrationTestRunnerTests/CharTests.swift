@@ -0,0 +1,47 @@
+//
+//  CharTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for bridging Rust's `char` to Swift's `Unicode.Scalar`.
+class CharTests: XCTestCase {
+    /// Verify that we can pass and return a `char`.
+    func testRustReflectChar() throws {
+        XCTAssertEqual(rust_reflect_char("a"), "a")
+        XCTAssertEqual(rust_reflect_char("🦀"), "🦀")
+    }
+
+    /// Verify that we can pass and return an `Option<char>`.
+    func testRustReflectOptionChar() throws {
+        XCTAssertEqual(rust_reflect_option_char("é"), "é")
+        XCTAssertNil(rust_reflect_option_char(nil))
+    }
+
+    /// Verify that we can pass and return a `Vec<char>`.
+    func testVecOfChars() throws {
+        let chars = rust_string_to_vec_of_chars("hi🦀")
+        XCTAssertEqual(chars.len(), 3)
+        XCTAssertEqual(chars.get(index: 2), "🦀")
+
+        chars.push(value: "!")
+        XCTAssertEqual(rust_vec_of_chars_to_string(chars).toString(), "hi🦀!")
+    }
+
+    /// Verify that Rust replaces an invalid Unicode scalar value with U+FFFD instead of
+    /// creating an invalid `char`.
+    ///
+    /// `Unicode.Scalar` can never hold an invalid value, so we call the FFI function directly.
+    func testInvalidScalarFromSwiftIsReplaced() throws {
+        // 0xD800 is a surrogate, which is not a valid Unicode scalar value.
+        XCTAssertEqual(__swift_bridge__$rust_reflect_char(0xD800), 0xFFFD)
+        XCTAssertEqual(__swift_bridge__$rust_reflect_char(0x110000), 0xFFFD)
+    }
+
+    /// Verify that Rust can call Swift functions that take and return a `char`.
+    func testRustCallsSwiftChar() throws {
+        test_rust_calls_swift_char()
+    }
+}
@@ -0,0 +1,59 @@
+//
+//  CodableTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for the `#[swift_bridge(Codable)]` attribute.
+/// See crates/swift-integration-tests/src/codable.rs
+class CodableTests: XCTestCase {
+    /// Verify that Rust can deserialize a struct that Swift encoded, and that Swift can decode a
+    /// struct that Rust serialized.
+    func testCodableStructRoundTrip() throws {
+        let value = CodableStruct(name: RustString("Ferris"), count: 3, nickname: nil)
+
+        let json = String(data: try JSONEncoder().encode(value), encoding: .utf8)!
+        let fromRust = codable_struct_from_json(json)
+        XCTAssertEqual(fromRust.name.toString(), "Ferris")
+        XCTAssertEqual(fromRust.count, 3)
+        XCTAssertNil(fromRust.nickname)
+
+        let rustJson = codable_struct_to_json(
+            CodableStruct(name: RustString("Ferris"), count: 3, nickname: RustString("Crab"))
+        ).toString()
+        XCTAssertTrue(rustJson.contains("\"displayName\":\"Ferris\""))
+
+        let decoded = try JSONDecoder().decode(CodableStruct.self, from: rustJson.data(using: .utf8)!)
+        XCTAssertEqual(decoded.name.toString(), "Ferris")
+        XCTAssertEqual(decoded.count, 3)
+        XCTAssertEqual(decoded.nickname!.toString(), "Crab")
+    }
+
+    /// Verify that each kind of enum variant round trips through Rust's serde representation.
+    func testCodableEnumRoundTrip() throws {
+        let values: [CodableEnum] = [
+            .Empty,
+            .Count(5),
+            .Pair(1, RustString("two")),
+            .Moved(x: -1, y: 2),
+        ]
+        let expectedRustJson = [
+            "\"none\"",
+            "{\"Count\":5}",
+            "{\"Pair\":[1,\"two\"]}",
+            "{\"Moved\":{\"dx\":-1,\"y\":2}}",
+        ]
+
+        for (value, expected) in zip(values, expectedRustJson) {
+            let json = String(data: try JSONEncoder().encode(value), encoding: .utf8)!
+            let rustJson = codable_enum_to_json(codable_enum_from_json(json)).toString()
+            XCTAssertEqual(rustJson, expected)
+
+            let decoded = try JSONDecoder().decode(CodableEnum.self, from: rustJson.data(using: .utf8)!)
+            let reencoded = String(data: try JSONEncoder().encode(decoded), encoding: .utf8)!
+            XCTAssertEqual(codable_enum_to_json(codable_enum_from_json(reencoded)).toString(), expected)
+        }
+    }
+}
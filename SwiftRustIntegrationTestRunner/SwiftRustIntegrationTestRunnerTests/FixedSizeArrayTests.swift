@@ -0,0 +1,54 @@
+//
+//  FixedSizeArrayTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for fixed-size arrays such as `[u8; 32]`, which Swift sees as an `Array`.
+class FixedSizeArrayTests: XCTestCase {
+    /// Verify that we can pass and return a `[u8; 32]`.
+    func testRustReflectArrayOfPrimitives() throws {
+        let hash: [UInt8] = Array(0..<32)
+        XCTAssertEqual(rust_reflect_array_u8(hash), hash)
+    }
+
+    /// Verify that we can pass and return a `[[f32; 4]; 4]`.
+    func testRustReflectArrayOfArrays() throws {
+        let matrix: [[Float]] = [
+            [1, 0, 0, 0],
+            [0, 1, 0, 0],
+            [0, 0, 1, 0],
+            [5, 6, 7, 1],
+        ]
+        XCTAssertEqual(rust_reflect_array_of_arrays(matrix), matrix)
+    }
+
+    /// Verify that we can pass and return an `Option<[u8; 4]>`.
+    func testRustReflectOptionOfArray() throws {
+        XCTAssertEqual(rust_reflect_option_array([1, 2, 3, 4]), [1, 2, 3, 4])
+        XCTAssertNil(rust_reflect_option_array(nil))
+    }
+
+    /// Verify that we can pass and return a `Vec<[u8; 4]>`.
+    func testRustReflectVecOfArrays() throws {
+        let colors: [[UInt8]] = [[255, 0, 0, 255], [0, 255, 0, 128]]
+        XCTAssertEqual(rust_reflect_vec_of_arrays(colors), colors)
+    }
+
+    /// Verify that we can pass and return a shared struct with fixed-size array fields.
+    func testRustReflectStructWithArrayFields() throws {
+        let reflected = rust_reflect_fixed_size_array_struct(
+            FixedSizeArrayStruct(rgba: [1, 2, 3, 4], matrix: [[1.5, 2.5], [3.5, 4.5]])
+        )
+
+        XCTAssertEqual(reflected.rgba, [1, 2, 3, 4])
+        XCTAssertEqual(reflected.matrix, [[1.5, 2.5], [3.5, 4.5]])
+    }
+
+    /// Verify that Rust can call Swift functions that take and return fixed-size arrays.
+    func testRustCallsSwiftFixedSizeArray() throws {
+        test_rust_calls_swift_fixed_size_array()
+    }
+}
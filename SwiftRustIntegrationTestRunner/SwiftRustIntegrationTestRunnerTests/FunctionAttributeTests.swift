@@ -24,4 +24,27 @@ class FunctionAttributeTests: XCTestCase {
     func testSwiftNameAttribute() throws {
         XCTAssertEqual(testCallRustFromSwiftByNameAttribute().toString(), "StringFromRust")
     }
+
+    /// Verify that the `swift_bridge(instantiate(T = x))` attribute gives Swift an overload for
+    /// each of the generic function's instantiations.
+    func testInstantiateAttribute() throws {
+        let int: UInt32 = rust_parse("123")
+        XCTAssertEqual(int, 123)
+
+        let string: RustString = rust_parse("hello")
+        XCTAssertEqual(string.toString(), "hello")
+
+        let bytes = RustVec<UInt8>()
+        bytes.push(value: 5)
+        let firstByte: UInt8 = rust_first_or_default(bytes)
+        XCTAssertEqual(firstByte, 5)
+
+        let firstInt: Int64 = rust_first_or_default(RustVec<Int64>())
+        XCTAssertEqual(firstInt, 0)
+
+        let counter = GenericFunctionCounter()
+        XCTAssertEqual(counter.increment_and_reflect(UInt16(10)), 10)
+        XCTAssertEqual(counter.increment_and_reflect(true), true)
+        XCTAssertEqual(counter.count(), 2)
+    }
 }
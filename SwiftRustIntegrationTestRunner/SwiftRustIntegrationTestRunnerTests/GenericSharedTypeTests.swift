@@ -0,0 +1,55 @@
+//
+//  GenericSharedTypeTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for generic shared structs and enums.
+class GenericSharedTypeTests: XCTestCase {
+    func testGenericSharedStructU32() {
+        let items = RustVec<UInt32>()
+        items.push(value: 1)
+        items.push(value: 2)
+
+        let page = reflect_generic_page_u32(GenericPage(items: items, next: create_string("next")))
+
+        XCTAssertEqual(page.items.len(), 2)
+        XCTAssertEqual(page.items.get(index: 1), 2)
+        XCTAssertEqual(page.next!.toString(), "next")
+    }
+
+    func testGenericSharedStructString() {
+        let items = RustVec<RustString>()
+        items.push(value: create_string("hello"))
+
+        let page = reflect_generic_page_string(GenericPage(items: items, next: nil))
+
+        XCTAssertEqual(page.items.get(index: 0)!.as_str().toString(), "hello")
+        XCTAssertNil(page.next)
+    }
+
+    func testGenericSharedEnum() {
+        switch reflect_generic_event_u8(GenericEvent.Data(123)) {
+        case .Data(let value):
+            XCTAssertEqual(value, 123)
+        default:
+            XCTFail()
+        }
+
+        switch reflect_generic_event_u8(GenericEvent.Named(value: 5)) {
+        case .Named(let value):
+            XCTAssertEqual(value, 5)
+        default:
+            XCTFail()
+        }
+
+        switch reflect_generic_event_u8(GenericEvent<UInt8>.Closed) {
+        case .Closed:
+            break
+        default:
+            XCTFail()
+        }
+    }
+}
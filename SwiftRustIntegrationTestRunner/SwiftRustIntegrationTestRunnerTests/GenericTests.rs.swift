@@ -32,5 +32,16 @@ class GenericTests: XCTestCase {
         let val = new_generic_with_inner_opaque_type()
         let _: GenericWithOpaqueRustInnerTy<InnerTy> = reflect_generic_with_inner_opaque_type(val)
     }
+
+    func testGenericWithNonPrimitiveTypes() {
+        let _: GenericWithNonPrimitiveTys<RustString, InnerTy> = new_generic_with_string_and_opaque_tys()
+
+        let val: GenericWithNonPrimitiveTys<RustVec<UInt8>, Optional<UInt32>> = new_generic_with_vec_and_option_tys()
+        XCTAssertEqual(generic_with_vec_and_option_tys_len(val), 3)
+    }
+
+    func testGenericWithMoreThanEightTypes() {
+        let _: GenericWithManyTys<UInt8, UInt16, UInt32, UInt64, Int8, Int16, Int32, Int64, Bool> = new_generic_with_many_tys()
+    }
 }
 
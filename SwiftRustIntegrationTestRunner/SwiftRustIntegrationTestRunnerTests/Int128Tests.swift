@@ -0,0 +1,76 @@
+//
+//  Int128Tests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for bridging Rust's `i128` and `u128` to Swift's `Int128` and `UInt128`.
+///
+/// `Int128` and `UInt128` are only available on macOS 15 and newer, so the tests are skipped on
+/// older OSes.
+class Int128Tests: XCTestCase {
+    /// Verify that we can pass and return `i128` and `u128`, including values that do not fit in
+    /// 64 bits.
+    func testRustReflectInt128() throws {
+        guard #available(macOS 15.0, iOS 18.0, *) else {
+            throw XCTSkip("Int128 requires macOS 15")
+        }
+
+        XCTAssertEqual(rust_reflect_i128(Int128.min), Int128.min)
+        XCTAssertEqual(rust_reflect_i128(-1), -1)
+        XCTAssertEqual(rust_reflect_i128(Int128(Int64.max) + 1), Int128(Int64.max) + 1)
+
+        XCTAssertEqual(rust_reflect_u128(UInt128.max), UInt128.max)
+        XCTAssertEqual(rust_reflect_u128(UInt128(1) << 64), UInt128(1) << 64)
+    }
+
+    /// Verify that values created in Rust arrive intact.
+    func testRustReturnsInt128Extremes() throws {
+        guard #available(macOS 15.0, iOS 18.0, *) else {
+            throw XCTSkip("Int128 requires macOS 15")
+        }
+
+        XCTAssertEqual(rust_u128_max(), UInt128.max)
+        XCTAssertEqual(rust_i128_min(), Int128.min)
+    }
+
+    /// Verify that we can pass and return an `Option<i128>` and an `Option<u128>`.
+    func testRustReflectOptionInt128() throws {
+        guard #available(macOS 15.0, iOS 18.0, *) else {
+            throw XCTSkip("Int128 requires macOS 15")
+        }
+
+        XCTAssertEqual(rust_reflect_option_i128(Int128.min), Int128.min)
+        XCTAssertNil(rust_reflect_option_i128(nil))
+
+        XCTAssertEqual(rust_reflect_option_u128(UInt128.max), UInt128.max)
+        XCTAssertNil(rust_reflect_option_u128(nil))
+    }
+
+    /// Verify that we can pass and return a `Vec<i128>`.
+    func testRustReflectVecOfInt128() throws {
+        guard #available(macOS 15.0, iOS 18.0, *) else {
+            throw XCTSkip("Int128 requires macOS 15")
+        }
+
+        let vec = RustVec<Int128>()
+        vec.push(value: Int128.min)
+        vec.push(value: 5)
+
+        let reflected = rust_reflect_vec_i128(vec)
+        XCTAssertEqual(reflected.len(), 2)
+        XCTAssertEqual(reflected.get(index: 0), Int128.min)
+        XCTAssertEqual(reflected.pop(), 5)
+    }
+
+    /// Verify that Rust can call Swift functions that take and return 128-bit integers.
+    func testRustCallsSwiftInt128() throws {
+        guard #available(macOS 15.0, iOS 18.0, *) else {
+            throw XCTSkip("Int128 requires macOS 15")
+        }
+
+        test_rust_calls_swift_int128()
+    }
+}
@@ -0,0 +1,83 @@
+//
+//  MapTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for `HashMap<K, V>` and `BTreeMap<K, V>`.
+class MapTests: XCTestCase {
+    /// Verify that we can pass and return a map of primitives.
+    func testRustReflectHashMapPrimitives() throws {
+        let map = RustMap<UInt16, Int64>([1: -1, 2: -2])
+
+        let reflected = rust_reflect_hashmap_primitives(map)
+        XCTAssertEqual(reflected.len(), 2)
+        XCTAssertEqual(reflected.toDictionary(), [1: -1, 2: -2])
+        XCTAssertEqual(reflected.len(), 0)
+    }
+
+    /// Verify that we can pass and return a map that has `String` keys.
+    func testRustReflectHashMapStringKeys() throws {
+        let reflected = rust_reflect_hashmap_string_keys(RustMap(["hello": 1, "world": 2]))
+        XCTAssertEqual(reflected.toDictionary(), ["hello": 1, "world": 2])
+    }
+
+    /// Verify that we can pass and return a map that has opaque Rust type values.
+    func testRustReflectBTreeMapOpaqueRustValues() throws {
+        let map = RustMap<UInt8, MapTestOpaqueRustType>()
+        map.insert(key: 5, value: MapTestOpaqueRustType(50))
+        map.insert(key: 6, value: MapTestOpaqueRustType(60))
+
+        let reflected = rust_reflect_btreemap_opaque_rust_values(map)
+
+        var entries: [(UInt8, UInt32)] = []
+        for (key, value) in reflected {
+            entries.append((key, value.val()))
+        }
+        XCTAssertEqual(entries.map { $0.0 }, [5, 6])
+        XCTAssertEqual(entries.map { $0.1 }, [50, 60])
+    }
+
+    /// Verify that a map with `Hashable` opaque Rust type keys can be moved into a Swift
+    /// `Dictionary`.
+    func testRustReflectHashMapOpaqueRustKeys() throws {
+        let key = MapTestHashableRustKey(5)
+
+        let map = RustMap<MapTestHashableRustKey, UInt8>()
+        map.insert(key: MapTestHashableRustKey(5), value: 50)
+
+        let dictionary = rust_reflect_hashmap_opaque_rust_keys(map).toDictionary()
+        XCTAssertEqual(dictionary.count, 1)
+        XCTAssertEqual(dictionary[key], 50)
+    }
+
+    /// Verify that a map's `String` values become Swift `String`s.
+    func testRustReflectHashMapStringValues() throws {
+        let reflected = rust_reflect_hashmap_string_values(RustMap([1: "one", 2: "two"]))
+        XCTAssertEqual(reflected.toDictionary(), [1: "one", 2: "two"])
+    }
+
+    /// Verify that we can pass and return an `Option<HashMap<K, V>>`.
+    func testRustReflectOptionHashMap() throws {
+        let reflected = rust_reflect_option_hashmap(RustMap<UInt16, Int64>([1: -1]))
+        XCTAssertEqual(reflected!.toDictionary(), [1: -1])
+
+        XCTAssertNil(rust_reflect_option_hashmap(nil))
+    }
+
+    /// Verify that inserting the same key twice keeps the most recent value.
+    func testRustMapInsertSameKeyTwice() throws {
+        let map = RustMap<UInt16, Int64>()
+        map.insert(key: 1, value: 10)
+        map.insert(key: 1, value: 20)
+
+        XCTAssertEqual(rust_reflect_hashmap_primitives(map).toDictionary(), [1: 20])
+    }
+
+    /// Verify that Rust can call Swift functions that take and return maps.
+    func testRustCallsSwiftMaps() throws {
+        test_rust_calls_swift_maps()
+    }
+}
@@ -0,0 +1,149 @@
+//
+//  NestedContainersTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for containers that hold other containers, such as `Option<Option<T>>`,
+/// `Vec<Option<T>>` and `Option<(A, B)>`.
+class NestedContainersTests: XCTestCase {
+    /// Verify that we can pass and return an `Option<Option<u8>>` without losing the
+    /// difference between `None` and `Some(None)`.
+    func testRustReflectOptionOfOption() throws {
+        XCTAssertEqual(rust_reflect_option_option_u8(.some(.some(5))), .some(.some(5)))
+        XCTAssertEqual(rust_reflect_option_option_u8(.some(.none)), .some(.none))
+        XCTAssertEqual(rust_reflect_option_option_u8(.none), .none)
+    }
+
+    /// Verify that we can pass and return a `Vec<Option<String>>`, which Swift sees as an
+    /// `Array`.
+    func testRustReflectVecOfOptionString() throws {
+        let reflected = rust_reflect_vec_option_string([RustString("hello"), nil, RustString("world")])
+
+        XCTAssertEqual(reflected.count, 3)
+        XCTAssertEqual(reflected[0]?.toString(), "hello")
+        XCTAssertNil(reflected[1])
+        XCTAssertEqual(reflected[2]?.toString(), "world")
+
+        XCTAssertEqual(rust_reflect_vec_option_string([]).count, 0)
+    }
+
+    /// Verify that we can pass and return a `Vec<Vec<u8>>`.
+    func testRustReflectVecOfVec() throws {
+        let inner = RustVec<UInt8>()
+        inner.push(value: 1)
+        inner.push(value: 2)
+
+        let reflected = rust_reflect_vec_vec_u8([inner, RustVec<UInt8>()])
+
+        XCTAssertEqual(reflected.count, 2)
+        XCTAssertEqual(reflected[0].len(), 2)
+        XCTAssertEqual(reflected[0].get(index: 1), 2)
+        XCTAssertEqual(reflected[1].len(), 0)
+    }
+
+    /// Verify that we can pass and return an `Option<(u8, String)>`.
+    func testRustReflectOptionOfTuple() throws {
+        let reflected = rust_reflect_option_tuple((5, RustString("hello")))
+        XCTAssertEqual(reflected?.0, 5)
+        XCTAssertEqual(reflected?.1.toString(), "hello")
+
+        XCTAssertNil(rust_reflect_option_tuple(nil))
+    }
+
+    /// Verify that we can pass and return an `Option<*const u8>`.
+    func testRustReflectOptionOfPointer() throws {
+        var value: UInt8 = 5
+        withUnsafePointer(to: &value) { ptr in
+            XCTAssertEqual(rust_reflect_option_const_ptr_u8(ptr), ptr)
+        }
+
+        XCTAssertNil(rust_reflect_option_const_ptr_u8(nil))
+    }
+
+    /// Verify that we can pass and return an `Option<&[u8]>`.
+    func testRustReflectOptionOfSlice() throws {
+        let bytes: [UInt8] = [1, 2, 3]
+        bytes.withUnsafeBufferPointer { buffer in
+            let reflected = rust_reflect_option_slice_u8(buffer)
+            XCTAssertEqual(reflected.map { Array($0) }, [1, 2, 3])
+        }
+
+        XCTAssertNil(rust_reflect_option_slice_u8(nil))
+    }
+
+    /// Verify that we can pass and return an `Option<Result<u8, String>>`.
+    func testRustReflectOptionOfResult() throws {
+        switch rust_reflect_option_result(.Ok(5)) {
+        case .some(.Ok(let ok)):
+            XCTAssertEqual(ok, 5)
+        default:
+            XCTFail()
+        }
+
+        switch rust_reflect_option_result(.Err(RustString("error"))) {
+        case .some(.Err(let err)):
+            XCTAssertEqual(err.toString(), "error")
+        default:
+            XCTFail()
+        }
+
+        XCTAssertNil(rust_reflect_option_result(nil))
+    }
+
+    /// Verify that we can return a `Result<Option<u32>, String>`.
+    func testRustReturnsResultOfOption() throws {
+        XCTAssertEqual(try rust_return_result_option_u32(true), 123)
+
+        do {
+            let _ = try rust_return_result_option_u32(false)
+            XCTFail()
+        } catch let error as RustString {
+            XCTAssertEqual(error.toString(), "failed")
+        }
+    }
+
+    /// Verify that we can return a `Result<Vec<Vec<u8>>, String>`.
+    func testRustReturnsResultOfVecOfVec() throws {
+        let reflected = try rust_return_result_vec_vec_u8(true)
+        XCTAssertEqual(reflected.count, 2)
+        XCTAssertEqual(reflected[0].get(index: 1), 2)
+        XCTAssertEqual(reflected[1].len(), 0)
+
+        do {
+            let _ = try rust_return_result_vec_vec_u8(false)
+            XCTFail()
+        } catch let error as RustString {
+            XCTAssertEqual(error.toString(), "failed")
+        }
+    }
+
+    /// Verify that we can return a `Result<Vec<Option<u8>>, String>`.
+    func testRustReturnsResultOfVecOfOption() throws {
+        XCTAssertEqual(try rust_return_result_vec_option_u8(true), [1, nil])
+
+        do {
+            let _ = try rust_return_result_vec_option_u8(false)
+            XCTFail()
+        } catch let error as RustString {
+            XCTAssertEqual(error.toString(), "failed")
+        }
+    }
+
+    /// Verify that we can pass and return a shared struct that has nested container fields.
+    func testRustReflectStructWithNestedContainerFields() throws {
+        let reflected = rust_reflect_nested_containers_struct(
+            NestedContainersStruct(field: [1, nil, 3], optional_option: .some(.none))
+        )
+
+        XCTAssertEqual(reflected.field, [1, nil, 3])
+        XCTAssertEqual(reflected.optional_option, .some(.none))
+    }
+
+    /// Verify that Rust can call Swift functions that take and return nested containers.
+    func testRustCallsSwiftNestedContainers() throws {
+        test_rust_calls_swift_nested_containers()
+    }
+}
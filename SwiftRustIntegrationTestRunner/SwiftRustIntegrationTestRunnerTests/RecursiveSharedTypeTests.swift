@@ -0,0 +1,65 @@
+//
+//  RecursiveSharedTypeTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for shared enums and structs that contain themselves through a `Box` or a `Vec`.
+class RecursiveSharedTypeTests: XCTestCase {
+    /// Verify that we can pass a recursive shared enum from Swift to Rust.
+    func testRecursiveSharedEnum() {
+        let expr = RecursiveSharedEnum.Add(
+            .Num(1.5),
+            .Neg(inner: .Num(4.0))
+        )
+
+        XCTAssertEqual(rust_eval_recursive_shared_enum(expr), -2.5)
+    }
+
+    /// Verify that we can pass a `Box<RecursiveSharedEnum>` to and from Rust.
+    func testBoxedRecursiveSharedEnum() {
+        let negated = rust_negate_boxed_recursive_shared_enum(.Num(2.0))
+
+        switch negated {
+        case .Neg(let inner):
+            switch inner {
+            case .Num(let num):
+                XCTAssertEqual(num, 2.0)
+            default:
+                XCTFail()
+            }
+        default:
+            XCTFail()
+        }
+
+        XCTAssertEqual(rust_eval_recursive_shared_enum(negated), -2.0)
+    }
+
+    /// Verify that we can pass a shared struct that contains an `Option<Box<Self>>` field
+    /// to and from Rust.
+    func testRecursiveSharedStruct() {
+        let list = rust_make_recursive_shared_struct(3)
+        XCTAssertEqual(list.value, 1)
+        XCTAssertEqual(list.next?.value, 2)
+        XCTAssertEqual(list.next?.next?.value, 3)
+        XCTAssertNil(list.next?.next?.next)
+
+        let prepended = RecursiveSharedStruct(value: 10, next: list)
+        XCTAssertEqual(rust_sum_recursive_shared_struct(prepended), 16)
+    }
+
+    /// Verify that we can pass a shared struct that contains a `Vec<Self>` field to and from Rust.
+    func testRecursiveSharedStructThroughVec() {
+        let tree = rust_make_shared_tree()
+        XCTAssertEqual(tree.value, 1)
+        XCTAssertEqual(tree.children.len(), 2)
+        XCTAssertEqual(tree.children.get(index: 1)?.value, 3)
+
+        let children = RustVec<SharedTreeNode>()
+        children.push(value: tree)
+        let root = SharedTreeNode(value: 10, children: children)
+        XCTAssertEqual(rust_sum_shared_tree(root), 16)
+    }
+}
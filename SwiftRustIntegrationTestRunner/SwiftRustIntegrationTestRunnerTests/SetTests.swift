@@ -0,0 +1,90 @@
+//
+//  SetTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for `HashSet<T>` and `BTreeSet<T>`.
+class SetTests: XCTestCase {
+    /// Verify that we can pass and return a set of primitives.
+    func testRustReflectHashSetPrimitives() throws {
+        let set = RustSet<UInt16>([1, 2, 3])
+
+        let reflected = rust_reflect_hashset_primitives(set)
+        XCTAssertEqual(reflected.len(), 3)
+        XCTAssertEqual(reflected.toSet(), [1, 2, 3])
+    }
+
+    /// Verify that we can pass and return a set of `String`s.
+    func testRustReflectHashSetStrings() throws {
+        let reflected = rust_reflect_hashset_strings(RustSet(["hello", "world"]))
+
+        XCTAssertTrue(reflected.contains("hello"))
+        XCTAssertFalse(reflected.contains("goodbye"))
+        XCTAssertEqual(reflected.toSet(), ["hello", "world"])
+    }
+
+    /// Verify that we can pass and return a `BTreeSet<T>`.
+    func testRustReflectBTreeSetBools() throws {
+        let reflected = rust_reflect_btreeset_bools(RustSet([true]))
+        XCTAssertEqual(reflected.toSet(), [true])
+    }
+
+    /// Verify that insert, remove and contains are handled by the Rust `HashSet<T>`.
+    func testRustSetInsertRemoveContains() throws {
+        let set = rust_return_hashset_of_evens(6)
+        XCTAssertEqual(set.len(), 3)
+
+        XCTAssertTrue(set.contains(4))
+        XCTAssertFalse(set.contains(5))
+
+        XCTAssertFalse(set.insert(4))
+        XCTAssertTrue(set.insert(5))
+        XCTAssertTrue(set.contains(5))
+
+        XCTAssertTrue(set.remove(0))
+        XCTAssertFalse(set.remove(0))
+        XCTAssertFalse(set.contains(0))
+
+        XCTAssertEqual(set.toSet(), [2, 4, 5])
+    }
+
+    /// Verify that we can iterate over a `RustSet<T>`.
+    func testRustSetSequence() throws {
+        let set = rust_return_hashset_of_evens(10)
+
+        var sum: Int32 = 0
+        for num in set {
+            sum += num
+        }
+        XCTAssertEqual(sum, 20)
+
+        let strings = RustSet<RustString>(["a", "b"])
+        XCTAssertEqual(Set(strings.map { $0.as_str().toString() }), ["a", "b"])
+    }
+
+    /// Verify that a `RustSet<T>` can be mutated while it is being iterated over.
+    func testRustSetMutatedWhileIterating() throws {
+        let strings = RustSet<RustString>(["a", "b", "c"])
+
+        var seen = Set<String>()
+        for value in strings {
+            let string = value.as_str().toString()
+            strings.remove(string)
+            for idx in 0..<100 {
+                strings.insert(RustString("\(string)\(idx)"))
+            }
+            seen.insert(value.as_str().toString())
+        }
+
+        XCTAssertEqual(seen, ["a", "b", "c"])
+        XCTAssertEqual(strings.len(), 300)
+    }
+
+    /// Verify that Rust can call Swift functions that take and return sets.
+    func testRustCallsSwiftSets() throws {
+        test_rust_calls_swift_sets()
+    }
+}
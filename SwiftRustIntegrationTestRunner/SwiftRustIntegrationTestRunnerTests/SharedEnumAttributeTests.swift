@@ -19,6 +19,24 @@ class SharedEnumAttributeTests: XCTestCase {
             EnumRename.Variant1
         )
     }
+
+    /// Verify that we change the Swift names of a transparent enum's variants and their fields.
+    /// See crates/swift-integration-tests/src/enum_attributes/swift_name.rs
+    func testSharedEnumVariantSwiftName() throws {
+        switch double_enum_with_renamed_variants(.circle(radiusInMeters: 1.5)) {
+        case .circle(let radiusInMeters):
+            XCTAssertEqual(radiusInMeters, 3.0)
+        case .empty:
+            XCTFail()
+        }
+
+        switch double_enum_with_renamed_variants(.empty) {
+        case .empty:
+            break
+        case .circle:
+            XCTFail()
+        }
+    }
     
     
     /// Verify that we can call a rust function from swift that uses a type that was already declared in a different bridge module.
@@ -42,5 +60,100 @@ class SharedEnumAttributeTests: XCTestCase {
         XCTAssertEqual(debugString, "Variant")
     }
 
-}
+    /// Verify that `#[derive(PartialEq, Hash, PartialOrd)]` on a fieldless enum gives us an enum
+    /// that is `Equatable`, `Hashable` and `Comparable`.
+    func testSharedEnumDeriveComparable() throws {
+        let reflected = reflect_derive_comparable_enum(DeriveComparableEnum.High)
+
+        XCTAssertEqual(reflected, DeriveComparableEnum.High)
+        XCTAssertLessThan(DeriveComparableEnum.Low, reflected)
+        XCTAssertEqual(Set([reflected, DeriveComparableEnum.High]).count, 1)
+    }
+
+    /// Verify that an enum with explicit discriminants is ordered by its discriminants, like it is
+    /// in Rust, instead of by the order that its cases are declared in.
+    func testSharedEnumDeriveComparableWithDiscriminants() throws {
+        let reflected = reflect_derive_comparable_enum_with_discriminants(
+            DeriveComparableEnumWithDiscriminants.Low
+        )
+
+        XCTAssertEqual(reflected, DeriveComparableEnumWithDiscriminants.Low)
+        XCTAssertLessThan(reflected, DeriveComparableEnumWithDiscriminants.High)
+        XCTAssertEqual(
+            [DeriveComparableEnumWithDiscriminants.High, DeriveComparableEnumWithDiscriminants.Low].sorted(),
+            [DeriveComparableEnumWithDiscriminants.Low, DeriveComparableEnumWithDiscriminants.High]
+        )
+    }
+
+    /// Verify that `#[derive(PartialEq, Hash)]` on an enum that has variants with data gives us an
+    /// enum that is `Equatable` and `Hashable`.
+    func testSharedEnumWithDataDeriveHashable() throws {
+        let named = DeriveHashableEnumWithData.Named(name: RustString("hello"))
+        let reflected = reflect_derive_hashable_enum_with_data(named)
+
+        XCTAssertEqual(reflected, DeriveHashableEnumWithData.Named(name: RustString("hello")))
+        XCTAssertNotEqual(reflected, DeriveHashableEnumWithData.Number(5))
+
+        let set: Set<DeriveHashableEnumWithData> = [
+            reflected,
+            DeriveHashableEnumWithData.Named(name: RustString("hello")),
+            DeriveHashableEnumWithData.Empty,
+        ]
+        XCTAssertEqual(set.count, 2)
+    }
 
+    /// Verify that `RawRepresentable = "Int"` uses the Rust enum's discriminants as raw values and
+    /// that `CaseIterable` lists all of the cases.
+    func testSharedEnumIntRawRepresentable() throws {
+        XCTAssertEqual(HttpStatusCode.Ok.rawValue, 200)
+        XCTAssertEqual(HttpStatusCode.Created.rawValue, 201)
+        XCTAssertEqual(HttpStatusCode(rawValue: 404), HttpStatusCode.NotFound)
+        XCTAssertNil(HttpStatusCode(rawValue: 500))
+
+        XCTAssertEqual(HttpStatusCode.allCases, [.Ok, .Created, .NotFound])
+        XCTAssertEqual(reflect_http_status_code(HttpStatusCode.Created).rawValue, 201)
+    }
+
+    /// Verify that `RawRepresentable = "String"` uses the variant names as raw values, unless
+    /// they were renamed using `#[serde(rename = "...")]`.
+    func testSharedEnumStringRawRepresentable() throws {
+        XCTAssertEqual(StringRawValueEnum.FirstVariant.rawValue, "first_variant")
+        XCTAssertEqual(StringRawValueEnum.SecondVariant.rawValue, "SecondVariant")
+        XCTAssertEqual(StringRawValueEnum(rawValue: "first_variant"), StringRawValueEnum.FirstVariant)
+
+        XCTAssertEqual(StringRawValueEnum.allCases.count, 2)
+        XCTAssertEqual(
+            reflect_string_raw_value_enum(StringRawValueEnum.SecondVariant),
+            StringRawValueEnum.SecondVariant
+        )
+    }
+
+    /// Verify that the Rust enum's `#[repr(...)]` and explicit discriminants are preserved, so
+    /// Swift can read a variant's numeric value and build a variant from it.
+    func testSharedEnumDiscriminants() throws {
+        XCTAssertEqual(WireMessageKind.Ping.discriminant, 1)
+        XCTAssertEqual(WireMessageKind.Pong.discriminant, 2)
+        XCTAssertEqual(WireMessageKind(discriminant: 404), WireMessageKind.Error)
+        XCTAssertNil(WireMessageKind(discriminant: 3))
+
+        let reflected = reflect_wire_message_kind(WireMessageKind.Error)
+        XCTAssertEqual(reflected.discriminant, 404)
+        XCTAssertEqual(wire_message_kind_discriminant(WireMessageKind.Pong), 2)
+    }
+
+    /// Verify that an enum with data keeps its discriminants when passed between Swift and Rust.
+    func testSharedEnumWithDataDiscriminants() throws {
+        let reflected = reflect_wire_message(WireMessage.Data(5))
+        XCTAssertEqual(reflected.discriminant, 10)
+
+        switch reflected {
+        case .Data(let value):
+            XCTAssertEqual(value, 5)
+        case .Close:
+            XCTFail()
+        }
+
+        XCTAssertEqual(reflect_wire_message(WireMessage.Close).discriminant, 11)
+    }
+
+}
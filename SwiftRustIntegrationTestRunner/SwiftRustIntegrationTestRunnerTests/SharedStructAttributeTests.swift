@@ -34,5 +34,42 @@ class SharedStructAttributeTests: XCTestCase {
     func testSharedStructAlreadyDeclared() throws {
         test_rust_calls_swift_already_declared_struct()
     }
+
+    /// Verify that `#[derive(Debug)]` on a `Copy` struct gives us a struct whose
+    /// `debugDescription` comes from its Rust `Debug` impl.
+    /// See crates/swift-integration-tests/src/struct_attributes/derive.rs
+    func testSharedStructDeriveDebug() throws {
+        let debugString = String(reflecting: StructDeriveDebug(field: 5))
+        XCTAssertEqual(debugString, "StructDeriveDebug { field: 5 }")
+    }
+
+    /// Verify that `#[derive(PartialEq, Hash)]` on a struct gives us a struct that is
+    /// `Equatable` and `Hashable`.
+    func testSharedStructDeriveHashable() throws {
+        let reflected = reflect_struct_derive_hashable(
+            StructDeriveHashable(name: RustString("hello"), count: 1)
+        )
+
+        XCTAssertEqual(reflected, StructDeriveHashable(name: RustString("hello"), count: 1))
+        XCTAssertNotEqual(reflected, StructDeriveHashable(name: RustString("hello"), count: 2))
+
+        let set: Set<StructDeriveHashable> = [
+            reflected,
+            StructDeriveHashable(name: RustString("hello"), count: 1),
+            StructDeriveHashable(name: RustString("world"), count: 1),
+        ]
+        XCTAssertEqual(set.count, 2)
+    }
+
+    /// Verify that we change the Swift names of a shared struct's fields.
+    /// See crates/swift-integration-tests/src/struct_attributes/swift_name.rs
+    func testSharedStructFieldSwiftName() throws {
+        let value = increment_struct_with_renamed_fields(
+            StructWithRenamedFields(defaultValue: 1, isEnabled: false)
+        )
+
+        XCTAssertEqual(value.defaultValue, 2)
+        XCTAssertTrue(value.isEnabled)
+    }
 }
 
@@ -0,0 +1,48 @@
+//
+//  SharedTypeMethodTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for methods on shared structs and enums.
+class SharedTypeMethodTests: XCTestCase {
+    func testSharedStructInitializerAndAssociatedFunction() {
+        let point = SharedStructWithMethods(1, 2)
+        XCTAssertEqual(point.x, 1)
+        XCTAssertEqual(point.y, 2)
+
+        XCTAssertTrue(SharedStructWithMethods.origin().is_origin())
+    }
+
+    func testSharedStructMethods() {
+        var point = SharedStructWithMethods(1, 2)
+        XCTAssertFalse(point.is_origin())
+
+        point.translate(-1, -2)
+        XCTAssertEqual(point.x, 0)
+        XCTAssertEqual(point.y, 0)
+        XCTAssertTrue(point.is_origin())
+
+        point.translate(3, 4)
+        XCTAssertEqual(point.sum(), 7)
+    }
+
+    func testSharedEnumMethods() {
+        XCTAssertNil(SharedEnumWithMethods(0))
+
+        var counter = SharedEnumWithMethods(5)!
+        counter.increment()
+        XCTAssertEqual(counter.count(), 6)
+
+        XCTAssertEqual(counter.stop(), 6)
+        XCTAssertEqual(counter.count(), 0)
+        switch counter {
+        case .Stopped:
+            break
+        default:
+            XCTFail()
+        }
+    }
+}
@@ -0,0 +1,40 @@
+//
+//  SwiftNamingTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for the `#[swift_bridge::bridge(swift_naming = "camelCase")]` module attribute.
+class SwiftNamingTests: XCTestCase {
+    /// Verify that we can call methods on an opaque Rust type using their camelCase names.
+    func testCamelCaseMethods() throws {
+        let counter = CamelCaseCounter(5)
+        counter.addAmount(10)
+
+        XCTAssertEqual(counter.currentValue(), 15)
+    }
+
+    /// Verify that shared struct fields use their camelCase names, and that an explicit
+    /// `swift_name` takes precedence.
+    func testCamelCaseStructFields() throws {
+        let options = doubleMaxRetries(
+            CamelCaseRequestOptions(maxRetries: 3, timeout: 30)
+        )
+
+        XCTAssertEqual(options.maxRetries, 6)
+        XCTAssertEqual(options.timeout, 30)
+    }
+
+    /// Verify that shared enum variants and their fields use their camelCase names.
+    func testCamelCaseEnumVariants() throws {
+        XCTAssertEqual(retryAfterOrZero(.notFound), 0)
+        XCTAssertEqual(retryAfterOrZero(.tooManyRequests(retryAfter: 60)), 60)
+    }
+
+    /// Verify that Rust calls a Swift function using its camelCase name and argument labels.
+    func testRustCallsSwiftFunctionByCamelCaseName() throws {
+        testRustCallsCamelCaseSwiftFunction()
+    }
+}
@@ -99,6 +99,52 @@ class VecTests: XCTestCase {
         XCTAssertEqual(reflected.pop()!, TransparentEnumInsideVecT.VariantB)
     }
     
+    /// Verify that a Vec<T> of transparent structs can be used as an argument and return
+    /// type for extern "Rust" functions.
+    func testReflectVecOfTransparentStruct() throws {
+        let vec: RustVec<TransparentStructInsideVecT> = RustVec()
+        vec.push(value: TransparentStructInsideVecT(
+            integer: 123,
+            string: "hello".intoRustString(),
+            maybe_string: "world".intoRustString(),
+            maybe_integer: nil
+        ))
+        
+        let reflected = rust_reflect_vec_transparent_struct(vec)
+        XCTAssertEqual(reflected.len(), 1)
+        
+        let element = reflected.get(index: 0)!
+        XCTAssertEqual(element.integer, 123)
+        XCTAssertEqual(element.string.toString(), "hello")
+        XCTAssertEqual(element.maybe_string!.toString(), "world")
+        XCTAssertNil(element.maybe_integer)
+        
+        let popped = reflected.pop()!
+        XCTAssertEqual(popped.string.toString(), "hello")
+        XCTAssertEqual(reflected.len(), 0)
+    }
+    
+    /// Verify that we can iterate over a Vec<T> of transparent structs that was returned from Rust.
+    func testIterateVecOfTransparentStruct() throws {
+        let vec = rust_return_vec_transparent_struct()
+        
+        var integers: [UInt32] = []
+        var strings: [String] = []
+        var maybeStrings: [String?] = []
+        var maybeIntegers: [Int64?] = []
+        for element in vec {
+            integers.append(element.integer)
+            strings.append(element.string.toString())
+            maybeStrings.append(element.maybe_string?.toString())
+            maybeIntegers.append(element.maybe_integer)
+        }
+        
+        XCTAssertEqual(integers, [1, 2])
+        XCTAssertEqual(strings, ["first", "second"])
+        XCTAssertEqual(maybeStrings, ["some", nil])
+        XCTAssertEqual(maybeIntegers, [nil, -5])
+    }
+    
     /// Verify that we can construct a RustVec of every primitive type.
     /// We tested all of the methods on  two different primitives above to be sure that our
     /// functions that generate the pieces of the RustVec support aren't accidentally hard coded to
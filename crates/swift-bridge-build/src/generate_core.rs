@@ -1,6 +1,10 @@
 use crate::generate_core::boxed_fn_support::{
     C_CALLBACK_SUPPORT_NO_ARGS_NO_RETURN, SWIFT_CALLBACK_SUPPORT_NO_ARGS_NO_RETURN,
+    SWIFT_CALLBACK_SUPPORT_SWIFT_CLOSURE,
 };
+use crate::generate_core::ffi_array_support::{C_FFI_ARRAY_SUPPORT, SWIFT_FFI_ARRAY_SUPPORT};
+use crate::generate_core::fixed_size_array_support::SWIFT_FIXED_SIZE_ARRAY_SUPPORT;
+use crate::generate_core::int128_support::{c_int128_support, swift_int128_support};
 use crate::generate_core::option_support::{
     swift_option_primitive_support, C_OPTION_PRIMITIVE_SUPPORT,
 };
@@ -12,8 +16,13 @@ const RUST_STRING_C: &'static str = include_str!("./generate_core/rust_string.c.
 
 const STRING_SWIFT: &'static str = include_str!("./generate_core/string.swift");
 const RUST_VEC_SWIFT: &'static str = include_str!("./generate_core/rust_vec.swift");
+const RUST_MAP_SWIFT: &'static str = include_str!("./generate_core/rust_map.swift");
+const RUST_SET_SWIFT: &'static str = include_str!("./generate_core/rust_set.swift");
 
 mod boxed_fn_support;
+mod ffi_array_support;
+mod fixed_size_array_support;
+mod int128_support;
 mod option_support;
 mod result_support;
 
@@ -24,10 +33,17 @@ pub(super) fn write_core_swift_and_c(out_dir: &Path) {
     swift += &RUST_STRING_SWIFT;
     swift += "\n";
     swift += &SWIFT_CALLBACK_SUPPORT_NO_ARGS_NO_RETURN;
+    swift += SWIFT_CALLBACK_SUPPORT_SWIFT_CLOSURE;
     swift += "\n";
     swift += &SWIFT_RUST_RESULT;
     swift += "\n";
     swift += &swift_option_primitive_support();
+    swift += "\n";
+    swift += &swift_int128_support();
+    swift += "\n";
+    swift += SWIFT_FFI_ARRAY_SUPPORT;
+    swift += "\n";
+    swift += SWIFT_FIXED_SIZE_ARRAY_SUPPORT;
 
     std::fs::write(core_swift_out, swift).unwrap();
 
@@ -39,6 +55,8 @@ pub(super) fn write_core_swift_and_c(out_dir: &Path) {
     c_header += &C_CALLBACK_SUPPORT_NO_ARGS_NO_RETURN;
     c_header += "\n";
     c_header += &C_RESULT_SUPPORT;
+    c_header += "\n";
+    c_header += C_FFI_ARRAY_SUPPORT;
 
     std::fs::write(core_c_header_out, c_header).unwrap();
 }
@@ -48,6 +66,8 @@ fn core_swift() -> String {
 
     core_swift += STRING_SWIFT;
     core_swift += RUST_VEC_SWIFT;
+    core_swift += RUST_MAP_SWIFT;
+    core_swift += RUST_SET_SWIFT;
 
     for (swift_ty, rust_ty) in vec![
         ("UInt8", "u8"),
@@ -69,10 +89,30 @@ fn core_swift() -> String {
     ] {
         core_swift += &conform_to_vectorizable(swift_ty, rust_ty);
     }
+    core_swift += unicode_scalar_conform_to_vectorizable();
+
+    for (swift_ty, rust_ty) in vec![
+        ("UInt8", "u8"),
+        ("UInt16", "u16"),
+        ("UInt32", "u32"),
+        ("UInt64", "u64"),
+        ("UInt", "usize"),
+        //
+        ("Int8", "i8"),
+        ("Int16", "i16"),
+        ("Int32", "i32"),
+        ("Int64", "i64"),
+        ("Int", "isize"),
+        //
+        ("Bool", "bool"),
+    ] {
+        core_swift += &conform_to_rust_set_element(swift_ty, rust_ty);
+    }
 
     core_swift += &generic_freer();
     core_swift += &generic_copy_type_ffi_repr();
     core_swift += &unchecked_sendable_wrapper();
+    core_swift += indirect_property_wrapper();
 
     core_swift
 }
@@ -82,6 +122,7 @@ fn core_c_header() -> String {
 #include <stdbool.h> 
 typedef struct RustStr { uint8_t* const start; uintptr_t len; } RustStr;
 typedef struct __private__FfiSlice { void* const start; uintptr_t len; } __private__FfiSlice;
+typedef struct __private__FfiMap { void* keys; void* values; } __private__FfiMap;
 void* __swift_bridge__null_pointer(void);
 
 "#
@@ -108,6 +149,26 @@ void* __swift_bridge__null_pointer(void);
     ] {
         header += &vec_of_primitive_headers(rust_ty, c_ty);
     }
+    header += VEC_OF_CHAR_HEADERS;
+    header += &c_int128_support();
+
+    for (rust_ty, c_ty) in vec![
+        ("u8", "uint8_t"),
+        ("u16", "uint16_t"),
+        ("u32", "uint32_t"),
+        ("u64", "uint64_t"),
+        ("usize", "uintptr_t"),
+        //
+        ("i8", "int8_t"),
+        ("i16", "int16_t"),
+        ("i32", "int32_t"),
+        ("i64", "int64_t"),
+        ("isize", "intptr_t"),
+        //
+        ("bool", "bool"),
+    ] {
+        header += &set_of_primitive_headers(rust_ty, c_ty);
+    }
 
     header
 }
@@ -140,6 +201,47 @@ void __swift_bridge__$Vec_{rust_ty}$push(void* const vec, {c_ty} val);
     )
 }
 
+/// Headers for Vec<char>. A `char` crosses the FFI boundary as a `uint32_t`.
+const VEC_OF_CHAR_HEADERS: &'static str = r#"
+void* __swift_bridge__$Vec_char$new();
+void __swift_bridge__$Vec_char$_free(void* const vec);
+uintptr_t __swift_bridge__$Vec_char$len(void* const vec);
+void __swift_bridge__$Vec_char$push(void* const vec, uint32_t val);
+__private__OptionChar __swift_bridge__$Vec_char$pop(void* const vec);
+__private__OptionChar __swift_bridge__$Vec_char$get(void* const vec, uintptr_t index);
+__private__OptionChar __swift_bridge__$Vec_char$get_mut(void* const vec, uintptr_t index);
+uint32_t const * __swift_bridge__$Vec_char$as_ptr(void* const vec);
+"#;
+
+/// Headers for HashSet<T> where T is a primitive such as u8, i32, bool
+fn set_of_primitive_headers(rust_ty: &str, c_ty: &str) -> String {
+    let mut chars = rust_ty.chars();
+
+    // u8 -> U8, bool -> Bool, etc...
+    let capatilized_first_letter =
+        chars.next().unwrap().to_string().to_uppercase() + chars.as_str();
+
+    // __private__OptionU8 ... etc
+    let option_ty = format!("{}{}", "__private__Option", capatilized_first_letter);
+
+    format!(
+        r#"
+void* __swift_bridge__$HashSet_{rust_ty}$new();
+void __swift_bridge__$HashSet_{rust_ty}$_free(void* const set);
+uintptr_t __swift_bridge__$HashSet_{rust_ty}$len(void* const set);
+bool __swift_bridge__$HashSet_{rust_ty}$insert(void* const set, {c_ty} val);
+bool __swift_bridge__$HashSet_{rust_ty}$remove(void* const set, {c_ty} val);
+bool __swift_bridge__$HashSet_{rust_ty}$contains(void* const set, {c_ty} val);
+void* __swift_bridge__$HashSet_{rust_ty}$iter(void* const set);
+{option_ty} __swift_bridge__$HashSet_{rust_ty}$iter_next(void* const iter);
+void __swift_bridge__$HashSet_{rust_ty}$iter_free(void* const iter);
+"#,
+        rust_ty = rust_ty,
+        c_ty = c_ty,
+        option_ty = option_ty
+    )
+}
+
 fn conform_to_vectorizable(swift_ty: &str, rust_ty: &str) -> String {
     format!(
         r#"
@@ -197,6 +299,97 @@ extension {swift_ty}: Vectorizable {{
     )
 }
 
+/// A Rust `char` is seen on the Swift side as a `Unicode.Scalar`.
+/// Both are 32-bit values, so `vecOfSelfAsPtr` can point directly into the Rust `Vec<char>`.
+fn unicode_scalar_conform_to_vectorizable() -> &'static str {
+    r#"
+extension Unicode.Scalar: Vectorizable {
+    public static func vecOfSelfNew() -> UnsafeMutableRawPointer {
+        __swift_bridge__$Vec_char$new()
+    }
+
+    public static func vecOfSelfFree(vecPtr: UnsafeMutableRawPointer) {
+        __swift_bridge__$Vec_char$_free(vecPtr)
+    }
+
+    public static func vecOfSelfPush(vecPtr: UnsafeMutableRawPointer, value: Self) {
+        __swift_bridge__$Vec_char$push(vecPtr, value.value)
+    }
+
+    public static func vecOfSelfPop(vecPtr: UnsafeMutableRawPointer) -> Optional<Self> {
+        __swift_bridge__$Vec_char$pop(vecPtr).intoSwiftRepr()
+    }
+
+    public static func vecOfSelfGet(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<Self> {
+        __swift_bridge__$Vec_char$get(vecPtr, index).intoSwiftRepr()
+    }
+
+    public static func vecOfSelfGetMut(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<Self> {
+        __swift_bridge__$Vec_char$get_mut(vecPtr, index).intoSwiftRepr()
+    }
+
+    public static func vecOfSelfAsPtr(vecPtr: UnsafeMutableRawPointer) -> UnsafePointer<Self> {
+        UnsafePointer<Self>(OpaquePointer(__swift_bridge__$Vec_char$as_ptr(vecPtr)))
+    }
+
+    public static func vecOfSelfLen(vecPtr: UnsafeMutableRawPointer) -> UInt {
+        __swift_bridge__$Vec_char$len(vecPtr)
+    }
+}
+"#
+}
+
+fn conform_to_rust_set_element(swift_ty: &str, rust_ty: &str) -> String {
+    format!(
+        r#"
+extension {swift_ty}: RustSetElement {{
+    public static func setOfSelfNew() -> UnsafeMutableRawPointer {{
+        __swift_bridge__$HashSet_{rust_ty}$new()
+    }}
+
+    public static func setOfSelfFree(setPtr: UnsafeMutableRawPointer) {{
+        __swift_bridge__$HashSet_{rust_ty}$_free(setPtr)
+    }}
+
+    public static func setOfSelfLen(setPtr: UnsafeMutableRawPointer) -> UInt {{
+        __swift_bridge__$HashSet_{rust_ty}$len(setPtr)
+    }}
+
+    public static func setOfSelfInsert(setPtr: UnsafeMutableRawPointer, value: Self) -> Bool {{
+        __swift_bridge__$HashSet_{rust_ty}$insert(setPtr, value)
+    }}
+
+    public static func setOfSelfRemove(setPtr: UnsafeMutableRawPointer, value: Self) -> Bool {{
+        __swift_bridge__$HashSet_{rust_ty}$remove(setPtr, value)
+    }}
+
+    public static func setOfSelfContains(setPtr: UnsafeMutableRawPointer, value: Self) -> Bool {{
+        __swift_bridge__$HashSet_{rust_ty}$contains(setPtr, value)
+    }}
+
+    public static func setOfSelfIter(setPtr: UnsafeMutableRawPointer) -> UnsafeMutableRawPointer {{
+        __swift_bridge__$HashSet_{rust_ty}$iter(setPtr)
+    }}
+
+    public static func setOfSelfIterNext(iterPtr: UnsafeMutableRawPointer) -> Optional<Self> {{
+        let val = __swift_bridge__$HashSet_{rust_ty}$iter_next(iterPtr)
+        if val.is_some {{
+            return val.val
+        }} else {{
+            return nil
+        }}
+    }}
+
+    public static func setOfSelfIterFree(iterPtr: UnsafeMutableRawPointer) {{
+        __swift_bridge__$HashSet_{rust_ty}$iter_free(iterPtr)
+    }}
+}}
+    "#,
+        rust_ty = rust_ty,
+        swift_ty = swift_ty
+    )
+}
+
 /// Used to free memory for generic Opaque Rust types such as `type SomeType<u32>`
 fn generic_freer() -> &'static str {
     r#"
@@ -224,3 +417,43 @@ public struct __private__UncheckedSendable<T>: @unchecked Sendable {
 }
 "#
 }
+
+/// Stores a shared struct's `Box<T>` field on the heap, since a Swift struct can't contain
+/// itself.
+fn indirect_property_wrapper() -> &'static str {
+    r#"
+@propertyWrapper
+public enum Indirect<T> {
+    indirect case wrapped(T)
+
+    public init(wrappedValue: T) {
+        self = .wrapped(wrappedValue)
+    }
+
+    public var wrappedValue: T {
+        get {
+            switch self {
+            case .wrapped(let value):
+                return value
+            }
+        }
+        set {
+            self = .wrapped(newValue)
+        }
+    }
+}
+extension Indirect: Equatable where T: Equatable {}
+extension Indirect: Hashable where T: Hashable {}
+extension Indirect: Sendable where T: Sendable {}
+extension Indirect: Encodable where T: Encodable {
+    public func encode(to encoder: Encoder) throws {
+        try wrappedValue.encode(to: encoder)
+    }
+}
+extension Indirect: Decodable where T: Decodable {
+    public init(from decoder: Decoder) throws {
+        self.init(wrappedValue: try T(from: decoder))
+    }
+}
+"#
+}
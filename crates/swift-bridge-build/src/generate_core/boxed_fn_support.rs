@@ -27,6 +27,21 @@ public class __private__RustFnOnceCallbackNoArgsNoRet {
 }
 "#;
 
+/// Holds a Swift closure that was passed to Rust as a `Box<dyn Fn>`, `Box<dyn FnMut>` or
+/// `Box<dyn FnOnce>`.
+///
+/// Rust holds on to a retained pointer to this class, and releases it when the boxed function is
+/// dropped.
+pub const SWIFT_CALLBACK_SUPPORT_SWIFT_CLOSURE: &str = r#"
+public class __private__SwiftCallback<F> {
+    let callback: F
+
+    init(_ callback: F) {
+        self.callback = callback
+    }
+}
+"#;
+
 pub const C_CALLBACK_SUPPORT_NO_ARGS_NO_RETURN: &'static str = r#"
 void __swift_bridge__$call_boxed_fn_once_no_args_no_return(void* boxed_fnonce);
 void __swift_bridge__$free_boxed_fn_once_no_args_no_return(void* boxed_fnonce);
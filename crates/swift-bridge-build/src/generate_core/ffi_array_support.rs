@@ -0,0 +1,42 @@
+/// A `Vec<T>` of nested containers such as `Vec<Option<T>>` or `Vec<Vec<T>>` crosses the FFI
+/// boundary as an `__private__FfiArray` of FFI values, which gets converted to and from a Swift
+/// `Array`.
+///
+/// The buffer is allocated and freed by Rust. Swift tells Rust the size and alignment of the
+/// elements, which are C types, so they have the same layout on both sides.
+pub const SWIFT_FFI_ARRAY_SUPPORT: &str = r#"
+public func __private__intoSwiftArray<F, T>(_ ffiArray: __private__FfiArray, _ convert: (F) -> T) -> [T] {
+    let len = Int(ffiArray.len)
+    var array: [T] = []
+    array.reserveCapacity(len)
+
+    if len > 0 {
+        let start = ffiArray.start!.bindMemory(to: F.self, capacity: len)
+        for index in 0..<len {
+            array.append(convert((start + index).move()))
+        }
+    }
+
+    __swift_bridge__$FfiArray$free(ffiArray, UInt(MemoryLayout<F>.stride), UInt(MemoryLayout<F>.alignment))
+    return array
+}
+
+public func __private__intoFfiArray<T, F>(_ array: [T], _ convert: (T) -> F) -> __private__FfiArray {
+    let ffiArray = __swift_bridge__$FfiArray$alloc(UInt(array.count), UInt(MemoryLayout<F>.stride), UInt(MemoryLayout<F>.alignment))
+
+    if !array.isEmpty {
+        let start = ffiArray.start!.bindMemory(to: F.self, capacity: array.count)
+        for (index, value) in array.enumerated() {
+            (start + index).initialize(to: convert(value))
+        }
+    }
+
+    return ffiArray
+}
+"#;
+
+pub const C_FFI_ARRAY_SUPPORT: &str = r#"
+typedef struct __private__FfiArray { void* start; uintptr_t len; } __private__FfiArray;
+__private__FfiArray __swift_bridge__$FfiArray$alloc(uintptr_t len, uintptr_t size, uintptr_t align);
+void __swift_bridge__$FfiArray$free(__private__FfiArray array, uintptr_t size, uintptr_t align);
+"#;
@@ -0,0 +1,24 @@
+/// A `[T; N]` crosses the FFI boundary by value as a C struct that holds a `T values[N]` array,
+/// which Swift imports as a tuple. We read and write the tuple's elements through its raw bytes,
+/// since a C array's elements are laid out at `MemoryLayout<F>.stride` offsets.
+pub const SWIFT_FIXED_SIZE_ARRAY_SUPPORT: &str = r#"
+public func __private__intoSwiftFixedSizeArray<Ffi, F, T>(_ ffi: Ffi, _ count: Int, _ convert: (F) -> T) -> [T] {
+    withUnsafeBytes(of: ffi) { buffer in
+        (0..<count).map { index in
+            convert(buffer.load(fromByteOffset: index * MemoryLayout<F>.stride, as: F.self))
+        }
+    }
+}
+
+public func __private__intoFfiFixedSizeArray<Ffi, T, F>(_ ffi: Ffi, _ array: [T], _ count: Int, _ convert: (T) -> F) -> Ffi {
+    precondition(array.count == count, "Expected an array with \(count) elements but it had \(array.count) elements.")
+
+    var ffi = ffi
+    withUnsafeMutableBytes(of: &ffi) { buffer in
+        for (index, value) in array.enumerated() {
+            buffer.storeBytes(of: convert(value), toByteOffset: index * MemoryLayout<F>.stride, as: F.self)
+        }
+    }
+    return ffi
+}
+"#;
@@ -0,0 +1,142 @@
+use swift_bridge_ir::SWIFT_INT128_AVAILABILITY;
+
+/// 128-bit integers cross the FFI boundary as a struct holding their low and high 64 bits.
+///
+/// `Int128` and `UInt128` were added in Swift 6.0. Older compilers get a struct with the same
+/// name that holds the integer's low and high 64 bits, so that the generated code still compiles.
+pub(super) fn swift_int128_support() -> String {
+    let types = [
+        ("I128", "i128", "Int128", "Int64"),
+        ("U128", "u128", "UInt128", "UInt64"),
+    ];
+    let mut all = "".to_string();
+
+    for (suffix, rust_ty, swift_ty, high_ty) in types {
+        let ffi_ty = format!("__private__Ffi{suffix}");
+        let option_ffi_ty = format!("__private__Option{suffix}");
+        let available = SWIFT_INT128_AVAILABILITY;
+
+        all += &format!(
+            r#"
+#if compiler(>=6.0)
+{available}
+extension {swift_ty} {{
+    init(ffiRepr: {ffi_ty}) {{
+        self = ({swift_ty}(ffiRepr.high) << 64) | {swift_ty}(ffiRepr.low)
+    }}
+
+    func intoFfiRepr() -> {ffi_ty} {{
+        {ffi_ty}(low: UInt64(truncatingIfNeeded: self), high: {high_ty}(truncatingIfNeeded: self >> 64))
+    }}
+}}
+#else
+public struct {swift_ty}: Equatable, Hashable {{
+    public var low: UInt64
+    public var high: {high_ty}
+
+    public init(low: UInt64, high: {high_ty}) {{
+        self.low = low
+        self.high = high
+    }}
+}}
+extension {swift_ty} {{
+    init(ffiRepr: {ffi_ty}) {{
+        self.init(low: ffiRepr.low, high: ffiRepr.high)
+    }}
+
+    func intoFfiRepr() -> {ffi_ty} {{
+        {ffi_ty}(low: self.low, high: self.high)
+    }}
+}}
+#endif
+{available}
+extension {option_ffi_ty} {{
+    func intoSwiftRepr() -> Optional<{swift_ty}> {{
+        if self.is_some {{
+            return {swift_ty}(ffiRepr: self.val)
+        }} else {{
+            return nil
+        }}
+    }}
+
+    init(_ val: Optional<{swift_ty}>) {{
+        if let val = val {{
+            self = Self(val: val.intoFfiRepr(), is_some: true)
+        }} else {{
+            self = Self(val: {ffi_ty}(low: 0, high: 0), is_some: false)
+        }}
+    }}
+}}
+{available}
+extension Optional where Wrapped == {swift_ty} {{
+    func intoFfiRepr() -> {option_ffi_ty} {{
+        {option_ffi_ty}(self)
+    }}
+}}
+{available}
+extension {swift_ty}: Vectorizable {{
+    public static func vecOfSelfNew() -> UnsafeMutableRawPointer {{
+        __swift_bridge__$Vec_{rust_ty}$new()
+    }}
+
+    public static func vecOfSelfFree(vecPtr: UnsafeMutableRawPointer) {{
+        __swift_bridge__$Vec_{rust_ty}$_free(vecPtr)
+    }}
+
+    public static func vecOfSelfPush(vecPtr: UnsafeMutableRawPointer, value: Self) {{
+        __swift_bridge__$Vec_{rust_ty}$push(vecPtr, value.intoFfiRepr())
+    }}
+
+    public static func vecOfSelfPop(vecPtr: UnsafeMutableRawPointer) -> Optional<Self> {{
+        __swift_bridge__$Vec_{rust_ty}$pop(vecPtr).intoSwiftRepr()
+    }}
+
+    public static func vecOfSelfGet(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<Self> {{
+        __swift_bridge__$Vec_{rust_ty}$get(vecPtr, index).intoSwiftRepr()
+    }}
+
+    public static func vecOfSelfGetMut(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<Self> {{
+        __swift_bridge__$Vec_{rust_ty}$get_mut(vecPtr, index).intoSwiftRepr()
+    }}
+
+    public static func vecOfSelfAsPtr(vecPtr: UnsafeMutableRawPointer) -> UnsafePointer<Self> {{
+        UnsafePointer<Self>(OpaquePointer(__swift_bridge__$Vec_{rust_ty}$as_ptr(vecPtr)))
+    }}
+
+    public static func vecOfSelfLen(vecPtr: UnsafeMutableRawPointer) -> UInt {{
+        __swift_bridge__$Vec_{rust_ty}$len(vecPtr)
+    }}
+}}
+"#
+        );
+    }
+
+    all
+}
+
+pub(super) fn c_int128_support() -> String {
+    let types = [("I128", "i128", "int64_t"), ("U128", "u128", "uint64_t")];
+    let mut all = "".to_string();
+
+    for (suffix, rust_ty, high_ty) in types {
+        let ffi_ty = format!("__private__Ffi{suffix}");
+        let option_ffi_ty = format!("__private__Option{suffix}");
+
+        all += &format!(
+            r#"
+typedef struct {ffi_ty} {{ uint64_t low; {high_ty} high; }} {ffi_ty};
+typedef struct {option_ffi_ty} {{ {ffi_ty} val; bool is_some; }} {option_ffi_ty};
+void* __swift_bridge__$Vec_{rust_ty}$new();
+void __swift_bridge__$Vec_{rust_ty}$_free(void* const vec);
+uintptr_t __swift_bridge__$Vec_{rust_ty}$len(void* const vec);
+void __swift_bridge__$Vec_{rust_ty}$push(void* const vec, {ffi_ty} val);
+{option_ffi_ty} __swift_bridge__$Vec_{rust_ty}$pop(void* const vec);
+{option_ffi_ty} __swift_bridge__$Vec_{rust_ty}$get(void* const vec, uintptr_t index);
+{option_ffi_ty} __swift_bridge__$Vec_{rust_ty}$get_mut(void* const vec, uintptr_t index);
+void const * __swift_bridge__$Vec_{rust_ty}$as_ptr(void* const vec);
+"#
+        );
+    }
+
+    all
+}
@@ -47,6 +47,32 @@ extension Optional where Wrapped == {inner_ty} {{
         );
     }
 
+    // A `char` crosses the FFI boundary as a `UInt32`.
+    all += r#"
+extension __private__OptionChar {
+    func intoSwiftRepr() -> Optional<Unicode.Scalar> {
+        if self.is_some {
+            return Unicode.Scalar(self.val)!
+        } else {
+            return nil
+        }
+    }
+
+    init(_ val: Optional<Unicode.Scalar>) {
+        if let val = val {
+            self = Self(val: val.value, is_some: true)
+        } else {
+            self = Self(val: 0, is_some: false)
+        }
+    }
+}
+extension Optional where Wrapped == Unicode.Scalar {
+    func intoFfiRepr() -> __private__OptionChar {
+        __private__OptionChar(self)
+    }
+}
+"#;
+
     all
 }
 
@@ -64,4 +90,5 @@ typedef struct __private__OptionIsize { intptr_t val; bool is_some; } __private_
 typedef struct __private__OptionF32 { float val; bool is_some; } __private__OptionF32;
 typedef struct __private__OptionF64 { double val; bool is_some; } __private__OptionF64;
 typedef struct __private__OptionBool { bool val; bool is_some; } __private__OptionBool;
+typedef struct __private__OptionChar { uint32_t val; bool is_some; } __private__OptionChar;
 "#;
@@ -0,0 +1,144 @@
+
+/// A Rust `HashMap<K, V>` or `BTreeMap<K, V>`.
+///
+/// Maps cross the FFI boundary as two `RustVec`s of equal length, one for the keys and one for
+/// the values. Use `toDictionary()` to move the entries into a Swift `Dictionary`.
+public class RustMap<K: Vectorizable, V: Vectorizable> {
+    var keys: RustVec<K>
+    var values: RustVec<V>
+
+    public init() {
+        keys = RustVec()
+        values = RustVec()
+    }
+
+    init(ffiRepr: __private__FfiMap) {
+        keys = RustVec(ptr: ffiRepr.keys)
+        values = RustVec(ptr: ffiRepr.values)
+    }
+
+    /// If the same key is inserted more than once, the most recently inserted value wins.
+    public func insert(key: K, value: V) {
+        keys.push(value: key)
+        values.push(value: value)
+    }
+
+    public func len() -> Int {
+        keys.len()
+    }
+
+    func intoFfiRepr() -> __private__FfiMap {
+        keys.isOwned = false
+        values.isOwned = false
+        return __private__FfiMap(keys: keys.ptr, values: values.ptr)
+    }
+}
+
+extension RustMap: Sequence {
+    public func makeIterator() -> RustMapIterator<K, V> {
+        return RustMapIterator(self)
+    }
+}
+
+public struct RustMapIterator<K: Vectorizable, V: Vectorizable>: IteratorProtocol {
+    var keys: RustVecIterator<K>
+    var values: RustVecIterator<V>
+
+    init (_ rustMap: RustMap<K, V>) {
+        self.keys = rustMap.keys.makeIterator()
+        self.values = rustMap.values.makeIterator()
+    }
+
+    public mutating func next() -> (key: K.SelfRef, value: V.SelfRef)? {
+        guard let key = keys.next(), let value = values.next() else {
+            return nil
+        }
+        return (key, value)
+    }
+}
+
+extension RustMap where K: Hashable {
+    public convenience init(_ dictionary: [K: V]) {
+        self.init()
+        for (key, value) in dictionary {
+            self.insert(key: key, value: value)
+        }
+    }
+
+    /// Move every entry into a Swift `Dictionary`, leaving this `RustMap` empty.
+    public func toDictionary() -> [K: V] {
+        var dictionary = [K: V](minimumCapacity: len())
+        // We pop from the back, so the first value that we see for a key is the newest one.
+        while let key = keys.pop(), let value = values.pop() {
+            if dictionary[key] == nil {
+                dictionary[key] = value
+            }
+        }
+        return dictionary
+    }
+}
+
+extension RustMap where K == RustString {
+    public convenience init(_ dictionary: [String: V]) {
+        self.init()
+        for (key, value) in dictionary {
+            self.insert(key: RustString(key), value: value)
+        }
+    }
+
+    /// Move every entry into a Swift `Dictionary`, leaving this `RustMap` empty.
+    public func toDictionary() -> [String: V] {
+        var dictionary = [String: V](minimumCapacity: len())
+        // We pop from the back, so the first value that we see for a key is the newest one.
+        while let key = keys.pop(), let value = values.pop() {
+            let key = key.toString()
+            if dictionary[key] == nil {
+                dictionary[key] = value
+            }
+        }
+        return dictionary
+    }
+}
+
+extension RustMap where K: Hashable, V == RustString {
+    public convenience init(_ dictionary: [K: String]) {
+        self.init()
+        for (key, value) in dictionary {
+            self.insert(key: key, value: RustString(value))
+        }
+    }
+
+    /// Move every entry into a Swift `Dictionary`, leaving this `RustMap` empty.
+    public func toDictionary() -> [K: String] {
+        var dictionary = [K: String](minimumCapacity: len())
+        // We pop from the back, so the first value that we see for a key is the newest one.
+        while let key = keys.pop(), let value = values.pop() {
+            if dictionary[key] == nil {
+                dictionary[key] = value.toString()
+            }
+        }
+        return dictionary
+    }
+}
+
+extension RustMap where K == RustString, V == RustString {
+    public convenience init(_ dictionary: [String: String]) {
+        self.init()
+        for (key, value) in dictionary {
+            self.insert(key: RustString(key), value: RustString(value))
+        }
+    }
+
+    /// Move every entry into a Swift `Dictionary`, leaving this `RustMap` empty.
+    public func toDictionary() -> [String: String] {
+        var dictionary = [String: String](minimumCapacity: len())
+        // We pop from the back, so the first value that we see for a key is the newest one.
+        while let key = keys.pop(), let value = values.pop() {
+            let key = key.toString()
+            if dictionary[key] == nil {
+                dictionary[key] = value.toString()
+            }
+        }
+        return dictionary
+    }
+}
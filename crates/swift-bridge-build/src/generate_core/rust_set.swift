@@ -0,0 +1,138 @@
+
+/// A Rust `HashSet<T>` or `BTreeSet<T>`.
+///
+/// The elements live in a Rust `HashSet<T>`, so `insert`, `remove` and `contains` are all
+/// handled by Rust. Use `toSet()` to copy the elements into a Swift `Set`.
+public class RustSet<T: RustSetElement> {
+    var ptr: UnsafeMutableRawPointer
+    var isOwned: Bool = true
+
+    public init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+    }
+
+    public init() {
+        ptr = T.setOfSelfNew()
+        isOwned = true
+    }
+
+    /// Returns `true` if the value was not already in the set.
+    @discardableResult
+    public func insert(_ value: T) -> Bool {
+        T.setOfSelfInsert(setPtr: ptr, value: value)
+    }
+
+    /// Returns `true` if the value was in the set.
+    @discardableResult
+    public func remove(_ value: T.SelfRef) -> Bool {
+        T.setOfSelfRemove(setPtr: ptr, value: value)
+    }
+
+    public func contains(_ value: T.SelfRef) -> Bool {
+        T.setOfSelfContains(setPtr: ptr, value: value)
+    }
+
+    /// Rust returns a UInt, but we cast to an Int because many Swift APIs expect Int.
+    public func len() -> Int {
+        Int(T.setOfSelfLen(setPtr: ptr))
+    }
+
+    deinit {
+        if isOwned {
+            T.setOfSelfFree(setPtr: ptr)
+        }
+    }
+}
+
+extension RustSet: Sequence {
+    /// The iterator walks over a copy of the set's elements, so the set can be mutated while it
+    /// is being iterated over.
+    public func makeIterator() -> RustSetIterator<T> {
+        return RustSetIterator(self)
+    }
+}
+
+public class RustSetIterator<T: RustSetElement>: IteratorProtocol {
+    // The elements that `next()` returns are owned by the Rust iterator, so they are only valid
+    // until the iterator is freed.
+    var iterPtr: UnsafeMutableRawPointer
+
+    init (_ rustSet: RustSet<T>) {
+        self.iterPtr = T.setOfSelfIter(setPtr: rustSet.ptr)
+    }
+
+    public func next() -> T.SelfRef? {
+        T.setOfSelfIterNext(iterPtr: iterPtr)
+    }
+
+    deinit {
+        T.setOfSelfIterFree(iterPtr: iterPtr)
+    }
+}
+
+extension RustSet where T: Hashable, T.SelfRef == T {
+    public convenience init(_ set: Set<T>) {
+        self.init()
+        for value in set {
+            self.insert(value)
+        }
+    }
+
+    /// Copy every element into a Swift `Set`.
+    public func toSet() -> Set<T> {
+        var set = Set<T>(minimumCapacity: len())
+        for value in self {
+            set.insert(value)
+        }
+        return set
+    }
+}
+
+extension RustSet where T == RustString {
+    public convenience init(_ set: Set<String>) {
+        self.init()
+        for value in set {
+            self.insert(RustString(value))
+        }
+    }
+
+    public func contains<GenericToRustStr: ToRustStr>(_ value: GenericToRustStr) -> Bool {
+        contains(RustString(value))
+    }
+
+    @discardableResult
+    public func remove<GenericToRustStr: ToRustStr>(_ value: GenericToRustStr) -> Bool {
+        remove(RustString(value))
+    }
+
+    /// Copy every element into a Swift `Set`.
+    public func toSet() -> Set<String> {
+        var set = Set<String>(minimumCapacity: len())
+        for value in self {
+            set.insert(value.as_str().toString())
+        }
+        return set
+    }
+}
+
+public protocol RustSetElement {
+    associatedtype SelfRef
+
+    static func setOfSelfNew() -> UnsafeMutableRawPointer;
+
+    static func setOfSelfFree(setPtr: UnsafeMutableRawPointer)
+
+    static func setOfSelfLen(setPtr: UnsafeMutableRawPointer) -> UInt
+
+    static func setOfSelfInsert(setPtr: UnsafeMutableRawPointer, value: Self) -> Bool
+
+    static func setOfSelfRemove(setPtr: UnsafeMutableRawPointer, value: SelfRef) -> Bool
+
+    static func setOfSelfContains(setPtr: UnsafeMutableRawPointer, value: SelfRef) -> Bool
+
+    static func setOfSelfIter(setPtr: UnsafeMutableRawPointer) -> UnsafeMutableRawPointer
+
+    static func setOfSelfIterNext(iterPtr: UnsafeMutableRawPointer) -> Optional<SelfRef>
+
+    static func setOfSelfIterFree(iterPtr: UnsafeMutableRawPointer)
+}
@@ -11,6 +11,16 @@ void* __swift_bridge__$Vec_RustString$get_mut(void* vec_ptr, uintptr_t index);
 uintptr_t __swift_bridge__$Vec_RustString$len(void* vec_ptr);
 void* __swift_bridge__$Vec_RustString$as_ptr(void* vec_ptr);
 
+void* __swift_bridge__$HashSet_RustString$new(void);
+void __swift_bridge__$HashSet_RustString$_free(void* set_ptr);
+uintptr_t __swift_bridge__$HashSet_RustString$len(void* set_ptr);
+bool __swift_bridge__$HashSet_RustString$insert(void* set_ptr, void* item_ptr);
+bool __swift_bridge__$HashSet_RustString$remove(void* set_ptr, void* item_ptr);
+bool __swift_bridge__$HashSet_RustString$contains(void* set_ptr, void* item_ptr);
+void* __swift_bridge__$HashSet_RustString$iter(void* set_ptr);
+void* __swift_bridge__$HashSet_RustString$iter_next(void* iter_ptr);
+void __swift_bridge__$HashSet_RustString$iter_free(void* iter_ptr);
+
 void* __swift_bridge__$RustString$new(void);
 void* __swift_bridge__$RustString$new_with_str(struct RustStr str);
 uintptr_t __swift_bridge__$RustString$len(void* self);
@@ -10,6 +10,13 @@ public class RustString: RustStringRefMut {
             __swift_bridge__$RustString$_free(ptr)
         }
     }
+
+    /// Allows `#[swift_bridge(Codable)]` shared structs and enums to contain a `String`.
+    /// `Decodable` requires a non-final class to declare its initializer in the class itself.
+    public required convenience init(from decoder: Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        self.init(try container.decode(String.self))
+    }
 }
 
 /// Tested in:
@@ -66,6 +73,23 @@ extension RustStringRef {
         __swift_bridge__$RustString$trim(ptr)
     }
 }
+/// Allows shared structs and enums that contain a `String` to derive `PartialEq` and `Hash`.
+extension RustStringRef: Equatable {
+    public static func == (lhs: RustStringRef, rhs: RustStringRef) -> Bool {
+        lhs.as_str() == rhs.as_str()
+    }
+}
+extension RustStringRef: Hashable {
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(self.as_str().toString())
+    }
+}
+extension RustString: Codable {
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        try container.encode(self.toString())
+    }
+}
 extension RustString: Vectorizable {
     public static func vecOfSelfNew() -> UnsafeMutableRawPointer {
         __swift_bridge__$Vec_RustString$new()
@@ -114,3 +138,47 @@ extension RustString: Vectorizable {
         __swift_bridge__$Vec_RustString$len(vecPtr)
     }
 }
+
+
+extension RustString: RustSetElement {
+    public static func setOfSelfNew() -> UnsafeMutableRawPointer {
+        __swift_bridge__$HashSet_RustString$new()
+    }
+
+    public static func setOfSelfFree(setPtr: UnsafeMutableRawPointer) {
+        __swift_bridge__$HashSet_RustString$_free(setPtr)
+    }
+
+    public static func setOfSelfLen(setPtr: UnsafeMutableRawPointer) -> UInt {
+        __swift_bridge__$HashSet_RustString$len(setPtr)
+    }
+
+    public static func setOfSelfInsert(setPtr: UnsafeMutableRawPointer, value: RustString) -> Bool {
+        __swift_bridge__$HashSet_RustString$insert(setPtr, {value.isOwned = false; return value.ptr;}())
+    }
+
+    public static func setOfSelfRemove(setPtr: UnsafeMutableRawPointer, value: RustStringRef) -> Bool {
+        __swift_bridge__$HashSet_RustString$remove(setPtr, value.ptr)
+    }
+
+    public static func setOfSelfContains(setPtr: UnsafeMutableRawPointer, value: RustStringRef) -> Bool {
+        __swift_bridge__$HashSet_RustString$contains(setPtr, value.ptr)
+    }
+
+    public static func setOfSelfIter(setPtr: UnsafeMutableRawPointer) -> UnsafeMutableRawPointer {
+        __swift_bridge__$HashSet_RustString$iter(setPtr)
+    }
+
+    public static func setOfSelfIterNext(iterPtr: UnsafeMutableRawPointer) -> Optional<RustStringRef> {
+        let pointer = __swift_bridge__$HashSet_RustString$iter_next(iterPtr)
+        if pointer == nil {
+            return nil
+        } else {
+            return RustStringRef(ptr: pointer!)
+        }
+    }
+
+    public static func setOfSelfIterFree(iterPtr: UnsafeMutableRawPointer) {
+        __swift_bridge__$HashSet_RustString$iter_free(iterPtr)
+    }
+}
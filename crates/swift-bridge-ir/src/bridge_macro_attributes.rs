@@ -1,6 +1,6 @@
 use proc_macro2::Ident;
 use syn::parse::{Parse, ParseStream};
-use syn::{Path, Token};
+use syn::{LitStr, Path, Token};
 
 /// The `...` in
 /// `#\[swift_bridge::bridge(...)\]`
@@ -15,6 +15,23 @@ pub enum SwiftBridgeModuleAttr {
     /// as `RustString`.
     /// `#\[swift_bridge::bridge(swift_bridge_path = swift_bridge)\]`
     SwiftBridgePath(Path),
+    /// Sets the naming convention that the generated Swift code uses for the module's functions,
+    /// methods, arguments, struct fields and enum variants.
+    /// `#\[swift_bridge::bridge(swift_naming = "camelCase")\]`
+    SwiftNaming(SwiftNaming),
+    /// Gives every `Option` argument of the module's `extern "Rust"` functions a default value
+    /// of `nil` in the generated Swift code, unless the argument has its own
+    /// `#[swift_bridge(default = ...)]`.
+    /// `#\[swift_bridge::bridge(optional_args_default = None)\]`
+    OptionalArgsDefaultNone,
+}
+
+/// The naming convention that the generated Swift code uses for a bridge module's items.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SwiftNaming {
+    /// Convert names to Swift's `lowerCamelCase`, so that `fn make_request(max_retries: u8)`
+    /// becomes `func makeRequest(_ maxRetries: UInt8)`.
+    CamelCase,
 }
 
 impl Parse for SwiftBridgeModuleAttrs {
@@ -38,6 +55,29 @@ impl Parse for SwiftBridgeModuleAttr {
 
         let attr = match key.to_string().as_str() {
             "swift_bridge_path" => SwiftBridgeModuleAttr::SwiftBridgePath(input.parse()?),
+            "swift_naming" => {
+                let value: LitStr = input.parse()?;
+                let naming = match value.value().as_str() {
+                    "camelCase" => SwiftNaming::CamelCase,
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            value,
+                            r#"The only supported swift_naming is "camelCase"."#,
+                        ));
+                    }
+                };
+                SwiftBridgeModuleAttr::SwiftNaming(naming)
+            }
+            "optional_args_default" => {
+                let value: Ident = input.parse()?;
+                if value != "None" {
+                    return Err(syn::Error::new_spanned(
+                        value,
+                        "The only supported optional_args_default is None.",
+                    ));
+                }
+                SwiftBridgeModuleAttr::OptionalArgsDefaultNone
+            }
             _ => {
                 return Err(syn::Error::new(input.span(), "Unknown attribute."));
             }
@@ -8,29 +8,46 @@ use quote::{quote, quote_spanned};
 use syn::{FnArg, Pat, PatType, Path, ReturnType, Type};
 
 pub(crate) use self::bridged_opaque_type::OpaqueForeignType;
-use crate::bridged_type::boxed_fn::BridgeableBoxedFnOnce;
+use crate::bridged_type::boxed_fn::BridgeableBoxedFn;
 use crate::bridged_type::bridgeable_pointer::{BuiltInPointer, Pointee, PointerKind};
 pub(crate) use crate::bridged_type::bridgeable_result::BuiltInResult;
 use crate::bridged_type::bridgeable_string::BridgedString;
+pub(crate) use crate::bridged_type::bridged_box::BridgedBox;
+use crate::bridged_type::bridged_trait_object::BridgedTraitObject;
+use crate::bridged_type::built_in_array::BuiltInArray;
+use crate::bridged_type::built_in_map::BuiltInMap;
+use crate::bridged_type::built_in_set::BuiltInSet;
 use crate::bridged_type::built_in_tuple::BuiltInTuple;
+pub(crate) use crate::bridged_type::built_in_vec::BuiltInVec;
 
 use crate::parse::{HostLang, TypeDeclaration, TypeDeclarations};
 
 use self::bridged_option::BridgedOption;
-pub(crate) use self::shared_enum::{DeriveAttrs, EnumVariant, SharedEnum};
+pub(crate) use self::shared_enum::{
+    integer_discriminant_value, DeriveAttrs, EnumRawValueType, EnumVariant, SharedEnum,
+};
 pub(crate) use self::shared_struct::{SharedStruct, StructFields, StructSwiftRepr};
+pub(crate) use self::shared_type_generics::SharedTypeGenerics;
 
 pub(crate) mod boxed_fn;
 mod bridgeable_pointer;
 mod bridgeable_result;
 pub mod bridgeable_str;
 pub mod bridgeable_string;
+mod bridged_box;
 pub mod bridged_opaque_type;
 mod bridged_option;
+pub(crate) mod bridged_trait_object;
+pub(crate) mod built_in_array;
+mod built_in_map;
 mod built_in_primitive;
+mod built_in_set;
 mod built_in_tuple;
+mod built_in_vec;
+pub(crate) mod serde_attributes;
 mod shared_enum;
 pub(crate) mod shared_struct;
+pub(crate) mod shared_type_generics;
 
 /// Used to declare structures in a C header file.
 pub(crate) struct CFfiStruct {
@@ -294,6 +311,12 @@ pub(crate) trait BridgeableType: Debug {
     ///  of checking the type.
     fn contains_ref_string_recursive(&self) -> bool;
 
+    /// Whether or not the type is an `i128` or `u128`, or a type that contains one such as
+    /// `Option<u128>`.
+    fn contains_128_bit_integer_recursive(&self) -> bool {
+        false
+    }
+
     // TODO: Is this used? Do we need this?
     #[allow(unused)]
     /// Parse the type from a `FnArg`.
@@ -385,11 +408,18 @@ pub(crate) enum StdLibType {
     I32,
     U64,
     I64,
+    /// `u128`, which crosses the FFI boundary as a `swift_bridge::int128::FfiU128`.
+    U128,
+    /// `i128`, which crosses the FFI boundary as a `swift_bridge::int128::FfiI128`.
+    I128,
     Usize,
     Isize,
     F32,
     F64,
     Bool,
+    /// `char`, which crosses the FFI boundary as a `u32` and is seen by Swift as a
+    /// `Unicode.Scalar`.
+    Char,
     /// `*const T` or `*mut T`
     Pointer(BuiltInPointer),
     /// `&[T]` or `&mut [T]`
@@ -397,7 +427,14 @@ pub(crate) enum StdLibType {
     /// &str
     Str,
     Vec(BuiltInVec),
-    BoxedFnOnce(BridgeableBoxedFnOnce),
+    /// `[T; N]`
+    Array(BuiltInArray),
+    /// `HashMap<K, V>` or `BTreeMap<K, V>`
+    Map(BuiltInMap),
+    /// `HashSet<T>` or `BTreeSet<T>`
+    Set(BuiltInSet),
+    /// `Box<dyn FnOnce(A) -> B>`, `Box<dyn FnMut(A) -> B>` or `Box<dyn Fn(A) -> B>`
+    BoxedFn(BridgeableBoxedFn),
     Option(BridgedOption),
     Result(BuiltInResult),
     Tuple(BuiltInTuple),
@@ -420,16 +457,17 @@ pub(crate) struct BuiltInRefSlice {
     pub ty: Box<BridgedType>,
 }
 
-/// Vec<T>
-#[derive(Debug)]
-pub(crate) struct BuiltInVec {
-    pub ty: Box<BridgedType>,
-}
-
 impl BridgedType {
     pub fn is_null(&self) -> bool {
         matches!(self, BridgedType::StdLib(StdLibType::Null))
     }
+
+    pub fn as_boxed_fn(&self) -> Option<&BridgeableBoxedFn> {
+        match self {
+            BridgedType::StdLib(StdLibType::BoxedFn(boxed_fn)) => Some(boxed_fn),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -506,7 +544,8 @@ impl BridgeableType for BridgedType {
 
     fn is_passed_via_pointer(&self) -> bool {
         match self {
-            BridgedType::StdLib(StdLibType::Vec(_)) => true,
+            // A `Vec` that Swift sees as an `Array` is passed as an `FfiArray` value.
+            BridgedType::StdLib(StdLibType::Vec(vec)) => !vec.is_converted_to_swift_array(),
             BridgedType::StdLib(_) => false,
             BridgedType::Foreign(_) => false,
             BridgedType::Bridgeable(ty) => ty.is_passed_via_pointer(),
@@ -526,6 +565,15 @@ impl BridgeableType for BridgedType {
                 StdLibType::Tuple(ty) => {
                     ty.generate_custom_rust_ffi_types(swift_bridge_path, types)
                 }
+                StdLibType::Option(ty) => {
+                    ty.generate_custom_rust_ffi_types(swift_bridge_path, types)
+                }
+                StdLibType::Vec(ty) => ty
+                    .ty
+                    .generate_custom_rust_ffi_types(swift_bridge_path, types),
+                StdLibType::Array(ty) => {
+                    ty.generate_custom_rust_ffi_types(swift_bridge_path, types)
+                }
                 _ => None,
             },
             BridgedType::Foreign(_) => None,
@@ -540,10 +588,13 @@ impl BridgeableType for BridgedType {
             BridgedType::StdLib(ty) => match ty {
                 StdLibType::Result(ty) => ty.generate_custom_c_ffi_types(types),
                 StdLibType::Tuple(ty) => ty.generate_custom_c_ffi_types(types),
+                StdLibType::Option(ty) => ty.generate_custom_c_ffi_types(types),
+                StdLibType::Vec(ty) => ty.ty.generate_custom_c_ffi_types(types),
+                StdLibType::Array(ty) => Some(ty.generate_custom_c_ffi_types(types)),
                 _ => None,
             },
             BridgedType::Foreign(_) => None,
-            BridgedType::Bridgeable(_) => None,
+            BridgedType::Bridgeable(ty) => ty.generate_custom_c_ffi_types(types),
         }
     }
 
@@ -722,6 +773,10 @@ impl BridgeableType for BridgedType {
         todo!()
     }
 
+    fn contains_128_bit_integer_recursive(&self) -> bool {
+        self.contains_128_bit_integer_recursive()
+    }
+
     fn has_swift_bridge_copy_annotation(&self) -> bool {
         match self {
             BridgedType::Bridgeable(b) => b.has_swift_bridge_copy_annotation(),
@@ -783,8 +838,12 @@ impl BridgedType {
                 Type::Slice(slice) => Self::new_with_type(&slice.elem, types).map(|ty| {
                     BridgedType::StdLib(StdLibType::RefSlice(BuiltInRefSlice { ty: Box::new(ty) }))
                 }),
+                Type::TraitObject(_) | Type::Paren(_) => BridgedTraitObject::from_type(ty, types)
+                    .map(|trait_object| BridgedType::Bridgeable(Box::new(trait_object))),
                 _ => None,
             },
+            Type::Array(array) => BuiltInArray::from_type_array(array, types)
+                .map(|array| BridgedType::StdLib(StdLibType::Array(array))),
             Type::Tuple(tuple) => {
                 if tuple.elems.len() == 0 {
                     Some(BridgedType::StdLib(StdLibType::Null))
@@ -817,8 +876,10 @@ impl BridgedType {
         let tokens = tokens.replace("\n", " ");
         let tokens = tokens.as_str();
         if tokens.starts_with("Vec < ") {
-            let inner = tokens.trim_start_matches("Vec < ");
-            let inner = inner.trim_end_matches(" >");
+            let inner = tokens.strip_prefix("Vec < ").unwrap_or(tokens);
+            // Only strip the final bracket so that nested generics such as
+            // "Vec < Vec < u8 > >" keep their own brackets.
+            let inner = inner.strip_suffix(" >").unwrap_or(inner);
 
             let inner = if let Some(declared_ty) = types.get(inner) {
                 declared_ty.to_bridged_type(false, false)
@@ -834,16 +895,9 @@ impl BridgedType {
             let last_bracket = tokens.rfind(">")?;
 
             let inner = &tokens[0..last_bracket];
-            let inner = inner.trim_start_matches("Option < ");
-
-            // Remove spaces from generics. i.e. "SomeType < u32 > " -> "SomeType<u32>"
-            let inner = if inner.contains("<") {
-                inner.replace(" ", "")
-            } else {
-                inner.to_string()
-            };
+            let inner = inner.strip_prefix("Option < ").unwrap_or(inner);
 
-            let inner: Type = syn::parse2(TokenStream::from_str(&inner).unwrap()).unwrap();
+            let inner: Type = syn::parse2(TokenStream::from_str(inner).unwrap()).unwrap();
             let inner = BridgedType::new_with_type(&inner, types)?;
 
             return Some(BridgedType::StdLib(StdLibType::Option(BridgedOption {
@@ -853,13 +907,32 @@ impl BridgedType {
             return Some(BridgedType::StdLib(StdLibType::Result(
                 BuiltInResult::from_str_tokens(&tokens, types)?,
             )));
-        } else if tokens.starts_with("Box < dyn FnOnce") {
-            return Some(BridgedType::StdLib(StdLibType::BoxedFnOnce(
-                BridgeableBoxedFnOnce::from_str_tokens(&tokens, types)?,
+        } else if BuiltInMap::can_parse_str_tokens(tokens) {
+            return Some(BridgedType::StdLib(StdLibType::Map(
+                BuiltInMap::from_str_tokens(tokens, types)?,
+            )));
+        } else if BuiltInSet::can_parse_str_tokens(tokens) {
+            return Some(BridgedType::StdLib(StdLibType::Set(
+                BuiltInSet::from_str_tokens(tokens, types)?,
+            )));
+        } else if BridgedTraitObject::can_parse_token_stream_str(tokens)
+            && BridgedTraitObject::parse_token_stream_str(tokens, types).is_some()
+        {
+            return BridgedTraitObject::parse_token_stream_str(tokens, types)
+                .map(|trait_object| BridgedType::Bridgeable(Box::new(trait_object)));
+        } else if OpaqueForeignType::can_parse_arc_str_tokens(tokens) {
+            return OpaqueForeignType::arc_from_str_tokens(tokens, types)
+                .map(|arc| BridgedType::Bridgeable(Box::new(arc)));
+        } else if BridgeableBoxedFn::can_parse_str_tokens(tokens) {
+            return Some(BridgedType::StdLib(StdLibType::BoxedFn(
+                BridgeableBoxedFn::from_str_tokens(&tokens, types)?,
             )));
-        } else if tokens.starts_with("(") {
-            let tuple: Type = syn::parse2(TokenStream::from_str(&tokens).unwrap()).unwrap();
-            return BridgedType::new_with_type(&tuple, types);
+        } else if BridgedBox::can_parse_token_stream_str(tokens) {
+            return BridgedBox::parse_token_stream_str(tokens, types)
+                .map(|boxed| BridgedType::Bridgeable(Box::new(boxed)));
+        } else if tokens.starts_with("(") || tokens.starts_with("[") {
+            let ty: Type = syn::parse2(TokenStream::from_str(&tokens).unwrap()).unwrap();
+            return BridgedType::new_with_type(&ty, types);
         }
 
         let ty = match tokens {
@@ -871,11 +944,14 @@ impl BridgedType {
             "i32" => BridgedType::StdLib(StdLibType::I32),
             "u64" => BridgedType::StdLib(StdLibType::U64),
             "i64" => BridgedType::StdLib(StdLibType::I64),
+            "u128" => BridgedType::StdLib(StdLibType::U128),
+            "i128" => BridgedType::StdLib(StdLibType::I128),
             "usize" => BridgedType::StdLib(StdLibType::Usize),
             "isize" => BridgedType::StdLib(StdLibType::Isize),
             "f32" => BridgedType::StdLib(StdLibType::F32),
             "f64" => BridgedType::StdLib(StdLibType::F64),
             "bool" => BridgedType::StdLib(StdLibType::Bool),
+            "char" => BridgedType::StdLib(StdLibType::Char),
             "()" => BridgedType::StdLib(StdLibType::Null),
             _ => {
                 if let Some(b) = bridgeable_type_from_token_stream_str(tokens, types) {
@@ -914,6 +990,9 @@ impl BridgedType {
                 StdLibType::F32 => quote! { f32 },
                 StdLibType::F64 => quote! { f64 },
                 StdLibType::Bool => quote! { bool },
+                StdLibType::Char => quote! { char },
+                StdLibType::U128 => quote! { u128 },
+                StdLibType::I128 => quote! { i128 },
                 StdLibType::Pointer(ptr) => ptr.to_rust_type_path(types),
                 StdLibType::RefSlice(ref_slice) => {
                     let ty = ref_slice.ty.to_rust_type_path(types);
@@ -924,35 +1003,43 @@ impl BridgedType {
                     let ty = v.ty.to_rust_type_path(types);
                     quote! { Vec<#ty> }
                 }
+                StdLibType::Array(array) => array.to_rust_type_path(types),
+                StdLibType::Map(map) => map.to_rust_type_path(types),
+                StdLibType::Set(set) => set.to_rust_type_path(types),
                 StdLibType::Option(opt) => {
                     let ty = opt.ty.to_rust_type_path(types);
                     quote! { Option<#ty> }
                 }
                 StdLibType::Result(result) => result.to_rust_type_path(types),
-                StdLibType::BoxedFnOnce(fn_once) => fn_once.to_rust_type_path(types),
+                StdLibType::BoxedFn(fn_once) => fn_once.to_rust_type_path(types),
                 StdLibType::Tuple(tuple) => tuple.to_rust_type_path(types),
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
                 let ty_name = &shared_struct.name;
+                let lifetimes = shared_struct.static_lifetime_generics();
+                let generics = shared_struct
+                    .generics
+                    .angle_bracketed_concrete_rust_types_tokens(types);
                 if shared_struct.already_declared {
                     quote! {
-                        super::#ty_name
+                        super::#ty_name #lifetimes #generics
                     }
                 } else {
                     quote! {
-                        #ty_name
+                        #ty_name #lifetimes #generics
                     }
                 }
             }
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Enum(shared_enum))) => {
                 let enum_name = &shared_enum.name;
+                let generics = shared_enum.ty_generics(types);
                 if shared_enum.already_declared {
                     quote! {
-                        super::#enum_name
+                        super::#enum_name #generics
                     }
                 } else {
                     quote! {
-                        #enum_name
+                        #enum_name #generics
                     }
                 }
             }
@@ -985,6 +1072,9 @@ impl BridgedType {
                 StdLibType::Usize => quote! { usize },
                 StdLibType::Isize => quote! { isize },
                 StdLibType::Bool => quote! { bool },
+                StdLibType::Char => quote! { u32 },
+                StdLibType::U128 => quote! { #swift_bridge_path::int128::FfiU128 },
+                StdLibType::I128 => quote! { #swift_bridge_path::int128::FfiI128 },
                 StdLibType::Pointer(ptr) => {
                     ptr.to_ffi_compatible_rust_type(swift_bridge_path, types)
                 }
@@ -1000,9 +1090,12 @@ impl BridgedType {
                 StdLibType::Null => {
                     quote! { () }
                 }
-                StdLibType::Vec(ty) => {
-                    let ty = ty.ty.to_rust_type_path(types);
-                    quote! { *mut Vec<#ty> }
+                StdLibType::Vec(vec) => vec.to_ffi_compatible_rust_type(swift_bridge_path, types),
+                StdLibType::Map(map) => map.to_ffi_compatible_rust_type(swift_bridge_path, types),
+                StdLibType::Set(set) => set.to_ffi_compatible_rust_type(swift_bridge_path, types),
+                StdLibType::Option(opt) if opt.is_nested_container() => {
+                    let ty = opt.nested_ffi_rust_name(types);
+                    quote! { #ty }
                 }
                 StdLibType::Option(opt) => match opt.ty.deref() {
                     BridgedType::Bridgeable(b) => {
@@ -1051,11 +1144,14 @@ impl BridgedType {
                         StdLibType::Bool => {
                             quote! { #swift_bridge_path::option::OptionBool }
                         }
-                        StdLibType::Pointer(_) => {
-                            todo!("Option<*const T> and Option<*mut T> are not yet supported")
+                        StdLibType::Char => {
+                            quote! { #swift_bridge_path::option::OptionChar }
                         }
-                        StdLibType::RefSlice(_) => {
-                            todo!("Option<&[T]> is not yet supported")
+                        StdLibType::U128 => {
+                            quote! { #swift_bridge_path::option::OptionU128 }
+                        }
+                        StdLibType::I128 => {
+                            quote! { #swift_bridge_path::option::OptionI128 }
                         }
                         StdLibType::Str => {
                             quote! { #swift_bridge_path::string::RustStr }
@@ -1064,16 +1160,21 @@ impl BridgedType {
                             let ty = ty.ty.to_rust_type_path(types);
                             quote! { *mut Vec<#ty> }
                         }
-                        StdLibType::Option(_) => {
-                            todo!("Option<Option<T>> is not yet supported")
+                        StdLibType::Set(set) => {
+                            set.to_ffi_compatible_rust_type(swift_bridge_path, types)
                         }
-                        StdLibType::Result(_) => {
-                            todo!("Option<Result<T, E>> is not yet supported")
+                        StdLibType::BoxedFn(_) => {
+                            unreachable!("Boxed functions can only be function arguments")
                         }
-                        StdLibType::BoxedFnOnce(_) => {
-                            todo!("Support Box<dyn FnOnce(A, B) -> C>")
+                        StdLibType::Pointer(_)
+                        | StdLibType::RefSlice(_)
+                        | StdLibType::Map(_)
+                        | StdLibType::Option(_)
+                        | StdLibType::Result(_)
+                        | StdLibType::Tuple(_)
+                        | StdLibType::Array(_) => {
+                            unreachable!("Nested containers are handled above")
                         }
-                        StdLibType::Tuple(_) => todo!(),
                     },
                     BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(
                         shared_struct,
@@ -1091,19 +1192,21 @@ impl BridgedType {
                 StdLibType::Result(result) => {
                     result.to_ffi_compatible_rust_type(swift_bridge_path, types)
                 }
-                StdLibType::BoxedFnOnce(fn_once) => fn_once.to_ffi_compatible_rust_type(types),
+                StdLibType::BoxedFn(fn_once) => fn_once.to_ffi_compatible_rust_type(types),
                 StdLibType::Tuple(tuple) => {
                     tuple.to_ffi_compatible_rust_type(swift_bridge_path, types)
                 }
+                StdLibType::Array(array) => array.to_ffi_compatible_rust_type(types),
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
-                shared_struct.type_name_with_swift_bridge_prefix(swift_bridge_path)
+                shared_struct.type_name_with_swift_bridge_prefix(swift_bridge_path, types)
             }
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Enum(shared_enum))) => {
                 let ty_name = &shared_enum.name;
+                let generics = shared_enum.ty_generics(types);
 
                 if shared_enum.already_declared {
-                    quote! { <super:: #ty_name as #swift_bridge_path::SharedEnum>::FfiRepr }
+                    quote! { <super:: #ty_name #generics as #swift_bridge_path::SharedEnum>::FfiRepr }
                 } else {
                     let ffi_ty_name = shared_enum.ffi_name_tokens();
                     quote! { #ffi_ty_name }
@@ -1139,6 +1242,36 @@ impl BridgedType {
                 StdLibType::Usize => "UInt".to_string(),
                 StdLibType::Isize => "Int".to_string(),
                 StdLibType::Bool => "Bool".to_string(),
+                StdLibType::Char => match type_pos {
+                    TypePosition::FnArg(func_host_lang, _)
+                    | TypePosition::FnReturn(func_host_lang)
+                        if func_host_lang.is_swift() =>
+                    {
+                        "UInt32".to_string()
+                    }
+                    TypePosition::ResultFfiReturnType => "UInt32".to_string(),
+                    _ => "Unicode.Scalar".to_string(),
+                },
+                StdLibType::U128 => match type_pos {
+                    TypePosition::FnArg(func_host_lang, _)
+                    | TypePosition::FnReturn(func_host_lang)
+                        if func_host_lang.is_swift() =>
+                    {
+                        "__private__FfiU128".to_string()
+                    }
+                    TypePosition::ResultFfiReturnType => "__private__FfiU128".to_string(),
+                    _ => "UInt128".to_string(),
+                },
+                StdLibType::I128 => match type_pos {
+                    TypePosition::FnArg(func_host_lang, _)
+                    | TypePosition::FnReturn(func_host_lang)
+                        if func_host_lang.is_swift() =>
+                    {
+                        "__private__FfiI128".to_string()
+                    }
+                    TypePosition::ResultFfiReturnType => "__private__FfiI128".to_string(),
+                    _ => "Int128".to_string(),
+                },
                 StdLibType::Pointer(ptr) => {
                     let maybe_mutable = match ptr.kind {
                         PointerKind::Const => "",
@@ -1158,29 +1291,29 @@ impl BridgedType {
                         }
                     }
                 }
-                StdLibType::RefSlice(slice) => {
-                    match type_pos {
-                        TypePosition::FnArg(func_host_lang, _)
-                        | TypePosition::FnReturn(func_host_lang) => {
-                            if func_host_lang.is_swift() {
-                                "__private__FfiSlice".to_string()
-                            } else {
-                                format!(
-                                    "UnsafeBufferPointer<{}>",
-                                    slice.ty.to_swift_type(type_pos, types, swift_bridge_path)
-                                )
-                            }
-                        }
-                        TypePosition::SharedStructField => {
-                            //
-                            unimplemented!()
-                        }
-                        TypePosition::ResultFfiReturnType => {
-                            unimplemented!()
+                StdLibType::RefSlice(slice) => match type_pos {
+                    TypePosition::FnArg(func_host_lang, _)
+                    | TypePosition::FnReturn(func_host_lang) => {
+                        if func_host_lang.is_swift() {
+                            "__private__FfiSlice".to_string()
+                        } else {
+                            format!(
+                                "UnsafeBufferPointer<{}>",
+                                slice.ty.to_swift_type(type_pos, types, swift_bridge_path)
+                            )
                         }
-                        TypePosition::ThrowingInit(_) => unimplemented!(),
                     }
-                }
+                    TypePosition::SharedStructField => {
+                        format!(
+                            "UnsafeBufferPointer<{}>",
+                            slice.ty.to_swift_type(type_pos, types, swift_bridge_path)
+                        )
+                    }
+                    TypePosition::ResultFfiReturnType => {
+                        unimplemented!()
+                    }
+                    TypePosition::ThrowingInit(_) => unimplemented!(),
+                },
                 StdLibType::Null => "()".to_string(),
                 StdLibType::Str => match type_pos {
                     TypePosition::FnArg(func_host_lang, _) => {
@@ -1197,53 +1330,32 @@ impl BridgedType {
                     }
                     TypePosition::ThrowingInit(_) => unimplemented!(),
                 },
-                StdLibType::Vec(ty) => match type_pos {
-                    TypePosition::FnArg(func_host_lang, _) => {
-                        if func_host_lang.is_rust() {
-                            format!(
-                                "RustVec<{}>",
-                                ty.ty.to_swift_type(type_pos, types, swift_bridge_path)
-                            )
-                        } else {
-                            "UnsafeMutableRawPointer".to_string()
-                        }
-                    }
-                    TypePosition::FnReturn(func_host_lang) => {
-                        if func_host_lang.is_rust() {
-                            format!(
-                                "RustVec<{}>",
-                                ty.ty.to_swift_type(type_pos, types, swift_bridge_path)
-                            )
-                        } else {
-                            "UnsafeMutableRawPointer".to_string()
-                        }
-                    }
-                    TypePosition::ThrowingInit(_) => unimplemented!(),
-                    _ => {
-                        format!(
-                            "RustVec<{}>",
-                            ty.ty.to_swift_type(type_pos, types, swift_bridge_path)
-                        )
-                    }
-                },
+                StdLibType::Vec(vec) => vec.to_swift_type(type_pos, types, swift_bridge_path),
+                StdLibType::Map(map) => map.to_swift_type(type_pos, types, swift_bridge_path),
+                StdLibType::Set(set) => set.to_swift_type(type_pos, types, swift_bridge_path),
                 StdLibType::Option(opt) => opt.to_swift_type(swift_bridge_path, type_pos, types),
                 StdLibType::Result(result) => {
                     result.to_swift_type(type_pos, types, swift_bridge_path)
                 }
-                StdLibType::BoxedFnOnce(boxed_fn) => boxed_fn.to_swift_type().to_string(),
+                StdLibType::BoxedFn(boxed_fn) => {
+                    boxed_fn.to_swift_type(type_pos, types, swift_bridge_path)
+                }
                 StdLibType::Tuple(tuple) => tuple.to_swift_type(type_pos, types, swift_bridge_path),
+                StdLibType::Array(array) => array.to_swift_type(type_pos, types, swift_bridge_path),
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
                 match type_pos {
                     TypePosition::FnArg(func_host_lang, _)
                     | TypePosition::FnReturn(func_host_lang) => {
                         if func_host_lang.is_rust() {
-                            shared_struct.swift_name_string()
+                            shared_struct.swift_type_string(types, swift_bridge_path)
                         } else {
                             shared_struct.ffi_name_string()
                         }
                     }
-                    TypePosition::SharedStructField => shared_struct.swift_name_string(),
+                    TypePosition::SharedStructField => {
+                        shared_struct.swift_type_string(types, swift_bridge_path)
+                    }
                     TypePosition::ResultFfiReturnType => shared_struct.ffi_name_string(),
                     TypePosition::ThrowingInit(_) => unimplemented!(),
                 }
@@ -1253,12 +1365,14 @@ impl BridgedType {
                     TypePosition::FnArg(func_host_lang, _)
                     | TypePosition::FnReturn(func_host_lang) => {
                         if func_host_lang.is_rust() {
-                            shared_enum.swift_name_string()
+                            shared_enum.swift_type_string(types, swift_bridge_path)
                         } else {
                             shared_enum.ffi_name_string()
                         }
                     }
-                    TypePosition::SharedStructField => shared_enum.swift_name_string(),
+                    TypePosition::SharedStructField => {
+                        shared_enum.swift_type_string(types, swift_bridge_path)
+                    }
                     TypePosition::ResultFfiReturnType => {
                         unimplemented!()
                     }
@@ -1285,6 +1399,9 @@ impl BridgedType {
                 StdLibType::Usize => "uintptr_t".to_string(),
                 StdLibType::Isize => "intptr_t".to_string(),
                 StdLibType::Bool => "bool".to_string(),
+                StdLibType::Char => "uint32_t".to_string(),
+                StdLibType::U128 => "struct __private__FfiU128".to_string(),
+                StdLibType::I128 => "struct __private__FfiI128".to_string(),
                 StdLibType::Pointer(ptr) => {
                     let maybe_const = match ptr.kind {
                         PointerKind::Const => " const ",
@@ -1301,11 +1418,14 @@ impl BridgedType {
                 StdLibType::RefSlice(_slice) => "struct __private__FfiSlice".to_string(),
                 StdLibType::Str => "struct RustStr".to_string(),
                 StdLibType::Null => "void".to_string(),
-                StdLibType::Vec(_) => "void*".to_string(),
-                StdLibType::Option(opt) => opt.to_c(),
+                StdLibType::Vec(vec) => vec.to_c(),
+                StdLibType::Map(map) => map.to_c(),
+                StdLibType::Set(set) => set.to_c(),
+                StdLibType::Option(opt) => opt.to_c(types),
                 StdLibType::Result(result) => result.to_c(types).to_string(),
-                StdLibType::BoxedFnOnce(_) => "void*".to_string(),
+                StdLibType::BoxedFn(_) => "void*".to_string(),
                 StdLibType::Tuple(tuple) => tuple.to_c_type(types),
+                StdLibType::Array(array) => array.to_c(types),
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
                 format!("struct {}", shared_struct.ffi_name_string())
@@ -1380,6 +1500,15 @@ impl BridgedType {
                 | StdLibType::Bool => {
                     quote! { #expression }
                 }
+                StdLibType::Char => {
+                    quote! { #expression as u32 }
+                }
+                StdLibType::U128 => {
+                    quote! { #swift_bridge_path::int128::FfiU128::from(#expression) }
+                }
+                StdLibType::I128 => {
+                    quote! { #swift_bridge_path::int128::FfiI128::from(#expression) }
+                }
                 StdLibType::Pointer(_) => {
                     quote! {
                         #expression
@@ -1395,19 +1524,31 @@ impl BridgedType {
                         #swift_bridge_path::string::RustStr::from_str( #expression )
                     }
                 }
-                StdLibType::Vec(_) => {
-                    quote! { Box::into_raw(Box::new( #expression )) }
+                StdLibType::Vec(vec) => vec.convert_rust_expression_to_ffi_type(
+                    expression,
+                    swift_bridge_path,
+                    types,
+                    span,
+                ),
+                StdLibType::Map(map) => {
+                    map.convert_rust_expression_to_ffi_type(expression, swift_bridge_path, types)
                 }
-                StdLibType::Option(opt) => {
-                    opt.convert_rust_expression_to_ffi_type(expression, swift_bridge_path)
+                StdLibType::Set(set) => {
+                    set.convert_rust_expression_to_ffi_type(expression, swift_bridge_path, types)
                 }
+                StdLibType::Option(opt) => opt.convert_rust_expression_to_ffi_type(
+                    expression,
+                    swift_bridge_path,
+                    types,
+                    span,
+                ),
                 StdLibType::Result(result) => result.convert_rust_expression_to_ffi_type(
                     expression,
                     swift_bridge_path,
                     types,
                     span,
                 ),
-                StdLibType::BoxedFnOnce(fn_once) => {
+                StdLibType::BoxedFn(fn_once) => {
                     fn_once.convert_rust_value_to_ffi_compatible_value(expression, types)
                 }
                 StdLibType::Tuple(tuple) => tuple.convert_rust_expression_to_ffi_type(
@@ -1416,6 +1557,12 @@ impl BridgedType {
                     types,
                     span,
                 ),
+                StdLibType::Array(array) => array.convert_rust_expression_to_ffi_type(
+                    expression,
+                    swift_bridge_path,
+                    types,
+                    span,
+                ),
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
                 shared_struct.convert_rust_expression_to_ffi_type(expression)
@@ -1470,6 +1617,19 @@ impl BridgedType {
                 | StdLibType::Bool => {
                     quote_spanned! {span=> #value }
                 }
+                // Swift's `Unicode.Scalar` is always a valid `char`, but we never trust the value
+                // that we receive over FFI since an invalid `char` is undefined behavior.
+                StdLibType::Char => {
+                    quote_spanned! {span=>
+                        char::from_u32(#value).unwrap_or(char::REPLACEMENT_CHARACTER)
+                    }
+                }
+                StdLibType::U128 => {
+                    quote_spanned! {span=> u128::from(#value) }
+                }
+                StdLibType::I128 => {
+                    quote_spanned! {span=> i128::from(#value) }
+                }
                 StdLibType::Pointer(_) => {
                     quote_spanned! {span=> #value }
                 }
@@ -1479,23 +1639,28 @@ impl BridgedType {
                 StdLibType::Str => {
                     quote_spanned! {span=> #value.to_str() }
                 }
-                StdLibType::Vec(_) => {
-                    quote_spanned! {span=>
-                        unsafe { * Box::from_raw(#value) }
-                    }
-                }
-                StdLibType::Option(bridged_option) => {
-                    bridged_option.convert_ffi_expression_to_rust_type(value)
+                StdLibType::Vec(vec) => {
+                    vec.convert_ffi_expression_to_rust_type(value, span, swift_bridge_path, types)
                 }
+                StdLibType::Map(map) => map.convert_ffi_expression_to_rust_type(value, types),
+                StdLibType::Set(set) => set.convert_ffi_expression_to_rust_type(value, types),
+                StdLibType::Option(bridged_option) => bridged_option
+                    .convert_ffi_expression_to_rust_type(value, span, swift_bridge_path, types),
                 StdLibType::Result(result) => {
                     result.convert_ffi_value_to_rust_value(value, span, swift_bridge_path, types)
                 }
-                StdLibType::BoxedFnOnce(_) => {
-                    todo!("Support Box<dyn FnOnce(A, B) -> C>")
+                StdLibType::BoxedFn(_) => {
+                    // Swift closures are converted by
+                    // `BridgeableBoxedFn::convert_swift_callback_to_rust_boxed_fn`, since it needs
+                    // to know the names of the functions that call and free the closure.
+                    unreachable!("Swift closures are converted when generating the call's args")
                 }
                 StdLibType::Tuple(tuple) => {
                     tuple.convert_ffi_expression_to_rust_type(value, span, swift_bridge_path, types)
                 }
+                StdLibType::Array(array) => {
+                    array.convert_ffi_expression_to_rust_type(value, span, swift_bridge_path, types)
+                }
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
                 shared_struct.convert_ffi_expression_to_rust_type(value, span)
@@ -1551,6 +1716,9 @@ impl BridgedType {
                 | StdLibType::F32
                 | StdLibType::F64
                 | StdLibType::Bool => expression.to_string(),
+                StdLibType::Char => format!("Unicode.Scalar({})!", expression),
+                StdLibType::U128 => format!("UInt128(ffiRepr: {})", expression),
+                StdLibType::I128 => format!("Int128(ffiRepr: {})", expression),
                 StdLibType::Pointer(ptr) => match &ptr.pointee {
                     Pointee::BuiltIn(_) => expression.to_string(),
                     Pointee::Void(_ty) => match ptr.kind {
@@ -1577,32 +1745,42 @@ impl BridgedType {
                     },
                 },
                 StdLibType::RefSlice(ty) => {
-                    format!(
+                    let convert = format!(
                         "let slice = {value}; return UnsafeBufferPointer(start: slice.start.assumingMemoryBound(to: {ty}.self), count: Int(slice.len));",
                         value = expression,
                         ty = ty.ty.to_swift_type(type_pos,types,swift_bridge_path)
-                       )
+                       );
+
+                    match type_pos {
+                        TypePosition::SharedStructField => format!("{{ {} }}()", convert),
+                        _ => convert,
+                    }
                 }
                 StdLibType::Str => expression.to_string(),
-                StdLibType::Vec(_ty) => {
-                    format!("RustVec(ptr: {})", expression)
+                StdLibType::Vec(vec) => {
+                    vec.convert_ffi_expression_to_swift_type(expression, types, swift_bridge_path)
+                }
+                StdLibType::Map(map) => map.convert_ffi_expression_to_swift_type(expression),
+                StdLibType::Set(set) => set.convert_ffi_expression_to_swift_type(expression),
+                StdLibType::Option(opt) => {
+                    opt.convert_ffi_expression_to_swift_type(expression, types, swift_bridge_path)
                 }
-                StdLibType::Option(opt) => opt.convert_ffi_expression_to_swift_type(expression),
                 StdLibType::Result(result) => result.convert_ffi_value_to_swift_value(
                     expression,
                     type_pos,
                     types,
                     swift_bridge_path,
                 ),
-                StdLibType::BoxedFnOnce(fn_once) => {
-                    fn_once.convert_ffi_value_to_swift_value(type_pos)
-                }
+                StdLibType::BoxedFn(fn_once) => fn_once.convert_ffi_value_to_swift_value(type_pos),
                 StdLibType::Tuple(tuple) => tuple.convert_ffi_expression_to_swift_type(
                     expression,
                     type_pos,
                     types,
                     swift_bridge_path,
                 ),
+                StdLibType::Array(array) => {
+                    array.convert_ffi_expression_to_swift_type(expression, types, swift_bridge_path)
+                }
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
                 shared_struct.convert_ffi_expression_to_swift_type(expression)
@@ -1645,6 +1823,8 @@ impl BridgedType {
                 | StdLibType::F32
                 | StdLibType::F64
                 | StdLibType::Bool => expression.to_string(),
+                StdLibType::Char => format!("{}.value", expression),
+                StdLibType::U128 | StdLibType::I128 => format!("{}.intoFfiRepr()", expression),
                 StdLibType::RefSlice(_) => {
                     format!("{}.toFfiSlice()", expression)
                 }
@@ -1660,7 +1840,11 @@ impl BridgedType {
                             }
                         }
                         TypePosition::SharedStructField => {
-                            todo!("Pointers in shared struct fields are not yet supported")
+                            if ptr.kind == PointerKind::Const {
+                                format!("UnsafeMutableRawPointer(mutating: {})", expression)
+                            } else {
+                                expression.to_string()
+                            }
                         }
                         TypePosition::ResultFfiReturnType => {
                             unimplemented!()
@@ -1677,32 +1861,30 @@ impl BridgedType {
                             expression.to_string()
                         }
                     }
-                    TypePosition::SharedStructField => {
-                        todo!("&str in shared struct fields is not yet supported")
-                    }
+                    TypePosition::SharedStructField => expression.to_string(),
                     TypePosition::ResultFfiReturnType => {
                         unimplemented!()
                     }
                     TypePosition::ThrowingInit(_) => unimplemented!(),
                 },
-                StdLibType::Vec(_) => {
-                    format!(
-                        "{{ let val = {value}; val.isOwned = false; return val.ptr }}()",
-                        value = expression
-                    )
-                }
+                StdLibType::Vec(vec) => vec.convert_swift_expression_to_ffi_type(expression, types),
+                StdLibType::Map(map) => map.convert_swift_expression_to_ffi_type(expression),
+                StdLibType::Set(set) => set.convert_swift_expression_to_ffi_type(expression),
                 StdLibType::Option(option) => {
-                    option.convert_swift_expression_to_ffi_type(expression, type_pos)
+                    option.convert_swift_expression_to_ffi_type(expression, types, type_pos)
                 }
                 StdLibType::Result(result) => {
                     result.convert_swift_expression_to_ffi_compatible(expression, types, type_pos)
                 }
-                StdLibType::BoxedFnOnce(_) => {
-                    todo!("Support Box<dyn FnOnce(A, B) -> C>")
+                StdLibType::BoxedFn(boxed_fn) => {
+                    boxed_fn.convert_swift_closure_to_ffi_type(expression)
                 }
                 StdLibType::Tuple(tuple) => {
                     tuple.convert_swift_expression_to_ffi_type(expression, types, type_pos)
                 }
+                StdLibType::Array(array) => {
+                    array.convert_swift_expression_to_ffi_type(expression, types)
+                }
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
                 shared_struct.convert_swift_expression_to_ffi_type(expression)
@@ -1780,7 +1962,10 @@ impl BridgedType {
                 | StdLibType::U64
                 | StdLibType::I64
                 | StdLibType::Usize
-                | StdLibType::Isize => Some(vec!["stdint.h"]),
+                | StdLibType::Isize
+                | StdLibType::Char
+                | StdLibType::U128
+                | StdLibType::I128 => Some(vec!["stdint.h"]),
                 StdLibType::Bool => Some(vec!["stdbool.h"]),
                 StdLibType::Pointer(ptr) => match &ptr.pointee {
                     Pointee::BuiltIn(ty) => ty.to_c_include(types),
@@ -1788,7 +1973,9 @@ impl BridgedType {
                 },
                 StdLibType::RefSlice(slice) => slice.ty.to_c_include(types),
                 StdLibType::Vec(_vec) => Some(vec!["stdint.h"]),
+                StdLibType::Set(_set) => Some(vec!["stdint.h"]),
                 StdLibType::Tuple(tuple) => tuple.to_c_include(types),
+                StdLibType::Array(array) => array.to_c_include(types),
                 _ => None,
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(_shared_struct))) => {
@@ -1833,12 +2020,18 @@ impl BridgedType {
                     rust: quote! { bool },
                     swift: "bool".into(),
                 },
-                StdLibType::Pointer(_) => {
-                    todo!("Support Option<*const T> and Option<*mut T>")
-                }
-                StdLibType::RefSlice(_) => {
-                    todo!("Support Option<&[T]>")
-                }
+                StdLibType::Char => UnusedOptionNoneValue {
+                    rust: quote! { 0 },
+                    swift: "0".into(),
+                },
+                StdLibType::U128 => UnusedOptionNoneValue {
+                    rust: quote! { #swift_bridge_path::int128::FfiU128 { low: 0, high: 0 } },
+                    swift: "__private__FfiU128(low: 0, high: 0)".into(),
+                },
+                StdLibType::I128 => UnusedOptionNoneValue {
+                    rust: quote! { #swift_bridge_path::int128::FfiI128 { low: 0, high: 0 } },
+                    swift: "__private__FfiI128(low: 0, high: 0)".into(),
+                },
                 StdLibType::Str => {
                     UnusedOptionNoneValue {
                         rust: quote! {
@@ -1853,16 +2046,30 @@ impl BridgedType {
                 StdLibType::Vec(_) => {
                     todo!("Support Option<Vec<T>>")
                 }
+                // `Option<HashSet<T>>` crosses the FFI boundary as a nullable pointer.
+                StdLibType::Set(_) => UnusedOptionNoneValue {
+                    rust: quote! { std::ptr::null_mut() },
+                    swift: "nil".into(),
+                },
                 StdLibType::Option(_) => {
                     todo!("Support nested Option<Option<T>>")
                 }
                 StdLibType::Result(_) => {
                     todo!("Result<T, E> is not yet supported")
                 }
-                StdLibType::BoxedFnOnce(_) => {
-                    todo!("Support Box<dyn FnOnce(A, B) -> C>")
+                StdLibType::BoxedFn(_) => {
+                    unreachable!("Boxed functions can only be function arguments")
                 }
                 StdLibType::Tuple(_tuple) => todo!(),
+                StdLibType::Pointer(_) | StdLibType::RefSlice(_) => {
+                    unreachable!("Option<*const T> and Option<&[T]> are nested containers")
+                }
+                StdLibType::Map(_) => {
+                    unreachable!("Option<HashMap<K, V>> is handled as a nested container")
+                }
+                StdLibType::Array(_) => {
+                    unreachable!("Option<[T; N]> is handled as a nested container")
+                }
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
                 let option_name = shared_struct.ffi_option_name_tokens();
@@ -1887,7 +2094,10 @@ impl BridgedType {
         match self {
             BridgedType::Bridgeable(b) => b.contains_owned_string_recursive(types),
             BridgedType::StdLib(stdlib_type) => match stdlib_type {
-                StdLibType::Vec(inner) => inner.ty.contains_owned_string_recursive(types),
+                // Vector elements and nested containers are seen by Swift as `RustString`s, so
+                // they don't need the `GenericIntoRustString` generic.
+                StdLibType::Vec(_) => false,
+                StdLibType::Option(inner) if inner.is_nested_container() => false,
                 StdLibType::Option(inner) => inner.ty.contains_owned_string_recursive(types),
                 StdLibType::Result(inner) => {
                     inner.ok_ty.contains_owned_string_recursive(types)
@@ -1907,6 +2117,82 @@ impl BridgedType {
         }
     }
 
+    /// Whether or not the type is an `i128` or `u128`, or a type that contains one such as
+    /// `Option<u128>` or `Vec<i128>`.
+    ///
+    /// Swift's `Int128` and `UInt128` are only available on newer OS versions, so the Swift
+    /// functions that use them need an `@available` attribute.
+    pub fn contains_128_bit_integer_recursive(&self) -> bool {
+        match self {
+            BridgedType::StdLib(stdlib_type) => match stdlib_type {
+                StdLibType::I128 | StdLibType::U128 => true,
+                StdLibType::Vec(inner) => inner.ty.contains_128_bit_integer_recursive(),
+                StdLibType::Option(inner) => inner.ty.contains_128_bit_integer_recursive(),
+                StdLibType::Result(inner) => {
+                    inner.ok_ty.contains_128_bit_integer_recursive()
+                        || inner.err_ty.contains_128_bit_integer_recursive()
+                }
+                StdLibType::Map(map) => {
+                    map.key.contains_128_bit_integer_recursive()
+                        || map.value.contains_128_bit_integer_recursive()
+                }
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Whether or not converting the type's FFI representation into the Rust type takes ownership
+    /// of a Rust value, such as the boxed `String` that a `RustString` points to.
+    ///
+    /// Primitives, pointers, `&str` and `&[T]`, and shared types whose fields are all made of
+    /// these, are plain data that can be copied across the FFI boundary.
+    pub fn ffi_repr_owns_rust_values(&self, types: &TypeDeclarations) -> bool {
+        match self {
+            BridgedType::StdLib(stdlib_type) => match stdlib_type {
+                StdLibType::Null
+                | StdLibType::U8
+                | StdLibType::I8
+                | StdLibType::U16
+                | StdLibType::I16
+                | StdLibType::U32
+                | StdLibType::I32
+                | StdLibType::U64
+                | StdLibType::I64
+                | StdLibType::U128
+                | StdLibType::I128
+                | StdLibType::Usize
+                | StdLibType::Isize
+                | StdLibType::F32
+                | StdLibType::F64
+                | StdLibType::Bool
+                | StdLibType::Char
+                | StdLibType::Pointer(_)
+                | StdLibType::RefSlice(_)
+                | StdLibType::Str => false,
+                StdLibType::Option(inner) => inner.ty.ffi_repr_owns_rust_values(types),
+                StdLibType::Array(inner) => inner.ty.ffi_repr_owns_rust_values(types),
+                _ => true,
+            },
+            BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
+                shared_struct.fields.ffi_repr_owns_rust_values(types)
+            }
+            BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Enum(shared_enum))) => {
+                shared_enum
+                    .variants
+                    .iter()
+                    .any(|variant| variant.fields.ffi_repr_owns_rust_values(types))
+            }
+            BridgedType::Bridgeable(_) => true,
+        }
+    }
+
+    /// Whether or not the generated Swift function takes this argument as a generic type, such as
+    /// `GenericToRustStr` for a `&str`.
+    pub fn is_generic_swift_arg(&self, types: &TypeDeclarations) -> bool {
+        self.contains_owned_string_recursive(types) || self.contains_ref_string_recursive()
+    }
+
     /// Whether or not the type is a `&str`, or a type that contains a &str such as
     /// `Option<&str>` or `struct Foo { field: &'static str } `
     pub fn contains_ref_string_recursive(&self) -> bool {
@@ -1930,10 +2216,17 @@ impl BridgedType {
     }
 
     /// Convert a rust expression into this type using
-    pub fn rust_expression_into(&self, expression: &TokenStream) -> TokenStream {
+    pub fn rust_expression_into(
+        &self,
+        expression: &TokenStream,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
         match self {
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
                 let struct_name = &shared_struct.name;
+                let generics = shared_struct
+                    .generics
+                    .angle_bracketed_concrete_rust_types_tokens(types);
 
                 let maybe_super = if shared_struct.already_declared {
                     quote! { super:: }
@@ -1942,11 +2235,12 @@ impl BridgedType {
                 };
 
                 quote! {
-                    { let val: #maybe_super #struct_name = #expression.into(); val }
+                    { let val: #maybe_super #struct_name #generics = #expression.into(); val }
                 }
             }
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Enum(shared_enum))) => {
                 let enum_name = &shared_enum.name;
+                let generics = shared_enum.ty_generics(types);
 
                 let maybe_super = if shared_enum.already_declared {
                     quote! { super:: }
@@ -1955,7 +2249,7 @@ impl BridgedType {
                 };
 
                 quote! {
-                    { let val: #maybe_super #enum_name = #expression.into(); val }
+                    { let val: #maybe_super #enum_name #generics = #expression.into(); val }
                 }
             }
             // TODO: Instead of this catchall.. explicitly match on all variants and use
@@ -1969,26 +2263,74 @@ impl BridgedType {
     pub fn to_alpha_numeric_underscore_name(&self, types: &TypeDeclarations) -> String {
         match self {
             BridgedType::StdLib(ty) => match ty {
-                StdLibType::Result(_ty) => todo!(),
                 StdLibType::Null => "Void".to_string(),
                 StdLibType::U8 => "U8".to_string(),
                 StdLibType::U16 => "U16".to_string(),
                 StdLibType::U32 => "U32".to_string(),
+                StdLibType::U64 => "U64".to_string(),
                 StdLibType::Usize => "UInt".to_string(),
                 StdLibType::I8 => "I8".to_string(),
                 StdLibType::I16 => "I16".to_string(),
                 StdLibType::I32 => "I32".to_string(),
+                StdLibType::I64 => "I64".to_string(),
                 StdLibType::Isize => "Int".to_string(),
                 StdLibType::Bool => "Bool".to_string(),
+                StdLibType::Char => "Char".to_string(),
+                StdLibType::U128 => "U128".to_string(),
+                StdLibType::I128 => "I128".to_string(),
                 StdLibType::F32 => "F32".to_string(),
                 StdLibType::F64 => "F64".to_string(),
+                StdLibType::Str => "Str".to_string(),
+                StdLibType::Vec(vec) => {
+                    format!("Vec{}", vec.ty.to_alpha_numeric_underscore_name(types))
+                }
+                StdLibType::Map(map) => format!(
+                    "HashMap{}And{}",
+                    map.key.to_alpha_numeric_underscore_name(types),
+                    map.value.to_alpha_numeric_underscore_name(types)
+                ),
+                StdLibType::Set(set) => {
+                    format!("HashSet{}", set.ty.to_alpha_numeric_underscore_name(types))
+                }
+                StdLibType::Option(opt) => {
+                    format!("Option{}", opt.ty.to_alpha_numeric_underscore_name(types))
+                }
+                StdLibType::Result(result) => format!(
+                    "Result{}And{}",
+                    result.ok_ty.to_alpha_numeric_underscore_name(types),
+                    result.err_ty.to_alpha_numeric_underscore_name(types)
+                ),
                 StdLibType::Tuple(ty) => ty.to_alpha_numeric_underscore_name(types),
+                StdLibType::Array(array) => array.to_alpha_numeric_underscore_name(types),
+                StdLibType::Pointer(ptr) => {
+                    let kind = match ptr.kind {
+                        PointerKind::Const => "ConstPtr",
+                        PointerKind::Mut => "MutPtr",
+                    };
+                    let pointee = match &ptr.pointee {
+                        Pointee::BuiltIn(ty) => ty.to_alpha_numeric_underscore_name(types),
+                        Pointee::Void(ty) => ty
+                            .to_token_stream()
+                            .to_string()
+                            .chars()
+                            .filter(|c| c.is_alphanumeric() || *c == '_')
+                            .collect(),
+                    };
+                    format!("{kind}{pointee}")
+                }
+                StdLibType::RefSlice(slice) => {
+                    format!("Slice{}", slice.ty.to_alpha_numeric_underscore_name(types))
+                }
                 _ => todo!(),
             },
             BridgedType::Foreign(ty) => match ty {
                 CustomBridgedType::Shared(ty) => match ty {
-                    SharedType::Struct(ty) => ty.name.to_string(),
-                    SharedType::Enum(ty) => ty.name.to_string(),
+                    SharedType::Struct(ty) => {
+                        ty.name.to_string() + &ty.generics.underscore_prefixed_args_string()
+                    }
+                    SharedType::Enum(ty) => {
+                        ty.name.to_string() + &ty.generics.underscore_prefixed_args_string()
+                    }
                 },
             },
             BridgedType::Bridgeable(b) => b.to_alpha_numeric_underscore_name(types),
@@ -2024,7 +2366,7 @@ mod tests {
 
         let parsed = BridgedType::new_with_str(tokens, &TypeDeclarations::default()).unwrap();
         match parsed {
-            BridgedType::StdLib(StdLibType::BoxedFnOnce(_)) => {}
+            BridgedType::StdLib(StdLibType::BoxedFn(_)) => {}
             _ => panic!(),
         };
     }
@@ -6,43 +6,74 @@ use proc_macro2::{Ident, Span, TokenStream};
 use quote::{quote, ToTokens};
 use std::collections::HashSet;
 use std::str::FromStr;
-use syn::parse::{Parse, ParseStream};
-use syn::punctuated::Punctuated;
-use syn::{Path, Type};
+use syn::{GenericArgument, Path, PathArguments, ReturnType, Type, TypeParamBound};
 
-/// Box<dyn FnOnce(A, B, C) -> ()>
+/// Box<dyn FnOnce(A, B, C) -> D>, Box<dyn FnMut(A, B, C) -> D> or Box<dyn Fn(A, B, C) -> D>
 #[derive(Debug)]
-pub(crate) struct BridgeableBoxedFnOnce {
+pub(crate) struct BridgeableBoxedFn {
+    /// Which of the `Fn` traits the boxed function implements.
+    pub kind: BoxedFnKind,
     /// The functions parameters.
     pub params: Vec<BridgedType>,
     /// The functions return type.
     pub ret: Box<BridgedType>,
+    /// Whether the trait object has a `+ Send` bound.
+    pub is_send: bool,
+    /// Whether the trait object has a `+ Sync` bound.
+    pub is_sync: bool,
 }
 
-/// example: Vec<SomeType, AnotherType, u32>
-pub(crate) struct FunctionArguments(pub Vec<Type>);
-impl Parse for FunctionArguments {
-    fn parse(input: ParseStream) -> syn::Result<Self> {
-        let args: Punctuated<Type, syn::Token![,]> = Punctuated::parse_terminated(input)?;
-        Ok(Self(args.into_iter().collect()))
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum BoxedFnKind {
+    FnOnce,
+    FnMut,
+    Fn,
+}
+
+impl BoxedFnKind {
+    fn to_trait_ident(self) -> Ident {
+        let name = match self {
+            BoxedFnKind::FnOnce => "FnOnce",
+            BoxedFnKind::FnMut => "FnMut",
+            BoxedFnKind::Fn => "Fn",
+        };
+        Ident::new(name, Span::call_site())
     }
 }
 
-impl BridgeableBoxedFnOnce {
+impl BridgeableBoxedFn {
     pub fn does_not_have_params_or_return(&self) -> bool {
         self.params.is_empty() && self.ret.is_null()
     }
 
+    /// `Box<dyn FnOnce()>` callbacks that are passed from Rust to Swift are handled by the
+    /// `__private__RustFnOnceCallbackNoArgsNoRet` class in the Swift core library, so we don't
+    /// generate any support code for them.
+    pub fn uses_no_args_no_return_support(&self) -> bool {
+        self.kind == BoxedFnKind::FnOnce && self.does_not_have_params_or_return()
+    }
+
+    /// Whether Swift can call a Rust callback of this type from any thread.
+    ///
+    /// Swift can call an `@Sendable` closure concurrently, so only a `Fn + Send + Sync` callback
+    /// qualifies.
+    pub fn rust_callback_is_sendable(&self) -> bool {
+        self.kind == BoxedFnKind::Fn && self.is_send && self.is_sync
+    }
+
+    /// Whether a Swift closure that gets passed to Rust as this type must be `@Sendable`.
+    ///
+    /// A `+ Send` callback can be moved to another thread, and a `+ Sync` callback can be called
+    /// from several threads at once.
+    pub fn swift_callback_must_be_sendable(&self) -> bool {
+        self.is_send || self.is_sync
+    }
+
     /// Box<dyn FnOnce(A, B) -> C>
     pub fn to_rust_type_path(&self, types: &TypeDeclarations) -> TokenStream {
-        let args: Vec<TokenStream> = self
-            .params
-            .iter()
-            .map(|a| a.to_rust_type_path(types))
-            .collect();
-        let ret = &self.ret.to_rust_type_path(types);
+        let dyn_fn = self.dyn_fn_tokens(types);
         quote! {
-            Box<dyn FnOnce(#(#args),*) -> #ret>
+            Box<#dyn_fn>
         }
     }
 
@@ -51,27 +82,132 @@ impl BridgeableBoxedFnOnce {
         expression: &TokenStream,
         types: &TypeDeclarations,
     ) -> TokenStream {
-        let args: Vec<TokenStream> = self
-            .params
-            .iter()
-            .map(|a| a.to_rust_type_path(types))
-            .collect();
-        let ret = &self.ret.to_rust_type_path(types);
+        let dyn_fn = self.dyn_fn_tokens(types);
 
         quote! {
-            Box::into_raw(Box::new(#expression)) as *mut Box<dyn FnOnce(#(#args),*) -> #ret>
+            Box::into_raw(Box::new(#expression)) as *mut Box<#dyn_fn>
         }
     }
 
     pub fn to_ffi_compatible_rust_type(&self, types: &TypeDeclarations) -> TokenStream {
-        let params: Vec<TokenStream> = self
+        let dyn_fn = self.dyn_fn_tokens(types);
+        quote! {
+            *mut Box<#dyn_fn>
+        }
+    }
+
+    /// dyn FnMut(A, B) -> C + Send
+    fn dyn_fn_tokens(&self, types: &TypeDeclarations) -> TokenStream {
+        let fn_trait = self.kind.to_trait_ident();
+        let args: Vec<TokenStream> = self
             .params
             .iter()
             .map(|a| a.to_rust_type_path(types))
             .collect();
         let ret = &self.ret.to_rust_type_path(types);
+
+        let maybe_send = if self.is_send {
+            quote! { + Send }
+        } else {
+            quote! {}
+        };
+        let maybe_sync = if self.is_sync {
+            quote! { + Sync }
+        } else {
+            quote! {}
+        };
+
         quote! {
-            *mut Box<dyn FnOnce(#(#params),*) -> #ret>
+            dyn #fn_trait(#(#args),*) -> #ret #maybe_send #maybe_sync
+        }
+    }
+
+    /// Calls a boxed function that Rust passed to Swift.
+    ///
+    /// A `FnOnce` gets consumed by the call, while a `Fn` or `FnMut` stays alive until Swift
+    /// frees it.
+    pub fn call_boxed_fn_pointer(
+        &self,
+        boxed_fn_pointer: &Ident,
+        call_args: &[TokenStream],
+    ) -> TokenStream {
+        match self.kind {
+            BoxedFnKind::FnOnce => quote! {
+                unsafe { Box::from_raw(#boxed_fn_pointer)(#(#call_args),*) }
+            },
+            BoxedFnKind::FnMut | BoxedFnKind::Fn => quote! {
+                unsafe { (*#boxed_fn_pointer)(#(#call_args),*) }
+            },
+        }
+    }
+
+    /// Wraps a Swift closure that was passed to Rust in a boxed Rust closure.
+    ///
+    /// The Swift closure is released when the boxed Rust closure is dropped.
+    ///
+    /// ```ignore
+    /// {
+    ///     let callback = unsafe {
+    ///         swift_bridge::boxed_fn_support::SwiftCallback::new(callback, free_some_function_param0)
+    ///     };
+    ///     Box::new(move |arg0: u8| -> u8 {
+    ///         unsafe { some_function_param0(callback.as_ptr(), arg0) }
+    ///     })
+    /// }
+    /// ```
+    pub fn convert_swift_callback_to_rust_boxed_fn(
+        &self,
+        expression: &TokenStream,
+        call_swift_callback: &Ident,
+        free_swift_callback: &Ident,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let mut closure_params = vec![];
+        let mut ffi_args = vec![];
+        for (idx, ty) in self.params.iter().enumerate() {
+            let param_name = Ident::new(&format!("arg{}", idx), Span::call_site());
+            let param_ty = ty.to_rust_type_path(types);
+
+            ffi_args.push(ty.convert_rust_expression_to_ffi_type(
+                &param_name.to_token_stream(),
+                swift_bridge_path,
+                types,
+                Span::call_site(),
+            ));
+            closure_params.push(quote! { #param_name: #param_ty });
+        }
+        let ret = self.ret.to_rust_type_path(types);
+
+        let call = quote! {
+            unsafe { #call_swift_callback(callback.as_ptr() #(, #ffi_args)*) }
+        };
+        let call = self.ret.convert_ffi_expression_to_rust_type(
+            &call,
+            Span::call_site(),
+            swift_bridge_path,
+            types,
+        );
+
+        // `@Sendable` Swift closures can be moved to, and called from, other threads.
+        let swift_callback = if self.swift_callback_must_be_sendable() {
+            quote! { SendableSwiftCallback }
+        } else {
+            quote! { SwiftCallback }
+        };
+
+        quote! {
+            {
+                let callback = unsafe {
+                    #swift_bridge_path::boxed_fn_support::#swift_callback::new(
+                        #expression,
+                        #free_swift_callback
+                    )
+                };
+                Box::new(move |#(#closure_params),*| -> #ret {
+                    #call
+                })
+            }
         }
     }
 
@@ -196,8 +332,70 @@ impl BridgeableBoxedFnOnce {
         args
     }
 
-    pub fn to_swift_type(&self) -> &'static str {
-        "UnsafeMutableRawPointer"
+    pub fn to_swift_type(
+        &self,
+        type_pos: TypePosition,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> String {
+        match type_pos {
+            TypePosition::FnArg(HostLang::Rust, _) => {
+                format!(
+                    "@escaping {}",
+                    self.to_swift_closure_type(types, swift_bridge_path)
+                )
+            }
+            _ => "UnsafeMutableRawPointer".to_string(),
+        }
+    }
+
+    /// The type of a Swift closure that gets passed to Rust.
+    ///
+    /// For example, `Box<dyn Fn(u8) -> u16 + Send>` becomes "@Sendable (UInt8) -> UInt16".
+    pub fn to_swift_closure_type(
+        &self,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> String {
+        let params = self
+            .params
+            .iter()
+            .map(|ty| {
+                ty.to_swift_type(
+                    TypePosition::FnReturn(HostLang::Rust),
+                    types,
+                    swift_bridge_path,
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(", ");
+        let ret = self.ret.to_swift_type(
+            TypePosition::FnReturn(HostLang::Rust),
+            types,
+            swift_bridge_path,
+        );
+
+        let maybe_sendable = if self.swift_callback_must_be_sendable() {
+            "@Sendable "
+        } else {
+            ""
+        };
+
+        format!("{maybe_sendable}({params}) -> {ret}")
+    }
+
+    /// The Swift class that wraps a boxed function that Rust passed to Swift.
+    pub fn swift_callback_class_prefix(&self) -> &'static str {
+        match self.kind {
+            BoxedFnKind::FnOnce => "__private__RustFnOnceCallback",
+            BoxedFnKind::FnMut => "__private__RustFnMutCallback",
+            BoxedFnKind::Fn => "__private__RustFnCallback",
+        }
+    }
+
+    /// Swift passes a closure to Rust by retaining it and passing Rust a pointer to it.
+    pub fn convert_swift_closure_to_ffi_type(&self, expression: &str) -> String {
+        format!("Unmanaged.passRetained(__private__SwiftCallback({expression})).toOpaque()")
     }
 
     pub fn convert_ffi_value_to_swift_value(&self, type_pos: TypePosition) -> String {
@@ -212,7 +410,7 @@ impl BridgeableBoxedFnOnce {
                     format!("{{ cb{param_idx}.call() }}")
                 }
             }
-            _ => todo!("Not yet supported"),
+            _ => unreachable!("Boxed functions can only be function arguments"),
         }
     }
 
@@ -248,45 +446,73 @@ impl BridgeableBoxedFnOnce {
     }
 }
 
-impl BridgeableBoxedFnOnce {
-    pub fn from_str_tokens(string: &str, types: &TypeDeclarations) -> Option<Self> {
-        // ( A , B , C ) -> D >
-        //   OR
-        // ( A , B , C ) >
-        let signature = string.trim_start_matches("Box < dyn FnOnce");
-
-        let open_parens = signature.find("(").unwrap();
-        let closing_parens = signature.find(")").unwrap();
-        // A, B, C
-        let args = &signature[open_parens + 1..closing_parens];
-
-        let return_idx = signature.rfind("->");
+impl BridgeableBoxedFn {
+    /// Whether or not the stringified tokens are a boxed function such as
+    /// "Box < dyn FnMut (u8) -> u16 >".
+    pub fn can_parse_str_tokens(tokens: &str) -> bool {
+        tokens.starts_with("Box < dyn Fn")
+    }
 
-        // D
-        let ret = return_idx.map(|idx| &signature[(idx + 3)..signature.len() - 2]);
+    pub fn from_str_tokens(string: &str, types: &TypeDeclarations) -> Option<Self> {
+        // Box < dyn FnOnce ( A , B , C ) -> D + Send + Sync >
+        let ty: Type = syn::parse2(TokenStream::from_str(string).ok()?).ok()?;
+        let ty = match ty {
+            Type::Path(ty) => ty,
+            _ => return None,
+        };
 
-        let args = TokenStream::from_str(args).unwrap();
-        let args: FunctionArguments = syn::parse2(args).unwrap();
+        let boxed = ty.path.segments.last()?;
+        let generics = match &boxed.arguments {
+            PathArguments::AngleBracketed(generics) => generics,
+            _ => return None,
+        };
+        let trait_object = generics.args.iter().find_map(|arg| match arg {
+            GenericArgument::Type(Type::TraitObject(trait_object)) => Some(trait_object),
+            _ => None,
+        })?;
+
+        let mut fn_trait = None;
+        let mut is_send = false;
+        let mut is_sync = false;
+        for bound in trait_object.bounds.iter() {
+            let bound = match bound {
+                TypeParamBound::Trait(bound) => bound,
+                _ => continue,
+            };
+            let segment = bound.path.segments.last()?;
+
+            match segment.ident.to_string().as_str() {
+                "FnOnce" => fn_trait = Some((BoxedFnKind::FnOnce, &segment.arguments)),
+                "FnMut" => fn_trait = Some((BoxedFnKind::FnMut, &segment.arguments)),
+                "Fn" => fn_trait = Some((BoxedFnKind::Fn, &segment.arguments)),
+                "Send" => is_send = true,
+                "Sync" => is_sync = true,
+                _ => return None,
+            };
+        }
 
-        let ret = if let Some(ret) = ret {
-            // Parse out the comma in:
-            //   Box<dyn FnOnce() -> (),>
-            let ret = ret.trim_end_matches(",");
+        let (kind, fn_signature) = fn_trait?;
+        let fn_signature = match fn_signature {
+            PathArguments::Parenthesized(fn_signature) => fn_signature,
+            _ => return None,
+        };
 
-            let ret = syn::parse2::<Type>(TokenStream::from_str(ret).unwrap()).unwrap();
-            BridgedType::new_with_type(&ret, types)?
-        } else {
-            BridgedType::StdLib(StdLibType::Null)
+        let ret = match &fn_signature.output {
+            ReturnType::Type(_, ret) => BridgedType::new_with_type(ret, types)?,
+            ReturnType::Default => BridgedType::StdLib(StdLibType::Null),
         };
 
-        let mut args_bridged_tys = Vec::with_capacity(args.0.len());
-        for arg in args.0 {
-            args_bridged_tys.push(BridgedType::new_with_type(&arg, types)?);
+        let mut args_bridged_tys = Vec::with_capacity(fn_signature.inputs.len());
+        for arg in fn_signature.inputs.iter() {
+            args_bridged_tys.push(BridgedType::new_with_type(arg, types)?);
         }
 
-        return Some(BridgeableBoxedFnOnce {
+        return Some(BridgeableBoxedFn {
+            kind,
             params: args_bridged_tys,
             ret: Box::new(ret),
+            is_send,
+            is_sync,
         });
     }
 }
@@ -301,7 +527,7 @@ mod tests {
         let tokens = quote! {Box<dyn FnOnce()>}.to_token_stream().to_string();
 
         assert!(
-            BridgeableBoxedFnOnce::from_str_tokens(&tokens, &TypeDeclarations::default())
+            BridgeableBoxedFn::from_str_tokens(&tokens, &TypeDeclarations::default())
                 .unwrap()
                 .ret
                 .is_null()
@@ -316,7 +542,7 @@ mod tests {
             .to_string();
 
         assert!(matches!(
-            *BridgeableBoxedFnOnce::from_str_tokens(&tokens, &TypeDeclarations::default())
+            *BridgeableBoxedFn::from_str_tokens(&tokens, &TypeDeclarations::default())
                 .unwrap()
                 .ret,
             BridgedType::StdLib(StdLibType::U8)
@@ -331,7 +557,7 @@ mod tests {
             .to_string();
 
         assert!(
-            BridgeableBoxedFnOnce::from_str_tokens(&tokens, &TypeDeclarations::default())
+            BridgeableBoxedFn::from_str_tokens(&tokens, &TypeDeclarations::default())
                 .unwrap()
                 .ret
                 .is_null(),
@@ -347,7 +573,7 @@ mod tests {
         let tokens = "Box < dyn FnOnce() -> () >";
 
         assert!(
-            BridgeableBoxedFnOnce::from_str_tokens(tokens, &TypeDeclarations::default())
+            BridgeableBoxedFn::from_str_tokens(tokens, &TypeDeclarations::default())
                 .unwrap()
                 .ret
                 .is_null(),
@@ -372,11 +598,44 @@ mod tests {
             let tokens = test.to_token_stream().to_string();
 
             assert!(
-                BridgeableBoxedFnOnce::from_str_tokens(&tokens, &TypeDeclarations::default())
+                BridgeableBoxedFn::from_str_tokens(&tokens, &TypeDeclarations::default())
                     .unwrap()
                     .ret
                     .is_null(),
             );
         }
     }
+
+    /// Verify that we can parse the kind of boxed fn along with its `Send` and `Sync` bounds.
+    #[test]
+    fn boxed_fn_kind_and_auto_trait_bounds() {
+        let tokens = quote! {Box<dyn FnMut(u8) + Send + Sync>}
+            .to_token_stream()
+            .to_string();
+        let boxed_fn =
+            BridgeableBoxedFn::from_str_tokens(&tokens, &TypeDeclarations::default()).unwrap();
+
+        assert_eq!(boxed_fn.kind, BoxedFnKind::FnMut);
+        assert_eq!(boxed_fn.params.len(), 1);
+        assert!(boxed_fn.is_send);
+        assert!(boxed_fn.is_sync);
+
+        let tokens = quote! {Box<dyn Fn() -> u8>}.to_token_stream().to_string();
+        let boxed_fn =
+            BridgeableBoxedFn::from_str_tokens(&tokens, &TypeDeclarations::default()).unwrap();
+
+        assert_eq!(boxed_fn.kind, BoxedFnKind::Fn);
+        assert!(!boxed_fn.is_send);
+        assert!(!boxed_fn.is_sync);
+    }
+
+    /// Verify that we do not parse trait objects with bounds other than `Send` and `Sync`.
+    #[test]
+    fn boxed_fn_with_unsupported_bound() {
+        let tokens = quote! {Box<dyn Fn() + Unpin>}.to_token_stream().to_string();
+
+        assert!(
+            BridgeableBoxedFn::from_str_tokens(&tokens, &TypeDeclarations::default()).is_none()
+        );
+    }
 }
@@ -264,6 +264,10 @@ impl BuiltInResult {
         types: &TypeDeclarations,
         swift_bridge_path: &Path,
     ) -> String {
+        if let TypePosition::SharedStructField = type_pos {
+            return self.convert_ffi_value_to_rust_result(expression, types, swift_bridge_path);
+        }
+
         if self.is_custom_result_type() {
             if self.err_ty.can_be_encoded_with_zero_bytes() {
                 todo!();
@@ -364,6 +368,73 @@ impl BuiltInResult {
         )
     }
 
+    /// Convert an FFI `Result` into a Swift `RustResult<T, E>` value.
+    ///
+    /// Used when the `Result` is nested inside of another container, such as an
+    /// `Option<Result<T, E>>`, instead of being a function's return value.
+    fn convert_ffi_value_to_rust_result(
+        &self,
+        expression: &str,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> String {
+        let type_pos = TypePosition::SharedStructField;
+        let rust_result = self.to_swift_type(type_pos, types, swift_bridge_path);
+
+        if self.is_custom_result_type() {
+            let ok = match self.ok_ty.only_encoding() {
+                Some(ok) => ok.swift,
+                None => self.ok_ty.convert_ffi_expression_to_swift_type(
+                    "val.payload.ok",
+                    type_pos,
+                    types,
+                    swift_bridge_path,
+                ),
+            };
+            let err = self.err_ty.convert_ffi_expression_to_swift_type(
+                "val.payload.err",
+                type_pos,
+                types,
+                swift_bridge_path,
+            );
+
+            return format!(
+                "{{ let val = {expression}; switch val.tag {{ case {c_ok_name}: return {rust_result}.Ok({ok}) case {c_err_name}: return {rust_result}.Err({err}) default: fatalError() }} }}()",
+                c_ok_name = self.c_ok_tag_name(types),
+                c_err_name = self.c_err_tag_name(types),
+            );
+        }
+
+        if let Some(ok) = self.ok_ty.only_encoding() {
+            let err = self.err_ty.convert_ffi_expression_to_swift_type(
+                "val!",
+                type_pos,
+                types,
+                swift_bridge_path,
+            );
+            return format!(
+                "{{ let val = {expression}; if val != nil {{ return {rust_result}.Err({err}) }} else {{ return {rust_result}.Ok({ok}) }} }}()",
+                ok = ok.swift
+            );
+        }
+
+        let ok = self.ok_ty.convert_ffi_expression_to_swift_type(
+            "val.ok_or_err!",
+            type_pos,
+            types,
+            swift_bridge_path,
+        );
+        let err = self.err_ty.convert_ffi_expression_to_swift_type(
+            "val.ok_or_err!",
+            type_pos,
+            types,
+            swift_bridge_path,
+        );
+        format!(
+            "{{ let val = {expression}; if val.is_ok {{ return {rust_result}.Ok({ok}) }} else {{ return {rust_result}.Err({err}) }} }}()"
+        )
+    }
+
     pub fn convert_swift_expression_to_ffi_compatible(
         &self,
         expression: &str,
@@ -377,9 +448,28 @@ impl BuiltInResult {
             .err_ty
             .convert_swift_expression_to_ffi_type("err", types, type_pos);
 
-        if self.ok_ty.can_be_encoded_with_zero_bytes() {
+        if self.is_custom_result_type() {
+            let c_ty = format!(
+                "{}${}",
+                SWIFT_BRIDGE_PREFIX,
+                self.custom_c_struct_name(types)
+            );
+            let (ok_pattern, ok_payload) = if self.ok_ty.can_be_encoded_with_zero_bytes() {
+                ("_", "".to_string())
+            } else {
+                ("let ok", format!("ok: {convert_ok}"))
+            };
+
             format!(
-                "{{ switch {val} {{ case .Ok(let ok): return __private__ResultPtrAndPtr(is_ok: true, ok_or_err: {convert_ok}) case .Err(let err): return __private__ResultPtrAndPtr(is_ok: false, ok_or_err: {convert_err}) }} }}()",
+                "{{ switch {val} {{ case .Ok({ok_pattern}): return {c_ty}(tag: {c_ok_name}, payload: {c_fields_name}({ok_payload})) case .Err(let err): return {c_ty}(tag: {c_err_name}, payload: {c_fields_name}(err: {convert_err})) }} }}()",
+                val = expression,
+                c_ok_name = self.c_ok_tag_name(types),
+                c_err_name = self.c_err_tag_name(types),
+                c_fields_name = self.c_fields_name(types),
+            )
+        } else if self.ok_ty.can_be_encoded_with_zero_bytes() {
+            format!(
+                "{{ switch {val} {{ case .Ok(_): return nil case .Err(let err): return {convert_err} }} }}()",
                 val = expression
             )
         } else {
@@ -594,14 +684,25 @@ impl BuiltInResult {
     /// Go from `Result < A , B >` to a `BuiltInResult`.
     pub fn from_str_tokens(string: &str, types: &TypeDeclarations) -> Option<Self> {
         // A , B >
-        let trimmed = string.trim_start_matches("Result < ");
+        let trimmed = string.strip_prefix("Result < ").unwrap_or(string);
         // A , B
-        let trimmed = trimmed.trim_end_matches(" >");
+        let trimmed = trimmed.strip_suffix(" >").unwrap_or(trimmed);
 
         // [A, B]
-        let ok_and_err = trimmed.rsplit_once(",")?;
-        let ok = ok_and_err.0.trim();
-        let err = ok_and_err.1.trim();
+        // The ok and err types can themselves contain commas, as in
+        // `Result<(u8, u16), HashMap<u8, u8>>`, so we split on the top level comma.
+        let mut depth = 0;
+        let comma = trimmed.char_indices().find_map(|(idx, c)| {
+            match c {
+                '<' | '(' | '[' => depth += 1,
+                '>' | ')' | ']' => depth -= 1,
+                ',' if depth == 0 => return Some(idx),
+                _ => {}
+            };
+            None
+        })?;
+        let ok = trimmed[..comma].trim();
+        let err = trimmed[comma + 1..].trim();
 
         let ok = BridgedType::new_with_str(ok, types)?;
         let err = BridgedType::new_with_str(err, types)?;
@@ -663,4 +764,23 @@ mod tests {
         assert!(result.ok_ty.is_null());
         assert!(result.err_ty.is_null());
     }
+
+    /// Verify that we can parse a `Result` whose ok and err types contain commas and brackets.
+    #[test]
+    fn result_with_nested_generics() {
+        let tokens = quote! { Result<(u8, u16), Option<Vec<u8>>> }
+            .to_token_stream()
+            .to_string();
+
+        let result = BuiltInResult::from_str_tokens(&tokens, &TypeDeclarations::default()).unwrap();
+
+        assert_eq!(
+            result
+                .ok_ty
+                .to_rust_type_path(&TypeDeclarations::default())
+                .to_string(),
+            "(u8 , u16)"
+        );
+        assert!(result.err_ty.as_option().is_some());
+    }
 }
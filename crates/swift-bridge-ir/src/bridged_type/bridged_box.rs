@@ -0,0 +1,450 @@
+use crate::bridged_type::{
+    BridgeableType, BridgedOption, BridgedType, BuiltInResult, CFfiStruct, OnlyEncoding,
+    TypePosition, UnusedOptionNoneValue,
+};
+use crate::parse::{SharedTypeDeclaration, TypeDeclaration, TypeDeclarations};
+use crate::SWIFT_BRIDGE_PREFIX;
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{GenericArgument, Path, PathArguments, Type};
+
+/// `Box<T>`, where `T` is a shared struct or enum.
+///
+/// This is what lets a shared struct or enum contain itself, such as
+/// `enum Expr { Num(f64), Add(Box<Expr>, Box<Expr>) }`.
+///
+/// The value crosses the FFI boundary as a pointer to a heap allocated FFI representation of `T`.
+/// Rust owns the allocation, so Swift calls into Rust to box and unbox the FFI representation.
+///
+/// Swift sees a `Box<T>` as a `T`. Enum cases that hold a `Box<T>` are declared `indirect`, and
+/// struct fields that hold a `Box<T>` use the `@Indirect` property wrapper.
+#[derive(Debug)]
+pub(crate) struct BridgedBox {
+    pub ty: Box<BridgedType>,
+    /// `Expr`
+    swift_name: String,
+    /// `__swift_bridge__$Expr`
+    ffi_name: String,
+}
+
+impl BridgedBox {
+    /// Whether a shared struct field or enum variant field holds a `Box<T>`, either directly or
+    /// inside of an `Option<Box<T>>`.
+    pub(crate) fn is_boxed_field_type(ty: &Type) -> bool {
+        match box_or_option_inner(ty) {
+            Some(("Box", inner)) => !matches!(inner, Type::TraitObject(_)),
+            Some(("Option", inner)) => matches!(
+                box_or_option_inner(inner),
+                Some(("Box", inner)) if !matches!(inner, Type::TraitObject(_))
+            ),
+            _ => false,
+        }
+    }
+
+    /// `__swift_bridge__$Box_Expr$new`
+    fn box_new_link_name(&self) -> String {
+        format!("{}$Box_{}$new", SWIFT_BRIDGE_PREFIX, self.swift_name)
+    }
+
+    /// `__swift_bridge__$Box_Expr$into_inner`
+    fn box_into_inner_link_name(&self) -> String {
+        format!("{}$Box_{}$into_inner", SWIFT_BRIDGE_PREFIX, self.swift_name)
+    }
+
+    fn ffi_pointer_swift_type(&self) -> String {
+        format!("UnsafeMutablePointer<{}>", self.ffi_name)
+    }
+}
+
+/// `Box<T>` -> `Some(("Box", T))`, `Option<T>` -> `Some(("Option", T))`
+fn box_or_option_inner(ty: &Type) -> Option<(&'static str, &Type)> {
+    let last = match ty {
+        Type::Path(path) => path.path.segments.last()?,
+        _ => return None,
+    };
+    let container = if last.ident == "Box" {
+        "Box"
+    } else if last.ident == "Option" {
+        "Option"
+    } else {
+        return None;
+    };
+
+    match &last.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match args.args.first()? {
+            GenericArgument::Type(inner) => Some((container, inner)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+impl BridgeableType for BridgedBox {
+    fn is_built_in_type(&self) -> bool {
+        false
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn as_option(&self) -> Option<&BridgedOption> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        true
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        let ffi_ty = self
+            .ty
+            .to_ffi_compatible_rust_type(swift_bridge_path, types);
+        let export_name_new = self.box_new_link_name();
+        let export_name_into_inner = self.box_into_inner_link_name();
+
+        Some(vec![quote! {
+            const _: () = {
+                #[doc(hidden)]
+                #[export_name = #export_name_new]
+                pub extern "C" fn _new(val: #ffi_ty) -> *mut #ffi_ty {
+                    Box::into_raw(Box::new(val))
+                }
+
+                #[doc(hidden)]
+                #[export_name = #export_name_into_inner]
+                pub extern "C" fn _into_inner(ptr: *mut #ffi_ty) -> #ffi_ty {
+                    *unsafe { Box::from_raw(ptr) }
+                }
+            };
+        }])
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        // The struct gets forward declared so that these functions can be declared before the
+        // struct's definition, which might contain a pointer to itself.
+        let c_ffi_type = format!(
+            r#"struct {ffi_name};
+struct {ffi_name}* {box_new}(struct {ffi_name} val);
+struct {ffi_name} {box_into_inner}(struct {ffi_name}* ptr);"#,
+            ffi_name = self.ffi_name,
+            box_new = self.box_new_link_name(),
+            box_into_inner = self.box_into_inner_link_name()
+        );
+
+        Some(CFfiStruct {
+            c_ffi_type,
+            fields: vec![],
+        })
+    }
+
+    fn to_rust_type_path(&self, types: &TypeDeclarations) -> TokenStream {
+        let ty = self.ty.to_rust_type_path(types);
+        quote! { Box<#ty> }
+    }
+
+    fn to_swift_type(
+        &self,
+        type_pos: TypePosition,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
+                if func_host_lang.is_rust() {
+                    self.ty.to_swift_type(type_pos, types, swift_bridge_path)
+                } else {
+                    self.ffi_pointer_swift_type()
+                }
+            }
+            TypePosition::SharedStructField
+            | TypePosition::ResultFfiReturnType
+            | TypePosition::ThrowingInit(_) => {
+                self.ty.to_swift_type(type_pos, types, swift_bridge_path)
+            }
+        }
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        format!("struct {}*", self.ffi_name)
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        None
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty = self
+            .ty
+            .to_ffi_compatible_rust_type(swift_bridge_path, types);
+        quote! { *mut #ty }
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        // `None` is a null pointer.
+        self.to_ffi_compatible_rust_type(swift_bridge_path, types)
+    }
+
+    fn to_ffi_compatible_option_swift_type(
+        &self,
+        _type_pos: TypePosition,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> String {
+        format!("{}?", self.ffi_pointer_swift_type())
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        format!("struct {}*", self.ffi_name)
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+        span: Span,
+    ) -> TokenStream {
+        let val = self.ty.convert_rust_expression_to_ffi_type(
+            &quote! { (*#expression) },
+            swift_bridge_path,
+            types,
+            span,
+        );
+        quote_spanned! {span=>
+            Box::into_raw(Box::new(#val))
+        }
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        quote! {
+            if let Some(val) = #expression {
+                Box::into_raw(Box::new((*val).into_ffi_repr()))
+            } else {
+                std::ptr::null_mut()
+            }
+        }
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        types: &TypeDeclarations,
+        type_pos: TypePosition,
+    ) -> String {
+        let val = self
+            .ty
+            .convert_swift_expression_to_ffi_type(expression, types, type_pos);
+        format!("{}({val})", self.box_new_link_name())
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!(
+            "{expression}.map {{ val -> {ptr} in {box_new}(val.intoFfiRepr()) }}",
+            ptr = self.ffi_pointer_swift_type(),
+            box_new = self.box_new_link_name()
+        )
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        span: Span,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let val = self.ty.convert_ffi_expression_to_rust_type(
+            &quote! { (*unsafe { Box::from_raw(#expression) }) },
+            span,
+            swift_bridge_path,
+            types,
+        );
+        quote_spanned! {span=>
+            Box::new(#val)
+        }
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(&self, expression: &TokenStream) -> TokenStream {
+        quote! {
+            {
+                let val = #expression;
+                if val.is_null() {
+                    None
+                } else {
+                    Some(Box::new((*unsafe { Box::from_raw(val) }).into_rust_repr()))
+                }
+            }
+        }
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        type_pos: TypePosition,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> String {
+        self.ty.convert_ffi_expression_to_swift_type(
+            &format!("{}({expression})", self.box_into_inner_link_name()),
+            type_pos,
+            types,
+            swift_bridge_path,
+        )
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, expression: &str) -> String {
+        format!(
+            "{expression}.map {{ {box_into_inner}($0).intoSwiftRepr() }}",
+            box_into_inner = self.box_into_inner_link_name()
+        )
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        ok_ffi_value: &TokenStream,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        self.convert_ffi_expression_to_rust_type(
+            ok_ffi_value,
+            Span::call_site(),
+            swift_bridge_path,
+            types,
+        )
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        err_ffi_value: &TokenStream,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        self.convert_ffi_expression_to_rust_type(
+            err_ffi_value,
+            Span::call_site(),
+            swift_bridge_path,
+            types,
+        )
+    }
+
+    fn unused_option_none_val(&self, _swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        UnusedOptionNoneValue {
+            rust: quote! { std::ptr::null_mut() },
+            swift: "nil".into(),
+        }
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        tokens.starts_with("Box < ") && !tokens.starts_with("Box < dyn ")
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let inner = match box_or_option_inner(ty)? {
+            ("Box", inner) => inner,
+            _ => return None,
+        };
+        let inner = match inner {
+            Type::Path(path) => path.path.get_ident()?.to_string(),
+            _ => return None,
+        };
+
+        // Generic and zero-sized shared types can't be boxed.
+        let declaration = types.get(&inner)?;
+        let (swift_name, ffi_name) = match declaration {
+            TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)) => {
+                if !shared_struct.generics.is_empty() || shared_struct.only_encoding().is_some() {
+                    return None;
+                }
+                (
+                    shared_struct.swift_name_string(),
+                    shared_struct.ffi_name_string(),
+                )
+            }
+            TypeDeclaration::Shared(SharedTypeDeclaration::Enum(shared_enum)) => {
+                let ty = declaration.to_bridged_type(false, false);
+                if !shared_enum.generics.is_empty() || ty.only_encoding().is_some() {
+                    return None;
+                }
+                (
+                    shared_enum.swift_name_string(),
+                    shared_enum.ffi_name_string(),
+                )
+            }
+            TypeDeclaration::Opaque(_) => return None,
+        };
+
+        Some(BridgedBox {
+            ty: Box::new(declaration.to_bridged_type(false, false)),
+            swift_name,
+            ffi_name,
+        })
+    }
+
+    fn parse_token_stream_str(tokens: &str, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let ty: Type = syn::parse_str(tokens).ok()?;
+        Self::from_type(&ty, types)
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        // Swift sees a `Box<T>` as a `T`, and shared types don't use the `IntoRustString`
+        // generic.
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, _types: &TypeDeclarations) -> String {
+        format!("Box{}", self.swift_name)
+    }
+}
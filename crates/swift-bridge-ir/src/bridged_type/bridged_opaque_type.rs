@@ -15,6 +15,8 @@ pub(crate) struct OpaqueForeignType {
     pub host_lang: HostLang,
     pub reference: bool,
     pub mutable: bool,
+    /// Whether this is an `Arc<T>` of an opaque Rust type.
+    pub arc: bool,
     pub has_swift_bridge_copy_annotation: bool,
     pub generics: OpaqueRustTypeGenerics,
 }
@@ -62,7 +64,11 @@ impl BridgeableType for OpaqueForeignType {
             .generics
             .angle_bracketed_concrete_generics_tokens(types);
 
-        if self.host_lang.is_rust() {
+        if self.arc {
+            quote! {
+                std::sync::Arc<super:: #ty_name>
+            }
+        } else if self.host_lang.is_rust() {
             quote! {
                 super:: #ty_name #generics
             }
@@ -85,7 +91,9 @@ impl BridgeableType for OpaqueForeignType {
                     if func_host_lang.is_rust() {
                         let mut class_name = self.ty.to_string();
 
-                        if !self.has_swift_bridge_copy_annotation {
+                        if self.arc {
+                            class_name += "Arc";
+                        } else if !self.has_swift_bridge_copy_annotation {
                             if self.reference {
                                 class_name += "Ref";
                             }
@@ -109,6 +117,10 @@ impl BridgeableType for OpaqueForeignType {
                     }
                 }
                 TypePosition::SharedStructField => {
+                    if self.arc {
+                        return self.swift_name();
+                    }
+
                     let class_name = self.ty.to_string();
                     if !self.has_swift_bridge_copy_annotation {
                         if self.mutable || self.reference {
@@ -175,7 +187,9 @@ impl BridgeableType for OpaqueForeignType {
     ) -> TokenStream {
         let ty_name = &self.ty;
 
-        if self.has_swift_bridge_copy_annotation {
+        if self.arc {
+            quote! { *const super::#ty_name }
+        } else if self.has_swift_bridge_copy_annotation {
             let ty = self.copy_rust_repr_type();
             quote! { #ty }
         } else {
@@ -191,7 +205,7 @@ impl BridgeableType for OpaqueForeignType {
                         quote! { *const }
                     };
 
-                    quote_spanned! {ty_name.span()=> #ptr super::#ty_name }
+                    quote_spanned! {ty_name.span()=> #ptr super::#ty_name #generics }
                 } else {
                     quote! { *mut super::#ty_name #generics }
                 }
@@ -216,7 +230,7 @@ impl BridgeableType for OpaqueForeignType {
                 .generics
                 .angle_bracketed_concrete_generics_tokens(types);
 
-            if self.reference {
+            if self.reference || self.arc {
                 quote! { *const super::#type_name #generics }
             } else {
                 quote! { *mut super::#type_name #generics }
@@ -251,7 +265,14 @@ impl BridgeableType for OpaqueForeignType {
         let ty_name = &self.ty;
 
         if self.host_lang.is_rust() {
-            if self.has_swift_bridge_copy_annotation {
+            if self.arc {
+                quote_spanned! {span=>
+                    std::sync::Arc::into_raw({
+                        let val: std::sync::Arc<super::#ty_name> = #expression;
+                        val
+                    })
+                }
+            } else if self.has_swift_bridge_copy_annotation {
                 let copy_ty = self.copy_rust_repr_type();
                 quote! {
                     #copy_ty::from_rust_repr(#expression)
@@ -316,6 +337,14 @@ impl BridgeableType for OpaqueForeignType {
                     std::ptr::null()
                 }
             }
+        } else if self.arc {
+            quote! {
+                if let Some(val) = #expression {
+                    std::sync::Arc::into_raw(val)
+                } else {
+                    std::ptr::null()
+                }
+            }
         } else {
             match self.host_lang {
                 HostLang::Rust => {
@@ -361,7 +390,13 @@ impl BridgeableType for OpaqueForeignType {
         let ty_name = &self.ty;
 
         if self.host_lang.is_rust() {
-            if self.has_swift_bridge_copy_annotation {
+            if self.arc {
+                // The receiving side takes ownership of a strong count, so we give it a new one.
+                format!(
+                    "{{let val = {expression}; {increment}(val.ptr); return val.ptr;}}()",
+                    increment = self.increment_strong_count_ffi_name()
+                )
+            } else if self.has_swift_bridge_copy_annotation {
                 format!("{}.intoFfiRepr()", expression)
             } else if self.reference {
                 format!("{}.ptr", expression)
@@ -446,6 +481,12 @@ impl BridgeableType for OpaqueForeignType {
                 "{{ if let val = {expression} {{ return val.ptr }} else {{ return nil }} }}()",
                 expression = expression,
             )
+        } else if self.arc {
+            format!(
+                "{{ if let val = {expression} {{ {increment}(val.ptr); return val.ptr }} else {{ return nil }} }}()",
+                expression = expression,
+                increment = self.increment_strong_count_ffi_name()
+            )
         } else {
             match self.host_lang {
                 HostLang::Rust => {
@@ -466,7 +507,11 @@ impl BridgeableType for OpaqueForeignType {
         _types: &TypeDeclarations,
     ) -> TokenStream {
         if self.host_lang.is_rust() {
-            if self.has_swift_bridge_copy_annotation {
+            if self.arc {
+                quote! {
+                    unsafe { std::sync::Arc::from_raw(#expression) }
+                }
+            } else if self.has_swift_bridge_copy_annotation {
                 let maybe_ref = if self.reference {
                     quote! {&}
                 } else {
@@ -518,6 +563,14 @@ impl BridgeableType for OpaqueForeignType {
                     Some(unsafe {& * #expression} )
                 }
             }
+        } else if self.arc {
+            quote! {
+                if #expression.is_null() {
+                    None
+                } else {
+                    Some(unsafe { std::sync::Arc::from_raw(#expression) })
+                }
+            }
         } else {
             match self.host_lang {
                 HostLang::Rust => {
@@ -563,7 +616,9 @@ impl BridgeableType for OpaqueForeignType {
         }
 
         if self.host_lang.is_rust() {
-            if self.has_swift_bridge_copy_annotation {
+            if self.arc {
+                format!("{}(ptr: {})", self.swift_name(), expression)
+            } else if self.has_swift_bridge_copy_annotation {
                 format!(
                     "{ty_name}(bytes: {value})",
                     ty_name = ty_name,
@@ -628,6 +683,11 @@ impl BridgeableType for OpaqueForeignType {
         let ty = &self.ty;
 
         match self.host_lang {
+            HostLang::Rust if self.arc => {
+                quote! {
+                    unsafe { std::sync::Arc::from_raw(#result.ok_or_err as *const super::#ty) }
+                }
+            }
             HostLang::Rust => {
                 quote! {
                     unsafe { *Box::from_raw(#result.ok_or_err as *mut super::#ty) }
@@ -650,6 +710,11 @@ impl BridgeableType for OpaqueForeignType {
         let ty = &self.ty;
 
         match self.host_lang {
+            HostLang::Rust if self.arc => {
+                quote! {
+                    unsafe { std::sync::Arc::from_raw(#result.ok_or_err as *const super::#ty) }
+                }
+            }
             HostLang::Rust => {
                 quote! {
                     unsafe { *Box::from_raw(#result.ok_or_err as *mut super::#ty) }
@@ -668,6 +733,11 @@ impl BridgeableType for OpaqueForeignType {
 
         if self.reference {
             todo!("Support returning Option<&T> where T is an opaque type")
+        } else if self.arc {
+            UnusedOptionNoneValue {
+                rust: quote! { std::ptr::null::<super::#ty_name>() },
+                swift: "TODO..Support Swift Option<T>::None value".into(),
+            }
         } else {
             UnusedOptionNoneValue {
                 rust: quote! { std::ptr::null::<#ty_name>() as *mut super::#ty_name },
@@ -747,19 +817,62 @@ impl BridgeableType for OpaqueForeignType {
         if self.generics.len() >= 1 {
             todo!()
         }
-        self.ty.to_string()
+
+        if self.arc {
+            format!("Arc_{}", self.ty)
+        } else {
+            self.ty.to_string()
+        }
     }
 }
 
 impl OpaqueForeignType {
+    /// Whether or not the tokens are an `Arc<T>`, such as "Arc < SomeType >" or
+    /// "std :: sync :: Arc < SomeType >".
+    pub fn can_parse_arc_str_tokens(tokens: &str) -> bool {
+        tokens.starts_with("Arc < ") || tokens.starts_with("std :: sync :: Arc < ")
+    }
+
+    /// Parse an `Arc<T>` where `T` is an opaque Rust type.
+    ///
+    /// Returns `None` if `T` is not an opaque Rust type, or if it is a generic or
+    /// `#[swift_bridge(Copy(...))]` type, since those don't support `Arc<T>`.
+    pub fn arc_from_str_tokens(tokens: &str, types: &TypeDeclarations) -> Option<Self> {
+        let inner = tokens.trim_start_matches("std :: sync :: ");
+        let inner = inner.strip_prefix("Arc < ")?.strip_suffix(" >")?;
+
+        let mut opaque = types.get(inner)?.to_opaque_type(false, false)?;
+        if !opaque.host_lang.is_rust()
+            || opaque.has_swift_bridge_copy_annotation
+            || !opaque.generics.is_empty()
+        {
+            return None;
+        }
+
+        opaque.arc = true;
+        Some(opaque)
+    }
+
     pub fn swift_name(&self) -> String {
-        if self.reference {
+        if self.arc {
+            format!("{}Arc", self.ty)
+        } else if self.reference {
             format!("{}Ref", self.ty)
         } else {
             format!("{}", self.ty)
         }
     }
 
+    /// The name of the function that increments the strong count of an `Arc<T>`.
+    ///
+    /// __swift_bridge__$SomeType$_increment_strong_count
+    pub fn increment_strong_count_ffi_name(&self) -> String {
+        format!(
+            "{}${}$_increment_strong_count",
+            SWIFT_BRIDGE_PREFIX, self.ty
+        )
+    }
+
     /// The name of the type used to pass a `#[swift_bridge(Copy(...))]` type over FFI
     ///
     /// __swift_bridge__SomeType
@@ -818,6 +931,7 @@ impl Debug for OpaqueForeignType {
             .field("host_lang", &self.host_lang)
             .field("reference", &self.reference)
             .field("mutable", &self.mutable)
+            .field("arc", &self.arc)
             .finish()
     }
 }
@@ -828,6 +942,7 @@ impl PartialEq for OpaqueForeignType {
             && self.host_lang == other.host_lang
             && self.reference == other.reference
             && self.mutable == other.mutable
+            && self.arc == other.arc
     }
 }
 
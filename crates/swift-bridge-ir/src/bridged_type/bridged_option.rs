@@ -1,8 +1,12 @@
 use crate::bridged_type::built_in_primitive::BuiltInPrimitive;
-use crate::bridged_type::{BridgedType, CustomBridgedType, SharedType, StdLibType, TypePosition};
+use crate::bridged_type::{
+    BridgeableType, BridgedType, CFfiStruct, CustomBridgedType, SharedType, StdLibType,
+    TypePosition,
+};
 use crate::parse::TypeDeclarations;
-use proc_macro2::TokenStream;
-use quote::quote;
+use crate::SWIFT_BRIDGE_PREFIX;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
 use std::ops::Deref;
 use syn::Path;
 
@@ -17,7 +21,18 @@ impl BridgedOption {
         &self,
         expression: &TokenStream,
         swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+        span: Span,
     ) -> TokenStream {
+        if self.is_nested_container() {
+            return self.convert_rust_expression_to_nested_ffi_type(
+                expression,
+                swift_bridge_path,
+                types,
+                span,
+            );
+        }
+
         let option_rust_primitive_to_ffi_primitive =
             move |ffi_option_name: TokenStream, unused_none: TokenStream| {
                 quote! {
@@ -76,11 +91,44 @@ impl BridgedOption {
                 StdLibType::Bool => {
                     option_rust_primitive_to_ffi_primitive(quote! {OptionBool}, quote! {false})
                 }
-                StdLibType::Pointer(_) => {
-                    todo!("Support Option<*const T> and Option<*mut T>")
+                StdLibType::U128 => {
+                    quote! {
+                        if let Some(val) = #expression {
+                            #swift_bridge_path::option::OptionU128 {
+                                val: #swift_bridge_path::int128::FfiU128::from(val),
+                                is_some: true
+                            }
+                        } else {
+                            #swift_bridge_path::option::OptionU128 {
+                                val: #swift_bridge_path::int128::FfiU128 { low: 0, high: 0 },
+                                is_some: false
+                            }
+                        }
+                    }
                 }
-                StdLibType::RefSlice(_) => {
-                    todo!("Support Option<&[T]> and Option<&mut [T]>")
+                StdLibType::I128 => {
+                    quote! {
+                        if let Some(val) = #expression {
+                            #swift_bridge_path::option::OptionI128 {
+                                val: #swift_bridge_path::int128::FfiI128::from(val),
+                                is_some: true
+                            }
+                        } else {
+                            #swift_bridge_path::option::OptionI128 {
+                                val: #swift_bridge_path::int128::FfiI128 { low: 0, high: 0 },
+                                is_some: false
+                            }
+                        }
+                    }
+                }
+                StdLibType::Char => {
+                    quote! {
+                        if let Some(val) = #expression {
+                            #swift_bridge_path::option::OptionChar { val: val as u32, is_some: true }
+                        } else {
+                            #swift_bridge_path::option::OptionChar { val: 0, is_some: false }
+                        }
+                    }
                 }
                 StdLibType::Str => {
                     quote! {
@@ -100,16 +148,33 @@ impl BridgedOption {
                         }
                     }
                 }
-                StdLibType::Option(_) => {
-                    todo!("Support Option<Option<T>>")
+                StdLibType::Set(_) => {
+                    let convert_val = self.ty.convert_rust_expression_to_ffi_type(
+                        &quote! { val },
+                        swift_bridge_path,
+                        types,
+                        span,
+                    );
+                    quote! {
+                        if let Some(val) = #expression {
+                            #convert_val
+                        } else {
+                            std::ptr::null_mut()
+                        }
+                    }
                 }
-                StdLibType::Result(_) => {
-                    todo!("Support Option<Result<T, E>>")
+                StdLibType::BoxedFn(_) => {
+                    unreachable!("Boxed functions can only be function arguments")
                 }
-                StdLibType::BoxedFnOnce(_) => {
-                    todo!("Option<Box<dyn FnOnce(A, B) -> C>> is not yet supported")
+                StdLibType::Pointer(_)
+                | StdLibType::RefSlice(_)
+                | StdLibType::Map(_)
+                | StdLibType::Option(_)
+                | StdLibType::Result(_)
+                | StdLibType::Tuple(_)
+                | StdLibType::Array(_) => {
+                    unreachable!("Nested containers are handled above")
                 }
-                StdLibType::Tuple(_) => todo!(),
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
                 let option_name = shared_struct.ffi_option_name_tokens();
@@ -129,7 +194,19 @@ impl BridgedOption {
     pub(super) fn convert_ffi_expression_to_rust_type(
         &self,
         expression: &TokenStream,
+        span: Span,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
     ) -> TokenStream {
+        if self.is_nested_container() {
+            return self.convert_nested_ffi_expression_to_rust_type(
+                expression,
+                span,
+                swift_bridge_path,
+                types,
+            );
+        }
+
         match self.ty.deref() {
             BridgedType::Bridgeable(b) => b.convert_ffi_option_expression_to_rust_type(expression),
             BridgedType::StdLib(stdlib_ty) => match stdlib_ty {
@@ -160,11 +237,41 @@ impl BridgedOption {
                         }
                     }
                 }
-                StdLibType::Pointer(_) => {
-                    todo!("Option<*const T> and Option<*mut T> are not yet supported.")
+                StdLibType::U128 => {
+                    quote! {
+                        {
+                            let val = #expression;
+                            if val.is_some {
+                                Some(u128::from(val.val))
+                            } else {
+                                None
+                            }
+                        }
+                    }
                 }
-                StdLibType::RefSlice(_) => {
-                    todo!("Option<*const T> and Option<*mut T> are not yet supported.")
+                StdLibType::I128 => {
+                    quote! {
+                        {
+                            let val = #expression;
+                            if val.is_some {
+                                Some(i128::from(val.val))
+                            } else {
+                                None
+                            }
+                        }
+                    }
+                }
+                StdLibType::Char => {
+                    quote! {
+                        {
+                            let val = #expression;
+                            if val.is_some {
+                                Some(char::from_u32(val.val).unwrap_or(char::REPLACEMENT_CHARACTER))
+                            } else {
+                                None
+                            }
+                        }
+                    }
                 }
                 StdLibType::Str => {
                     quote! {
@@ -184,16 +291,32 @@ impl BridgedOption {
                         if #expression.is_null() { None } else { Some( unsafe { * Box::from_raw(#expression) } ) }
                     }
                 }
-                StdLibType::Option(_) => {
-                    todo!("Option<Option<T>> is not yet supported")
+                StdLibType::Set(_) => {
+                    let convert_val = self.ty.convert_ffi_expression_to_rust_type(
+                        &quote! { val },
+                        span,
+                        swift_bridge_path,
+                        types,
+                    );
+                    quote! {
+                        {
+                            let val = #expression;
+                            if val.is_null() { None } else { Some(#convert_val) }
+                        }
+                    }
                 }
-                StdLibType::Result(_) => {
-                    todo!("Option<Result<T, E>> is not yet supported")
+                StdLibType::BoxedFn(_) => {
+                    unreachable!("Boxed functions can only be function arguments")
                 }
-                StdLibType::BoxedFnOnce(_) => {
-                    todo!("Option<Box<dyn FnOnce(A, B) -> C>> is not yet supported")
+                StdLibType::Pointer(_)
+                | StdLibType::RefSlice(_)
+                | StdLibType::Map(_)
+                | StdLibType::Option(_)
+                | StdLibType::Result(_)
+                | StdLibType::Tuple(_)
+                | StdLibType::Array(_) => {
+                    unreachable!("Nested containers are handled above")
                 }
-                StdLibType::Tuple(_) => todo!(),
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(_shared_struct))) => {
                 quote! {
@@ -208,7 +331,24 @@ impl BridgedOption {
         }
     }
 
-    pub(super) fn convert_ffi_expression_to_swift_type(&self, expression: &str) -> String {
+    pub(super) fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> String {
+        if self.is_nested_container() {
+            let convert_val = self.ty.convert_ffi_value_to_swift_value(
+                "val.val",
+                TypePosition::SharedStructField,
+                types,
+                swift_bridge_path,
+            );
+            return format!(
+                "{{ let val = {expression}; if val.is_some {{ return {convert_val} }} else {{ return nil }} }}()"
+            );
+        }
+
         match self.ty.deref() {
             BridgedType::Bridgeable(b) => b.convert_ffi_option_expression_to_swift_type(expression),
             BridgedType::StdLib(stdlib_type) => match stdlib_type {
@@ -227,15 +367,12 @@ impl BridgedOption {
                 | StdLibType::Isize
                 | StdLibType::F32
                 | StdLibType::F64
-                | StdLibType::Bool => {
+                | StdLibType::Bool
+                | StdLibType::Char
+                | StdLibType::U128
+                | StdLibType::I128 => {
                     format!("{expression}.intoSwiftRepr()")
                 }
-                StdLibType::Pointer(_) => {
-                    todo!("Support Option<*const T> and Option<*mut T>")
-                }
-                StdLibType::RefSlice(_) => {
-                    todo!("Support Option<&[T]>")
-                }
                 StdLibType::Str => {
                     format!(
                             "{{ let val = {val}; if val.start != nil {{ return val; }} else {{ return nil; }} }}()",
@@ -249,16 +386,29 @@ impl BridgedOption {
                     expression = expression
                     )
                 }
-                StdLibType::Option(_) => {
-                    todo!("Support Option<Option<T>>")
+                StdLibType::Set(_) => {
+                    let convert_val = self.ty.convert_ffi_value_to_swift_value(
+                        "val!",
+                        TypePosition::SharedStructField,
+                        types,
+                        swift_bridge_path,
+                    );
+                    format!(
+                        "{{ let val = {expression}; if val != nil {{ return {convert_val} }} else {{ return nil }} }}()"
+                    )
                 }
-                StdLibType::Result(_) => {
-                    todo!("Option<Result<T, E>> is not yet supported")
+                StdLibType::BoxedFn(_) => {
+                    unreachable!("Boxed functions can only be function arguments")
                 }
-                StdLibType::BoxedFnOnce(_) => {
-                    todo!("Option<Box<dyn FnOnce(A, B) -> C>> is not yet supported")
+                StdLibType::Pointer(_)
+                | StdLibType::RefSlice(_)
+                | StdLibType::Map(_)
+                | StdLibType::Option(_)
+                | StdLibType::Result(_)
+                | StdLibType::Tuple(_)
+                | StdLibType::Array(_) => {
+                    unreachable!("Nested containers are handled above")
                 }
-                StdLibType::Tuple(_) => todo!(),
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(_shared_struct))) => {
                 format!("{expression}.intoSwiftRepr()", expression = expression)
@@ -272,8 +422,21 @@ impl BridgedOption {
     pub fn convert_swift_expression_to_ffi_type(
         &self,
         expression: &str,
+        types: &TypeDeclarations,
         type_pos: TypePosition,
     ) -> String {
+        if self.is_nested_container() {
+            let convert_val = self.ty.convert_swift_expression_to_ffi_type(
+                "val",
+                types,
+                TypePosition::SharedStructField,
+            );
+            return format!(
+                "{{ var option = {ffi_name}(); if let val = {expression} {{ option.is_some = true; option.val = {convert_val} }}; return option }}()",
+                ffi_name = self.nested_ffi_c_name(types),
+            );
+        }
+
         match self.ty.deref() {
             BridgedType::Bridgeable(b) => {
                 b.convert_option_swift_expression_to_ffi_type(expression, type_pos)
@@ -294,15 +457,12 @@ impl BridgedOption {
                 | StdLibType::Isize
                 | StdLibType::F32
                 | StdLibType::F64
-                | StdLibType::Bool => {
+                | StdLibType::Bool
+                | StdLibType::Char
+                | StdLibType::U128
+                | StdLibType::I128 => {
                     format!("{expression}.intoFfiRepr()")
                 }
-                StdLibType::Pointer(_) => {
-                    todo!("Option<*const T> and Option<*mut T> are not yet supported")
-                }
-                StdLibType::RefSlice(_) => {
-                    todo!("Option<&[T]> is not yet supported")
-                }
                 StdLibType::Str => match type_pos {
                     TypePosition::FnArg(host_lang, _) => {
                         if host_lang.is_rust() {
@@ -332,16 +492,23 @@ impl BridgedOption {
                     , expression = expression
                     )
                 }
-                StdLibType::Option(_) => {
-                    todo!("Option<Option<T> is not yet supported")
+                StdLibType::Set(_) => {
+                    format!(
+                        "{{ if let val = {expression} {{ val.isOwned = false; return val.ptr }} else {{ return nil }} }}()"
+                    )
                 }
-                StdLibType::Result(_) => {
-                    todo!("Option<Result<T, E>> is not yet supported")
+                StdLibType::BoxedFn(_) => {
+                    unreachable!("Boxed functions can only be function arguments")
                 }
-                StdLibType::BoxedFnOnce(_) => {
-                    todo!("Option<Box<dyn FnOnce(A, B) -> C>> is not yet supported")
+                StdLibType::Pointer(_)
+                | StdLibType::RefSlice(_)
+                | StdLibType::Map(_)
+                | StdLibType::Option(_)
+                | StdLibType::Result(_)
+                | StdLibType::Tuple(_)
+                | StdLibType::Array(_) => {
+                    unreachable!("Nested containers are handled above")
                 }
-                StdLibType::Tuple(_) => todo!(),
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
                 let ffi_name = shared_struct.ffi_option_name_string();
@@ -368,6 +535,13 @@ impl BridgedOption {
         type_pos: TypePosition,
         types: &TypeDeclarations,
     ) -> String {
+        // A nested `Result<T, E>` is a `RustResult<T, E>` value, not a throwing function.
+        let inner_type_pos = if self.is_nested_container() {
+            TypePosition::SharedStructField
+        } else {
+            type_pos
+        };
+
         match type_pos {
             TypePosition::FnArg(func_host_lang, _) => {
                 if func_host_lang.is_swift() {
@@ -375,7 +549,8 @@ impl BridgedOption {
                 } else {
                     format!(
                         "Optional<{}>",
-                        self.ty.to_swift_type(type_pos, types, swift_bridge_path)
+                        self.ty
+                            .to_swift_type(inner_type_pos, types, swift_bridge_path)
                     )
                 }
             }
@@ -385,14 +560,16 @@ impl BridgedOption {
                 } else {
                     format!(
                         "Optional<{}>",
-                        self.ty.to_swift_type(type_pos, types, swift_bridge_path)
+                        self.ty
+                            .to_swift_type(inner_type_pos, types, swift_bridge_path)
                     )
                 }
             }
             TypePosition::SharedStructField => {
                 format!(
                     "Optional<{}>",
-                    self.ty.to_swift_type(type_pos, types, swift_bridge_path)
+                    self.ty
+                        .to_swift_type(inner_type_pos, types, swift_bridge_path)
                 )
             }
             TypePosition::ResultFfiReturnType => {
@@ -408,6 +585,10 @@ impl BridgedOption {
         swift_bridge_path: &Path,
         types: &TypeDeclarations,
     ) -> String {
+        if self.is_nested_container() {
+            return self.nested_ffi_c_name(types);
+        }
+
         match self.ty.deref() {
             BridgedType::StdLib(stdlib_type) => match stdlib_type {
                 StdLibType::Null => {
@@ -425,31 +606,29 @@ impl BridgedOption {
                 | StdLibType::Isize
                 | StdLibType::F32
                 | StdLibType::F64
-                | StdLibType::Bool => BuiltInPrimitive::new_with_stdlib_type(stdlib_type)
+                | StdLibType::Bool
+                | StdLibType::Char
+                | StdLibType::U128
+                | StdLibType::I128 => BuiltInPrimitive::new_with_stdlib_type(stdlib_type)
                     .unwrap()
                     .to_option_ffi_repr_name()
                     .to_string(),
-                StdLibType::Pointer(_) => {
-                    todo!()
-                }
-                StdLibType::RefSlice(_) => {
-                    todo!()
-                }
                 StdLibType::Str => "RustStr".to_string(),
                 StdLibType::Vec(_) => {
                     todo!()
                 }
-                StdLibType::BoxedFnOnce(_) => {
+                StdLibType::Set(_) => "UnsafeMutableRawPointer?".to_string(),
+                StdLibType::BoxedFn(_) => {
                     todo!()
                 }
-                StdLibType::Option(_) => {
-                    todo!()
-                }
-                StdLibType::Result(_) => {
-                    todo!()
-                }
-                StdLibType::Tuple(_) => {
-                    todo!()
+                StdLibType::Pointer(_)
+                | StdLibType::RefSlice(_)
+                | StdLibType::Map(_)
+                | StdLibType::Option(_)
+                | StdLibType::Result(_)
+                | StdLibType::Tuple(_)
+                | StdLibType::Array(_) => {
+                    unreachable!("Nested containers are handled above")
                 }
             },
             BridgedType::Foreign(_) => {
@@ -463,7 +642,11 @@ impl BridgedOption {
 }
 
 impl BridgedOption {
-    pub fn to_c(&self) -> String {
+    pub fn to_c(&self, types: &TypeDeclarations) -> String {
+        if self.is_nested_container() {
+            return format!("struct {}", self.nested_ffi_c_name(types));
+        }
+
         match self.ty.deref() {
             BridgedType::Bridgeable(b) => b.to_ffi_compatible_option_c_type(),
             BridgedType::StdLib(stdlib_type) => match stdlib_type {
@@ -483,24 +666,24 @@ impl BridgedOption {
                 StdLibType::F32 => "struct __private__OptionF32".to_string(),
                 StdLibType::F64 => "struct __private__OptionF64".to_string(),
                 StdLibType::Bool => "struct __private__OptionBool".to_string(),
-                StdLibType::Pointer(_) => {
-                    todo!("Option<*const T> and Option<*mut T> are not yet supported")
-                }
-                StdLibType::RefSlice(_) => {
-                    todo!("Option<&[T]> is not yet supported")
-                }
+                StdLibType::Char => "struct __private__OptionChar".to_string(),
+                StdLibType::U128 => "struct __private__OptionU128".to_string(),
+                StdLibType::I128 => "struct __private__OptionI128".to_string(),
                 StdLibType::Str => "struct RustStr".to_string(),
                 StdLibType::Vec(_) => "void*".to_string(),
-                StdLibType::Option(_) => {
-                    todo!("Option<Option<T>> is not yet supported")
-                }
-                StdLibType::Result(_) => {
-                    todo!("Option<Result<T, E>> is not yet supported")
-                }
-                StdLibType::BoxedFnOnce(_) => {
-                    todo!("Option<Box<dyn FnOnce(A, B) -> C>> is not yet supported")
+                StdLibType::Set(_) => "void*".to_string(),
+                StdLibType::BoxedFn(_) => {
+                    unreachable!("Boxed functions can only be function arguments")
+                }
+                StdLibType::Pointer(_)
+                | StdLibType::RefSlice(_)
+                | StdLibType::Map(_)
+                | StdLibType::Option(_)
+                | StdLibType::Result(_)
+                | StdLibType::Tuple(_)
+                | StdLibType::Array(_) => {
+                    unreachable!("Nested containers are handled above")
                 }
-                StdLibType::Tuple(_) => todo!(),
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
                 format!("struct {}", shared_struct.ffi_option_name_string())
@@ -512,6 +695,155 @@ impl BridgedOption {
     }
 }
 
+/// `Option<T>` where `T` is itself a container, such as `Option<Option<T>>`,
+/// `Option<Result<T, E>>`, `Option<(A, B)>`, `Option<HashMap<K, V>>`, `Option<*const T>` or
+/// `Option<&[T]>`.
+///
+/// These have no spare value that can be used to represent `None`, so each one crosses the FFI
+/// boundary as a generated `{ is_some, val }` struct.
+impl BridgedOption {
+    pub(super) fn is_nested_container(&self) -> bool {
+        match self.ty.deref() {
+            BridgedType::StdLib(StdLibType::Pointer(_))
+            | BridgedType::StdLib(StdLibType::RefSlice(_))
+            | BridgedType::StdLib(StdLibType::Map(_))
+            | BridgedType::StdLib(StdLibType::Option(_))
+            | BridgedType::StdLib(StdLibType::Result(_))
+            | BridgedType::StdLib(StdLibType::Tuple(_))
+            | BridgedType::StdLib(StdLibType::Array(_)) => true,
+            BridgedType::StdLib(StdLibType::Vec(vec)) => vec.is_converted_to_swift_array(),
+            _ => false,
+        }
+    }
+
+    /// `Option<Option<u8>>` -> `__swift_bridge__Option_OptionU8`
+    pub(super) fn nested_ffi_rust_name(&self, types: &TypeDeclarations) -> Ident {
+        format_ident!(
+            "{}Option_{}",
+            SWIFT_BRIDGE_PREFIX,
+            self.ty.to_alpha_numeric_underscore_name(types)
+        )
+    }
+
+    /// `Option<Option<u8>>` -> `__swift_bridge__$Option$OptionU8`
+    fn nested_ffi_c_name(&self, types: &TypeDeclarations) -> String {
+        format!(
+            "{}$Option${}",
+            SWIFT_BRIDGE_PREFIX,
+            self.ty.to_alpha_numeric_underscore_name(types)
+        )
+    }
+
+    pub(super) fn generate_custom_rust_ffi_types(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        let mut custom_rust_ffi_types = self
+            .ty
+            .generate_custom_rust_ffi_types(swift_bridge_path, types)
+            .unwrap_or_default();
+
+        if self.is_nested_container() {
+            let ty = self.nested_ffi_rust_name(types);
+            let val = self
+                .ty
+                .to_ffi_compatible_rust_type(swift_bridge_path, types);
+
+            // TODO: remove `#[allow(unused)]` when rustc no longer issues dead code warnings for `#[repr(C)]`
+            //  structs or enums: https://github.com/rust-lang/rust/issues/126706
+            custom_rust_ffi_types.push(quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct #ty {
+                    #[allow(unused)]
+                    is_some: bool,
+                    #[allow(unused)]
+                    val: std::mem::MaybeUninit<#val>,
+                }
+            });
+        }
+
+        if custom_rust_ffi_types.is_empty() {
+            None
+        } else {
+            Some(custom_rust_ffi_types)
+        }
+    }
+
+    pub(super) fn generate_custom_c_ffi_types(
+        &self,
+        types: &TypeDeclarations,
+    ) -> Option<CFfiStruct> {
+        let inner = self.ty.generate_custom_c_ffi_types(types);
+
+        if !self.is_nested_container() {
+            return inner;
+        }
+
+        let c_name = self.nested_ffi_c_name(types);
+        let c_ffi_type = format!(
+            "typedef struct {c_name} {{ bool is_some; {val} val; }} {c_name};",
+            val = self.ty.to_c(types)
+        );
+        Some(CFfiStruct {
+            c_ffi_type,
+            fields: inner.into_iter().collect(),
+        })
+    }
+
+    fn convert_rust_expression_to_nested_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+        span: Span,
+    ) -> TokenStream {
+        let ty = self.nested_ffi_rust_name(types);
+        let convert_val = self.ty.convert_rust_expression_to_ffi_type(
+            &quote! { val },
+            swift_bridge_path,
+            types,
+            span,
+        );
+
+        quote! {
+            if let Some(val) = #expression {
+                #ty { is_some: true, val: std::mem::MaybeUninit::new(#convert_val) }
+            } else {
+                #ty { is_some: false, val: std::mem::MaybeUninit::uninit() }
+            }
+        }
+    }
+
+    fn convert_nested_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        span: Span,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let convert_val = self.ty.convert_ffi_expression_to_rust_type(
+            &quote! { val },
+            span,
+            swift_bridge_path,
+            types,
+        );
+
+        quote! {
+            {
+                let val = #expression;
+                if val.is_some {
+                    let val = unsafe { val.val.assume_init() };
+                    Some(#convert_val)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
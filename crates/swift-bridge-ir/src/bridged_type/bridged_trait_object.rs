@@ -0,0 +1,400 @@
+use crate::bridged_type::{
+    BridgeableType, BridgedOption, BuiltInResult, CFfiStruct, OnlyEncoding, TypePosition,
+    UnusedOptionNoneValue,
+};
+use crate::parse::TypeDeclarations;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{Path, Type, TypeParamBound};
+
+/// `&dyn SomeTrait`, `&mut dyn SomeTrait`, `Box<dyn SomeTrait>` or `Arc<dyn SomeTrait>`, where
+/// `SomeTrait` was declared in the bridge module.
+///
+/// The trait object can also have `Send` and `Sync` bounds, such as
+/// `Arc<dyn SomeTrait + Send + Sync>`, as long as `SomeTrait` was declared with those supertraits.
+///
+/// Swift sees the trait as a protocol. Any object that conforms to the protocol, such as a Swift
+/// class or an opaque Rust type that implements the trait, can be passed to Rust.
+///
+/// Swift passes Rust a retained pointer to the object. Rust wraps the pointer in a type that
+/// implements the trait by calling the object's protocol methods, and releases the object when
+/// the wrapper is dropped.
+#[derive(Debug)]
+pub(crate) struct BridgedTraitObject {
+    pub trait_name: Ident,
+    pub kind: TraitObjectKind,
+    /// `dyn SomeTrait + Send`
+    pub send: bool,
+    /// `dyn SomeTrait + Sync`
+    pub sync: bool,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum TraitObjectKind {
+    /// `&dyn SomeTrait`
+    Ref,
+    /// `&mut dyn SomeTrait`
+    RefMut,
+    /// `Box<dyn SomeTrait>`
+    Boxed,
+    /// `Arc<dyn SomeTrait>`
+    Arc,
+}
+
+impl BridgedTraitObject {
+    /// Parse a `dyn SomeTrait`, where `SomeTrait` was declared in the bridge module.
+    fn from_trait_object(
+        ty: &Type,
+        kind: TraitObjectKind,
+        types: &TypeDeclarations,
+    ) -> Option<Self> {
+        let trait_object = match ty {
+            Type::TraitObject(trait_object) => trait_object,
+            // `&(dyn SomeTrait + Send)`
+            Type::Paren(paren) => return Self::from_trait_object(&paren.elem, kind, types),
+            _ => return None,
+        };
+
+        let mut trait_name = None;
+        let mut send = false;
+        let mut sync = false;
+        for bound in trait_object.bounds.iter() {
+            let bound = match bound {
+                TypeParamBound::Trait(bound) => bound.path.get_ident()?,
+                _ => return None,
+            };
+
+            if bound == "Send" {
+                send = true;
+            } else if bound == "Sync" {
+                sync = true;
+            } else if trait_name.is_none() {
+                trait_name = Some(bound.clone());
+            } else {
+                return None;
+            }
+        }
+
+        // The Swift object wrapper only implements `Send` and `Sync` if the trait has them as
+        // supertraits.
+        let trait_name = trait_name?;
+        let trait_declaration = types.get_trait(&trait_name.to_string())?;
+        if (send && !trait_declaration.send) || (sync && !trait_declaration.sync) {
+            return None;
+        }
+
+        Some(BridgedTraitObject {
+            trait_name,
+            kind,
+            send,
+            sync,
+        })
+    }
+
+    fn swift_protocol_type(&self) -> String {
+        format!("any {}", self.trait_name)
+    }
+}
+
+impl BridgeableType for BridgedTraitObject {
+    fn is_built_in_type(&self) -> bool {
+        false
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn as_option(&self) -> Option<&BridgedOption> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        true
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        None
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        None
+    }
+
+    fn to_rust_type_path(&self, _types: &TypeDeclarations) -> TokenStream {
+        let trait_name = &self.trait_name;
+        let maybe_send = if self.send {
+            quote! { + Send }
+        } else {
+            quote! {}
+        };
+        let maybe_sync = if self.sync {
+            quote! { + Sync }
+        } else {
+            quote! {}
+        };
+        let trait_object = quote! { dyn super::#trait_name #maybe_send #maybe_sync };
+
+        match self.kind {
+            TraitObjectKind::Ref => quote! { &#trait_object },
+            TraitObjectKind::RefMut => quote! { &mut #trait_object },
+            TraitObjectKind::Boxed => quote! { Box<#trait_object> },
+            TraitObjectKind::Arc => quote! { std::sync::Arc<#trait_object> },
+        }
+    }
+
+    fn to_swift_type(
+        &self,
+        type_pos: TypePosition,
+        _types: &TypeDeclarations,
+        _swift_bridge_path: &Path,
+    ) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
+                if func_host_lang.is_rust() {
+                    self.swift_protocol_type()
+                } else {
+                    "UnsafeMutableRawPointer".to_string()
+                }
+            }
+            TypePosition::SharedStructField => self.swift_protocol_type(),
+            TypePosition::ResultFfiReturnType | TypePosition::ThrowingInit(_) => {
+                unreachable!("Trait objects can only be Rust function arguments")
+            }
+        }
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        "void*".to_string()
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        None
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote! { *mut std::ffi::c_void }
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Trait objects can't be nested in an Option")
+    }
+
+    fn to_ffi_compatible_option_swift_type(
+        &self,
+        _type_pos: TypePosition,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> String {
+        unreachable!("Trait objects can't be nested in an Option")
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        unreachable!("Trait objects can't be nested in an Option")
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        _expression: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        _span: Span,
+    ) -> TokenStream {
+        unreachable!("Trait objects can only be passed from Swift to Rust")
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        _expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        unreachable!("Trait objects can't be nested in an Option")
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!("Unmanaged.passRetained({expression} as AnyObject).toOpaque()")
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        _expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        unreachable!("Trait objects can't be nested in an Option")
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        span: Span,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let wrapper = types
+            .get_trait(&self.trait_name.to_string())
+            .unwrap()
+            .swift_object_wrapper_ident();
+
+        match self.kind {
+            TraitObjectKind::Ref => quote_spanned! {span=> &#wrapper(#expression) },
+            TraitObjectKind::RefMut => quote_spanned! {span=> &mut #wrapper(#expression) },
+            TraitObjectKind::Boxed => quote_spanned! {span=> Box::new(#wrapper(#expression)) },
+            TraitObjectKind::Arc => {
+                quote_spanned! {span=> std::sync::Arc::new(#wrapper(#expression)) }
+            }
+        }
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(&self, _expression: &TokenStream) -> TokenStream {
+        unreachable!("Trait objects can't be nested in an Option")
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        _expression: &str,
+        _type_pos: TypePosition,
+        _types: &TypeDeclarations,
+        _swift_bridge_path: &Path,
+    ) -> String {
+        unreachable!("Trait objects can only be passed from Swift to Rust")
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, _expression: &str) -> String {
+        unreachable!("Trait objects can't be nested in an Option")
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        _ok_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Trait objects can't be nested in a Result")
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        _err_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Trait objects can't be nested in a Result")
+    }
+
+    fn unused_option_none_val(&self, _swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        unreachable!("Trait objects can't be nested in an Option")
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        tokens.starts_with("Box < dyn ")
+            || tokens.starts_with("Arc < dyn ")
+            || tokens.starts_with("std :: sync :: Arc < dyn ")
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Reference(ty_ref) => {
+                let kind = if ty_ref.mutability.is_some() {
+                    TraitObjectKind::RefMut
+                } else {
+                    TraitObjectKind::Ref
+                };
+
+                Self::from_trait_object(&ty_ref.elem, kind, types)
+            }
+            Type::Path(path) => {
+                let last = path.path.segments.last()?;
+                let kind = if last.ident == "Box" {
+                    TraitObjectKind::Boxed
+                } else if last.ident == "Arc" {
+                    TraitObjectKind::Arc
+                } else {
+                    return None;
+                };
+
+                let inner = match &last.arguments {
+                    syn::PathArguments::AngleBracketed(args) if args.args.len() == 1 => {
+                        match args.args.first()? {
+                            syn::GenericArgument::Type(inner) => inner,
+                            _ => return None,
+                        }
+                    }
+                    _ => return None,
+                };
+
+                Self::from_trait_object(inner, kind, types)
+            }
+            _ => None,
+        }
+    }
+
+    fn parse_token_stream_str(tokens: &str, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let ty: Type = syn::parse_str(tokens).ok()?;
+        Self::from_type(&ty, types)
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, _types: &TypeDeclarations) -> String {
+        let prefix = match self.kind {
+            TraitObjectKind::Ref => "RefDyn",
+            TraitObjectKind::RefMut => "RefMutDyn",
+            TraitObjectKind::Boxed => "BoxDyn",
+            TraitObjectKind::Arc => "ArcDyn",
+        };
+
+        format!("{}{}", prefix, self.trait_name)
+    }
+}
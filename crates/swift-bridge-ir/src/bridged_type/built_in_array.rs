@@ -0,0 +1,323 @@
+use crate::bridged_type::{
+    BridgeableType, BridgedType, CFfiStruct, CustomBridgedType, SharedType, StdLibType,
+    TypePosition,
+};
+use crate::parse::TypeDeclarations;
+use crate::SWIFT_BRIDGE_PREFIX;
+use proc_macro2::{Ident, Literal, Span, TokenStream};
+use quote::{format_ident, quote};
+use std::ops::Deref;
+use syn::{Expr, Lit, Path, TypeArray};
+
+/// [T; N]
+///
+/// A fixed-size array crosses the FFI boundary by value inside of a generated `#[repr(C)]`
+/// struct, such as `__swift_bridge__$Array$U8$32 { uint8_t values[32]; }`.
+/// Swift sees the array as an `Array`, and checks its length before passing it to Rust.
+#[derive(Debug)]
+pub(crate) struct BuiltInArray {
+    pub ty: Box<BridgedType>,
+    pub len: usize,
+}
+
+impl BuiltInArray {
+    /// Parse a `[T; N]` where `N` is an integer literal.
+    ///
+    /// Returns `None` if `N` is not an integer literal, if the array is empty, or if `T` can't be
+    /// stored in a C array.
+    pub fn from_type_array(array: &TypeArray, types: &TypeDeclarations) -> Option<Self> {
+        let len = Self::len(array)?;
+
+        let ty = BridgedType::new_with_type(&array.elem, types)?;
+        if !Self::is_supported_element(&ty) {
+            return None;
+        }
+
+        Some(BuiltInArray {
+            ty: Box::new(ty),
+            len,
+        })
+    }
+
+    /// The `N` in `[T; N]`, if it is an integer literal that isn't zero.
+    pub(crate) fn len(array: &TypeArray) -> Option<usize> {
+        let len = match &array.len {
+            Expr::Lit(lit) => match &lit.lit {
+                Lit::Int(int) => int.base10_parse::<usize>().ok()?,
+                _ => return None,
+            },
+            _ => return None,
+        };
+
+        (len != 0).then_some(len)
+    }
+
+    /// Elements get copied in and out of the C array by value, so they need to be plain values
+    /// that Swift and Rust can both represent without any pointers.
+    pub(crate) fn is_supported_element(ty: &BridgedType) -> bool {
+        matches!(
+            ty,
+            BridgedType::StdLib(
+                StdLibType::U8
+                    | StdLibType::I8
+                    | StdLibType::U16
+                    | StdLibType::I16
+                    | StdLibType::U32
+                    | StdLibType::I32
+                    | StdLibType::U64
+                    | StdLibType::I64
+                    | StdLibType::Usize
+                    | StdLibType::Isize
+                    | StdLibType::F32
+                    | StdLibType::F64
+                    | StdLibType::Bool
+                    | StdLibType::Array(_)
+            ) | BridgedType::Foreign(CustomBridgedType::Shared(
+                SharedType::Struct(_) | SharedType::Enum(_)
+            ))
+        )
+    }
+
+    /// Primitive elements have the same representation on both sides of the FFI boundary.
+    fn elements_need_conversion(&self) -> bool {
+        !matches!(
+            self.ty.deref(),
+            BridgedType::StdLib(
+                StdLibType::U8
+                    | StdLibType::I8
+                    | StdLibType::U16
+                    | StdLibType::I16
+                    | StdLibType::U32
+                    | StdLibType::I32
+                    | StdLibType::U64
+                    | StdLibType::I64
+                    | StdLibType::Usize
+                    | StdLibType::Isize
+                    | StdLibType::F32
+                    | StdLibType::F64
+                    | StdLibType::Bool
+            )
+        )
+    }
+
+    /// `[u8; 32]` -> `__swift_bridge__Array_U8_32`
+    fn ffi_rust_name(&self, types: &TypeDeclarations) -> Ident {
+        format_ident!(
+            "{}Array_{}_{}",
+            SWIFT_BRIDGE_PREFIX,
+            self.ty.to_alpha_numeric_underscore_name(types),
+            self.len
+        )
+    }
+
+    /// `[u8; 32]` -> `__swift_bridge__$Array$U8$32`
+    fn ffi_c_name(&self, types: &TypeDeclarations) -> String {
+        format!(
+            "{}$Array${}${}",
+            SWIFT_BRIDGE_PREFIX,
+            self.ty.to_alpha_numeric_underscore_name(types),
+            self.len
+        )
+    }
+
+    pub fn to_alpha_numeric_underscore_name(&self, types: &TypeDeclarations) -> String {
+        format!(
+            "Array{}_{}",
+            self.ty.to_alpha_numeric_underscore_name(types),
+            self.len
+        )
+    }
+
+    pub fn to_rust_type_path(&self, types: &TypeDeclarations) -> TokenStream {
+        let ty = self.ty.to_rust_type_path(types);
+        let len = Literal::usize_unsuffixed(self.len);
+        quote! { [#ty; #len] }
+    }
+
+    pub fn to_ffi_compatible_rust_type(&self, types: &TypeDeclarations) -> TokenStream {
+        let ty = self.ffi_rust_name(types);
+        quote! { #ty }
+    }
+
+    pub fn to_swift_type(
+        &self,
+        type_pos: TypePosition,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang)
+                if func_host_lang.is_swift() =>
+            {
+                self.ffi_c_name(types)
+            }
+            TypePosition::ResultFfiReturnType => self.ffi_c_name(types),
+            TypePosition::ThrowingInit(_) => unimplemented!(),
+            _ => format!("[{}]", self.element_swift_type(types, swift_bridge_path)),
+        }
+    }
+
+    pub fn to_c(&self, types: &TypeDeclarations) -> String {
+        format!("struct {}", self.ffi_c_name(types))
+    }
+
+    pub fn to_c_include(&self, types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        self.ty.to_c_include(types)
+    }
+
+    pub fn generate_custom_rust_ffi_types(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        let mut custom_rust_ffi_types = self
+            .ty
+            .generate_custom_rust_ffi_types(swift_bridge_path, types)
+            .unwrap_or_default();
+
+        let ty = self.ffi_rust_name(types);
+        let element = self
+            .ty
+            .to_ffi_compatible_rust_type(swift_bridge_path, types);
+        let len = Literal::usize_unsuffixed(self.len);
+
+        // TODO: remove `#[allow(unused)]` when rustc no longer issues dead code warnings for `#[repr(C)]`
+        //  structs or enums: https://github.com/rust-lang/rust/issues/126706
+        custom_rust_ffi_types.push(quote! {
+            #[repr(C)]
+            #[doc(hidden)]
+            pub struct #ty(#[allow(unused)] [#element; #len]);
+        });
+
+        Some(custom_rust_ffi_types)
+    }
+
+    pub fn generate_custom_c_ffi_types(&self, types: &TypeDeclarations) -> CFfiStruct {
+        let c_name = self.ffi_c_name(types);
+        let c_ffi_type = format!(
+            "typedef struct {c_name} {{ {element} values[{len}]; }} {c_name};",
+            element = self.ty.to_c(types),
+            len = self.len
+        );
+
+        CFfiStruct {
+            c_ffi_type,
+            fields: self
+                .ty
+                .generate_custom_c_ffi_types(types)
+                .into_iter()
+                .collect(),
+        }
+    }
+
+    pub fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+        span: Span,
+    ) -> TokenStream {
+        let ty = self.ffi_rust_name(types);
+
+        if !self.elements_need_conversion() {
+            return quote! { #ty(#expression) };
+        }
+
+        let convert_element = self.ty.convert_rust_expression_to_ffi_type(
+            &quote! { val },
+            swift_bridge_path,
+            types,
+            span,
+        );
+        quote! {
+            #ty(#expression.map(|val| #convert_element))
+        }
+    }
+
+    pub fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        span: Span,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        if !self.elements_need_conversion() {
+            return quote! { #expression.0 };
+        }
+
+        let convert_element = self.ty.convert_ffi_expression_to_rust_type(
+            &quote! { val },
+            span,
+            swift_bridge_path,
+            types,
+        );
+        quote! {
+            #expression.0.map(|val| #convert_element)
+        }
+    }
+
+    pub fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> String {
+        let convert_element = self.ty.convert_ffi_value_to_swift_value(
+            "val",
+            TypePosition::SharedStructField,
+            types,
+            swift_bridge_path,
+        );
+        format!(
+            "__private__intoSwiftFixedSizeArray({expression}, {len}, {{ (val: {ffi_ty}) -> {swift_ty} in {convert_element} }})",
+            len = self.len,
+            ffi_ty = self.element_ffi_swift_type(types, swift_bridge_path),
+            swift_ty = self.element_swift_type(types, swift_bridge_path),
+        )
+    }
+
+    /// The element type of the Swift `Array` gets inferred from the expression.
+    pub fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        types: &TypeDeclarations,
+    ) -> String {
+        let ffi_name = self.ffi_c_name(types);
+        let len = self.len;
+
+        if !self.elements_need_conversion() {
+            return format!(
+                "__private__intoFfiFixedSizeArray({ffi_name}(), {expression}, {len}, {{ $0 }})"
+            );
+        }
+
+        let convert_element = self.ty.convert_swift_expression_to_ffi_type(
+            "val",
+            types,
+            TypePosition::SharedStructField,
+        );
+        format!(
+            "__private__intoFfiFixedSizeArray({ffi_name}(), {expression}, {len}, {{ (val) -> {ffi_ty} in {convert_element} }})",
+            ffi_ty = self.ty.to_c(types).trim_start_matches("struct "),
+        )
+    }
+
+    /// The Swift type of an element of the Swift `Array`, such as `UInt8`.
+    fn element_swift_type(&self, types: &TypeDeclarations, swift_bridge_path: &Path) -> String {
+        self.ty
+            .to_swift_type(TypePosition::SharedStructField, types, swift_bridge_path)
+    }
+
+    /// The type that Swift sees when it reads an element out of the C array, such as
+    /// `UInt8` or `__swift_bridge__$SomeStruct`.
+    fn element_ffi_swift_type(&self, types: &TypeDeclarations, swift_bridge_path: &Path) -> String {
+        if self.elements_need_conversion() {
+            self.ty
+                .to_c(types)
+                .trim_start_matches("struct ")
+                .to_string()
+        } else {
+            self.element_swift_type(types, swift_bridge_path)
+        }
+    }
+}
@@ -0,0 +1,242 @@
+use crate::bridged_type::{BridgedType, TypePosition};
+use crate::parse::{HostLang, TypeDeclarations};
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::str::FromStr;
+use syn::{GenericArgument, Path, PathArguments, Type};
+
+/// `HashMap<K, V>` or `BTreeMap<K, V>`
+///
+/// Maps cross the FFI boundary as a `swift_bridge::map::FfiMap<K, V>`, which holds a `Vec<K>` of
+/// keys and a `Vec<V>` of values. On the Swift side this becomes a `RustMap<K, V>`.
+#[derive(Debug)]
+pub(crate) struct BuiltInMap {
+    pub kind: MapKind,
+    pub key: Box<BridgedType>,
+    pub value: Box<BridgedType>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum MapKind {
+    HashMap,
+    BTreeMap,
+}
+
+impl BuiltInMap {
+    /// Whether or not the stringified tokens are a map type such as "HashMap < u8 , u16 >" or
+    /// "std :: collections :: BTreeMap < String , u32 >".
+    pub fn can_parse_str_tokens(tokens: &str) -> bool {
+        let tokens = tokens.trim_start_matches("std :: collections :: ");
+        tokens.starts_with("HashMap < ") || tokens.starts_with("BTreeMap < ")
+    }
+
+    pub fn from_str_tokens(tokens: &str, types: &TypeDeclarations) -> Option<Self> {
+        let ty: Type = syn::parse2(TokenStream::from_str(tokens).ok()?).ok()?;
+        let ty = match ty {
+            Type::Path(ty) => ty,
+            _ => return None,
+        };
+
+        let last = ty.path.segments.last()?;
+        let kind = match last.ident.to_string().as_str() {
+            "HashMap" => MapKind::HashMap,
+            "BTreeMap" => MapKind::BTreeMap,
+            _ => return None,
+        };
+
+        let generics = match &last.arguments {
+            PathArguments::AngleBracketed(generics) => generics,
+            _ => return None,
+        };
+        let mut generics = generics.args.iter().filter_map(|arg| match arg {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        });
+
+        let key = BridgedType::new_with_type(generics.next()?, types)?;
+        let value = BridgedType::new_with_type(generics.next()?, types)?;
+
+        Some(BuiltInMap {
+            kind,
+            key: Box::new(key),
+            value: Box::new(value),
+        })
+    }
+}
+
+impl BuiltInMap {
+    pub fn to_rust_type_path(&self, types: &TypeDeclarations) -> TokenStream {
+        let key = self.key.to_rust_type_path(types);
+        let value = self.value.to_rust_type_path(types);
+
+        match self.kind {
+            MapKind::HashMap => quote! { std::collections::HashMap<#key, #value> },
+            MapKind::BTreeMap => quote! { std::collections::BTreeMap<#key, #value> },
+        }
+    }
+
+    pub fn to_ffi_compatible_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let key = vec_element_rust_type(&self.key, swift_bridge_path, types);
+        let value = vec_element_rust_type(&self.value, swift_bridge_path, types);
+
+        quote! { #swift_bridge_path::map::FfiMap<#key, #value> }
+    }
+
+    pub fn to_swift_type(
+        &self,
+        type_pos: TypePosition,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang)
+                if func_host_lang.is_swift() =>
+            {
+                "__private__FfiMap".to_string()
+            }
+            TypePosition::ResultFfiReturnType => "__private__FfiMap".to_string(),
+            _ => {
+                format!(
+                    "RustMap<{}, {}>",
+                    vec_element_swift_type(&self.key, types, swift_bridge_path),
+                    vec_element_swift_type(&self.value, types, swift_bridge_path)
+                )
+            }
+        }
+    }
+
+    pub fn to_c(&self) -> String {
+        "struct __private__FfiMap".to_string()
+    }
+
+    pub fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let key = quote! { key };
+        let value = quote! { value };
+
+        let key_converted = rust_value_to_vec_element(&self.key, &key, swift_bridge_path, types);
+        let value_converted =
+            rust_value_to_vec_element(&self.value, &value, swift_bridge_path, types);
+
+        if key_converted.to_string() == key.to_string()
+            && value_converted.to_string() == value.to_string()
+        {
+            quote! {
+                #swift_bridge_path::map::FfiMap::from_entries(#expression)
+            }
+        } else {
+            quote! {
+                #swift_bridge_path::map::FfiMap::from_entries(
+                    #expression.into_iter().map(|(#key, #value)| (#key_converted, #value_converted))
+                )
+            }
+        }
+    }
+
+    pub fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let key = quote! { key };
+        let value = quote! { value };
+
+        let key_converted = vec_element_to_rust_value(&self.key, &key, types);
+        let value_converted = vec_element_to_rust_value(&self.value, &value, types);
+
+        let map = self.to_rust_type_path(types);
+
+        let maybe_convert_entries = if key_converted.to_string() == key.to_string()
+            && value_converted.to_string() == value.to_string()
+        {
+            quote! {}
+        } else {
+            quote! { .map(|(#key, #value)| (#key_converted, #value_converted)) }
+        };
+
+        quote! {
+            #expression.into_entries() #maybe_convert_entries .collect::<#map>()
+        }
+    }
+
+    pub fn convert_swift_expression_to_ffi_type(&self, expression: &str) -> String {
+        format!("{}.intoFfiRepr()", expression)
+    }
+
+    pub fn convert_ffi_expression_to_swift_type(&self, expression: &str) -> String {
+        format!("RustMap(ffiRepr: {})", expression)
+    }
+}
+
+/// Keys and values are stored in a `RustVec<T>` on the Swift side, so they use the same
+/// representation as a `Vec<T>`'s elements.
+/// `String`s are stored as `RustString`s since that is what `RustVec<RustString>` expects.
+fn vec_element_rust_type(
+    ty: &BridgedType,
+    swift_bridge_path: &Path,
+    types: &TypeDeclarations,
+) -> TokenStream {
+    if is_owned_string(ty, types) {
+        quote! { #swift_bridge_path::string::RustString }
+    } else {
+        ty.to_rust_type_path(types)
+    }
+}
+
+fn vec_element_swift_type(
+    ty: &BridgedType,
+    types: &TypeDeclarations,
+    swift_bridge_path: &Path,
+) -> String {
+    if is_owned_string(ty, types) {
+        "RustString".to_string()
+    } else {
+        ty.to_swift_type(
+            TypePosition::FnReturn(HostLang::Rust),
+            types,
+            swift_bridge_path,
+        )
+    }
+}
+
+fn rust_value_to_vec_element(
+    ty: &BridgedType,
+    value: &TokenStream,
+    swift_bridge_path: &Path,
+    types: &TypeDeclarations,
+) -> TokenStream {
+    if is_owned_string(ty, types) {
+        quote! { #swift_bridge_path::string::RustString(#value) }
+    } else {
+        quote! { #value }
+    }
+}
+
+fn vec_element_to_rust_value(
+    ty: &BridgedType,
+    value: &TokenStream,
+    types: &TypeDeclarations,
+) -> TokenStream {
+    if is_owned_string(ty, types) {
+        quote! { #value.0 }
+    } else {
+        quote! { #value }
+    }
+}
+
+fn is_owned_string(ty: &BridgedType, types: &TypeDeclarations) -> bool {
+    match ty {
+        BridgedType::Bridgeable(b) => {
+            b.is_built_in_type() && b.contains_owned_string_recursive(types)
+        }
+        _ => false,
+    }
+}
@@ -1,6 +1,6 @@
 use crate::bridged_type::StdLibType;
 
-/// Primitive types such as `()`, `u8` and `bool`.
+/// Primitive types such as `()`, `u8`, `bool` and `char`.
 pub(crate) enum BuiltInPrimitive {
     Null,
     U8,
@@ -11,11 +11,14 @@ pub(crate) enum BuiltInPrimitive {
     I32,
     U64,
     I64,
+    U128,
+    I128,
     Usize,
     Isize,
     F32,
     F64,
     Bool,
+    Char,
 }
 
 impl BuiltInPrimitive {
@@ -31,11 +34,14 @@ impl BuiltInPrimitive {
             BuiltInPrimitive::I32 => "__private__OptionI32",
             BuiltInPrimitive::U64 => "__private__OptionU64",
             BuiltInPrimitive::I64 => "__private__OptionI64",
+            BuiltInPrimitive::U128 => "__private__OptionU128",
+            BuiltInPrimitive::I128 => "__private__OptionI128",
             BuiltInPrimitive::Usize => "__private__OptionUsize",
             BuiltInPrimitive::Isize => "__private__OptionIsize",
             BuiltInPrimitive::F32 => "__private__OptionF32",
             BuiltInPrimitive::F64 => "__private__OptionF64",
             BuiltInPrimitive::Bool => "__private__OptionBool",
+            BuiltInPrimitive::Char => "__private__OptionChar",
         }
     }
 
@@ -52,11 +58,14 @@ impl BuiltInPrimitive {
             StdLibType::I32 => Self::I32,
             StdLibType::U64 => Self::U64,
             StdLibType::I64 => Self::I64,
+            StdLibType::U128 => Self::U128,
+            StdLibType::I128 => Self::I128,
             StdLibType::Usize => Self::Usize,
             StdLibType::Isize => Self::Isize,
             StdLibType::F32 => Self::F32,
             StdLibType::F64 => Self::F64,
             StdLibType::Bool => Self::Bool,
+            StdLibType::Char => Self::Char,
             _ => None?,
         };
         Some(ty)
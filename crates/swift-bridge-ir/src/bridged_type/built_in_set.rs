@@ -0,0 +1,221 @@
+use crate::bridged_type::{BridgedType, StdLibType, TypePosition};
+use crate::parse::{HostLang, TypeDeclarations};
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::str::FromStr;
+use syn::{GenericArgument, Path, PathArguments, Type};
+
+/// `HashSet<T>` or `BTreeSet<T>`
+///
+/// Sets cross the FFI boundary as a `*mut HashSet<T>`. On the Swift side this becomes a
+/// `RustSet<T>`.
+///
+/// The elements can be any of the integer types, `bool` or `String`. These are the types that the
+/// `swift_bridge` runtime exposes `HashSet<T>` functions for.
+#[derive(Debug)]
+pub(crate) struct BuiltInSet {
+    pub kind: SetKind,
+    pub ty: Box<BridgedType>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum SetKind {
+    HashSet,
+    BTreeSet,
+}
+
+impl BuiltInSet {
+    /// Whether or not the stringified tokens are a set type such as "HashSet < u8 >" or
+    /// "std :: collections :: BTreeSet < String >".
+    pub fn can_parse_str_tokens(tokens: &str) -> bool {
+        let tokens = tokens.trim_start_matches("std :: collections :: ");
+        tokens.starts_with("HashSet < ") || tokens.starts_with("BTreeSet < ")
+    }
+
+    pub fn from_str_tokens(tokens: &str, types: &TypeDeclarations) -> Option<Self> {
+        let ty: Type = syn::parse2(TokenStream::from_str(tokens).ok()?).ok()?;
+        let ty = match ty {
+            Type::Path(ty) => ty,
+            _ => return None,
+        };
+
+        let last = ty.path.segments.last()?;
+        let kind = match last.ident.to_string().as_str() {
+            "HashSet" => SetKind::HashSet,
+            "BTreeSet" => SetKind::BTreeSet,
+            _ => return None,
+        };
+
+        let generics = match &last.arguments {
+            PathArguments::AngleBracketed(generics) => generics,
+            _ => return None,
+        };
+        let element = generics.args.iter().find_map(|arg| match arg {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        })?;
+        let element = BridgedType::new_with_type(element, types)?;
+
+        if !is_supported_element(&element, types) {
+            return None;
+        }
+
+        Some(BuiltInSet {
+            kind,
+            ty: Box::new(element),
+        })
+    }
+}
+
+impl BuiltInSet {
+    pub fn to_rust_type_path(&self, types: &TypeDeclarations) -> TokenStream {
+        let ty = self.ty.to_rust_type_path(types);
+
+        match self.kind {
+            SetKind::HashSet => quote! { std::collections::HashSet<#ty> },
+            SetKind::BTreeSet => quote! { std::collections::BTreeSet<#ty> },
+        }
+    }
+
+    pub fn to_ffi_compatible_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty = self.ffi_element_rust_type(swift_bridge_path, types);
+        quote! { *mut std::collections::HashSet<#ty> }
+    }
+
+    pub fn to_swift_type(
+        &self,
+        type_pos: TypePosition,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang)
+                if func_host_lang.is_swift() =>
+            {
+                "UnsafeMutableRawPointer".to_string()
+            }
+            _ => {
+                let ty = if self.is_set_of_strings(types) {
+                    "RustString".to_string()
+                } else {
+                    self.ty.to_swift_type(
+                        TypePosition::FnReturn(HostLang::Rust),
+                        types,
+                        swift_bridge_path,
+                    )
+                };
+                format!("RustSet<{}>", ty)
+            }
+        }
+    }
+
+    pub fn to_c(&self) -> String {
+        "void*".to_string()
+    }
+
+    /// Strings are stored as `RustString`s, and a `BTreeSet<T>` gets collected into a `HashSet<T>`.
+    pub fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let set = if self.is_set_of_strings(types) {
+            quote! {
+                #expression
+                    .into_iter()
+                    .map(#swift_bridge_path::string::RustString)
+                    .collect::<std::collections::HashSet<_>>()
+            }
+        } else if self.kind == SetKind::BTreeSet {
+            quote! {
+                #expression.into_iter().collect::<std::collections::HashSet<_>>()
+            }
+        } else {
+            quote! { #expression }
+        };
+
+        quote! { Box::into_raw(Box::new(#set)) }
+    }
+
+    pub fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let set = self.to_rust_type_path(types);
+        let expression = quote! { unsafe { *Box::from_raw(#expression) } };
+
+        if self.is_set_of_strings(types) {
+            quote! {
+                #expression.into_iter().map(|string| string.0).collect::<#set>()
+            }
+        } else if self.kind == SetKind::BTreeSet {
+            quote! {
+                #expression.into_iter().collect::<#set>()
+            }
+        } else {
+            expression
+        }
+    }
+
+    pub fn convert_swift_expression_to_ffi_type(&self, expression: &str) -> String {
+        format!(
+            "{{ let val = {value}; val.isOwned = false; return val.ptr }}()",
+            value = expression
+        )
+    }
+
+    pub fn convert_ffi_expression_to_swift_type(&self, expression: &str) -> String {
+        format!("RustSet(ptr: {})", expression)
+    }
+
+    fn ffi_element_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        if self.is_set_of_strings(types) {
+            quote! { #swift_bridge_path::string::RustString }
+        } else {
+            self.ty.to_rust_type_path(types)
+        }
+    }
+
+    fn is_set_of_strings(&self, types: &TypeDeclarations) -> bool {
+        is_owned_string(&self.ty, types)
+    }
+}
+
+fn is_supported_element(ty: &BridgedType, types: &TypeDeclarations) -> bool {
+    match ty {
+        BridgedType::StdLib(ty) => matches!(
+            ty,
+            StdLibType::U8
+                | StdLibType::I8
+                | StdLibType::U16
+                | StdLibType::I16
+                | StdLibType::U32
+                | StdLibType::I32
+                | StdLibType::U64
+                | StdLibType::I64
+                | StdLibType::Usize
+                | StdLibType::Isize
+                | StdLibType::Bool
+        ),
+        _ => is_owned_string(ty, types),
+    }
+}
+
+fn is_owned_string(ty: &BridgedType, types: &TypeDeclarations) -> bool {
+    match ty {
+        BridgedType::Bridgeable(b) => {
+            b.is_built_in_type() && b.contains_owned_string_recursive(types)
+        }
+        _ => false,
+    }
+}
@@ -82,11 +82,16 @@ impl BridgeableType for BuiltInTuple {
             .0
             .combine_field_types_into_ffi_name_tokens(swift_bridge_path, types);
         let prefixed_ty_name = self.prefixed_ty_name(types);
-        Some(vec![quote! {
+        let mut custom_rust_ffi_types = vec![quote! {
             #[repr(C)]
             #[doc(hidden)]
             pub struct #prefixed_ty_name ( #(#combined_types_tokens),* );
-        }])
+        }];
+        custom_rust_ffi_types.extend(
+            self.0
+                .generate_field_custom_rust_ffi_types(swift_bridge_path, types),
+        );
+        Some(custom_rust_ffi_types)
     }
 
     fn generate_custom_c_ffi_types(&self, types: &TypeDeclarations) -> Option<CFfiStruct> {
@@ -97,7 +102,7 @@ impl BridgeableType for BuiltInTuple {
         let c_decl = format!("typedef struct __swift_bridge__$tuple${combined_types} {{ {fields} }} __swift_bridge__$tuple${combined_types};");
         Some(CFfiStruct {
             c_ffi_type: c_decl,
-            fields: vec![],
+            fields: self.0.generate_field_custom_c_ffi_types(types),
         })
     }
 
@@ -133,8 +138,14 @@ impl BridgeableType for BuiltInTuple {
                         .to_swift_tuple_signature(type_pos, types, swift_bridge_path)
                 }
             }
-            TypePosition::SharedStructField => todo!(),
-            TypePosition::ResultFfiReturnType => todo!(),
+            TypePosition::SharedStructField => {
+                self.0
+                    .to_swift_tuple_signature(type_pos, types, swift_bridge_path)
+            }
+            TypePosition::ResultFfiReturnType => {
+                let field_signatures = self.0.combine_field_types_into_ffi_name_string(types);
+                format!("__swift_bridge__$tuple${field_signatures}")
+            }
             TypePosition::ThrowingInit(_) => todo!(),
         }
     }
@@ -226,7 +237,10 @@ impl BridgeableType for BuiltInTuple {
                     self.to_ffi_compatible_swift_type(expression, types, type_pos)
                 }
             }
-            TypePosition::SharedStructField => todo!(),
+            TypePosition::SharedStructField => {
+                let ffi_type = self.to_ffi_compatible_swift_type("val", types, type_pos);
+                format!("{{ let val = {expression}; return {ffi_type}; }}()")
+            }
             TypePosition::ResultFfiReturnType => todo!(),
             TypePosition::ThrowingInit(_) => todo!(),
         }
@@ -0,0 +1,231 @@
+use crate::bridged_type::{BridgedType, StdLibType, TypePosition};
+use crate::parse::TypeDeclarations;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use std::ops::Deref;
+use syn::Path;
+
+/// Vec<T>
+///
+/// Most vectors cross the FFI boundary as a `*mut Vec<T>`, which Swift wraps in a `RustVec<T>`.
+///
+/// A vector whose elements are themselves containers, such as `Vec<Option<T>>`, `Vec<Vec<T>>`
+/// or `Vec<(A, B)>`, gets converted into a Swift `Array` instead, since Swift's `Optional`, tuples
+/// and our `RustVec<T>` can't implement `Vectorizable`.
+/// Each element is converted to its FFI representation, and the elements cross the boundary in a
+/// single `swift_bridge::array::FfiArray`.
+#[derive(Debug)]
+pub(crate) struct BuiltInVec {
+    pub ty: Box<BridgedType>,
+}
+
+impl BuiltInVec {
+    /// Whether or not this vector crosses the FFI boundary as an `FfiArray` and is seen by Swift
+    /// as an `Array`.
+    pub fn is_converted_to_swift_array(&self) -> bool {
+        matches!(
+            self.ty.deref(),
+            BridgedType::StdLib(
+                StdLibType::Option(_)
+                    | StdLibType::Result(_)
+                    | StdLibType::Tuple(_)
+                    | StdLibType::Array(_)
+                    | StdLibType::Vec(_)
+                    | StdLibType::Map(_)
+                    | StdLibType::Set(_)
+            )
+        )
+    }
+
+    pub fn to_ffi_compatible_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        if self.is_converted_to_swift_array() {
+            quote! { #swift_bridge_path::array::FfiArray }
+        } else {
+            let ty = self.ty.to_rust_type_path(types);
+            quote! { *mut Vec<#ty> }
+        }
+    }
+
+    pub fn to_swift_type(
+        &self,
+        type_pos: TypePosition,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> String {
+        if self.is_converted_to_swift_array() {
+            return match type_pos {
+                TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang)
+                    if func_host_lang.is_swift() =>
+                {
+                    "__private__FfiArray".to_string()
+                }
+                TypePosition::ResultFfiReturnType => "__private__FfiArray".to_string(),
+                TypePosition::ThrowingInit(_) => unimplemented!(),
+                _ => format!("[{}]", self.element_swift_type(types, swift_bridge_path)),
+            };
+        }
+
+        // A `RustVec<T>`'s elements are always concrete types, such as `RustVec<RustString>`,
+        // even when the vector is a function argument.
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
+                if func_host_lang.is_rust() {
+                    format!(
+                        "RustVec<{}>",
+                        self.element_swift_type(types, swift_bridge_path)
+                    )
+                } else {
+                    "UnsafeMutableRawPointer".to_string()
+                }
+            }
+            TypePosition::ThrowingInit(_) => unimplemented!(),
+            _ => {
+                format!(
+                    "RustVec<{}>",
+                    self.element_swift_type(types, swift_bridge_path)
+                )
+            }
+        }
+    }
+
+    pub fn to_c(&self) -> String {
+        if self.is_converted_to_swift_array() {
+            "struct __private__FfiArray".to_string()
+        } else {
+            "void*".to_string()
+        }
+    }
+
+    pub fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+        span: Span,
+    ) -> TokenStream {
+        if !self.is_converted_to_swift_array() {
+            return quote! { Box::into_raw(Box::new( #expression )) };
+        }
+
+        let convert_element = self.ty.convert_rust_expression_to_ffi_type(
+            &quote! { val },
+            swift_bridge_path,
+            types,
+            span,
+        );
+        quote! {
+            #swift_bridge_path::array::FfiArray::from_vec(
+                #expression.into_iter().map(|val| #convert_element).collect::<Vec<_>>()
+            )
+        }
+    }
+
+    pub fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        span: Span,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        if !self.is_converted_to_swift_array() {
+            return quote! { unsafe { * Box::from_raw(#expression) } };
+        }
+
+        let ffi_element = self
+            .ty
+            .to_ffi_compatible_rust_type(swift_bridge_path, types);
+        let convert_element = self.ty.convert_ffi_expression_to_rust_type(
+            &quote! { val },
+            span,
+            swift_bridge_path,
+            types,
+        );
+        quote! {
+            unsafe { #expression.into_vec::<#ffi_element>() }
+                .into_iter()
+                .map(|val| #convert_element)
+                .collect::<Vec<_>>()
+        }
+    }
+
+    pub fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> String {
+        if !self.is_converted_to_swift_array() {
+            return format!("RustVec(ptr: {})", expression);
+        }
+
+        let convert_element = self.ty.convert_ffi_value_to_swift_value(
+            "val",
+            TypePosition::SharedStructField,
+            types,
+            swift_bridge_path,
+        );
+        format!(
+            "__private__intoSwiftArray({expression}, {{ (val: {ffi_ty}) -> {swift_ty} in {convert_element} }})",
+            ffi_ty = self.element_ffi_swift_type(types),
+            swift_ty = self.element_swift_type(types, swift_bridge_path),
+        )
+    }
+
+    /// The element type of the Swift `Array` gets inferred from the expression.
+    pub fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        types: &TypeDeclarations,
+    ) -> String {
+        if !self.is_converted_to_swift_array() {
+            return format!(
+                "{{ let val = {value}; val.isOwned = false; return val.ptr }}()",
+                value = expression
+            );
+        }
+
+        let convert_element = self.ty.convert_swift_expression_to_ffi_type(
+            "val",
+            types,
+            TypePosition::SharedStructField,
+        );
+        format!(
+            "__private__intoFfiArray({expression}, {{ (val) -> {ffi_ty} in {convert_element} }})",
+            ffi_ty = self.element_ffi_swift_type(types),
+        )
+    }
+
+    /// The Swift type of an element of the Swift `Array`, such as `Optional<UInt8>`.
+    fn element_swift_type(&self, types: &TypeDeclarations, swift_bridge_path: &Path) -> String {
+        self.ty
+            .to_swift_type(TypePosition::SharedStructField, types, swift_bridge_path)
+    }
+
+    /// The type that Swift sees when it reads an element's FFI representation out of the
+    /// `FfiArray`, such as `__private__OptionU8`.
+    ///
+    /// Elements are always containers, so their FFI representation is either a pointer or a
+    /// C struct.
+    fn element_ffi_swift_type(&self, types: &TypeDeclarations) -> String {
+        let c_ty = self.ty.to_c(types);
+
+        if c_ty == "void*" {
+            // `Option<T>` and `Result<(), E>` use a null pointer, everything else is non-null.
+            let is_nullable = matches!(
+                self.ty.deref(),
+                BridgedType::StdLib(StdLibType::Option(_) | StdLibType::Result(_))
+            );
+            return if is_nullable {
+                "UnsafeMutableRawPointer?".to_string()
+            } else {
+                "UnsafeMutableRawPointer".to_string()
+            };
+        }
+
+        c_ty.trim_start_matches("struct ").to_string()
+    }
+}
@@ -0,0 +1,122 @@
+use syn::{Attribute, Lit, Meta, NestedMeta, Path};
+
+/// Whether or not the derive is `serde::Serialize` or `serde::Deserialize`.
+///
+/// These get added to the Rust type as written, so that the Rust type serializes to the same JSON
+/// as its `#[swift_bridge(Codable)]` Swift type.
+pub(crate) fn is_serde_derive(derive: &Path) -> bool {
+    match derive.segments.last() {
+        Some(segment) => segment.ident == "Serialize" || segment.ident == "Deserialize",
+        None => false,
+    }
+}
+
+/// `#[serde(...)]`
+pub(crate) fn is_serde_attribute(attr: &Attribute) -> bool {
+    attr.path.is_ident("serde")
+}
+
+/// The `"..."` in `#[serde(rename = "...")]`.
+pub(crate) fn serde_rename(attrs: &[Attribute]) -> Option<String> {
+    for attr in attrs.iter().filter(|attr| is_serde_attribute(attr)) {
+        let meta_list = match attr.parse_meta() {
+            Ok(Meta::List(meta_list)) => meta_list,
+            _ => continue,
+        };
+
+        for nested in meta_list.nested.iter() {
+            if let NestedMeta::Meta(Meta::NameValue(name_value)) = nested {
+                if let (true, Lit::Str(rename)) =
+                    (name_value.path.is_ident("rename"), &name_value.lit)
+                {
+                    return Some(rename.value());
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// The first `#[serde(...)]` option that changes how a type is serialized in a way that a
+/// `#[swift_bridge(Codable)]` type doesn't support, such as the `tag` in
+/// `#[serde(tag = "type")]`.
+pub(crate) fn unsupported_codable_serde_option(attrs: &[Attribute]) -> Option<Path> {
+    const UNSUPPORTED: &[&str] = &[
+        "rename_all",
+        "rename_all_fields",
+        "tag",
+        "content",
+        "untagged",
+        "flatten",
+        "skip",
+        "skip_serializing",
+        "skip_deserializing",
+    ];
+
+    for attr in attrs.iter().filter(|attr| is_serde_attribute(attr)) {
+        let meta_list = match attr.parse_meta() {
+            Ok(Meta::List(meta_list)) => meta_list,
+            _ => continue,
+        };
+
+        for nested in meta_list.nested.iter() {
+            let path = match nested {
+                NestedMeta::Meta(meta) => meta.path(),
+                NestedMeta::Lit(_) => continue,
+            };
+            if UNSUPPORTED.iter().any(|option| path.is_ident(option)) {
+                return Some(path.clone());
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    /// Verify that we find the name in a `#[serde(rename = "...")]` attribute.
+    #[test]
+    fn serde_rename_attribute() {
+        let attrs: Vec<Attribute> = vec![
+            parse_quote! { #[doc = "Some docs"] },
+            parse_quote! { #[serde(default, rename = "renamed")] },
+        ];
+        assert_eq!(serde_rename(&attrs).unwrap(), "renamed");
+
+        let attrs: Vec<Attribute> = vec![parse_quote! { #[serde(default)] }];
+        assert!(serde_rename(&attrs).is_none());
+    }
+
+    /// Verify that we recognize the serde derives, whether or not they are fully qualified.
+    #[test]
+    fn serde_derives() {
+        assert!(is_serde_derive(&parse_quote! { serde::Serialize }));
+        assert!(is_serde_derive(&parse_quote! { Deserialize }));
+        assert!(!is_serde_derive(&parse_quote! { Clone }));
+    }
+
+    /// Verify that we find the serde options that `#[swift_bridge(Codable)]` doesn't support.
+    #[test]
+    fn unsupported_codable_serde_options() {
+        let attrs: Vec<Attribute> = vec![
+            parse_quote! { #[serde(rename = "renamed")] },
+            parse_quote! { #[serde(deny_unknown_fields, tag = "type")] },
+        ];
+        assert!(unsupported_codable_serde_option(&attrs)
+            .unwrap()
+            .is_ident("tag"));
+
+        let attrs: Vec<Attribute> = vec![parse_quote! { #[serde(skip)] }];
+        assert!(unsupported_codable_serde_option(&attrs)
+            .unwrap()
+            .is_ident("skip"));
+
+        let attrs: Vec<Attribute> = vec![parse_quote! { #[serde(default, rename = "renamed")] }];
+        assert!(unsupported_codable_serde_option(&attrs).is_none());
+    }
+}
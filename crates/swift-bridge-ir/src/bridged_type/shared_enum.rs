@@ -1,17 +1,67 @@
+use crate::bridged_type::SharedTypeGenerics;
+use crate::parse::TypeDeclarations;
 use crate::SWIFT_BRIDGE_PREFIX;
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 use std::fmt::{Debug, Formatter};
-use syn::LitStr;
+use syn::{Attribute, LitStr, Path};
 
 mod enum_variant;
-pub(crate) use self::enum_variant::EnumVariant;
+pub(crate) use self::enum_variant::{integer_discriminant_value, EnumVariant};
 
 use super::StructFields;
 
 #[derive(Default, Clone)]
 pub(crate) struct DeriveAttrs {
     pub debug: bool,
+    pub clone: bool,
+    pub copy: bool,
+    pub partial_eq: bool,
+    pub eq: bool,
+    pub hash: bool,
+    pub partial_ord: bool,
+    pub ord: bool,
+    /// `serde::Serialize` and `serde::Deserialize`, as written.
+    pub serde: Vec<Path>,
+}
+
+impl DeriveAttrs {
+    /// The Swift protocols that the derives map to.
+    ///
+    /// `PartialEq` -> `Equatable`, `Hash` -> `Hashable`, `PartialOrd` -> `Comparable`,
+    /// `Copy` -> `Sendable`.
+    ///
+    /// A `Copy` enum doesn't own any Rust resources, so its Swift representation can be safely
+    /// shared across concurrency domains.
+    pub(crate) fn swift_protocols(&self) -> Vec<&'static str> {
+        let mut protocols = vec![];
+
+        if self.partial_eq || self.eq {
+            protocols.push("Equatable");
+        }
+        if self.hash {
+            protocols.push("Hashable");
+        }
+        if self.partial_ord || self.ord {
+            protocols.push("Comparable");
+        }
+        if self.copy {
+            protocols.push("@unchecked Sendable");
+        }
+
+        protocols
+    }
+}
+
+/// The type of a fieldless enum's Swift raw values.
+///
+/// `#[swift_bridge(RawRepresentable = "String")]`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum EnumRawValueType {
+    /// The variants' discriminants.
+    Int,
+    /// The variants' names, or their `#[serde(rename = "...")]`.
+    String,
 }
 
 #[derive(Clone)]
@@ -21,6 +71,25 @@ pub(crate) struct SharedEnum {
     pub already_declared: bool,
     pub swift_name: Option<LitStr>,
     pub derive: DeriveAttrs,
+    /// `#[swift_bridge(Codable)]`
+    pub codable: bool,
+    /// The enum's `#[serde(...)]` attributes, which get added to the Rust enum as written.
+    pub serde_attrs: Vec<Attribute>,
+    /// `#[swift_bridge(CaseIterable)]`
+    pub case_iterable: bool,
+    /// `#[swift_bridge(RawRepresentable = "...")]`
+    pub raw_value_type: Option<EnumRawValueType>,
+    /// The `u8` in `#[repr(u8)]`.
+    pub repr: Option<Ident>,
+    /// The enum's generic type parameters, along with the concrete types of this instantiation.
+    /// Each instantiation of a generic enum gets parsed as its own `SharedEnum` whose `variants`
+    /// use the concrete types.
+    pub generics: SharedTypeGenerics,
+    /// The variants that the generic enum was declared with, before its generic type parameters
+    /// were replaced with concrete types.
+    /// Only the first instantiation holds these, since that's the one that declares the generic
+    /// enum.
+    pub declared_variants: Option<Vec<EnumVariant>>,
 }
 
 impl SharedEnum {
@@ -33,9 +102,24 @@ impl SharedEnum {
         }
     }
 
+    /// SomeEnum, or SomeEnum<UInt32> for an instantiation of a generic enum.
+    pub fn swift_type_string(&self, types: &TypeDeclarations, swift_bridge_path: &Path) -> String {
+        format!(
+            "{}{}",
+            self.swift_name_string(),
+            self.generics
+                .angle_bracketed_concrete_swift_types_string(types, swift_bridge_path)
+        )
+    }
+
     /// __swift_bridge__$SomeEnum
     pub fn ffi_name_string(&self) -> String {
-        format!("{}${}", SWIFT_BRIDGE_PREFIX, self.swift_name_string())
+        format!(
+            "{}${}{}",
+            SWIFT_BRIDGE_PREFIX,
+            self.swift_name_string(),
+            self.generics.dollar_prefixed_args_string()
+        )
     }
 
     /// __swift_bridge__$SomeEnumTag
@@ -45,22 +129,43 @@ impl SharedEnum {
 
     /// __swift_bridge__SomeEnum
     pub fn ffi_name_tokens(&self) -> TokenStream {
-        let name = Ident::new(
-            &format!("{}{}", SWIFT_BRIDGE_PREFIX, self.name),
-            self.name.span(),
-        );
+        let name = self.ffi_name_ident();
         quote! { #name }
     }
 
+    /// __swift_bridge__SomeEnum
+    pub fn ffi_name_ident(&self) -> Ident {
+        Ident::new(
+            &format!(
+                "{}{}{}",
+                SWIFT_BRIDGE_PREFIX,
+                self.name,
+                self.generics.underscore_prefixed_args_string()
+            ),
+            self.name.span(),
+        )
+    }
+
     /// __swift_bridge__Option_SomeEnum
     pub fn ffi_option_name_tokens(&self) -> TokenStream {
         let name = Ident::new(
-            &format!("{}Option_{}", SWIFT_BRIDGE_PREFIX, self.name),
+            &format!(
+                "{}Option_{}{}",
+                SWIFT_BRIDGE_PREFIX,
+                self.name,
+                self.generics.underscore_prefixed_args_string()
+            ),
             self.name.span(),
         );
         quote! { #name }
     }
 
+    /// `<u32>`, or nothing if the enum isn't generic.
+    pub fn ty_generics(&self, types: &TypeDeclarations) -> TokenStream {
+        self.generics
+            .angle_bracketed_concrete_rust_types_tokens(types)
+    }
+
     /// __swift_bridge__$SomeEnumFields
     pub fn ffi_union_name_string(&self) -> String {
         format!("{}Fields", self.ffi_name_string())
@@ -76,7 +181,7 @@ impl SharedEnum {
             let union_field = format!(
                 " {} {};",
                 variant.union_name_string(&self.ffi_name_string()),
-                variant.name
+                variant.swift_name_string()
             );
             union_fields += &union_field;
         }
@@ -87,9 +192,10 @@ impl SharedEnum {
     /// __swift_bridge__$Option$SomeEnum
     pub fn ffi_option_name_string(&self) -> String {
         format!(
-            "{}$Option${}",
+            "{}$Option${}{}",
             SWIFT_BRIDGE_PREFIX,
-            self.swift_name_string()
+            self.swift_name_string(),
+            self.generics.dollar_prefixed_args_string()
         )
     }
 }
@@ -111,11 +217,121 @@ impl SharedEnum {
     pub fn all_variants_empty(&self) -> bool {
         self.variants.iter().all(|v| v.fields.is_empty())
     }
+
+    /// The discriminant of each of the variants.
+    ///
+    /// Like in Rust, a variant without an explicit discriminant is one more than the previous
+    /// variant, and the first variant defaults to `0`.
+    pub(crate) fn discriminant_values(&self) -> Vec<i128> {
+        let mut next = 0;
+
+        self.variants
+            .iter()
+            .map(|variant| {
+                let value = variant
+                    .discriminant
+                    .as_ref()
+                    .and_then(integer_discriminant_value)
+                    .unwrap_or(next);
+                next = value + 1;
+                value
+            })
+            .collect()
+    }
+
+    /// Whether or not the enum has a `#[repr(...)]` or any explicit discriminants, in which case
+    /// Swift can read and build variants from their numeric values.
+    pub(crate) fn has_explicit_discriminants(&self) -> bool {
+        self.repr.is_some() || self.variants.iter().any(|v| v.discriminant.is_some())
+    }
+
+    /// The `u8` in `#[repr(u8)]`.
+    ///
+    /// `None` for `#[repr(C)]` and for enums without a `#[repr(...)]`.
+    pub(crate) fn repr_integer_type(&self) -> Option<&Ident> {
+        self.repr
+            .as_ref()
+            .filter(|repr| repr_integer_swift_and_c_types(&repr.to_string()).is_some())
+    }
+
+    /// The Swift type of the discriminants.
+    ///
+    /// `#[repr(u8)]` -> "UInt8". Rust enums default to `isize`, which is a Swift `Int`.
+    pub(crate) fn discriminant_swift_type(&self) -> &'static str {
+        match self.repr.as_ref().map(|repr| repr.to_string()).as_deref() {
+            // `#[repr(C)]` enums are C `int`s.
+            Some("C") => "Int32",
+            Some(repr) => repr_integer_swift_and_c_types(repr)
+                .map(|(swift_ty, _)| swift_ty)
+                .unwrap_or("Int"),
+            None => "Int",
+        }
+    }
+
+    /// The C type of the FFI representation's tag, if the enum has an integer `#[repr(...)]`.
+    ///
+    /// `#[repr(u8)]` -> "uint8_t".
+    pub(crate) fn c_tag_type(&self) -> Option<&'static str> {
+        self.repr_integer_type()
+            .and_then(|repr| repr_integer_swift_and_c_types(&repr.to_string()))
+            .map(|(_, c_ty)| c_ty)
+    }
+
+    /// The Swift raw value of each of the variants.
+    ///
+    /// `404` for `RawRepresentable = "Int"`, or `"not_found"` for `RawRepresentable = "String"`.
+    pub(crate) fn swift_raw_values(&self) -> Option<Vec<String>> {
+        let raw_values = match self.raw_value_type? {
+            EnumRawValueType::Int => self
+                .discriminant_values()
+                .iter()
+                .map(|value| value.to_string())
+                .collect(),
+            EnumRawValueType::String => self
+                .variants
+                .iter()
+                .map(|variant| format!("{:?}", variant.raw_value_string()))
+                .collect(),
+        };
+
+        Some(raw_values)
+    }
+
+    /// ": Int" or ": String" for an enum with raw values, otherwise "".
+    pub(crate) fn swift_raw_value_type_string(&self) -> String {
+        match self.raw_value_type {
+            Some(EnumRawValueType::Int) => format!(": {}", self.discriminant_swift_type()),
+            Some(EnumRawValueType::String) => ": String".to_string(),
+            None => "".to_string(),
+        }
+    }
+}
+
+/// The Swift and C types of an integer `#[repr(...)]`.
+fn repr_integer_swift_and_c_types(repr: &str) -> Option<(&'static str, &'static str)> {
+    let types = match repr {
+        "u8" => ("UInt8", "uint8_t"),
+        "i8" => ("Int8", "int8_t"),
+        "u16" => ("UInt16", "uint16_t"),
+        "i16" => ("Int16", "int16_t"),
+        "u32" => ("UInt32", "uint32_t"),
+        "i32" => ("Int32", "int32_t"),
+        "u64" => ("UInt64", "uint64_t"),
+        "i64" => ("Int64", "int64_t"),
+        "usize" => ("UInt", "uintptr_t"),
+        "isize" => ("Int", "intptr_t"),
+        _ => return None,
+    };
+
+    Some(types)
 }
 
 impl PartialEq for SharedEnum {
     fn eq(&self, other: &Self) -> bool {
-        self.name.to_string() == other.name.to_string() && self.variants == other.variants
+        self.name.to_string() == other.name.to_string()
+            && self.variants == other.variants
+            && self.generics.dollar_prefixed_args_string()
+                == other.generics.dollar_prefixed_args_string()
     }
 }
 
@@ -1,3 +1,4 @@
+use crate::bridged_type::serde_attributes::serde_rename;
 use crate::bridged_type::{BridgedType, StructFields, TypePosition};
 use crate::parse::TypeDeclarations;
 use proc_macro2::Ident;
@@ -5,16 +6,51 @@ use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 use std::fmt::{Debug, Formatter};
 use syn::spanned::Spanned;
-use syn::Path;
+use syn::{Attribute, Expr, ExprLit, ExprUnary, Lit, LitStr, Path, UnOp};
 
 #[derive(Clone)]
 pub(crate) struct EnumVariant {
     pub name: Ident,
     #[allow(unused)]
     pub fields: StructFields,
+    /// The variant's `#[serde(...)]` attributes, which get added to the Rust enum as written.
+    pub serde_attrs: Vec<Attribute>,
+    /// The `404` in `Variant = 404`.
+    pub discriminant: Option<Expr>,
+    /// `#[swift_bridge(swift_name = "...")]`
+    pub swift_name: Option<LitStr>,
+    /// `#[swift_bridge(rename = "...")]`
+    pub rename: Option<LitStr>,
 }
 
 impl EnumVariant {
+    /// The variant's name in Swift and in the C header.
+    ///
+    /// `#[swift_bridge(swift_name = "...")]`, or the variant's Rust name.
+    pub(crate) fn swift_name_string(&self) -> String {
+        match self.swift_name.as_ref() {
+            Some(swift_name) => swift_name.value(),
+            None => self.name.to_string(),
+        }
+    }
+
+    /// The variant's Swift `String` raw value.
+    ///
+    /// `#[swift_bridge(rename = "...")]`, or the variant's Swift name.
+    pub(crate) fn raw_value_string(&self) -> String {
+        match self.rename.as_ref() {
+            Some(rename) => rename.value(),
+            None => self.swift_name_string(),
+        }
+    }
+
+    /// The variant's name in its serialized representation.
+    ///
+    /// `#[serde(rename = "...")]`, or the variant's name.
+    pub(crate) fn serde_name_string(&self) -> String {
+        serde_rename(&self.serde_attrs).unwrap_or_else(|| self.name.to_string())
+    }
+
     pub(crate) fn convert_rust_expression_to_ffi_repr(
         &self,
         types: &TypeDeclarations,
@@ -120,6 +156,7 @@ impl EnumVariant {
         &self,
         types: &TypeDeclarations,
         enum_name: String,
+        ffi_enum_name: String,
         swift_bridge_path: &Path,
     ) -> String {
         let converted_fields: Vec<String> = self
@@ -127,12 +164,12 @@ impl EnumVariant {
             .normalized_fields()
             .iter()
             .map(|norm_field| {
-                let field_name = norm_field.ffi_field_name();
+                let field_name = norm_field.swift_name_string();
                 let ty = BridgedType::new_with_type(&norm_field.ty, types).unwrap();
                 let field = ty.convert_ffi_value_to_swift_value(
                     &format!(
                         "self.payload.{variant_name}.{field_name}",
-                        variant_name = self.name,
+                        variant_name = self.swift_name_string(),
                         field_name = field_name
                     ),
                     TypePosition::SharedStructField,
@@ -146,17 +183,19 @@ impl EnumVariant {
 
         if self.fields.is_empty() {
             format!(
-                "            case __swift_bridge__${enum_name}${variant_name}:
+                "            case {ffi_enum_name}${variant_name}:
                 return {enum_name}.{variant_name}\n",
                 enum_name = enum_name,
-                variant_name = self.name
+                ffi_enum_name = ffi_enum_name,
+                variant_name = self.swift_name_string()
             )
         } else {
             format!(
-                "            case __swift_bridge__${enum_name}${variant_name}:
+                "            case {ffi_enum_name}${variant_name}:
                 return {enum_name}.{variant_name}({converted_fields})\n",
                 enum_name = enum_name,
-                variant_name = self.name,
+                ffi_enum_name = ffi_enum_name,
+                variant_name = self.swift_name_string(),
                 converted_fields = converted_fields
             )
         }
@@ -174,7 +213,7 @@ impl EnumVariant {
                 "            case {enum_name}.{variant_name}:
                 return {ffi_enum_name}(tag: {ffi_enum_name}${variant_name})\n",
                 enum_name = enum_name,
-                variant_name = self.name,
+                variant_name = self.swift_name_string(),
                 ffi_enum_name = ffi_enum_name
             );
         }
@@ -183,10 +222,10 @@ impl EnumVariant {
             .normalized_fields()
             .iter()
             .map(|norm_field| {
-                let ffi_field_name = norm_field.ffi_field_name();
+                let field_name = norm_field.swift_name_string();
                 let ty = BridgedType::new_with_type(&norm_field.ty, types).unwrap();
                 let variant_field = ty.convert_swift_expression_to_ffi_type(
-                    &format!("{}", ffi_field_name),
+                    &field_name,
                     types,
                     TypePosition::SharedStructField,
                 );
@@ -200,7 +239,7 @@ impl EnumVariant {
             .normalized_fields()
             .iter()
             .map(|norm_field| {
-                let field_name = norm_field.ffi_field_name();
+                let field_name = norm_field.swift_name_string();
                 format!("let {field_name}", field_name = field_name)
             })
             .collect();
@@ -208,10 +247,10 @@ impl EnumVariant {
 
         if self.fields.is_empty() {
             format!("            case {enum_name}.{variant_name}:
-                return {{var val = {ffi_enum_name}(); val.tag = {ffi_enum_name}${variant_name}; return val }}()\n", enum_name = enum_name, variant_name = self.name, ffi_enum_name = ffi_enum_name)
+                return {{var val = {ffi_enum_name}(); val.tag = {ffi_enum_name}${variant_name}; return val }}()\n", enum_name = enum_name, variant_name = self.swift_name_string(), ffi_enum_name = ffi_enum_name)
         } else {
             format!("            case {enum_name}.{variant_name}({associated_values}):
-                return {ffi_enum_name}(tag: {ffi_enum_name}${variant_name}, payload: {ffi_enum_name}Fields({variant_name}: {ffi_enum_name}$FieldOf{variant_name}({converted_fields})))\n", ffi_enum_name = ffi_enum_name, associated_values = associated_values, enum_name = enum_name, variant_name = self.name, converted_fields = converted_fields)
+                return {ffi_enum_name}(tag: {ffi_enum_name}${variant_name}, payload: {ffi_enum_name}Fields({variant_name}: {ffi_enum_name}$FieldOf{variant_name}({converted_fields})))\n", ffi_enum_name = ffi_enum_name, associated_values = associated_values, enum_name = enum_name, variant_name = self.swift_name_string(), converted_fields = converted_fields)
         }
     }
 
@@ -234,7 +273,11 @@ impl EnumVariant {
     }
 
     pub(crate) fn union_name_string(&self, parent_enum_ffi_name: &String) -> String {
-        format!("{}$FieldOf{}", parent_enum_ffi_name, self.name.to_string())
+        format!(
+            "{}$FieldOf{}",
+            parent_enum_ffi_name,
+            self.swift_name_string()
+        )
     }
 }
 
@@ -252,3 +295,20 @@ impl Debug for EnumVariant {
             .finish()
     }
 }
+
+/// The value of an integer literal discriminant, such as `404` or `-1`.
+///
+/// Returns `None` if the discriminant isn't an integer literal.
+pub(crate) fn integer_discriminant_value(discriminant: &Expr) -> Option<i128> {
+    match discriminant {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(int), ..
+        }) => int.base10_parse().ok(),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => integer_discriminant_value(expr).map(|value| -value),
+        _ => None,
+    }
+}
@@ -1,22 +1,34 @@
 pub(crate) use self::struct_field::StructField;
 pub(crate) use self::struct_field::StructFields;
 use self::struct_field::UnnamedStructField;
-use crate::bridged_type::{BridgedType, OnlyEncoding, TypePosition};
+use crate::bridged_type::{
+    BridgeableType, BridgedType, CFfiStruct, OnlyEncoding, SharedTypeGenerics, TypePosition,
+};
 use crate::parse::TypeDeclarations;
 use crate::SWIFT_BRIDGE_PREFIX;
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
-use quote::{format_ident, quote_spanned};
+use quote::quote_spanned;
 use std::fmt::{Debug, Formatter};
 use std::str::FromStr;
 use syn::spanned::Spanned;
-use syn::{LitStr, Path, Type};
+use syn::{Attribute, Lifetime, LitStr, Path, Type};
 
 mod struct_field;
 
 #[derive(Debug)]
 pub(crate) struct UnnamedStructFields(Vec<UnnamedStructField>);
 
+/// A `Result` inside of a tuple is a `RustResult<T, E>` value, even when the tuple is a function's
+/// return value.
+fn field_type_pos(ty: &BridgedType, type_pos: TypePosition) -> TypePosition {
+    if ty.is_result() {
+        TypePosition::SharedStructField
+    } else {
+        type_pos
+    }
+}
+
 impl UnnamedStructFields {
     pub fn new_with_types(types: Vec<Type>) -> Self {
         let unnamed_fields = types
@@ -41,7 +53,7 @@ impl UnnamedStructFields {
                 let converted_field = ty.convert_swift_expression_to_ffi_type(
                     &format!("{expression}.{idx}"),
                     types,
-                    type_pos,
+                    field_type_pos(&ty, type_pos),
                 );
                 format!("_{idx}: ") + &converted_field
             })
@@ -64,9 +76,8 @@ impl UnnamedStructFields {
             .iter()
             .enumerate()
             .map(|(_idx, field)| {
-                BridgedType::new_with_type(&field.ty, types)
-                    .unwrap()
-                    .to_swift_type(type_pos, types, swift_bridge_path)
+                let ty = BridgedType::new_with_type(&field.ty, types).unwrap();
+                ty.to_swift_type(field_type_pos(&ty, type_pos), types, swift_bridge_path)
             })
             .collect();
         let names = names.join(", ");
@@ -137,7 +148,7 @@ impl UnnamedStructFields {
                 let ty = BridgedType::new_with_type(&field.ty, types).unwrap();
                 let converted_field = ty.convert_ffi_value_to_swift_value(
                     &format!("val._{idx}"),
-                    type_pos,
+                    field_type_pos(&ty, type_pos),
                     types,
                     swift_bridge_path,
                 );
@@ -196,6 +207,32 @@ impl UnnamedStructFields {
             })
             .collect()
     }
+    /// The custom FFI types, such as nested tuples or `Result`s, that the fields depend on.
+    pub fn generate_field_custom_rust_ffi_types(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> Vec<TokenStream> {
+        self.0
+            .iter()
+            .filter_map(|field| {
+                BridgedType::new_with_type(&field.ty, types)
+                    .unwrap()
+                    .generate_custom_rust_ffi_types(swift_bridge_path, types)
+            })
+            .flatten()
+            .collect()
+    }
+    pub fn generate_field_custom_c_ffi_types(&self, types: &TypeDeclarations) -> Vec<CFfiStruct> {
+        self.0
+            .iter()
+            .filter_map(|field| {
+                BridgedType::new_with_type(&field.ty, types)
+                    .unwrap()
+                    .generate_custom_c_ffi_types(types)
+            })
+            .collect()
+    }
     pub fn contains_owned_string_recursive(&self, types: &TypeDeclarations) -> bool {
         self.0
             .iter()
@@ -257,12 +294,51 @@ pub(crate) struct SharedStruct {
     pub swift_name: Option<LitStr>,
     pub already_declared: bool,
     pub derives: StructDerives,
+    /// `#[swift_bridge(Codable)]`
+    pub codable: bool,
+    /// The struct's `#[serde(...)]` attributes, which get added to the Rust struct as written.
+    pub serde_attrs: Vec<Attribute>,
+    /// The struct's lifetime parameters, such as the `'a` in `struct Token<'a>`.
+    pub lifetimes: Vec<Lifetime>,
+    /// The struct's generic type parameters, along with the concrete types of this instantiation.
+    /// Each instantiation of a generic struct gets parsed as its own `SharedStruct` whose `fields`
+    /// use the concrete types.
+    pub generics: SharedTypeGenerics,
+    /// The fields that the generic struct was declared with, before its generic type parameters
+    /// were replaced with concrete types.
+    /// Only the first instantiation holds these, since that's the one that declares the generic
+    /// struct.
+    pub declared_fields: Option<StructFields>,
 }
 
 #[derive(Clone)]
 pub(crate) struct StructDerives {
     pub copy: bool,
     pub clone: bool,
+    pub debug: bool,
+    pub partial_eq: bool,
+    pub eq: bool,
+    pub hash: bool,
+    /// `serde::Serialize` and `serde::Deserialize`, as written.
+    pub serde: Vec<Path>,
+}
+
+impl StructDerives {
+    /// The Swift protocols that the derives map to.
+    ///
+    /// `PartialEq` -> `Equatable`, `Hash` -> `Hashable`.
+    pub(crate) fn swift_protocols(&self) -> Vec<&'static str> {
+        let mut protocols = vec![];
+
+        if self.partial_eq || self.eq {
+            protocols.push("Equatable");
+        }
+        if self.hash {
+            protocols.push("Hashable");
+        }
+
+        protocols
+    }
 }
 
 impl SharedStruct {
@@ -273,27 +349,61 @@ impl SharedStruct {
         }
     }
 
+    /// SomeStruct, or SomeStruct<UInt32> for an instantiation of a generic struct.
+    pub(crate) fn swift_type_string(
+        &self,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> String {
+        format!(
+            "{}{}",
+            self.swift_name_string(),
+            self.generics
+                .angle_bracketed_concrete_swift_types_string(types, swift_bridge_path)
+        )
+    }
+
     pub(crate) fn ffi_name_string(&self) -> String {
         let name = self.swift_name_string();
 
-        format!("{}${}", SWIFT_BRIDGE_PREFIX, name)
+        format!(
+            "{}${}{}",
+            SWIFT_BRIDGE_PREFIX,
+            name,
+            self.generics.dollar_prefixed_args_string()
+        )
     }
 
     pub(crate) fn ffi_name_tokens(&self) -> TokenStream {
-        let name = Ident::new(
-            &format!("{}{}", SWIFT_BRIDGE_PREFIX, &self.name),
-            self.name.span(),
-        );
+        let name = self.ffi_name_ident();
 
         quote! {
             #name
         }
     }
 
+    /// __swift_bridge__SomeStruct
+    pub(crate) fn ffi_name_ident(&self) -> Ident {
+        Ident::new(
+            &format!(
+                "{}{}{}",
+                SWIFT_BRIDGE_PREFIX,
+                &self.name,
+                self.generics.underscore_prefixed_args_string()
+            ),
+            self.name.span(),
+        )
+    }
+
     /// __swift_bridge__Option_SomeStruct
     pub fn ffi_option_name_tokens(&self) -> TokenStream {
         let name = Ident::new(
-            &format!("{}Option_{}", SWIFT_BRIDGE_PREFIX, self.name),
+            &format!(
+                "{}Option_{}{}",
+                SWIFT_BRIDGE_PREFIX,
+                self.name,
+                self.generics.underscore_prefixed_args_string()
+            ),
             self.name.span(),
         );
         quote! { #name }
@@ -302,7 +412,73 @@ impl SharedStruct {
     /// __swift_bridge__$Option$SomeStruct
     pub fn ffi_option_name_string(&self) -> String {
         let name = self.swift_name_string();
-        format!("{}$Option${}", SWIFT_BRIDGE_PREFIX, name,)
+        format!(
+            "{}$Option${}{}",
+            SWIFT_BRIDGE_PREFIX,
+            name,
+            self.generics.dollar_prefixed_args_string()
+        )
+    }
+
+    /// `<'a, 'b>`, or nothing if the struct doesn't have any lifetime parameters.
+    pub(crate) fn lifetime_generics(&self) -> TokenStream {
+        if self.lifetimes.is_empty() {
+            return quote! {};
+        }
+
+        let lifetimes = &self.lifetimes;
+        quote! { <#(#lifetimes),*> }
+    }
+
+    /// `<'a, 'b>` or `<u32>`, or nothing if the struct doesn't have any lifetime or generic type
+    /// parameters.
+    pub(crate) fn ty_generics(&self, types: &TypeDeclarations) -> TokenStream {
+        if self.generics.is_empty() {
+            self.lifetime_generics()
+        } else {
+            self.generics
+                .angle_bracketed_concrete_rust_types_tokens(types)
+        }
+    }
+
+    /// `<'static, 'static>`, or nothing if the struct doesn't have any lifetime parameters.
+    ///
+    /// Used when we need to name the struct outside of a function that borrows from the data
+    /// that it points to, such as in the `*mut Vec<T>` that backs a `RustVec<T>`.
+    /// The Swift side is responsible for not using the struct after the data has been freed.
+    pub(crate) fn static_lifetime_generics(&self) -> TokenStream {
+        if self.lifetimes.is_empty() {
+            return quote! {};
+        }
+
+        let lifetimes = self.lifetimes.iter().map(|_| quote! { 'static });
+        quote! { <#(#lifetimes),*> }
+    }
+
+    /// Whether or not we generate the `Vectorizable` support that lets the struct be used in a
+    /// `Vec<T>`.
+    /// Swift gets a copy of the element when it reads from a `RustVec<T>`, so this requires
+    /// `#[derive(Clone)]`.
+    pub fn is_vectorizable(&self) -> bool {
+        // A generic struct can only conform to `Vectorizable` once, so its instantiations can't
+        // each be put in a `Vec<T>`.
+        self.derives.clone
+            && matches!(self.swift_repr, StructSwiftRepr::Structure)
+            && self.generics.is_empty()
+    }
+
+    /// Whether or not the struct's `debugDescription` gets generated using its Rust `Debug` impl.
+    ///
+    /// Swift gives up ownership of a field such as a `RustString` when it passes the struct to
+    /// Rust, so we only do this for structs whose fields don't own any Rust values.
+    /// A generic struct can only conform to `CustomDebugStringConvertible` once, so its
+    /// instantiations can't each use their own Rust `Debug` impl.
+    /// `#[derive(Debug)]` on any other struct is a parse error.
+    pub(crate) fn has_swift_debug_description(&self, types: &TypeDeclarations) -> bool {
+        self.derives.debug
+            && !self.fields.ffi_repr_owns_rust_values(types)
+            && matches!(self.swift_repr, StructSwiftRepr::Structure)
+            && self.generics.is_empty()
     }
 
     /// Some if the struct has a single variant.
@@ -377,7 +553,7 @@ impl SharedStruct {
         span: Span,
     ) -> TokenStream {
         let struct_name = &self.name;
-        let struct_ffi_name = format_ident!("{}{}", SWIFT_BRIDGE_PREFIX, struct_name);
+        let struct_ffi_name = self.ffi_name_ident();
 
         let converted_fields: Vec<TokenStream> = self
             .fields
@@ -415,8 +591,11 @@ impl SharedStruct {
             }
         };
 
+        let generics = self.lifetime_generics();
+        let ty_generics = self.ty_generics(types);
+
         quote! {
-            impl #struct_name {
+            impl #generics #struct_name #ty_generics {
                 #[doc(hidden)]
                 #[inline(always)]
                 pub fn into_ffi_repr(self) -> #struct_ffi_name {
@@ -438,7 +617,7 @@ impl SharedStruct {
             .normalized_fields()
             .iter()
             .map(|norm_field| {
-                let field_name = norm_field.ffi_field_name();
+                let field_name = norm_field.swift_name_string();
                 let ty = BridgedType::new_with_type(&norm_field.ty, types).unwrap();
                 let access_field = ty.convert_swift_expression_to_ffi_type(
                     &format!("val.{field_name}", field_name = field_name),
@@ -473,7 +652,7 @@ impl SharedStruct {
         types: &TypeDeclarations,
         swift_bridge_path: &Path,
     ) -> String {
-        let name = self.swift_name_string();
+        let name = self.swift_type_string(types, swift_bridge_path);
         let struct_name = &name;
 
         let converted_fields: Vec<String> = self
@@ -481,7 +660,7 @@ impl SharedStruct {
             .normalized_fields()
             .iter()
             .map(|norm_field| {
-                let field_name = norm_field.ffi_field_name();
+                let field_name = norm_field.swift_name_string();
 
                 let ty = BridgedType::new_with_type(&norm_field.ty, types).unwrap();
                 let access_field = ty.convert_ffi_value_to_swift_value(
@@ -531,16 +710,18 @@ impl SharedStruct {
         }
     }
 
-    pub fn type_name_with_swift_bridge_prefix(&self, swift_bridge_path: &Path) -> TokenStream {
+    pub fn type_name_with_swift_bridge_prefix(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
         let ty_name = &self.name;
+        let ty_generics = self.ty_generics(types);
 
-        let prefixed_ty_name = Ident::new(
-            &format!("{}{}", SWIFT_BRIDGE_PREFIX, ty_name),
-            ty_name.span(),
-        );
+        let prefixed_ty_name = self.ffi_name_ident();
 
         let prefixed_ty_name = if self.already_declared {
-            quote! { <super:: #ty_name as #swift_bridge_path::SharedStruct>::FfiRepr }
+            quote! { <super:: #ty_name #ty_generics as #swift_bridge_path::SharedStruct>::FfiRepr }
         } else {
             quote! { #prefixed_ty_name }
         };
@@ -589,6 +770,10 @@ impl PartialEq for SharedStruct {
             && self.swift_name.as_ref().map(|l| l.value())
                 == other.swift_name.as_ref().map(|l| l.value())
             && self.already_declared == other.already_declared
+            && self.lifetimes == other.lifetimes
+            && self.generics.params == other.generics.params
+            && self.generics.dollar_prefixed_args_string()
+                == other.generics.dollar_prefixed_args_string()
     }
 }
 
@@ -600,6 +785,14 @@ impl Debug for SharedStruct {
             .field("fields", &self.fields)
             .field("swift_name", &self.swift_name.as_ref().map(|l| l.value()))
             .field("already_declared", &self.already_declared)
+            .field(
+                "lifetimes",
+                &self
+                    .lifetimes
+                    .iter()
+                    .map(|l| l.to_string())
+                    .collect::<Vec<_>>(),
+            )
             .finish()
     }
 }
@@ -2,7 +2,11 @@ use std::fmt::{Debug, Formatter};
 
 use proc_macro2::{Ident, TokenStream};
 use quote::{quote, ToTokens};
-use syn::{Fields, Type};
+use syn::{Attribute, Fields, LitStr, Path, Type};
+
+use crate::bridged_type::serde_attributes::{is_serde_attribute, serde_rename};
+use crate::bridged_type::{BridgeableType, BridgedType, CFfiStruct};
+use crate::parse::{FieldAttributes, TypeDeclarations};
 
 pub(crate) use self::normalized_field::*;
 
@@ -32,6 +36,7 @@ impl StructFields {
                 .map(|n| NormalizedStructField {
                     accessor: NormalizedStructFieldAccessor::Named(n.name.clone()),
                     ty: n.ty.clone(),
+                    swift_name: n.swift_name.clone(),
                 })
                 .collect(),
             StructFields::Unnamed(unnamed) => unnamed
@@ -39,12 +44,24 @@ impl StructFields {
                 .map(|u| NormalizedStructField {
                     accessor: NormalizedStructFieldAccessor::Unnamed(u.idx),
                     ty: u.ty.clone(),
+                    swift_name: None,
                 })
                 .collect(),
             StructFields::Unit => Vec::new(),
         }
     }
 
+    /// Whether or not the FFI representation of any of the fields owns a Rust value, such as a
+    /// `String` field.
+    /// See [`BridgedType::ffi_repr_owns_rust_values`].
+    pub(crate) fn ffi_repr_owns_rust_values(&self, types: &TypeDeclarations) -> bool {
+        self.normalized_fields().iter().any(|field| {
+            BridgedType::new_with_type(&field.ty, types)
+                .map(|ty| ty.ffi_repr_owns_rust_values(types))
+                .unwrap_or(true)
+        })
+    }
+
     pub fn wrap_declaration_fields(&self, struct_fields: &[TokenStream]) -> TokenStream {
         match &self {
             StructFields::Named(_) => {
@@ -82,14 +99,23 @@ impl StructFields {
         }
     }
 
-    pub fn from_syn_fields(fields: Fields) -> Self {
-        match fields {
+    pub fn from_syn_fields(fields: Fields) -> syn::Result<Self> {
+        let fields = match fields {
             Fields::Named(f) => {
                 let mut fields = vec![];
                 for field in f.named.iter() {
+                    let attributes = FieldAttributes::from_attributes(&field.attrs)?;
+                    reject_rename_attribute(&attributes)?;
                     let field = NamedStructField {
                         name: field.ident.clone().unwrap(),
                         ty: field.ty.clone(),
+                        serde_attrs: field
+                            .attrs
+                            .iter()
+                            .filter(|attr| is_serde_attribute(attr))
+                            .cloned()
+                            .collect(),
+                        swift_name: attributes.swift_name,
                     };
                     fields.push(field);
                 }
@@ -99,6 +125,16 @@ impl StructFields {
             Fields::Unnamed(f) => {
                 let mut fields = vec![];
                 for (idx, field) in f.unnamed.iter().enumerate() {
+                    // Swift accesses unnamed fields by their position, so they can't be renamed.
+                    let attributes = FieldAttributes::from_attributes(&field.attrs)?;
+                    reject_rename_attribute(&attributes)?;
+                    if let Some(swift_name) = attributes.swift_name {
+                        return Err(syn::Error::new_spanned(
+                            swift_name,
+                            "The swift_name attribute can only be used on named fields.",
+                        ));
+                    }
+
                     let field = UnnamedStructField {
                         ty: field.ty.clone(),
                         idx,
@@ -109,7 +145,45 @@ impl StructFields {
                 StructFields::Unnamed(fields)
             }
             Fields::Unit => StructFields::Unit,
-        }
+        };
+
+        Ok(fields)
+    }
+
+    /// The custom FFI types, such as `Option<Option<T>>` or tuples, that the fields depend on.
+    pub fn generate_custom_rust_ffi_types(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> Vec<TokenStream> {
+        self.normalized_fields()
+            .iter()
+            .filter_map(|field| {
+                BridgedType::new_with_type(&field.ty, types)?
+                    .generate_custom_rust_ffi_types(swift_bridge_path, types)
+            })
+            .flatten()
+            .collect()
+    }
+
+    pub fn generate_custom_c_ffi_types(&self, types: &TypeDeclarations) -> Vec<CFfiStruct> {
+        self.normalized_fields()
+            .iter()
+            .filter_map(|field| {
+                BridgedType::new_with_type(&field.ty, types)?.generate_custom_c_ffi_types(types)
+            })
+            .collect()
+    }
+}
+
+/// The `rename` attribute sets an enum variant's `String` raw value, so fields can't use it.
+fn reject_rename_attribute(attributes: &FieldAttributes) -> syn::Result<()> {
+    match attributes.rename.as_ref() {
+        Some(rename) => Err(syn::Error::new_spanned(
+            rename,
+            "The rename attribute can only be used on enum variants.",
+        )),
+        None => Ok(()),
     }
 }
 
@@ -117,6 +191,19 @@ impl StructFields {
 pub(crate) struct NamedStructField {
     pub name: Ident,
     pub ty: Type,
+    /// The field's `#[serde(...)]` attributes, which get added to the Rust struct as written.
+    pub serde_attrs: Vec<Attribute>,
+    /// `#[swift_bridge(swift_name = "...")]`
+    pub swift_name: Option<LitStr>,
+}
+
+impl NamedStructField {
+    /// The field's name in its serialized representation.
+    ///
+    /// `#[serde(rename = "...")]`, or the field's name.
+    pub fn serde_name_string(&self) -> String {
+        serde_rename(&self.serde_attrs).unwrap_or_else(|| self.name.to_string())
+    }
 }
 
 #[derive(Clone)]
@@ -136,7 +223,10 @@ impl StructField for NamedStructField {
     }
 
     fn swift_name_string(&self) -> String {
-        self.name.to_string()
+        match self.swift_name.as_ref() {
+            Some(swift_name) => swift_name.value(),
+            None => self.name.to_string(),
+        }
     }
 }
 
@@ -1,11 +1,13 @@
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 use std::str::FromStr;
-use syn::Type;
+use syn::{LitStr, Type};
 
 pub(crate) struct NormalizedStructField {
     pub accessor: NormalizedStructFieldAccessor,
     pub ty: Type,
+    /// `#[swift_bridge(swift_name = "...")]`
+    pub swift_name: Option<LitStr>,
 }
 
 pub(crate) enum NormalizedStructFieldAccessor {
@@ -44,8 +46,8 @@ impl NormalizedStructField {
     /// Example if unnamed field -> "someValue".
     pub fn struct_field_setter_string(&self, value: String) -> String {
         match &self.accessor {
-            NormalizedStructFieldAccessor::Named(name) => {
-                format!("{}: {}", name.to_string(), value)
+            NormalizedStructFieldAccessor::Named(_) => {
+                format!("{}: {}", self.swift_name_string(), value)
             }
             NormalizedStructFieldAccessor::Unnamed(_) => {
                 format!("{}", value)
@@ -58,14 +60,7 @@ impl NormalizedStructField {
     /// Example if named field -> "field_name: someValue".
     /// Example if unnamed field -> "_0: someValue".
     pub fn struct_ffi_field_setter_string(&self, value: String) -> String {
-        match &self.accessor {
-            NormalizedStructFieldAccessor::Named(name) => {
-                format!("{}: {}", name.to_string(), value)
-            }
-            NormalizedStructFieldAccessor::Unnamed(idx) => {
-                format!("_{}: {}", idx, value)
-            }
-        }
+        format!("{}: {}", self.swift_name_string(), value)
     }
 
     /// Access a struct's field
@@ -94,4 +89,14 @@ impl NormalizedStructField {
             }
         }
     }
+
+    /// The field's name in Swift and in the C header.
+    ///
+    /// `#[swift_bridge(swift_name = "...")]`, or the field's Rust name.
+    pub fn swift_name_string(&self) -> String {
+        match self.swift_name.as_ref() {
+            Some(swift_name) => swift_name.value(),
+            None => self.ffi_field_name(),
+        }
+    }
 }
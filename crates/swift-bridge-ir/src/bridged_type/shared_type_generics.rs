@@ -0,0 +1,310 @@
+use crate::bridged_type::{BridgedType, TypePosition};
+use crate::parse::TypeDeclarations;
+use proc_macro2::{Ident, TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+use syn::{GenericArgument, Path, PathArguments, Type};
+
+/// The generic type parameters of a shared struct or enum, along with the concrete types of one
+/// of its instantiations.
+///
+/// ```no_run,ignore
+/// #[swift_bridge::bridge]
+/// mod ffi {
+///     // Gets parsed as two structs, `Page<u32>` and `Page<String>`.
+///     #[swift_bridge(swift_repr = "struct", instantiate(T = u32), instantiate(T = String))]
+///     struct Page<T> {
+///         items: Vec<T>,
+///     }
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub(crate) struct SharedTypeGenerics {
+    /// The generic type parameters, such as the `T` in `struct Page<T>`.
+    pub params: Vec<Ident>,
+    /// The concrete type of each of the generic type parameters, such as the `u32` in
+    /// `Page<u32>`.
+    pub args: Vec<Type>,
+}
+
+impl SharedTypeGenerics {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.params.is_empty()
+    }
+
+    /// The key that the instantiation gets stored under in the [`TypeDeclarations`].
+    ///
+    /// "Page<u32>" if there are generics.
+    /// "Page" if there are no generics.
+    pub(crate) fn type_declaration_key(&self, name: &Ident) -> String {
+        if self.is_empty() {
+            return name.to_string();
+        }
+
+        let args = &self.args;
+        quote! { #name<#(#args),*> }.to_string().replace(' ', "")
+    }
+
+    /// "$u32$Vec_u8" if there are generics.
+    /// "" if there are no generics.
+    pub(crate) fn dollar_prefixed_args_string(&self) -> String {
+        self.args
+            .iter()
+            .map(|arg| format!("${}", alpha_numeric_type_name(arg)))
+            .collect()
+    }
+
+    /// "_u32_Vec_u8" if there are generics.
+    /// "" if there are no generics.
+    pub(crate) fn underscore_prefixed_args_string(&self) -> String {
+        self.args
+            .iter()
+            .map(|arg| format!("_{}", alpha_numeric_type_name(arg)))
+            .collect()
+    }
+
+    /// "<T, U>" if there are generics.
+    /// "" if there are no generics.
+    pub(crate) fn angle_bracketed_params_tokens(&self) -> TokenStream {
+        if self.is_empty() {
+            return quote! {};
+        }
+
+        let params = &self.params;
+        quote! { <#(#params),*> }
+    }
+
+    /// "<u32, super::SomeType>" if there are generics.
+    /// "" if there are no generics.
+    pub(crate) fn angle_bracketed_concrete_rust_types_tokens(
+        &self,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        if self.is_empty() {
+            return quote! {};
+        }
+
+        let args = self.args.iter().map(|arg| {
+            BridgedType::new_with_type(arg, types)
+                .unwrap()
+                .to_rust_type_path(types)
+        });
+        quote! { <#(#args),*> }
+    }
+
+    /// "<UInt32, RustString>" if there are generics.
+    /// "" if there are no generics.
+    pub(crate) fn angle_bracketed_concrete_swift_types_string(
+        &self,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> String {
+        if self.is_empty() {
+            return "".to_string();
+        }
+
+        let args: Vec<String> = self
+            .args
+            .iter()
+            .map(|arg| concrete_swift_type(arg, types, swift_bridge_path))
+            .collect();
+        format!("<{}>", args.join(", "))
+    }
+
+    /// "<T: Vectorizable, U>" if there are generics.
+    /// "" if there are no generics.
+    ///
+    /// A generic type parameter that gets used inside of a `Vec` needs to be `Vectorizable`,
+    /// since that's required by `RustVec<T>`.
+    pub(crate) fn angle_bracketed_swift_params_string<'a>(
+        &self,
+        declared_field_types: impl Iterator<Item = &'a Type> + Clone,
+    ) -> String {
+        if self.is_empty() {
+            return "".to_string();
+        }
+
+        let params: Vec<String> = self
+            .params
+            .iter()
+            .map(|param| {
+                let is_vectorizable = declared_field_types
+                    .clone()
+                    .any(|ty| is_used_inside_of_vec(ty, param));
+
+                if is_vectorizable {
+                    format!("{}: Vectorizable", param)
+                } else {
+                    param.to_string()
+                }
+            })
+            .collect();
+        format!("<{}>", params.join(", "))
+    }
+
+    /// "T == UInt32, U == RustString"
+    pub(crate) fn swift_generic_bounds(
+        &self,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> String {
+        let bounds: Vec<String> = self
+            .params
+            .iter()
+            .zip(self.args.iter())
+            .map(|(param, arg)| {
+                format!(
+                    "{} == {}",
+                    param,
+                    concrete_swift_type(arg, types, swift_bridge_path)
+                )
+            })
+            .collect();
+        bounds.join(", ")
+    }
+
+    /// " where T: Equatable, U: Equatable" if there are generics.
+    /// "" if there are no generics.
+    ///
+    /// Used to conditionally conform a generic type to a protocol.
+    pub(crate) fn swift_conditional_conformance_bounds(&self, protocol: &str) -> String {
+        if self.is_empty() {
+            return "".to_string();
+        }
+
+        // `@unchecked Sendable` -> `Sendable`
+        let protocol = protocol.trim_start_matches("@unchecked ");
+        let bounds: Vec<String> = self
+            .params
+            .iter()
+            .map(|param| format!("{}: {}", param, protocol))
+            .collect();
+        format!(" where {}", bounds.join(", "))
+    }
+
+    /// The Swift type of one of the fields that the generic type was declared with.
+    ///
+    /// `T` -> "T", `Vec<T>` -> "RustVec<T>", `Option<String>` -> "Optional<RustString>"
+    pub(crate) fn declared_field_swift_type(
+        &self,
+        ty: &Type,
+        types: &TypeDeclarations,
+        swift_bridge_path: &Path,
+    ) -> String {
+        if let Some(param) = self.param(ty) {
+            return param.to_string();
+        }
+        if !self.contains_param(ty) {
+            return concrete_swift_type(ty, types, swift_bridge_path);
+        }
+
+        match container_and_inner(ty) {
+            Some(("Vec", inner)) => format!(
+                "RustVec<{}>",
+                self.declared_field_swift_type(inner, types, swift_bridge_path)
+            ),
+            Some(("Option", inner)) => format!(
+                "Optional<{}>",
+                self.declared_field_swift_type(inner, types, swift_bridge_path)
+            ),
+            _ => unreachable!("Unsupported uses of generic type parameters are a parse error"),
+        }
+    }
+
+    /// The Rust type of one of the fields that the generic type was declared with.
+    ///
+    /// `T` -> `T`, `Vec<T>` -> `Vec<T>`, `SomeType` -> `super::SomeType`
+    pub(crate) fn declared_field_rust_type(
+        &self,
+        ty: &Type,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        if self.contains_param(ty) {
+            return ty.to_token_stream();
+        }
+
+        BridgedType::new_with_type(ty, types)
+            .unwrap()
+            .to_rust_type_path(types)
+    }
+
+    /// Whether or not the generic type parameters are only used directly or inside of a `Vec` or
+    /// `Option`, since those are the only uses that we know how to declare in Swift.
+    pub(crate) fn is_supported_field_type(&self, ty: &Type) -> bool {
+        if self.param(ty).is_some() || !self.contains_param(ty) {
+            return true;
+        }
+
+        match container_and_inner(ty) {
+            Some(("Vec", inner)) | Some(("Option", inner)) => self.is_supported_field_type(inner),
+            _ => false,
+        }
+    }
+
+    fn param(&self, ty: &Type) -> Option<&Ident> {
+        let ident = match ty {
+            Type::Path(path) if path.qself.is_none() => path.path.get_ident()?,
+            _ => return None,
+        };
+
+        self.params.iter().find(|param| *param == ident)
+    }
+
+    fn contains_param(&self, ty: &Type) -> bool {
+        self.params
+            .iter()
+            .any(|param| tokens_contain_ident(ty.to_token_stream(), param))
+    }
+}
+
+/// `Vec<u8>` -> "Vec_u8"
+pub(crate) fn alpha_numeric_type_name(ty: &Type) -> String {
+    ty.to_token_stream()
+        .to_string()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+fn concrete_swift_type(ty: &Type, types: &TypeDeclarations, swift_bridge_path: &Path) -> String {
+    BridgedType::new_with_type(ty, types)
+        .unwrap()
+        .to_swift_type(TypePosition::SharedStructField, types, swift_bridge_path)
+}
+
+/// `Vec<T>` -> Some(("Vec", T))
+fn container_and_inner(ty: &Type) -> Option<(&'static str, &Type)> {
+    let segment = match ty {
+        Type::Path(path) if path.qself.is_none() => path.path.segments.last()?,
+        _ => return None,
+    };
+    let container = match segment.ident.to_string().as_str() {
+        "Vec" => "Vec",
+        "Option" => "Option",
+        _ => return None,
+    };
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            GenericArgument::Type(inner) => Some((container, inner)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn is_used_inside_of_vec(ty: &Type, param: &Ident) -> bool {
+    match container_and_inner(ty) {
+        Some(("Vec", inner)) => tokens_contain_ident(inner.to_token_stream(), param),
+        Some((_, inner)) => is_used_inside_of_vec(inner, param),
+        None => false,
+    }
+}
+
+fn tokens_contain_ident(tokens: TokenStream, ident: &Ident) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(token) => token == *ident,
+        TokenTree::Group(group) => tokens_contain_ident(group.stream(), ident),
+        _ => false,
+    })
+}
@@ -1,5 +1,9 @@
 use crate::bridge_module_attributes::CfgAttr;
+use crate::parse::{visit_nested_types, SharedTypeDeclaration, TypeDeclaration};
 use crate::SwiftBridgeModule;
+use proc_macro2::Ident;
+use quote::ToTokens;
+use syn::{FnArg, GenericArgument, PathArguments, ReturnType, Type};
 
 mod generate_c_header;
 mod generate_rust_tokens;
@@ -58,4 +62,77 @@ impl SwiftBridgeModule {
 
         true
     }
+
+    /// Whether or not `Arc<ty>` appears anywhere in the module, such as in a function signature
+    /// or a shared struct's field.
+    /// If not, we don't generate the functions that Swift uses to manage the `Arc`'s strong count.
+    fn uses_arc_of(&self, opaque_ty: &Ident) -> bool {
+        let mut types: Vec<Type> = vec![];
+
+        let functions = self
+            .functions
+            .iter()
+            .chain(self.types.traits().iter().flat_map(|t| t.methods.iter()));
+        for func in functions {
+            let sig = &func.func.sig;
+            for arg in sig.inputs.iter() {
+                if let FnArg::Typed(pat_ty) = arg {
+                    types.push(pat_ty.ty.as_ref().clone());
+                }
+            }
+            if let ReturnType::Type(_, ret) = &sig.output {
+                types.push(ret.as_ref().clone());
+            }
+        }
+
+        for declaration in self.types.types() {
+            match declaration {
+                TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)) => {
+                    types.extend(
+                        shared_struct
+                            .fields
+                            .normalized_fields()
+                            .into_iter()
+                            .map(|f| f.ty),
+                    );
+                }
+                TypeDeclaration::Shared(SharedTypeDeclaration::Enum(shared_enum)) => {
+                    for variant in shared_enum.variants.iter() {
+                        types.extend(variant.fields.normalized_fields().into_iter().map(|f| f.ty));
+                    }
+                }
+                TypeDeclaration::Opaque(_) => {}
+            }
+        }
+
+        let mut uses_arc = false;
+        for ty in types.iter() {
+            visit_nested_types(ty, &mut |ty| {
+                uses_arc |= is_arc_of(ty, opaque_ty);
+            });
+        }
+        uses_arc
+    }
+}
+
+/// `Arc<SomeType>` or `std::sync::Arc<SomeType>`
+fn is_arc_of(ty: &Type, opaque_ty: &Ident) -> bool {
+    let last = match ty {
+        Type::Path(path) => match path.path.segments.last() {
+            Some(last) => last,
+            None => return false,
+        },
+        _ => return false,
+    };
+    if last.ident != "Arc" {
+        return false;
+    }
+
+    match &last.arguments {
+        PathArguments::AngleBracketed(args) => args.args.iter().any(|arg| match arg {
+            GenericArgument::Type(inner) => *opaque_ty == inner.to_token_stream().to_string(),
+            _ => false,
+        }),
+        _ => false,
+    }
 }
@@ -28,27 +28,46 @@ use crate::test_utils::{
 };
 
 mod already_declared_attribute;
+mod arc_of_opaque_rust_type;
+mod argument_default;
 mod argument_label;
 mod async_function;
+mod boxed_fn;
 mod boxed_fnonce;
+mod bridged_trait;
 mod built_in_tuple;
 mod c_header_declaration_order;
+mod case_iterable_and_raw_representable;
+mod char;
+mod codable;
 mod conditional_compilation;
 mod derive_copy_clone;
 mod derive_debug;
+mod derive_protocol_conformance;
+mod enum_discriminant;
 mod extern_rust_function_opaque_rust_type_argument;
 mod extern_rust_function_opaque_rust_type_return;
 mod extern_rust_method_swift_class_placement;
+mod fixed_size_array;
 mod function_attribute;
+mod generic_function;
 mod generic_opaque_rust_type;
+mod generic_shared_type;
+mod int128;
+mod map;
+mod nested_containers;
 mod opaque_rust_type;
 mod opaque_swift_type;
 mod option;
+mod recursive_shared_type;
 mod result;
 mod return_into_attribute;
 mod sendable_attribute;
+mod set;
+mod shared_type_method;
 mod single_representation_type_elision;
 mod string;
+mod swift_naming;
 mod transparent_enum;
 mod transparent_struct;
 mod vec;
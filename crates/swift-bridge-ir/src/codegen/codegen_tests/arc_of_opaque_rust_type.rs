@@ -0,0 +1,317 @@
+//! See also: crates/swift-integration-tests/src/arc.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we generate the class that holds an `Arc<T>` on the Swift side, along with the
+/// functions that it uses to manage the strong count, when the module uses `Arc<T>`.
+mod arc_support {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                struct SomeStruct {
+                    field: Arc<SomeType>,
+                }
+
+                extern "Rust" {
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            const _: () = {
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$SomeType$_increment_strong_count"]
+                pub extern "C" fn _increment_strong_count(this: *const super::SomeType) {
+                    unsafe { std::sync::Arc::increment_strong_count(this) }
+                }
+
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$SomeType$_decrement_strong_count"]
+                pub extern "C" fn _decrement_strong_count(this: *const super::SomeType) {
+                    unsafe { std::sync::Arc::decrement_strong_count(this) }
+                }
+            };
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public class SomeTypeArc: SomeTypeRef {
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+
+    deinit {
+        __swift_bridge__$SomeType$_decrement_strong_count(ptr)
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$SomeType$_increment_strong_count(void* self);
+void __swift_bridge__$SomeType$_decrement_strong_count(void* self);
+"#,
+        )
+    }
+
+    #[test]
+    fn arc_support() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we don't generate the `Arc<T>` class or strong count functions for an opaque Rust
+/// type that the module never uses in an `Arc<T>`.
+mod no_arc_support_without_arc {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                    fn some_function(arg: &SomeType) -> Vec<SomeType>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::DoesNotContain(quote! {
+            #[export_name = "__swift_bridge__$SomeType$_increment_strong_count"]
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::DoesNotContainAfterTrim("SomeTypeArc")
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::DoesNotContainAfterTrim("strong_count")
+    }
+
+    #[test]
+    fn no_arc_support_without_arc() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that an extern "Rust" function can take and return an `Arc<T>`.
+/// Swift gives Rust a new strong count when passing an `Arc<T>` to Rust.
+mod extern_rust_fn_arc_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn some_function(arg: Arc<SomeType>) -> std::sync::Arc<SomeType>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: *const super::SomeType
+            ) -> *const super::SomeType {
+                std::sync::Arc::into_raw({
+                    let val: std::sync::Arc<super::SomeType> = super::some_function(
+                        unsafe { std::sync::Arc::from_raw(arg) }
+                    );
+                    val
+                })
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: SomeTypeArc) -> SomeTypeArc {
+    SomeTypeArc(ptr: __swift_bridge__$some_function({let val = arg; __swift_bridge__$SomeType$_increment_strong_count(val.ptr); return val.ptr;}()))
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$some_function(void* arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_arc_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that an extern "Rust" function can take and return an `Option<Arc<T>>`.
+mod extern_rust_fn_option_arc {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn some_function(arg: Option<Arc<SomeType>>) -> Option<Arc<SomeType>>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: *const super::SomeType
+            ) -> *const super::SomeType {
+                if let Some(val) = super::some_function(
+                    if arg.is_null() {
+                        None
+                    } else {
+                        Some(unsafe { std::sync::Arc::from_raw(arg) })
+                    }
+                ) {
+                    std::sync::Arc::into_raw(val)
+                } else {
+                    std::ptr::null()
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: Optional<SomeTypeArc>) -> Optional<SomeTypeArc> {
+    { let val = __swift_bridge__$some_function({ if let val = arg { __swift_bridge__$SomeType$_increment_strong_count(val.ptr); return val.ptr } else { return nil } }()); if val != nil { return SomeTypeArc(ptr: val!) } else { return nil } }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$some_function(void* arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_option_arc() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that an extern "Swift" function can take and return an `Arc<T>`.
+mod extern_swift_fn_arc_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                }
+
+                extern "Swift" {
+                    fn some_function(arg: Arc<SomeType>) -> Arc<SomeType>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: std::sync::Arc<super::SomeType>) -> std::sync::Arc<super::SomeType> {
+                    unsafe {
+                        std::sync::Arc::from_raw(
+                            unsafe {
+                                __swift_bridge__some_function(
+                                    std::sync::Arc::into_raw({
+                                        let val: std::sync::Arc<super::SomeType> = arg;
+                                        val
+                                    })
+                                )
+                            }
+                        )
+                    }
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(arg: *const super::SomeType) -> *const super::SomeType;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: UnsafeMutableRawPointer) -> UnsafeMutableRawPointer {
+    {let val = some_function(arg: SomeTypeArc(ptr: arg)); __swift_bridge__$SomeType$_increment_strong_count(val.ptr); return val.ptr;}()
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_arc_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -0,0 +1,129 @@
+//! See also: crates/swift-integration-tests/src/argument_attributes/argument_default.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that the generated Swift function uses the `#[swift_bridge(default = ...)]` values of
+/// its arguments.
+mod argument_default {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn some_function(
+                        value: u32,
+                        #[swift_bridge(label = "times", default = "2")] multiplier: u32,
+                        #[swift_bridge(default = None)] name: Option<u8>,
+                    );
+                    fn some_method(&self, #[swift_bridge(default = "true")] flag: bool);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                value: u32,
+                multiplier: u32,
+                name: swift_bridge::option::OptionU8
+            ) {
+                super::some_function(
+                    value,
+                    multiplier,
+                    { let val = name; if val.is_some { Some(val.val) } else { None } }
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func some_function(_ value: UInt32, times multiplier: UInt32 = 2, _ name: Optional<UInt8> = nil) {
+    __swift_bridge__$some_function(value, multiplier, name.intoFfiRepr())
+}
+"#,
+            r#"
+    public func some_method(_ flag: Bool = true) {
+        __swift_bridge__$SomeType$some_method(ptr, flag)
+    }
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$some_function(uint32_t value, uint32_t multiplier, struct __private__OptionU8 name);
+"#,
+        )
+    }
+
+    #[test]
+    fn argument_default() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that `optional_args_default = None` gives `Option` arguments a default value of `nil`,
+/// except for arguments that have their own default or that Swift takes as a generic type.
+mod optional_args_default_none {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge(optional_args_default = None)]
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(
+                        value: u32,
+                        minimum: Option<u32>,
+                        #[swift_bridge(default = "100")] maximum: Option<u32>,
+                        label: Option<&str>,
+                    );
+                }
+
+                extern "Swift" {
+                    fn swift_function(arg: Option<u8>);
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func some_function<GenericToRustStr: ToRustStr>(_ value: UInt32, _ minimum: Optional<UInt32> = nil, _ maximum: Optional<UInt32> = 100, _ label: Optional<GenericToRustStr>) {
+"#,
+            r#"
+@_cdecl("__swift_bridge__$swift_function")
+func __swift_bridge__swift_function (_ arg: __private__OptionU8) {
+"#,
+        ])
+    }
+
+    #[test]
+    fn optional_args_default_none() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
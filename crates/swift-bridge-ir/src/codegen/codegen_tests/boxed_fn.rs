@@ -0,0 +1,436 @@
+//! Tests for passing `Box<dyn Fn(A, B) -> C>` and `Box<dyn FnMut(A, B) -> C>` between languages.
+//!
+//! Unlike a `Box<dyn FnOnce>`, these can be called any number of times. They are freed when the
+//! other language releases them.
+//!
+//! See also: crates/swift-integration-tests/src/boxed_functions.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we can pass a `Box<dyn Fn>` from Rust to Swift.
+mod test_swift_takes_fn_callback {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(callback: Box<dyn Fn(u8) -> u8>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function (callback: Box<dyn Fn(u8) -> u8>) {
+                    unsafe {
+                        __swift_bridge__some_function(
+                            Box::into_raw(Box::new(callback)) as *mut Box<dyn Fn(u8) -> u8>
+                        )
+                    }
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$some_function$param0"]
+                pub extern "C" fn some_function_param0(some_function_callback: *mut Box<dyn Fn(u8) -> u8>, arg0: u8) -> u8 {
+                    unsafe { (*some_function_callback)(arg0) }
+                }
+
+                #[export_name = "__swift_bridge__$some_function$_free$param0"]
+                pub extern "C" fn free_some_function_param0(some_function_callback: *mut Box<dyn Fn(u8) -> u8>) {
+                    let _ = unsafe { Box::from_raw(some_function_callback) };
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+class __private__RustFnCallback$some_function$param0 {
+    let ptr: UnsafeMutableRawPointer
+
+    init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+    }
+
+    deinit {
+        __swift_bridge__$some_function$_free$param0(ptr)
+    }
+
+    func call(_ arg0: UInt8) -> UInt8 {
+        return __swift_bridge__$some_function$param0(ptr, arg0)
+    }
+}
+"#,
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ callback: UnsafeMutableRawPointer) {
+    { let cb0 = __private__RustFnCallback$some_function$param0(ptr: callback); let _ = some_function(callback: { arg0 in cb0.call(arg0) }) }()
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+uint8_t __swift_bridge__$some_function$param0(void* some_function_callback, uint8_t arg0);
+void __swift_bridge__$some_function$_free$param0(void* some_function_callback);
+"#,
+        )
+    }
+
+    #[test]
+    fn test_swift_takes_fn_callback() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a `Box<dyn Fn>` that gets passed from Rust to Swift and returns a `String`
+/// returns a `RustString` to Swift.
+mod test_swift_takes_fn_callback_string_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(callback: Box<dyn Fn(String) -> String>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function$param0"]
+            pub extern "C" fn some_function_param0(
+                some_function_callback: *mut Box<dyn Fn(String) -> String>,
+                arg0: *mut swift_bridge::string::RustString
+            ) -> *mut swift_bridge::string::RustString {
+                swift_bridge::string::RustString(
+                    unsafe { (*some_function_callback)(unsafe { Box::from_raw(arg0).0 }) }
+                ).box_into_raw()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+    func call<GenericIntoRustString: IntoRustString>(_ arg0: GenericIntoRustString) -> RustString {
+        return RustString(ptr: __swift_bridge__$some_function$param0(ptr, { let rustString = arg0.intoRustString(); rustString.isOwned = false; return rustString.ptr }()))
+    }
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$some_function$param0(void* some_function_callback, void* arg0);
+"#,
+        )
+    }
+
+    #[test]
+    fn test_swift_takes_fn_callback_string_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate a callback class for a `Box<dyn FnMut()>` that has no args or return
+/// value, since the shared no args no return support only handles `FnOnce`.
+mod test_swift_takes_fnmut_callback_no_args_no_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(callback: Box<dyn FnMut()>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function$param0"]
+            pub extern "C" fn some_function_param0(some_function_callback: *mut Box<dyn FnMut() -> ()>) {
+                unsafe { (*some_function_callback)() }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+class __private__RustFnMutCallback$some_function$param0 {
+    let ptr: UnsafeMutableRawPointer
+
+    init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+    }
+
+    deinit {
+        __swift_bridge__$some_function$_free$param0(ptr)
+    }
+
+    func call() {
+        return __swift_bridge__$some_function$param0(ptr)
+    }
+}
+"#,
+            r#"
+{ let cb0 = __private__RustFnMutCallback$some_function$param0(ptr: callback); let _ = some_function(callback: { cb0.call() }) }()
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$some_function$param0(void* some_function_callback);
+void __swift_bridge__$some_function$_free$param0(void* some_function_callback);
+"#,
+        )
+    }
+
+    #[test]
+    fn test_swift_takes_fnmut_callback_no_args_no_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a `Box<dyn Fn + Send + Sync>` that is passed from Rust to Swift can be called from
+/// any thread in Swift.
+mod test_swift_takes_sendable_fn_callback {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(callback: Box<dyn Fn(u8) + Send + Sync>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub fn some_function (callback: Box<dyn Fn(u8) -> () + Send + Sync>) {
+                unsafe {
+                    __swift_bridge__some_function(
+                        Box::into_raw(Box::new(callback)) as *mut Box<dyn Fn(u8) -> () + Send + Sync>
+                    )
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+class __private__RustFnCallback$some_function$param0: @unchecked Sendable {
+"#,
+        )
+    }
+
+    #[test]
+    fn test_swift_takes_sendable_fn_callback() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we can pass a Swift closure to Rust as a `Box<dyn Fn>`.
+mod test_rust_takes_fn_callback {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(callback: Box<dyn Fn(u8) -> u8>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$some_function"]
+                pub extern "C" fn __swift_bridge__some_function(callback: *mut std::ffi::c_void) {
+                    super::some_function({
+                        let callback = unsafe {
+                            swift_bridge::boxed_fn_support::SwiftCallback::new(
+                                callback,
+                                free_some_function_param0
+                            )
+                        };
+                        Box::new(move |arg0: u8| -> u8 {
+                            unsafe { some_function_param0(callback.as_ptr(), arg0) }
+                        })
+                    })
+                }
+            },
+            quote! {
+                extern "C" {
+                    #[link_name = "__swift_bridge__$some_function$param0"]
+                    fn some_function_param0(some_function_callback: *mut std::ffi::c_void, arg0: u8) -> u8;
+
+                    #[link_name = "__swift_bridge__$some_function$_free$param0"]
+                    fn free_some_function_param0(some_function_callback: *mut std::ffi::c_void);
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+@_cdecl("__swift_bridge__$some_function$param0")
+func __swift_bridge__some_function$param0 (_ ptr: UnsafeMutableRawPointer, _ arg0: UInt8) -> UInt8 {
+    Unmanaged<__private__SwiftCallback<(UInt8) -> UInt8>>.fromOpaque(ptr).takeUnretainedValue().callback(arg0)
+}
+
+@_cdecl("__swift_bridge__$some_function$_free$param0")
+func __swift_bridge__some_function$_free$param0 (_ ptr: UnsafeMutableRawPointer) {
+    Unmanaged<AnyObject>.fromOpaque(ptr).release()
+}
+"#,
+            r#"
+public func some_function(_ callback: @escaping (UInt8) -> UInt8) {
+    __swift_bridge__$some_function(Unmanaged.passRetained(__private__SwiftCallback(callback)).toOpaque())
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+void __swift_bridge__$some_function(void* callback);
+"#,
+        )
+    }
+
+    #[test]
+    fn test_rust_takes_fn_callback() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a Swift closure that gets passed to Rust as a `Box<dyn FnMut + Send>` must be
+/// `@Sendable`, since Rust can call it from another thread.
+mod test_rust_takes_sendable_fnmut_callback {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn on_progress(&self, callback: Box<dyn FnMut(u32) + Send>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub extern "C" fn __swift_bridge__SomeType_on_progress(
+                    this: *mut super::SomeType,
+                    callback: *mut std::ffi::c_void
+                ) {
+                    (unsafe { &*this }).on_progress({
+                        let callback = unsafe {
+                            swift_bridge::boxed_fn_support::SendableSwiftCallback::new(
+                                callback,
+                                free_SomeType_on_progress_param1
+                            )
+                        };
+                        Box::new(move |arg0: u32| -> () {
+                            unsafe { SomeType_on_progress_param1(callback.as_ptr(), arg0) }
+                        })
+                    })
+                }
+            },
+            quote! {
+                extern "C" {
+                    #[link_name = "__swift_bridge__$SomeType$on_progress$param1"]
+                    fn SomeType_on_progress_param1(on_progress_callback: *mut std::ffi::c_void, arg0: u32);
+
+                    #[link_name = "__swift_bridge__$SomeType$on_progress$_free$param1"]
+                    fn free_SomeType_on_progress_param1(on_progress_callback: *mut std::ffi::c_void);
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+@_cdecl("__swift_bridge__$SomeType$on_progress$param1")
+func __swift_bridge__SomeType_on_progress$param1 (_ ptr: UnsafeMutableRawPointer, _ arg0: UInt32) {
+    Unmanaged<__private__SwiftCallback<@Sendable (UInt32) -> ()>>.fromOpaque(ptr).takeUnretainedValue().callback(arg0)
+}
+"#,
+            r#"
+    public func on_progress(_ callback: @escaping @Sendable (UInt32) -> ()) {
+        __swift_bridge__$SomeType$on_progress(ptr, Unmanaged.passRetained(__private__SwiftCallback(callback)).toOpaque())
+    }
+"#,
+        ])
+    }
+
+    #[test]
+    fn test_rust_takes_sendable_fnmut_callback() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -0,0 +1,461 @@
+//! See also: crates/swift-integration-tests/src/bridged_trait.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we generate a Swift protocol for a trait, along with a Rust type that implements
+/// the trait by calling into a Swift object that conforms to the protocol.
+mod trait_protocol_and_swift_object_wrapper {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                trait Animal {
+                    fn name(&self) -> String;
+                    fn speak(&self, times: u8);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[doc(hidden)]
+                #[repr(C)]
+                pub struct __swift_bridge__Animal(*mut std::ffi::c_void);
+
+                impl super::Animal for __swift_bridge__Animal {
+                    fn name(&self) -> String {
+                        unsafe {
+                            Box::from_raw(unsafe {
+                                __swift_bridge__Animal_name(swift_bridge::PointerToSwiftType(self.0))
+                            }).0
+                        }
+                    }
+                    fn speak(&self, times: u8) {
+                        unsafe {
+                            __swift_bridge__Animal_speak(swift_bridge::PointerToSwiftType(self.0), times)
+                        }
+                    }
+                }
+
+                impl Drop for __swift_bridge__Animal {
+                    fn drop(&mut self) {
+                        unsafe { __swift_bridge__Animal__free(self.0) }
+                    }
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$Animal$name"]
+                fn __swift_bridge__Animal_name(this: swift_bridge::PointerToSwiftType) -> *mut swift_bridge::string::RustString;
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$Animal$speak"]
+                fn __swift_bridge__Animal_speak(this: swift_bridge::PointerToSwiftType, times: u8);
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$Animal$_free"]
+                fn __swift_bridge__Animal__free(this: *mut std::ffi::c_void);
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public protocol Animal: AnyObject {
+    func name() -> RustString
+    func speak(_ times: UInt8)
+}
+"#,
+            r#"
+@_cdecl("__swift_bridge__$Animal$name")
+func __swift_bridge__Animal_name (_ this: UnsafeMutableRawPointer) -> UnsafeMutableRawPointer {
+    { let rustString = (Unmanaged<AnyObject>.fromOpaque(this).takeUnretainedValue() as! any Animal).name().intoRustString(); rustString.isOwned = false; return rustString.ptr }()
+}
+"#,
+            r#"
+@_cdecl("__swift_bridge__$Animal$speak")
+func __swift_bridge__Animal_speak (_ this: UnsafeMutableRawPointer, _ times: UInt8) {
+    (Unmanaged<AnyObject>.fromOpaque(this).takeUnretainedValue() as! any Animal).speak(times)
+}
+"#,
+            r#"
+@_cdecl("__swift_bridge__$Animal$_free")
+func __swift_bridge__Animal__free (_ ptr: UnsafeMutableRawPointer) {
+    Unmanaged<AnyObject>.fromOpaque(ptr).release()
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim("")
+    }
+
+    #[test]
+    fn trait_protocol_and_swift_object_wrapper() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that an opaque Rust type that implements a trait conforms to the trait's protocol.
+mod opaque_rust_type_implements_trait {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                trait Animal {
+                    fn name(&self) -> String;
+                }
+
+                impl Animal for Dog {}
+
+                extern "Rust" {
+                    type Dog;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$Dog$Animal$name"]
+            pub extern "C" fn __swift_bridge__Dog_Animal_name(this: *mut super::Dog) -> *mut swift_bridge::string::RustString {
+                swift_bridge::string::RustString(
+                    <super::Dog as super::Animal>::name(unsafe { &*this })
+                ).box_into_raw()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension DogRef: Animal {
+    public func name() -> RustString {
+        RustString(ptr: __swift_bridge__$Dog$Animal$name(ptr))
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$Dog$Animal$name(void* self);
+"#,
+        )
+    }
+
+    #[test]
+    fn opaque_rust_type_implements_trait() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that an opaque Rust type's `RefMut` class conforms to a trait's protocol when one of
+/// the trait's methods takes `&mut self`.
+mod opaque_rust_type_implements_trait_with_mutable_method {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                trait Animal {
+                    fn rename(&mut self, name: String);
+                }
+
+                impl Animal for Dog {}
+
+                extern "Rust" {
+                    type Dog;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$Dog$Animal$rename"]
+            pub extern "C" fn __swift_bridge__Dog_Animal_rename(
+                this: *mut super::Dog,
+                name: *mut swift_bridge::string::RustString
+            ) {
+                <super::Dog as super::Animal>::rename(
+                    unsafe { &mut *this },
+                    unsafe { Box::from_raw(name).0 }
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public protocol Animal: AnyObject {
+    func rename<GenericIntoRustString: IntoRustString>(_ name: GenericIntoRustString)
+}
+"#,
+            r#"
+extension DogRefMut: Animal {
+    public func rename<GenericIntoRustString: IntoRustString>(_ name: GenericIntoRustString) {
+        __swift_bridge__$Dog$Animal$rename(ptr, { let rustString = name.intoRustString(); rustString.isOwned = false; return rustString.ptr }())
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$Dog$Animal$rename(void* self, void* name);
+"#,
+        )
+    }
+
+    #[test]
+    fn opaque_rust_type_implements_trait_with_mutable_method() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can pass any object that conforms to a trait's protocol to a Rust function
+/// that takes a `&dyn Trait`, `&mut dyn Trait` or `Box<dyn Trait>`.
+mod extern_rust_fn_trait_object_args {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                trait Animal {
+                    fn name(&self) -> String;
+                }
+
+                extern "Rust" {
+                    fn some_function(arg1: &dyn Animal, arg2: &mut dyn Animal, arg3: Box<dyn Animal>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg1: *mut std::ffi::c_void,
+                arg2: *mut std::ffi::c_void,
+                arg3: *mut std::ffi::c_void
+            ) {
+                super::some_function(
+                    &__swift_bridge__Animal(arg1),
+                    &mut __swift_bridge__Animal(arg2),
+                    Box::new(__swift_bridge__Animal(arg3))
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg1: any Animal, _ arg2: any Animal, _ arg3: any Animal) {
+    __swift_bridge__$some_function(Unmanaged.passRetained(arg1 as AnyObject).toOpaque(), Unmanaged.passRetained(arg2 as AnyObject).toOpaque(), Unmanaged.passRetained(arg3 as AnyObject).toOpaque())
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$some_function(void* arg1, void* arg2, void* arg3);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_trait_object_args() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that the Swift object wrapper implements `Send` and `Sync` when the trait has those
+/// supertraits, so that Swift objects can be passed to Rust as an `Arc<dyn Trait + Send + Sync>`.
+mod send_sync_trait_object {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                trait Storage: Send + Sync {
+                    fn get(&self, key: String) -> Option<String>;
+                }
+
+                extern "Rust" {
+                    fn some_function(arg1: Arc<dyn Storage + Send + Sync>, arg2: Box<dyn Storage + Send>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                unsafe impl Send for __swift_bridge__Storage {}
+                unsafe impl Sync for __swift_bridge__Storage {}
+            },
+            quote! {
+                pub extern "C" fn __swift_bridge__some_function(
+                    arg1: *mut std::ffi::c_void,
+                    arg2: *mut std::ffi::c_void
+                ) {
+                    super::some_function(
+                        std::sync::Arc::new(__swift_bridge__Storage(arg1)),
+                        Box::new(__swift_bridge__Storage(arg2))
+                    )
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func some_function(_ arg1: any Storage, _ arg2: any Storage) {
+    __swift_bridge__$some_function(Unmanaged.passRetained(arg1 as AnyObject).toOpaque(), Unmanaged.passRetained(arg2 as AnyObject).toOpaque())
+}
+"#,
+            r#"
+public protocol Storage: AnyObject, Sendable {
+    func get<GenericIntoRustString: IntoRustString>(_ key: GenericIntoRustString) -> Optional<RustString>
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$some_function(void* arg1, void* arg2);
+"#,
+        )
+    }
+
+    #[test]
+    fn send_sync_trait_object() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that an opaque Rust type's `Ref` class is `Sendable` when the type implements a trait
+/// that has `Send` and `Sync` supertraits.
+mod opaque_rust_type_implements_send_sync_trait {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                trait Clock: Send + Sync {
+                    fn now(&self) -> u64;
+                }
+
+                impl Clock for SystemClock {}
+
+                extern "Rust" {
+                    #[swift_bridge(Sendable)]
+                    type SystemClock;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::SkipTest
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SystemClockRef: Clock {
+    public func now() -> UInt64 {
+        __swift_bridge__$SystemClock$Clock$now(ptr)
+    }
+}
+extension SystemClockRef: @unchecked Sendable {}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn opaque_rust_type_implements_send_sync_trait() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+
+    /// Verify that we don't redeclare `Sendable` for the classes that inherit from the `Ref` class.
+    #[test]
+    fn does_not_redeclare_sendable() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: ExpectedSwiftCode::DoesNotContainManyAfterTrim(vec![
+                "extension SystemClock: @unchecked Sendable {}",
+                "extension SystemClockArc: @unchecked Sendable {}",
+            ]),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,226 @@
+//! See also: crates/swift-integration-tests/src/enum_attributes/raw_representable.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that `RawRepresentable = "Int"` uses the enum's `#[repr]` and discriminants, and that
+/// the Rust enum keeps them.
+mod int_raw_representable {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(RawRepresentable = "Int")]
+                #[repr(u16)]
+                enum SomeEnum {
+                    Variant1 = 200,
+                    Variant2,
+                    Variant3 = 404,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[derive(Copy, Clone)]
+            #[repr(u16)]
+            pub enum SomeEnum {
+                Variant1 = 200,
+                Variant2,
+                Variant3 = 404
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public enum SomeEnum: UInt16 {
+    case Variant1 = 200
+    case Variant2 = 201
+    case Variant3 = 404
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn int_raw_representable() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that an enum without a `#[repr]` gets `Int` raw values that start at 0.
+mod int_raw_representable_default_repr {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(RawRepresentable = "Int")]
+                enum SomeEnum {
+                    Variant1,
+                    Variant2
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::SkipTest
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public enum SomeEnum: Int {
+    case Variant1 = 0
+    case Variant2 = 1
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn int_raw_representable_default_repr() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that `RawRepresentable = "String"` uses the variants' `#[swift_bridge(rename = "...")]`,
+/// or their Swift names, as raw values.
+/// Their `#[serde(rename = "...")]` attributes don't affect the raw values.
+mod string_raw_representable {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(RawRepresentable = "String")]
+                enum SomeEnum {
+                    #[swift_bridge(rename = "renamed")]
+                    Variant1,
+                    #[swift_bridge(swift_name = "variant2")]
+                    Variant2,
+                    #[serde(rename = "serde_renamed")]
+                    Variant3
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[derive(Copy, Clone)]
+            pub enum SomeEnum {
+                Variant1,
+                Variant2,
+                Variant3
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public enum SomeEnum: String {
+    case Variant1 = "renamed"
+    case variant2 = "variant2"
+    case Variant3 = "Variant3"
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn string_raw_representable() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that `CaseIterable` gives the Swift enum `CaseIterable` conformance.
+mod case_iterable {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(CaseIterable)]
+                enum SomeEnum {
+                    Variant1,
+                    Variant2
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::SkipTest
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public enum SomeEnum {
+    case Variant1
+    case Variant2
+}
+"#,
+            r#"
+extension SomeEnum: CaseIterable {}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn case_iterable() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
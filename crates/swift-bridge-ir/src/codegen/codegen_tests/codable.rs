@@ -0,0 +1,456 @@
+//! See also: crates/swift-integration-tests/src/codable.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that a struct with named fields gets `CodingKeys` that follow the fields'
+/// `#[serde(rename = "...")]` attributes, and that the serde derives and attributes get added to
+/// the Rust struct.
+mod codable_struct {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", Codable)]
+                #[derive(serde::Serialize, serde::Deserialize)]
+                #[serde(deny_unknown_fields)]
+                struct SomeStruct {
+                    #[serde(rename = "renamedField")]
+                    field: String,
+                    another_field: Option<u8>
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[derive(serde::Serialize, serde::Deserialize)]
+            #[serde(deny_unknown_fields)]
+            pub struct SomeStruct {
+                #[serde(rename = "renamedField")]
+                pub field: String,
+                pub another_field: Option<u8>
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeStruct: Codable {
+    enum CodingKeys: String, CodingKey {
+        case field = "renamedField"
+        case another_field
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn codable_struct() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a tuple struct is encoded as an array, like serde does.
+mod codable_tuple_struct {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", Codable)]
+                struct SomeStruct(u8, String);
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::SkipTest
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeStruct: Codable {
+    public init(from decoder: Decoder) throws {
+        var container = try decoder.unkeyedContainer()
+        self.init(_0: try container.decode(UInt8.self), _1: try container.decode(RustString.self))
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.unkeyedContainer()
+        try container.encode(self._0)
+        try container.encode(self._1)
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn codable_tuple_struct() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a newtype struct is encoded as its field, like serde does.
+mod codable_newtype_struct {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", Codable)]
+                struct SomeStruct(u8);
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::SkipTest
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeStruct: Codable {
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        self.init(_0: try container.decode(UInt8.self))
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        try container.encode(self._0)
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn codable_newtype_struct() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a generic struct conditionally conforms to `Codable`.
+mod codable_generic_struct {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", Codable, instantiate(T = u8), instantiate(T = u16))]
+                struct SomeStruct<T> {
+                    field: T
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::SkipTest
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeStruct: Codable where T: Codable {
+    enum CodingKeys: String, CodingKey {
+        case field
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn codable_generic_struct() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a fieldless enum is encoded as the names of its variants, following the variants'
+/// `#[serde(rename = "...")]` attributes.
+mod codable_fieldless_enum {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(Codable)]
+                #[derive(serde::Serialize, serde::Deserialize)]
+                enum SomeEnum {
+                    #[serde(rename = "first")]
+                    Variant1,
+                    Variant2
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[derive(Copy, Clone, serde::Serialize, serde::Deserialize)]
+            pub enum SomeEnum {
+                #[serde(rename = "first")]
+                Variant1,
+                Variant2
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeEnum: Codable {
+    enum CodingKeys: String, CodingKey {
+        case Variant1 = "first"
+        case Variant2
+    }
+
+    public init(from decoder: Decoder) throws {
+        if let tag = try? decoder.singleValueContainer().decode(String.self) {
+            switch CodingKeys(rawValue: tag) {
+            case .Variant1:
+                self = .Variant1
+            case .Variant2:
+                self = .Variant2
+            default:
+                throw DecodingError.dataCorrupted(DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Unknown variant \(tag)"))
+            }
+            return
+        }
+
+        throw DecodingError.dataCorrupted(DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Expected a variant name"))
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        switch self {
+        case .Variant1:
+            var container = encoder.singleValueContainer()
+            try container.encode(CodingKeys.Variant1.rawValue)
+        case .Variant2:
+            var container = encoder.singleValueContainer()
+            try container.encode(CodingKeys.Variant2.rawValue)
+        }
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn codable_fieldless_enum() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that the variants of an enum with data are encoded using serde's externally tagged
+/// representation.
+mod codable_enum_with_data {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(Codable)]
+                #[derive(serde::Serialize)]
+                enum SomeEnum {
+                    Newtype(u32),
+                    Tuple(u8, String),
+                    Struct {
+                        #[serde(rename = "renamedField")]
+                        field: i32
+                    }
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[derive(serde::Serialize)]
+            pub enum SomeEnum {
+                Newtype(u32),
+                Tuple(u8, String),
+                Struct {
+                    #[serde(rename = "renamedField")]
+                    field: i32
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeEnum: Codable {
+    enum CodingKeys: String, CodingKey {
+        case Newtype
+        case Tuple
+        case Struct
+    }
+
+    enum StructCodingKeys: String, CodingKey {
+        case field = "renamedField"
+    }
+
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        guard container.allKeys.count == 1 else {
+            throw DecodingError.dataCorrupted(DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Expected exactly one variant"))
+        }
+        switch container.allKeys[0] {
+        case .Newtype:
+            self = .Newtype(try container.decode(UInt32.self, forKey: .Newtype))
+        case .Tuple:
+            var values = try container.nestedUnkeyedContainer(forKey: .Tuple)
+            self = .Tuple(try values.decode(UInt8.self), try values.decode(RustString.self))
+        case .Struct:
+            let values = try container.nestedContainer(keyedBy: StructCodingKeys.self, forKey: .Struct)
+            self = .Struct(field: try values.decode(Int32.self, forKey: .field))
+        }
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        switch self {
+        case .Newtype(let _0):
+            var container = encoder.container(keyedBy: CodingKeys.self)
+            try container.encode(_0, forKey: .Newtype)
+        case .Tuple(let _0, let _1):
+            var container = encoder.container(keyedBy: CodingKeys.self)
+            var values = container.nestedUnkeyedContainer(forKey: .Tuple)
+            try values.encode(_0)
+            try values.encode(_1)
+        case .Struct(let field):
+            var container = encoder.container(keyedBy: CodingKeys.self)
+            var values = container.nestedContainer(keyedBy: StructCodingKeys.self, forKey: .Struct)
+            try values.encode(field, forKey: .field)
+        }
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn codable_enum_with_data() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a generic enum conditionally conforms to `Codable`.
+mod codable_generic_enum {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(Codable, instantiate(T = u8))]
+                enum SomeEnum<T> {
+                    Variant(T)
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::SkipTest
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            "extension SomeEnum: Codable where T: Codable {",
+            "self = .Variant(try container.decode(T.self, forKey: .Variant))",
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn codable_generic_enum() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
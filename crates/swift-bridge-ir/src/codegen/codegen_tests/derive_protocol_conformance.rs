@@ -0,0 +1,368 @@
+//! See also: crates/swift-integration-tests/src/enum_attributes/derive.rs
+//! See also: crates/swift-integration-tests/src/struct_attributes/derive.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that the standard derives on a fieldless enum get added to the Rust enum, and that
+/// the Swift enum conforms to the corresponding Swift protocols.
+mod derive_protocol_conformance_enum {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+                enum SomeEnum {
+                    Variant1,
+                    Variant2
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[derive(
+                Copy,
+                Clone,
+                ::std::cmp::PartialEq,
+                ::std::cmp::Eq,
+                ::std::hash::Hash,
+                ::std::cmp::PartialOrd,
+                ::std::cmp::Ord
+            )]
+            pub enum SomeEnum {
+                Variant1,
+                Variant2
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeEnum: Equatable {}
+extension SomeEnum: Hashable {}
+extension SomeEnum: Comparable {}
+extension SomeEnum: @unchecked Sendable {}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn derive_protocol_conformance_enum() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can derive traits on an enum that has variants with data.
+/// We don't derive `Copy` or `Clone` for enums with data unless asked to.
+mod derive_protocol_conformance_enum_with_data {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[derive(Clone, PartialEq, Hash)]
+                enum SomeEnum {
+                    Variant1(String),
+                    Variant2 { field: u8 }
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[derive(Clone, ::std::cmp::PartialEq, ::std::hash::Hash)]
+            pub enum SomeEnum {
+                Variant1(String),
+                Variant2 { field: u8 }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeEnum: Equatable {}
+extension SomeEnum: Hashable {}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn derive_protocol_conformance_enum_with_data() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a generic enum conditionally conforms to the protocols that its derives map to.
+mod derive_protocol_conformance_generic_enum {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[derive(Copy, Clone, PartialEq)]
+                #[swift_bridge(instantiate(T = u8))]
+                enum SomeEnum<T> {
+                    Variant1(T)
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[derive(Copy, Clone, ::std::cmp::PartialEq)]
+            pub enum SomeEnum<T> {
+                Variant1(T)
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeEnum: Equatable where T: Equatable {}
+extension SomeEnum: @unchecked Sendable where T: Sendable {}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn derive_protocol_conformance_generic_enum() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that the standard derives on a struct get added to the Rust struct, and that the Swift
+/// struct conforms to the corresponding Swift protocols.
+/// A `Copy` struct's `debugDescription` uses its Rust `Debug` impl.
+mod derive_protocol_conformance_struct {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+                struct SomeStruct {
+                    field: u8
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[derive(
+                    Copy,
+                    Clone,
+                    ::std::cmp::PartialEq,
+                    ::std::cmp::Eq,
+                    ::std::hash::Hash,
+                    ::std::fmt::Debug
+                )]
+                pub struct SomeStruct {
+                    pub field: u8
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeStruct$Debug"]
+                pub extern "C" fn __swift_bridge__SomeStruct_Debug(this: __swift_bridge__SomeStruct) -> *mut swift_bridge::string::RustString {
+                    swift_bridge::string::RustString(format!("{:?}", this.into_rust_repr())).box_into_raw()
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeStruct: CustomDebugStringConvertible {
+    public var debugDescription: String {
+        RustString(ptr: __swift_bridge__$SomeStruct$Debug(self.intoFfiRepr())).toString()
+    }
+}
+extension SomeStruct: Equatable {}
+extension SomeStruct: Hashable {}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$SomeStruct$Debug(__swift_bridge__$SomeStruct this);
+"#,
+        )
+    }
+
+    #[test]
+    fn derive_protocol_conformance_struct() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a struct that isn't `Copy` gets a `debugDescription` that uses its Rust `Debug`
+/// impl, as long as its fields don't own Rust values.
+mod derive_debug_non_copy_struct {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                #[derive(Clone, Debug)]
+                struct SomeStruct {
+                    field: Option<u32>
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[derive(Clone, ::std::fmt::Debug)]
+                pub struct SomeStruct {
+                    pub field: Option<u32>
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeStruct$Debug"]
+                pub extern "C" fn __swift_bridge__SomeStruct_Debug(this: __swift_bridge__SomeStruct) -> *mut swift_bridge::string::RustString {
+                    swift_bridge::string::RustString(format!("{:?}", this.into_rust_repr())).box_into_raw()
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeStruct: CustomDebugStringConvertible {
+    public var debugDescription: String {
+        RustString(ptr: __swift_bridge__$SomeStruct$Debug(self.intoFfiRepr())).toString()
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$SomeStruct$Debug(__swift_bridge__$SomeStruct this);
+"#,
+        )
+    }
+
+    #[test]
+    fn derive_debug_non_copy_struct() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a generic struct conditionally conforms to the protocols that its derives map to.
+mod derive_protocol_conformance_generic_struct {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", instantiate(T = u8), instantiate(T = u16))]
+                #[derive(PartialEq, Hash)]
+                struct SomeStruct<T> {
+                    field: T
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[derive(::std::cmp::PartialEq, ::std::hash::Hash)]
+            pub struct SomeStruct<T> {
+                pub field: T
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeStruct: Equatable where T: Equatable {}
+extension SomeStruct: Hashable where T: Hashable {}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn derive_protocol_conformance_generic_struct() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,376 @@
+//! See also: crates/swift-integration-tests/src/enum_attributes/discriminant.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that the `#[repr(...)]` and explicit discriminants of a fieldless enum are preserved in
+/// the FFI representation, the C header and Swift.
+mod fieldless_enum_repr_and_discriminants {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[repr(u8)]
+                enum SomeEnum {
+                    Variant1 = 200,
+                    Variant2,
+                    Variant3 = 4,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[derive(Copy, Clone)]
+                #[repr(u8)]
+                pub enum SomeEnum {
+                    Variant1 = 200,
+                    Variant2,
+                    Variant3 = 4
+                }
+            },
+            quote! {
+                #[repr(u8)]
+                #[doc(hidden)]
+                pub enum __swift_bridge__SomeEnum {
+                    Variant1 = 200,
+                    Variant2,
+                    Variant3 = 4
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeEnum {
+    public var discriminant: UInt8 {
+        switch self {
+        case .Variant1:
+            return 200
+        case .Variant2:
+            return 201
+        case .Variant3:
+            return 4
+        }
+    }
+
+    public init?(discriminant: UInt8) {
+        switch discriminant {
+        case 200:
+            self = .Variant1
+        case 201:
+            self = .Variant2
+        case 4:
+            self = .Variant3
+        default:
+            return nil
+        }
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "#include <stdint.h>",
+            r#"
+typedef enum __swift_bridge__$SomeEnumTag : uint8_t { __swift_bridge__$SomeEnum$Variant1 = 200, __swift_bridge__$SomeEnum$Variant2, __swift_bridge__$SomeEnum$Variant3 = 4, } __swift_bridge__$SomeEnumTag;
+typedef struct __swift_bridge__$SomeEnum { __swift_bridge__$SomeEnumTag tag; } __swift_bridge__$SomeEnum;
+"#,
+        ])
+    }
+
+    #[test]
+    fn fieldless_enum_repr_and_discriminants() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that an enum with explicit discriminants but without a `#[repr(...)]` keeps a C `int`
+/// tag and that Swift uses `Int` for its discriminants.
+mod discriminants_without_repr {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                enum SomeEnum {
+                    Variant1 = -1,
+                    Variant2,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[repr(C)]
+            #[doc(hidden)]
+            pub enum __swift_bridge__SomeEnum {
+                Variant1 = -1,
+                Variant2
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            "public var discriminant: Int {",
+            "public init?(discriminant: Int) {",
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef enum __swift_bridge__$SomeEnumTag { __swift_bridge__$SomeEnum$Variant1 = -1, __swift_bridge__$SomeEnum$Variant2, } __swift_bridge__$SomeEnumTag;
+"#,
+        )
+    }
+
+    #[test]
+    fn discriminants_without_repr() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that an enum with data keeps its `#[repr(...)]` and discriminants, and that Swift can
+/// read, but not build from, the discriminants.
+mod enum_with_data_repr_and_discriminants {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[repr(i32)]
+                enum SomeEnum {
+                    Variant1(u8) = 5,
+                    Variant2,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[repr(C, i32)]
+            #[doc(hidden)]
+            pub enum __swift_bridge__SomeEnum {
+                Variant1(u8) = 5,
+                Variant2
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeEnum {
+    public var discriminant: Int32 {
+        switch self {
+        case .Variant1:
+            return 5
+        case .Variant2:
+            return 6
+        }
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef enum __swift_bridge__$SomeEnumTag : int32_t { __swift_bridge__$SomeEnum$Variant1 = 5, __swift_bridge__$SomeEnum$Variant2, } __swift_bridge__$SomeEnumTag;
+"#,
+        )
+    }
+
+    #[test]
+    fn enum_with_data_repr_and_discriminants() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that an enum without a `#[repr(...)]` or explicit discriminants doesn't expose its
+/// discriminants to Swift.
+mod no_discriminants {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                enum SomeEnum {
+                    Variant1,
+                    Variant2,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::SkipTest
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::DoesNotContainAfterTrim("discriminant")
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::DoesNotContainAfterTrim("stdint.h")
+    }
+
+    #[test]
+    fn no_discriminants() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that an enum with explicit discriminants is `Comparable` by its discriminants, like
+/// Rust's derived `PartialOrd`, instead of by the order that its cases are declared in.
+mod comparable_by_discriminants {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[derive(PartialEq, PartialOrd)]
+                enum Priority {
+                    High = 3,
+                    Low = 1,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::SkipTest
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension Priority: Comparable {
+    public static func < (lhs: Priority, rhs: Priority) -> Bool {
+        lhs.discriminant < rhs.discriminant
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn comparable_by_discriminants() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that two values of the same variant of an enum with explicit discriminants are
+/// compared by their fields, in order.
+mod comparable_by_discriminants_with_data {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[repr(u8)]
+                #[derive(PartialEq, PartialOrd)]
+                enum SomeEnum {
+                    Variant1(u8, u16) = 2,
+                    Variant2 { field: u32 } = 1,
+                    Variant3 = 0,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::SkipTest
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeEnum: Comparable {
+    public static func < (lhs: SomeEnum, rhs: SomeEnum) -> Bool {
+        if lhs.discriminant != rhs.discriminant {
+            return lhs.discriminant < rhs.discriminant
+        }
+        switch (lhs, rhs) {
+        case (.Variant1(let l0, let l1), .Variant1(let r0, let r1)):
+            if l0 != r0 { return l0 < r0 }
+            return l1 < r1
+        case (.Variant2(let l0), .Variant2(let r0)):
+            return l0 < r0
+        default:
+            return false
+        }
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn comparable_by_discriminants_with_data() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,327 @@
+//! See also: crates/swift-integration-tests/src/fixed_size_array.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we can use a `[u8; N]` as a Rust function arg and return type.
+mod extern_rust_fixed_size_array_of_primitives {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: [u8; 32]) -> [u8; 32];
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__Array_U8_32(#[allow(unused)] [u8; 32]);
+            },
+            quote! {
+                pub extern "C" fn __swift_bridge__some_function(arg: __swift_bridge__Array_U8_32) -> __swift_bridge__Array_U8_32 {
+                    __swift_bridge__Array_U8_32(super::some_function(arg.0))
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: [UInt8]) -> [UInt8] {
+    __private__intoSwiftFixedSizeArray(__swift_bridge__$some_function(__private__intoFfiFixedSizeArray(__swift_bridge__$Array$U8$32(), arg, 32, { $0 })), 32, { (val: UInt8) -> UInt8 in val })
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+#include <stdint.h>
+"#,
+            r#"
+typedef struct __swift_bridge__$Array$U8$32 { uint8_t values[32]; } __swift_bridge__$Array$U8$32;
+"#,
+            r#"
+struct __swift_bridge__$Array$U8$32 __swift_bridge__$some_function(struct __swift_bridge__$Array$U8$32 arg);
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fixed_size_array_of_primitives() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can use a nested fixed-size array such as a `[[f32; 4]; 4]` matrix.
+mod extern_rust_nested_fixed_size_array {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: [[f32; 4]; 4]);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub struct __swift_bridge__Array_ArrayF32_4_4(#[allow(unused)] [__swift_bridge__Array_F32_4; 4]);
+            },
+            quote! {
+                pub extern "C" fn __swift_bridge__some_function(arg: __swift_bridge__Array_ArrayF32_4_4) {
+                    super::some_function(arg.0.map(|val| val.0))
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: [[Float]]) {
+    __swift_bridge__$some_function(__private__intoFfiFixedSizeArray(__swift_bridge__$Array$ArrayF32_4$4(), arg, 4, { (val) -> __swift_bridge__$Array$F32$4 in __private__intoFfiFixedSizeArray(__swift_bridge__$Array$F32$4(), val, 4, { $0 }) }))
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+typedef struct __swift_bridge__$Array$F32$4 { float values[4]; } __swift_bridge__$Array$F32$4;
+typedef struct __swift_bridge__$Array$ArrayF32_4$4 { struct __swift_bridge__$Array$F32$4 values[4]; } __swift_bridge__$Array$ArrayF32_4$4;
+"#,
+            r#"
+void __swift_bridge__$some_function(struct __swift_bridge__$Array$ArrayF32_4$4 arg);
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_nested_fixed_size_array() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can use an `Option<[u8; N]>` as a Rust function arg and return type.
+mod extern_rust_option_fixed_size_array {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Option<[u8; 4]>) -> Option<[u8; 4]>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub struct __swift_bridge__Option_ArrayU8_4 {
+                #[allow(unused)]
+                is_some: bool,
+                #[allow(unused)]
+                val: std::mem::MaybeUninit<__swift_bridge__Array_U8_4>,
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: Optional<[UInt8]>) -> Optional<[UInt8]> {
+    { let val = __swift_bridge__$some_function({ var option = __swift_bridge__$Option$ArrayU8_4(); if let val = arg { option.is_some = true; option.val = __private__intoFfiFixedSizeArray(__swift_bridge__$Array$U8$4(), val, 4, { $0 }) }; return option }()); if val.is_some { return __private__intoSwiftFixedSizeArray(val.val, 4, { (val: UInt8) -> UInt8 in val }) } else { return nil } }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$Option$ArrayU8_4 { bool is_some; struct __swift_bridge__$Array$U8$4 val; } __swift_bridge__$Option$ArrayU8_4;
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_option_fixed_size_array() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can use a fixed-size array as a shared struct field, and that an array of
+/// shared structs converts each of its elements.
+mod shared_struct_fixed_size_array_field {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct Color {
+                    rgba: [u8; 4],
+                }
+
+                extern "Rust" {
+                    fn some_function(arg: [Color; 2]);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub struct __swift_bridge__Color {
+                    rgba: __swift_bridge__Array_U8_4
+                }
+            },
+            quote! {
+                pub extern "C" fn __swift_bridge__some_function(arg: __swift_bridge__Array_Color_2) {
+                    super::some_function(arg.0.map(|val| val.into_rust_repr()))
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public struct Color {
+    public var rgba: [UInt8]
+"#,
+            r#"
+return __swift_bridge__$Color(rgba: __private__intoFfiFixedSizeArray(__swift_bridge__$Array$U8$4(), val.rgba, 4, { $0 }));
+"#,
+            r#"
+return Color(rgba: __private__intoSwiftFixedSizeArray(val.rgba, 4, { (val: UInt8) -> UInt8 in val }));
+"#,
+            r#"
+__swift_bridge__$some_function(__private__intoFfiFixedSizeArray(__swift_bridge__$Array$Color$2(), arg, 2, { (val) -> __swift_bridge__$Color in val.intoFfiRepr() }))
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+typedef struct __swift_bridge__$Array$U8$4 { uint8_t values[4]; } __swift_bridge__$Array$U8$4;
+typedef struct __swift_bridge__$Color { struct __swift_bridge__$Array$U8$4 rgba; } __swift_bridge__$Color;
+"#,
+            r#"
+typedef struct __swift_bridge__$Array$Color$2 { struct __swift_bridge__$Color values[2]; } __swift_bridge__$Array$Color$2;
+"#,
+        ])
+    }
+
+    #[test]
+    fn shared_struct_fixed_size_array_field() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can use a `[u8; N]` as a Swift function arg and return type.
+mod extern_swift_fixed_size_array {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(arg: [u8; 32]) -> [u8; 32];
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: [u8; 32]) -> [u8; 32] {
+                    unsafe { __swift_bridge__some_function(__swift_bridge__Array_U8_32(arg)) }.0
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(arg: __swift_bridge__Array_U8_32) -> __swift_bridge__Array_U8_32;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: __swift_bridge__$Array$U8$32) -> __swift_bridge__$Array$U8$32 {
+    __private__intoFfiFixedSizeArray(__swift_bridge__$Array$U8$32(), some_function(arg: __private__intoSwiftFixedSizeArray(arg, 32, { (val: UInt8) -> UInt8 in val })), 32, { $0 })
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$Array$U8$32 { uint8_t values[32]; } __swift_bridge__$Array$U8$32;
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fixed_size_array() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,155 @@
+//! See also: crates/swift-integration-tests/src/function_attributes/instantiate.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we generate a function for each of a generic function's instantiations, and that
+/// Swift sees the instantiations as overloads of the same function.
+mod generic_function_instantiations {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(instantiate(T = u32), instantiate(T = String))]
+                    fn parse<T>(s: &str) -> T;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$parse$u32"]
+                pub extern "C" fn __swift_bridge__parse__u32(
+                    s: swift_bridge::string::RustStr
+                ) -> u32 {
+                    super::parse::<u32>(s.to_str())
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$parse$String"]
+                pub extern "C" fn __swift_bridge__parse__String(
+                    s: swift_bridge::string::RustStr
+                ) -> *mut swift_bridge::string::RustString {
+                    swift_bridge::string::RustString(super::parse::<String>(s.to_str())).box_into_raw()
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func parse<GenericToRustStr: ToRustStr>(_ s: GenericToRustStr) -> UInt32 {
+    return s.toRustStr({ sAsRustStr in
+        __swift_bridge__$parse$u32(sAsRustStr)
+    })
+}
+public func parse<GenericToRustStr: ToRustStr>(_ s: GenericToRustStr) -> RustString {
+    return s.toRustStr({ sAsRustStr in
+        RustString(ptr: __swift_bridge__$parse$String(sAsRustStr))
+    })
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"uint32_t __swift_bridge__$parse$u32(struct RustStr s);"#,
+            r#"void* __swift_bridge__$parse$String(struct RustStr s);"#,
+        ])
+    }
+
+    #[test]
+    fn generic_function_instantiations() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate a method for each of a generic method's instantiations, and that we
+/// substitute the concrete types inside of other types such as `Vec<T>` and `Option<T>`.
+mod generic_method_instantiations {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(instantiate(T = u8), instantiate(T = i64))]
+                    fn first<T>(&self, items: Vec<T>) -> Option<T>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$first$u8"]
+                pub extern "C" fn __swift_bridge__SomeType_first__u8(
+                    this: *mut super::SomeType,
+                    items: *mut Vec<u8>
+                ) -> swift_bridge::option::OptionU8 {
+                    if let Some(val) = (unsafe { &*this }).first::<u8>(unsafe { *Box::from_raw(items) }) {
+                        swift_bridge::option::OptionU8 { val, is_some: true }
+                    } else {
+                        swift_bridge::option::OptionU8 { val: 123, is_some: false }
+                    }
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$first$i64"]
+                pub extern "C" fn __swift_bridge__SomeType_first__i64(
+                    this: *mut super::SomeType,
+                    items: *mut Vec<i64>
+                ) -> swift_bridge::option::OptionI64
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+    public func first(_ items: RustVec<UInt8>) -> Optional<UInt8> {
+        __swift_bridge__$SomeType$first$u8(ptr, { let val = items; val.isOwned = false; return val.ptr }()).intoSwiftRepr()
+    }
+"#,
+            r#"
+    public func first(_ items: RustVec<Int64>) -> Optional<Int64> {
+        __swift_bridge__$SomeType$first$i64(ptr, { let val = items; val.isOwned = false; return val.ptr }()).intoSwiftRepr()
+    }
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"struct __private__OptionU8 __swift_bridge__$SomeType$first$u8(void* self, void* items);"#,
+            r#"struct __private__OptionI64 __swift_bridge__$SomeType$first$i64(void* self, void* items);"#,
+        ])
+    }
+
+    #[test]
+    fn generic_method_instantiations() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
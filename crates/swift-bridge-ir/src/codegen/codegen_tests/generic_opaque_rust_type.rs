@@ -513,3 +513,165 @@ mod generic_opaque_rust_type_inner_opaque_ty {
         .test();
     }
 }
+
+/// Verify that we can declare a generic type with more than 8 generic type parameters.
+mod declare_generic_many_params {
+    use super::*;
+
+    fn bridge_module() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(declare_generic)]
+                    type SomeType<A, B, C, D, E, F, G, H, I, J>;
+
+                    type SomeType<u8, u16, u32, u64, i8, i16, i32, i64, f32, f64>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$u8$u16$u32$u64$i8$i16$i32$i64$f32$f64$_free"]
+            pub extern "C" fn __swift_bridge__SomeType_u8_u16_u32_u64_i8_i16_i32_i64_f32_f64__free (
+                this: *mut super::SomeType<u8, u16, u32, u64, i8, i16, i32, i64, f32, f64>
+            ) {
+                let this = unsafe { Box::from_raw(this) };
+                drop(this);
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public class SomeType<A, B, C, D, E, F, G, H, I, J>: SomeTypeRefMut<A, B, C, D, E, F, G, H, I, J> {
+"#,
+            r#"
+extension SomeType: SwiftBridgeGenericFreer
+where A == UInt8, B == UInt16, C == UInt32, D == UInt64, E == Int8, F == Int16, G == Int32, H == Int64, I == Float, J == Double {
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"void __swift_bridge__$SomeType$u8$u16$u32$u64$i8$i16$i32$i64$f32$f64$_free(void* self);"#,
+        )
+    }
+
+    #[test]
+    fn declare_generic_many_params() {
+        CodegenTest {
+            bridge_module: bridge_module().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a generic opaque Rust type's concrete types can be non-primitive types such as
+/// `String`, `Vec<T>`, `Option<T>`, shared structs and other opaque Rust types.
+mod generic_opaque_rust_type_non_primitive_generics {
+    use super::*;
+
+    fn bridge_module() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SharedStruct {
+                    field: u8
+                }
+
+                extern "Rust" {
+                    type User;
+
+                    #[swift_bridge(declare_generic)]
+                    type Cache<A, B>;
+
+                    type Cache<String, User>;
+
+                    #[swift_bridge(instantiate(A = Vec<u8>, B = Option<SharedStruct>))]
+                    type Cache<A, B>;
+
+                    fn some_function(arg: &Cache<Vec<u8>, Option<SharedStruct>>) -> Cache<String, User>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$Cache$String$User$_free"]
+                pub extern "C" fn __swift_bridge__Cache_String_User__free (
+                    this: *mut super::Cache<String, super::User>
+                ) {
+                    let this = unsafe { Box::from_raw(this) };
+                    drop(this);
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$Cache$Vec_u8$Option_SharedStruct$_free"]
+                pub extern "C" fn __swift_bridge__Cache_Vec_u8_Option_SharedStruct__free (
+                    this: *mut super::Cache<Vec<u8>, Option<SharedStruct> >
+                ) {
+                    let this = unsafe { Box::from_raw(this) };
+                    drop(this);
+                }
+            },
+            quote! {
+                pub extern "C" fn __swift_bridge__some_function(
+                    arg: *const super::Cache<Vec<u8>, Option<SharedStruct> >
+                ) -> *mut super::Cache<String, super::User>
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+extension Cache: SwiftBridgeGenericFreer
+where A == RustString, B == User {
+    public func rust_free() {
+        __swift_bridge__$Cache$String$User$_free(ptr)
+    }
+}
+"#,
+            r#"
+extension Cache: SwiftBridgeGenericFreer
+where A == RustVec<UInt8>, B == Optional<SharedStruct> {
+    public func rust_free() {
+        __swift_bridge__$Cache$Vec_u8$Option_SharedStruct$_free(ptr)
+    }
+}
+"#,
+            r#"
+public func some_function(_ arg: CacheRef<RustVec<UInt8>, Optional<SharedStruct>>) -> Cache<RustString, User> {
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"void __swift_bridge__$Cache$String$User$_free(void* self);"#,
+            r#"void __swift_bridge__$Cache$Vec_u8$Option_SharedStruct$_free(void* self);"#,
+        ])
+    }
+
+    #[test]
+    fn generic_opaque_rust_type_non_primitive_generics() {
+        CodegenTest {
+            bridge_module: bridge_module().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
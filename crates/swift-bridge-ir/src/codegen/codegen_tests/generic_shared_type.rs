@@ -0,0 +1,236 @@
+//! See also: crates/swift-integration-tests/src/shared_types/generic_shared_type.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we declare a generic shared struct once, and generate an FFI representation for
+/// each of its instantiations.
+mod generic_shared_struct {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", instantiate(T = u32), instantiate(T = String))]
+                struct Page<T> {
+                    items: Vec<T>,
+                    next: Option<String>,
+                }
+
+                extern "Rust" {
+                    fn some_function(arg: Page<String>) -> Page<u32>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub struct Page<T> {
+                    pub items: Vec<T>,
+                    pub next: Option<String>
+                }
+            },
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__Page_u32 {
+                    items: *mut Vec<u32>,
+                    next: *mut swift_bridge::string::RustString
+                }
+            },
+            quote! {
+                impl swift_bridge::SharedStruct for Page<u32> {
+                    type FfiRepr = __swift_bridge__Page_u32;
+                }
+            },
+            quote! {
+                impl __swift_bridge__Page_String {
+                    #[doc(hidden)]
+                    #[inline(always)]
+                    pub fn into_rust_repr(self) -> Page<String> {
+                        {
+                            let val = self;
+                            Page {
+                                items: unsafe { *Box::from_raw(val.items) },
+                                next: {
+                                    let val = val.next;
+                                    if val.is_null() {
+                                        None
+                                    } else {
+                                        Some(unsafe { Box::from_raw(val).0 })
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$some_function"]
+                pub extern "C" fn __swift_bridge__some_function(
+                    arg: __swift_bridge__Page_String
+                ) -> __swift_bridge__Page_u32 {
+                    super::some_function(arg.into_rust_repr()).into_ffi_repr()
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func some_function(_ arg: Page<RustString>) -> Page<UInt32> {
+    __swift_bridge__$some_function(arg.intoFfiRepr()).intoSwiftRepr()
+}
+"#,
+            r#"
+public struct Page<T: Vectorizable> {
+    public var items: RustVec<T>
+    public var next: Optional<RustString>
+
+    public init(items: RustVec<T>,next: Optional<RustString>) {
+        self.items = items
+        self.next = next
+    }
+}
+extension Page where T == UInt32 {
+    @inline(__always)
+    func intoFfiRepr() -> __swift_bridge__$Page$u32 {
+"#,
+            r#"
+extension __swift_bridge__$Page$u32 {
+    @inline(__always)
+    func intoSwiftRepr() -> Page<UInt32> {
+        { let val = self; return Page<UInt32>(items: RustVec(ptr: val.items), next: { let val = val.next; if val != nil { return RustString(ptr: val!) } else { return nil } }()); }()
+    }
+}
+"#,
+            r#"
+extension Page where T == RustString {
+    @inline(__always)
+    func intoFfiRepr() -> __swift_bridge__$Page$String {
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"typedef struct __swift_bridge__$Page$u32 { void* items; void* next; } __swift_bridge__$Page$u32;"#,
+            r#"typedef struct __swift_bridge__$Page$String { void* items; void* next; } __swift_bridge__$Page$String;"#,
+            r#"struct __swift_bridge__$Page$u32 __swift_bridge__$some_function(struct __swift_bridge__$Page$String arg);"#,
+        ])
+    }
+
+    #[test]
+    fn generic_shared_struct() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we declare a generic shared enum once, and generate an FFI representation for
+/// each of its instantiations.
+mod generic_shared_enum {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(instantiate(T = u8))]
+                enum Event<T> {
+                    Data(T),
+                    Named { value: Option<T> },
+                    Closed,
+                }
+
+                extern "Rust" {
+                    fn some_function(arg: Event<u8>) -> Event<u8>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub enum Event<T> {
+                    Data(T),
+                    Named { value: Option<T> },
+                    Closed
+                }
+            },
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub enum __swift_bridge__Event_u8 {
+                    Data(u8),
+                    Named { value: swift_bridge::option::OptionU8 },
+                    Closed
+                }
+            },
+            quote! {
+                impl swift_bridge::SharedEnum for Event<u8> {
+                    type FfiRepr = __swift_bridge__Event_u8;
+                }
+            },
+            quote! {
+                pub extern "C" fn __swift_bridge__some_function(
+                    arg: __swift_bridge__Event_u8
+                ) -> __swift_bridge__Event_u8 {
+                    super::some_function(arg.into_rust_repr()).into_ffi_repr()
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public enum Event<T> {
+    case Data(T)
+    case Named(value: Optional<T>)
+    case Closed
+}
+extension Event where T == UInt8 {
+    func intoFfiRepr() -> __swift_bridge__$Event$u8 {
+        switch self {
+            case Event<UInt8>.Data(let _0):
+                return __swift_bridge__$Event$u8(tag: __swift_bridge__$Event$u8$Data, payload: __swift_bridge__$Event$u8Fields(Data: __swift_bridge__$Event$u8$FieldOfData(_0: _0)))
+"#,
+            r#"
+extension __swift_bridge__$Event$u8 {
+    func intoSwiftRepr() -> Event<UInt8> {
+        switch self.tag {
+            case __swift_bridge__$Event$u8$Data:
+                return Event<UInt8>.Data(self.payload.Data._0)
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"typedef struct __swift_bridge__$Event$u8$FieldOfData {uint8_t _0;} __swift_bridge__$Event$u8$FieldOfData;"#,
+            r#"typedef enum __swift_bridge__$Event$u8Tag { __swift_bridge__$Event$u8$Data, __swift_bridge__$Event$u8$Named, __swift_bridge__$Event$u8$Closed, } __swift_bridge__$Event$u8Tag;"#,
+            r#"typedef struct __swift_bridge__$Event$u8 { __swift_bridge__$Event$u8Tag tag; union __swift_bridge__$Event$u8Fields payload;} __swift_bridge__$Event$u8;"#,
+        ])
+    }
+
+    #[test]
+    fn generic_shared_enum() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
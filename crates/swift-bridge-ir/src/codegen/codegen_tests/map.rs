@@ -0,0 +1,317 @@
+//! See also: crates/swift-integration-tests/src/map.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Test code generation for Rust function that returns a HashMap<K, V> where the keys are
+/// Strings.
+mod extern_rust_fn_return_hashmap_of_string_keys {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function() -> HashMap<String, u64>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__some_function(
+            ) -> swift_bridge::map::FfiMap<swift_bridge::string::RustString, u64> {
+                swift_bridge::map::FfiMap::from_entries(
+                    super::some_function()
+                        .into_iter()
+                        .map(|(key, value)| (swift_bridge::string::RustString(key), value))
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function() -> RustMap<RustString, UInt64> {
+    RustMap(ffiRepr: __swift_bridge__$some_function())
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__FfiMap __swift_bridge__$some_function(void);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_return_hashmap_of_string_keys() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that takes a BTreeMap<K, V> argument where the values
+/// are an opaque Rust type.
+mod extern_rust_fn_arg_btreemap_of_opaque_rust_type_values {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type MyRustType;
+                    fn some_function(arg: BTreeMap<u8, MyRustType>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::map::FfiMap<u8, super::MyRustType>
+            ) {
+                super::some_function(
+                    arg.into_entries().collect::<std::collections::BTreeMap<u8, super::MyRustType> >()
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: RustMap<UInt8, MyRustType>) {
+    __swift_bridge__$some_function(arg.intoFfiRepr())
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$some_function(struct __private__FfiMap arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_arg_btreemap_of_opaque_rust_type_values() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that takes and returns an Option<HashMap<K, V>>.
+mod extern_rust_fn_option_hashmap {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Option<HashMap<u8, u16>>) -> Option<HashMap<u8, u16>>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: __swift_bridge__Option_HashMapU8AndU16
+            ) -> __swift_bridge__Option_HashMapU8AndU16 {
+                if let Some(val) = super::some_function(
+                    {
+                        let val = arg;
+                        if val.is_some {
+                            let val = unsafe { val.val.assume_init() };
+                            Some(val.into_entries().collect::<std::collections::HashMap<u8, u16> >())
+                        } else {
+                            None
+                        }
+                    }
+                ) {
+                    __swift_bridge__Option_HashMapU8AndU16 {
+                        is_some: true,
+                        val: std::mem::MaybeUninit::new(swift_bridge::map::FfiMap::from_entries(val))
+                    }
+                } else {
+                    __swift_bridge__Option_HashMapU8AndU16 {
+                        is_some: false,
+                        val: std::mem::MaybeUninit::uninit()
+                    }
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: Optional<RustMap<UInt8, UInt16>>) -> Optional<RustMap<UInt8, UInt16>> {
+    { let val = __swift_bridge__$some_function({ var option = __swift_bridge__$Option$HashMapU8AndU16(); if let val = arg { option.is_some = true; option.val = val.intoFfiRepr() }; return option }()); if val.is_some { return RustMap(ffiRepr: val.val) } else { return nil } }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+struct __swift_bridge__$Option$HashMapU8AndU16 __swift_bridge__$some_function(struct __swift_bridge__$Option$HashMapU8AndU16 arg);
+"#,
+            r#"
+typedef struct __swift_bridge__$Option$HashMapU8AndU16 { bool is_some; struct __private__FfiMap val; } __swift_bridge__$Option$HashMapU8AndU16;
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_option_hashmap() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can use a fully qualified `std::collections::HashMap` path.
+mod extern_rust_fn_fully_qualified_hashmap {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: std::collections::HashMap<u32, String>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::map::FfiMap<u32, swift_bridge::string::RustString>
+            ) {
+                super::some_function(
+                    arg.into_entries()
+                        .map(|(key, value)| (key, value.0))
+                        .collect::<std::collections::HashMap<u32, String> >()
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: RustMap<UInt32, RustString>) {
+    __swift_bridge__$some_function(arg.intoFfiRepr())
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$some_function(struct __private__FfiMap arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_fully_qualified_hashmap() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Swift functions that take and return a HashMap<K, V>.
+mod extern_swift_fn_hashmap {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(arg: HashMap<u8, u16>) -> HashMap<u8, u16>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(
+                    arg: std::collections::HashMap<u8, u16>
+                ) -> std::collections::HashMap<u8, u16> {
+                    unsafe {
+                        __swift_bridge__some_function(
+                            swift_bridge::map::FfiMap::from_entries(arg)
+                        )
+                    }
+                    .into_entries()
+                    .collect::<std::collections::HashMap<u8, u16> >()
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(
+                    arg: swift_bridge::map::FfiMap<u8, u16>
+                ) -> swift_bridge::map::FfiMap<u8, u16>;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: __private__FfiMap) -> __private__FfiMap {
+    some_function(arg: RustMap(ffiRepr: arg)).intoFfiRepr()
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_hashmap() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
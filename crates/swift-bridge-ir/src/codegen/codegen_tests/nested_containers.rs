@@ -0,0 +1,972 @@
+//! See also: crates/swift-integration-tests/src/nested_containers.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Test code generation for Rust function that takes and returns an `Option<Option<u8>>`.
+mod extern_rust_fn_option_of_option {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Option<Option<u8>>) -> Option<Option<u8>>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__Option_OptionU8 {
+                    #[allow(unused)]
+                    is_some: bool,
+                    #[allow(unused)]
+                    val: std::mem::MaybeUninit<swift_bridge::option::OptionU8>,
+                }
+            },
+            quote! {
+                pub extern "C" fn __swift_bridge__some_function(
+                    arg: __swift_bridge__Option_OptionU8
+                ) -> __swift_bridge__Option_OptionU8 {
+                    if let Some(val) = super::some_function({
+                        let val = arg;
+                        if val.is_some {
+                            let val = unsafe { val.val.assume_init() };
+                            Some({
+                                let val = val;
+                                if val.is_some { Some(val.val) } else { None }
+                            })
+                        } else {
+                            None
+                        }
+                    }) {
+                        __swift_bridge__Option_OptionU8 {
+                            is_some: true,
+                            val: std::mem::MaybeUninit::new(if let Some(val) = val {
+                                swift_bridge::option::OptionU8 { val, is_some: true }
+                            } else {
+                                swift_bridge::option::OptionU8 { val: 123, is_some: false }
+                            })
+                        }
+                    } else {
+                        __swift_bridge__Option_OptionU8 {
+                            is_some: false,
+                            val: std::mem::MaybeUninit::uninit()
+                        }
+                    }
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: Optional<Optional<UInt8>>) -> Optional<Optional<UInt8>> {
+    { let val = __swift_bridge__$some_function({ var option = __swift_bridge__$Option$OptionU8(); if let val = arg { option.is_some = true; option.val = val.intoFfiRepr() }; return option }()); if val.is_some { return val.val.intoSwiftRepr() } else { return nil } }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+struct __swift_bridge__$Option$OptionU8 __swift_bridge__$some_function(struct __swift_bridge__$Option$OptionU8 arg);
+"#,
+            r#"
+typedef struct __swift_bridge__$Option$OptionU8 { bool is_some; struct __private__OptionU8 val; } __swift_bridge__$Option$OptionU8;
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_option_of_option() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that takes and returns a `Vec<Option<String>>`.
+/// The vector gets converted to and from a Swift `Array`.
+mod extern_rust_fn_vec_of_option_string {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Vec<Option<String>>) -> Vec<Option<String>>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::array::FfiArray
+            ) -> swift_bridge::array::FfiArray {
+                swift_bridge::array::FfiArray::from_vec(
+                    super::some_function(
+                        unsafe { arg.into_vec::<*mut swift_bridge::string::RustString>() }
+                            .into_iter()
+                            .map(|val| {
+                                let val = val;
+                                if val.is_null() {
+                                    None
+                                } else {
+                                    Some(unsafe { Box::from_raw(val).0 })
+                                }
+                            })
+                            .collect::<Vec<_>>()
+                    )
+                    .into_iter()
+                    .map(|val| if let Some(val) = val {
+                        swift_bridge::string::RustString(val).box_into_raw()
+                    } else {
+                        std::ptr::null::<swift_bridge::string::RustString>() as *mut swift_bridge::string::RustString
+                    })
+                    .collect::<Vec<_>>()
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: [Optional<RustString>]) -> [Optional<RustString>] {
+    __private__intoSwiftArray(__swift_bridge__$some_function(__private__intoFfiArray(arg, { (val) -> UnsafeMutableRawPointer? in { if let rustString = optionalStringIntoRustString(val) { rustString.isOwned = false; return rustString.ptr } else { return nil } }() })), { (val: UnsafeMutableRawPointer?) -> Optional<RustString> in { let val = val; if val != nil { return RustString(ptr: val!) } else { return nil } }() })
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__FfiArray __swift_bridge__$some_function(struct __private__FfiArray arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_vec_of_option_string() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that takes and returns a `Vec<Vec<u8>>`.
+mod extern_rust_fn_vec_of_vec {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Vec<Vec<u8>>) -> Vec<Vec<u8>>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::array::FfiArray
+            ) -> swift_bridge::array::FfiArray {
+                swift_bridge::array::FfiArray::from_vec(
+                    super::some_function(
+                        unsafe { arg.into_vec::<*mut Vec<u8> >() }
+                            .into_iter()
+                            .map(|val| unsafe { *Box::from_raw(val) })
+                            .collect::<Vec<_>>()
+                    )
+                    .into_iter()
+                    .map(|val| Box::into_raw(Box::new(val)))
+                    .collect::<Vec<_>>()
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: [RustVec<UInt8>]) -> [RustVec<UInt8>] {
+    __private__intoSwiftArray(__swift_bridge__$some_function(__private__intoFfiArray(arg, { (val) -> UnsafeMutableRawPointer in { let val = val; val.isOwned = false; return val.ptr }() })), { (val: UnsafeMutableRawPointer) -> RustVec<UInt8> in RustVec(ptr: val) })
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__FfiArray __swift_bridge__$some_function(struct __private__FfiArray arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_vec_of_vec() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that takes and returns an `Option<(u8, String)>`.
+mod extern_rust_fn_option_of_tuple {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Option<(u8, String)>) -> Option<(u8, String)>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub struct __swift_bridge__Option_TupleU8String {
+                    #[allow(unused)]
+                    is_some: bool,
+                    #[allow(unused)]
+                    val: std::mem::MaybeUninit<__swift_bridge__tuple_U8String>,
+                }
+            },
+            quote! {
+                pub struct __swift_bridge__tuple_U8String(u8, *mut swift_bridge::string::RustString);
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: Optional<(UInt8, RustString)>) -> Optional<(UInt8, RustString)> {
+    { let val = __swift_bridge__$some_function({ var option = __swift_bridge__$Option$TupleU8String(); if let val = arg { option.is_some = true; option.val = { let val = val; return __swift_bridge__$tuple$U8String(_0: val.0, _1: { let rustString = val.1.intoRustString(); rustString.isOwned = false; return rustString.ptr }()); }() }; return option }()); if val.is_some { return { let val = val.val; return (val._0, RustString(ptr: val._1)); }() } else { return nil } }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+typedef struct __swift_bridge__$tuple$U8String { uint8_t _0; void* _1; } __swift_bridge__$tuple$U8String;
+typedef struct __swift_bridge__$Option$TupleU8String { bool is_some; struct __swift_bridge__$tuple$U8String val; } __swift_bridge__$Option$TupleU8String;
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_option_of_tuple() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that takes and returns an
+/// `Option<Result<u8, String>>`.
+/// The inner `Result` is exposed to Swift as a `RustResult`.
+mod extern_rust_fn_option_of_result {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Option<Result<u8, String>>) -> Option<Result<u8, String>>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub struct __swift_bridge__Option_ResultU8AndString {
+                    #[allow(unused)]
+                    is_some: bool,
+                    #[allow(unused)]
+                    val: std::mem::MaybeUninit<ResultU8AndString>,
+                }
+            },
+            quote! {
+                pub extern "C" fn __swift_bridge__some_function(
+                    arg: __swift_bridge__Option_ResultU8AndString
+                ) -> __swift_bridge__Option_ResultU8AndString
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: Optional<RustResult<UInt8, RustString>>) -> Optional<RustResult<UInt8, RustString>> {
+    { let val = __swift_bridge__$some_function({ var option = __swift_bridge__$Option$ResultU8AndString(); if let val = arg { option.is_some = true; option.val = { switch val { case .Ok(let ok): return __swift_bridge__$ResultU8AndString(tag: __swift_bridge__$ResultU8AndString$ResultOk, payload: __swift_bridge__$ResultU8AndString$Fields(ok: ok)) case .Err(let err): return __swift_bridge__$ResultU8AndString(tag: __swift_bridge__$ResultU8AndString$ResultErr, payload: __swift_bridge__$ResultU8AndString$Fields(err: { let rustString = err.intoRustString(); rustString.isOwned = false; return rustString.ptr }())) } }() }; return option }()); if val.is_some { return { let val = val.val; switch val.tag { case __swift_bridge__$ResultU8AndString$ResultOk: return RustResult<UInt8, RustString>.Ok(val.payload.ok) case __swift_bridge__$ResultU8AndString$ResultErr: return RustResult<UInt8, RustString>.Err(RustString(ptr: val.payload.err)) default: fatalError() } }() } else { return nil } }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$Option$ResultU8AndString { bool is_some; struct __swift_bridge__$ResultU8AndString val; } __swift_bridge__$Option$ResultU8AndString;
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_option_of_result() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that returns a `Result<Option<u32>, String>`.
+mod extern_rust_fn_return_result_of_option {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function() -> Result<Option<u32>, String>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub enum ResultOptionU32AndString {
+                    #[allow(unused)]
+                    Ok(swift_bridge::option::OptionU32),
+                    #[allow(unused)]
+                    Err(*mut swift_bridge::string::RustString),
+                }
+            },
+            quote! {
+                pub extern "C" fn __swift_bridge__some_function() -> ResultOptionU32AndString {
+                    match super::some_function() {
+                        Ok(ok) => ResultOptionU32AndString::Ok(if let Some(val) = ok {
+                            swift_bridge::option::OptionU32 { val, is_some: true }
+                        } else {
+                            swift_bridge::option::OptionU32 { val: 123, is_some: false }
+                        }),
+                        Err(err) => ResultOptionU32AndString::Err(
+                            swift_bridge::string::RustString(err).box_into_raw()
+                        ),
+                    }
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function() throws -> Optional<UInt32> {
+    try { let val = __swift_bridge__$some_function(); switch val.tag { case __swift_bridge__$ResultOptionU32AndString$ResultOk: return val.payload.ok.intoSwiftRepr() case __swift_bridge__$ResultOptionU32AndString$ResultErr: throw RustString(ptr: val.payload.err) default: fatalError() } }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+union __swift_bridge__$ResultOptionU32AndString$Fields {struct __private__OptionU32 ok; void* err;};
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_return_result_of_option() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for a Swift function that takes and returns an `Option<Option<u8>>`.
+mod extern_swift_fn_option_of_option {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(arg: Option<Option<u8>>) -> Option<Option<u8>>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[link_name = "__swift_bridge__$some_function"]
+            fn __swift_bridge__some_function(
+                arg: __swift_bridge__Option_OptionU8
+            ) -> __swift_bridge__Option_OptionU8;
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: __swift_bridge__$Option$OptionU8) -> __swift_bridge__$Option$OptionU8 {
+    { var option = __swift_bridge__$Option$OptionU8(); if let val = some_function(arg: { let val = arg; if val.is_some { return val.val.intoSwiftRepr() } else { return nil } }()) { option.is_some = true; option.val = val.intoFfiRepr() }; return option }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$Option$OptionU8 { bool is_some; struct __private__OptionU8 val; } __swift_bridge__$Option$OptionU8;
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_option_of_option() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a shared struct's nested container fields are converted, and that the custom FFI
+/// types that the fields depend on are declared before the struct in the C header.
+mod shared_struct_with_nested_container_fields {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    field: Vec<Option<u8>>,
+                    other: Option<Option<u8>>,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub struct __swift_bridge__SomeStruct {
+                    field: swift_bridge::array::FfiArray,
+                    other: __swift_bridge__Option_OptionU8
+                }
+            },
+            quote! {
+                pub struct __swift_bridge__Option_OptionU8 {
+                    #[allow(unused)]
+                    is_some: bool,
+                    #[allow(unused)]
+                    val: std::mem::MaybeUninit<swift_bridge::option::OptionU8>,
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public struct SomeStruct {
+    public var field: [Optional<UInt8>]
+    public var other: Optional<Optional<UInt8>>
+"#,
+            r#"
+    func intoFfiRepr() -> __swift_bridge__$SomeStruct {
+        { let val = self; return __swift_bridge__$SomeStruct(field: __private__intoFfiArray(val.field, { (val) -> __private__OptionU8 in val.intoFfiRepr() }), other: { var option = __swift_bridge__$Option$OptionU8(); if let val = val.other { option.is_some = true; option.val = val.intoFfiRepr() }; return option }()); }()
+    }
+"#,
+            r#"
+    func intoSwiftRepr() -> SomeStruct {
+        { let val = self; return SomeStruct(field: __private__intoSwiftArray(val.field, { (val: __private__OptionU8) -> Optional<UInt8> in val.intoSwiftRepr() }), other: { let val = val.other; if val.is_some { return val.val.intoSwiftRepr() } else { return nil } }()); }()
+    }
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$Option$OptionU8 { bool is_some; struct __private__OptionU8 val; } __swift_bridge__$Option$OptionU8;
+typedef struct __swift_bridge__$SomeStruct { struct __private__FfiArray field; struct __swift_bridge__$Option$OptionU8 other; } __swift_bridge__$SomeStruct;
+"#,
+        )
+    }
+
+    #[test]
+    fn shared_struct_with_nested_container_fields() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that takes an `Option<*const u8>` and an
+/// `Option<&[u16]>` and returns an `Option<&[u8]>`.
+mod extern_rust_fn_option_of_pointer_and_slice {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(
+                        arg: Option<*const u8>,
+                        slice: Option<&[u16]>
+                    ) -> Option<&'static [u8]>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__Option_ConstPtrU8 {
+                    #[allow(unused)]
+                    is_some: bool,
+                    #[allow(unused)]
+                    val: std::mem::MaybeUninit<*const u8>,
+                }
+            },
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__Option_SliceU16 {
+                    #[allow(unused)]
+                    is_some: bool,
+                    #[allow(unused)]
+                    val: std::mem::MaybeUninit<swift_bridge::FfiSlice<u16> >,
+                }
+            },
+            quote! {
+                pub extern "C" fn __swift_bridge__some_function(
+                    arg: __swift_bridge__Option_ConstPtrU8,
+                    slice: __swift_bridge__Option_SliceU16
+                ) -> __swift_bridge__Option_SliceU8 {
+                    if let Some(val) = super::some_function(
+                        {
+                            let val = arg;
+                            if val.is_some {
+                                let val = unsafe { val.val.assume_init() };
+                                Some(val)
+                            } else {
+                                None
+                            }
+                        },
+                        {
+                            let val = slice;
+                            if val.is_some {
+                                let val = unsafe { val.val.assume_init() };
+                                Some(val.as_slice())
+                            } else {
+                                None
+                            }
+                        }
+                    ) {
+                        __swift_bridge__Option_SliceU8 {
+                            is_some: true,
+                            val: std::mem::MaybeUninit::new(swift_bridge::FfiSlice::from_slice(val))
+                        }
+                    } else {
+                        __swift_bridge__Option_SliceU8 {
+                            is_some: false,
+                            val: std::mem::MaybeUninit::uninit()
+                        }
+                    }
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: Optional<UnsafePointer<UInt8>>, _ slice: Optional<UnsafeBufferPointer<UInt16>>) -> Optional<UnsafeBufferPointer<UInt8>> {
+    { let val = __swift_bridge__$some_function({ var option = __swift_bridge__$Option$ConstPtrU8(); if let val = arg { option.is_some = true; option.val = val }; return option }(), { var option = __swift_bridge__$Option$SliceU16(); if let val = slice { option.is_some = true; option.val = val.toFfiSlice() }; return option }()); if val.is_some { return { let slice = val.val; return UnsafeBufferPointer(start: slice.start.assumingMemoryBound(to: UInt8.self), count: Int(slice.len)); }() } else { return nil } }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+struct __swift_bridge__$Option$SliceU8 __swift_bridge__$some_function(struct __swift_bridge__$Option$ConstPtrU8 arg, struct __swift_bridge__$Option$SliceU16 slice);
+"#,
+            r#"
+typedef struct __swift_bridge__$Option$ConstPtrU8 { bool is_some; uint8_t const * val; } __swift_bridge__$Option$ConstPtrU8;
+"#,
+            r#"
+typedef struct __swift_bridge__$Option$SliceU16 { bool is_some; struct __private__FfiSlice val; } __swift_bridge__$Option$SliceU16;
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_option_of_pointer_and_slice() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for a Swift function that takes an `Option<*mut u16>` and returns an
+/// `Option<*const u8>`.
+mod extern_swift_fn_option_of_pointer {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(arg: Option<*mut u16>) -> Option<*const u8>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: Option<*mut u16>) -> Option<*const u8> {
+                    {
+                        let val = unsafe {
+                            __swift_bridge__some_function(if let Some(val) = arg {
+                                __swift_bridge__Option_MutPtrU16 {
+                                    is_some: true,
+                                    val: std::mem::MaybeUninit::new(val)
+                                }
+                            } else {
+                                __swift_bridge__Option_MutPtrU16 {
+                                    is_some: false,
+                                    val: std::mem::MaybeUninit::uninit()
+                                }
+                            })
+                        };
+                        if val.is_some {
+                            let val = unsafe { val.val.assume_init() };
+                            Some(val)
+                        } else {
+                            None
+                        }
+                    }
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(
+                    arg: __swift_bridge__Option_MutPtrU16
+                ) -> __swift_bridge__Option_ConstPtrU8;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: __swift_bridge__$Option$MutPtrU16) -> __swift_bridge__$Option$ConstPtrU8 {
+    { var option = __swift_bridge__$Option$ConstPtrU8(); if let val = some_function(arg: { let val = arg; if val.is_some { return val.val } else { return nil } }()) { option.is_some = true; option.val = val }; return option }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+typedef struct __swift_bridge__$Option$MutPtrU16 { bool is_some; uint16_t* val; } __swift_bridge__$Option$MutPtrU16;
+"#,
+            r#"
+typedef struct __swift_bridge__$Option$ConstPtrU8 { bool is_some; uint8_t const * val; } __swift_bridge__$Option$ConstPtrU8;
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_swift_fn_option_of_pointer() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that returns a `Result<Vec<Vec<u8>>, String>`.
+/// The `Vec<Vec<u8>>` crosses the FFI boundary as an `FfiArray` value, so the `Result` gets its
+/// own FFI type instead of a `ResultPtrAndPtr`.
+mod extern_rust_fn_return_result_of_vec_of_vec {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function() -> Result<Vec<Vec<u8>>, String>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[repr(C)]
+                pub enum ResultVecVecU8AndString {
+                    #[allow(unused)]
+                    Ok(swift_bridge::array::FfiArray),
+                    #[allow(unused)]
+                    Err(*mut swift_bridge::string::RustString),
+                }
+            },
+            quote! {
+                pub extern "C" fn __swift_bridge__some_function() -> ResultVecVecU8AndString {
+                    match super::some_function() {
+                        Ok(ok) => ResultVecVecU8AndString::Ok(
+                            swift_bridge::array::FfiArray::from_vec(
+                                ok.into_iter()
+                                    .map(|val| Box::into_raw(Box::new(val)))
+                                    .collect::<Vec<_>>()
+                            )
+                        ),
+                        Err(err) => ResultVecVecU8AndString::Err(
+                            swift_bridge::string::RustString(err).box_into_raw()
+                        ),
+                    }
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function() throws -> [RustVec<UInt8>] {
+    try { let val = __swift_bridge__$some_function(); switch val.tag { case __swift_bridge__$ResultVecVecU8AndString$ResultOk: return __private__intoSwiftArray(val.payload.ok, { (val: UnsafeMutableRawPointer) -> RustVec<UInt8> in RustVec(ptr: val) }) case __swift_bridge__$ResultVecVecU8AndString$ResultErr: throw RustString(ptr: val.payload.err) default: fatalError() } }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+struct __swift_bridge__$ResultVecVecU8AndString __swift_bridge__$some_function(void);
+"#,
+            r#"
+union __swift_bridge__$ResultVecVecU8AndString$Fields {struct __private__FfiArray ok; void* err;};
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_return_result_of_vec_of_vec() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that returns a `Result<Vec<Option<u8>>, String>`.
+mod extern_rust_fn_return_result_of_vec_of_option {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function() -> Result<Vec<Option<u8>>, String>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[repr(C)]
+                pub enum ResultVecOptionU8AndString {
+                    #[allow(unused)]
+                    Ok(swift_bridge::array::FfiArray),
+                    #[allow(unused)]
+                    Err(*mut swift_bridge::string::RustString),
+                }
+            },
+            quote! {
+                pub extern "C" fn __swift_bridge__some_function() -> ResultVecOptionU8AndString {
+                    match super::some_function() {
+                        Ok(ok) => ResultVecOptionU8AndString::Ok(
+                            swift_bridge::array::FfiArray::from_vec(
+                                ok.into_iter()
+                                    .map(|val| if let Some(val) = val {
+                                        swift_bridge::option::OptionU8 { val, is_some: true }
+                                    } else {
+                                        swift_bridge::option::OptionU8 { val: 123, is_some: false }
+                                    })
+                                    .collect::<Vec<_>>()
+                            )
+                        ),
+                        Err(err) => ResultVecOptionU8AndString::Err(
+                            swift_bridge::string::RustString(err).box_into_raw()
+                        ),
+                    }
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function() throws -> [Optional<UInt8>] {
+    try { let val = __swift_bridge__$some_function(); switch val.tag { case __swift_bridge__$ResultVecOptionU8AndString$ResultOk: return __private__intoSwiftArray(val.payload.ok, { (val: __private__OptionU8) -> Optional<UInt8> in val.intoSwiftRepr() }) case __swift_bridge__$ResultVecOptionU8AndString$ResultErr: throw RustString(ptr: val.payload.err) default: fatalError() } }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+struct __swift_bridge__$ResultVecOptionU8AndString __swift_bridge__$some_function(void);
+"#,
+            r#"
+union __swift_bridge__$ResultVecOptionU8AndString$Fields {struct __private__FfiArray ok; void* err;};
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_return_result_of_vec_of_option() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a `Vec<String>` inside of a tuple argument is seen by Swift as a
+/// `RustVec<RustString>`, and that the function doesn't need the `GenericIntoRustString` generic.
+mod extern_rust_fn_tuple_of_vec_of_string {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: (Vec<String>, u8)) -> (Vec<String>, u8);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub struct __swift_bridge__tuple_VecStringU8(*mut Vec<String>, u8);
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: (RustVec<RustString>, UInt8)) -> (RustVec<RustString>, UInt8) {
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn extern_rust_fn_tuple_of_vec_of_string() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
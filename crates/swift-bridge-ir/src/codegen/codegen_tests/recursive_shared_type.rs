@@ -0,0 +1,328 @@
+//! See also: crates/swift-integration-tests/src/shared_types/recursive_shared_type.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that a shared enum can contain itself through a `Box` and that we generate an
+/// `indirect` Swift enum case for the variants that do.
+mod recursive_shared_enum {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                enum Expr {
+                    Num(f64),
+                    Add(Box<Expr>, Box<Expr>),
+                    Neg { inner: Box<Expr> },
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub enum __swift_bridge__Expr {
+                    Num(f64),
+                    Add(*mut __swift_bridge__Expr, *mut __swift_bridge__Expr),
+                    Neg { inner: *mut __swift_bridge__Expr }
+                }
+            },
+            quote! {
+                Expr::Add(_0, _1) => __swift_bridge__Expr::Add(
+                    Box::into_raw(Box::new((*_0).into_ffi_repr())),
+                    Box::into_raw(Box::new((*_1).into_ffi_repr()))
+                ),
+            },
+            quote! {
+                __swift_bridge__Expr::Neg { inner } => Expr::Neg {
+                    inner: Box::new((*unsafe { Box::from_raw(inner) }).into_rust_repr())
+                }
+            },
+            quote! {
+                const _: () = {
+                    #[doc(hidden)]
+                    #[export_name = "__swift_bridge__$Box_Expr$new"]
+                    pub extern "C" fn _new(val: __swift_bridge__Expr) -> *mut __swift_bridge__Expr {
+                        Box::into_raw(Box::new(val))
+                    }
+
+                    #[doc(hidden)]
+                    #[export_name = "__swift_bridge__$Box_Expr$into_inner"]
+                    pub extern "C" fn _into_inner(ptr: *mut __swift_bridge__Expr) -> __swift_bridge__Expr {
+                        *unsafe { Box::from_raw(ptr) }
+                    }
+                };
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public enum Expr {
+    case Num(Double)
+    indirect case Add(Expr, Expr)
+    indirect case Neg(inner: Expr)
+}
+"#,
+            r#"
+            case Expr.Add(let _0, let _1):
+                return __swift_bridge__$Expr(tag: __swift_bridge__$Expr$Add, payload: __swift_bridge__$ExprFields(Add: __swift_bridge__$Expr$FieldOfAdd(_0: __swift_bridge__$Box_Expr$new(_0.intoFfiRepr()), _1: __swift_bridge__$Box_Expr$new(_1.intoFfiRepr()))))
+"#,
+            r#"
+            case __swift_bridge__$Expr$Neg:
+                return Expr.Neg(inner: __swift_bridge__$Box_Expr$into_inner(self.payload.Neg.inner).intoSwiftRepr())
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __swift_bridge__$Expr;
+struct __swift_bridge__$Expr* __swift_bridge__$Box_Expr$new(struct __swift_bridge__$Expr val);
+struct __swift_bridge__$Expr __swift_bridge__$Box_Expr$into_inner(struct __swift_bridge__$Expr* ptr);
+typedef struct __swift_bridge__$Expr$FieldOfNum {double _0;} __swift_bridge__$Expr$FieldOfNum;
+typedef struct __swift_bridge__$Expr$FieldOfAdd {struct __swift_bridge__$Expr* _0; struct __swift_bridge__$Expr* _1;} __swift_bridge__$Expr$FieldOfAdd;
+typedef struct __swift_bridge__$Expr$FieldOfNeg {struct __swift_bridge__$Expr* inner;} __swift_bridge__$Expr$FieldOfNeg;
+"#,
+        )
+    }
+
+    #[test]
+    fn recursive_shared_enum() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a shared struct can contain itself through an `Option<Box<Self>>` and that the
+/// Swift field is stored on the heap using the `@Indirect` property wrapper.
+mod recursive_shared_struct {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct Node {
+                    value: i32,
+                    next: Option<Box<Node>>,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub struct __swift_bridge__Node {
+                    value: i32,
+                    next: *mut __swift_bridge__Node
+                }
+            },
+            quote! {
+                next: if let Some(val) = val.next {
+                    Box::into_raw(Box::new((*val).into_ffi_repr()))
+                } else {
+                    std::ptr::null_mut()
+                }
+            },
+            quote! {
+                next: {
+                    let val = val.next;
+                    if val.is_null() {
+                        None
+                    } else {
+                        Some(Box::new((*unsafe { Box::from_raw(val) }).into_rust_repr()))
+                    }
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$Box_Node$new"]
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public struct Node {
+    public var value: Int32
+    @Indirect public var next: Optional<Node>
+"#,
+            r#"
+        { let val = self; return __swift_bridge__$Node(value: val.value, next: val.next.map { val -> UnsafeMutablePointer<__swift_bridge__$Node> in __swift_bridge__$Box_Node$new(val.intoFfiRepr()) }); }()
+"#,
+            r#"
+        { let val = self; return Node(value: val.value, next: val.next.map { __swift_bridge__$Box_Node$into_inner($0).intoSwiftRepr() }); }()
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __swift_bridge__$Node;
+struct __swift_bridge__$Node* __swift_bridge__$Box_Node$new(struct __swift_bridge__$Node val);
+struct __swift_bridge__$Node __swift_bridge__$Box_Node$into_inner(struct __swift_bridge__$Node* ptr);
+typedef struct __swift_bridge__$Node { int32_t value; struct __swift_bridge__$Node* next; } __swift_bridge__$Node;
+"#,
+        )
+    }
+
+    #[test]
+    fn recursive_shared_struct() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a `Clone` shared struct can contain itself through a `Vec<Self>` and that the
+/// struct conforms to `Vectorizable`.
+mod recursive_shared_struct_through_vec {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                #[derive(Clone)]
+                struct TreeNode {
+                    value: u32,
+                    children: Vec<TreeNode>,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__TreeNode {
+                    value: u32,
+                    children: *mut Vec<TreeNode>
+                }
+            },
+            quote! {
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_TreeNode$push"]
+                pub extern "C" fn _push(vec: *mut Vec<TreeNode>, val: __swift_bridge__TreeNode) {
+                    unsafe { &mut *vec }.push(val.into_rust_repr())
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public struct TreeNode {
+    public var value: UInt32
+    public var children: RustVec<TreeNode>
+"#,
+            r#"
+extension TreeNode: Vectorizable {
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "typedef struct __swift_bridge__$TreeNode { uint32_t value; void* children; } __swift_bridge__$TreeNode;",
+            "void __swift_bridge__$Vec_TreeNode$push(void* vec_ptr, __swift_bridge__$TreeNode item);",
+        ])
+    }
+
+    #[test]
+    fn recursive_shared_struct_through_vec() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can pass a `Box<SharedType>` to and from Rust functions.
+mod boxed_shared_type_function_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                enum Expr {
+                    Num(f64),
+                    Neg(Box<Expr>),
+                }
+
+                extern "Rust" {
+                    fn simplify(expr: Box<Expr>) -> Box<Expr>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$simplify"]
+            pub extern "C" fn __swift_bridge__simplify(expr: *mut __swift_bridge__Expr) -> *mut __swift_bridge__Expr {
+                Box::into_raw(Box::new(
+                    (*super::simplify(Box::new((*unsafe { Box::from_raw(expr) }).into_rust_repr()))).into_ffi_repr()
+                ))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func simplify(_ expr: Expr) -> Expr {
+    __swift_bridge__$Box_Expr$into_inner(__swift_bridge__$simplify(__swift_bridge__$Box_Expr$new(expr.intoFfiRepr()))).intoSwiftRepr()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __swift_bridge__$Expr* __swift_bridge__$simplify(struct __swift_bridge__$Expr* expr);
+"#,
+        )
+    }
+
+    #[test]
+    fn boxed_shared_type_function_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,259 @@
+//! See also: crates/swift-integration-tests/src/set.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Test code generation for Rust function that returns a HashSet<T> of a primitive type.
+mod extern_rust_fn_return_hashset_of_primitives {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function() -> HashSet<u32>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__some_function(
+            ) -> *mut std::collections::HashSet<u32> {
+                Box::into_raw(Box::new(super::some_function()))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function() -> RustSet<UInt32> {
+    RustSet(ptr: __swift_bridge__$some_function())
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$some_function(void);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_return_hashset_of_primitives() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that takes and returns a BTreeSet<String>.
+mod extern_rust_fn_btreeset_of_strings {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: BTreeSet<String>) -> BTreeSet<String>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: *mut std::collections::HashSet<swift_bridge::string::RustString>
+            ) -> *mut std::collections::HashSet<swift_bridge::string::RustString> {
+                Box::into_raw(Box::new(
+                    super::some_function(
+                        unsafe { *Box::from_raw(arg) }
+                            .into_iter()
+                            .map(|string| string.0)
+                            .collect::<std::collections::BTreeSet<String> >()
+                    )
+                    .into_iter()
+                    .map(swift_bridge::string::RustString)
+                    .collect::<std::collections::HashSet<_> >()
+                ))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: RustSet<RustString>) -> RustSet<RustString> {
+    RustSet(ptr: __swift_bridge__$some_function({ let val = arg; val.isOwned = false; return val.ptr }()))
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$some_function(void* arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_btreeset_of_strings() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Swift functions that take and return a HashSet<T>.
+mod extern_swift_fn_hashset {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(arg: std::collections::HashSet<i64>) -> HashSet<i64>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(
+                    arg: std::collections::HashSet<i64>
+                ) -> std::collections::HashSet<i64> {
+                    unsafe {
+                        *Box::from_raw(
+                            unsafe { __swift_bridge__some_function(Box::into_raw(Box::new(arg))) }
+                        )
+                    }
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(
+                    arg: *mut std::collections::HashSet<i64>
+                ) -> *mut std::collections::HashSet<i64>;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: UnsafeMutableRawPointer) -> UnsafeMutableRawPointer {
+    { let val = some_function(arg: RustSet(ptr: arg)); val.isOwned = false; return val.ptr }()
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_hashset() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust and Swift functions that take and return an Option<HashSet<T>>.
+mod extern_rust_and_swift_fn_option_hashset {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Option<HashSet<u8>>) -> Option<HashSet<u8>>;
+                }
+                extern "Swift" {
+                    fn another_function(arg: Option<HashSet<u8>>) -> Option<HashSet<u8>>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub extern "C" fn __swift_bridge__some_function(
+                    arg: *mut std::collections::HashSet<u8>
+                ) -> *mut std::collections::HashSet<u8> {
+                    if let Some(val) = super::some_function(
+                        {
+                            let val = arg;
+                            if val.is_null() { None } else { Some(unsafe { *Box::from_raw(val) }) }
+                        }
+                    ) {
+                        Box::into_raw(Box::new(val))
+                    } else {
+                        std::ptr::null_mut()
+                    }
+                }
+            },
+            quote! {
+                fn __swift_bridge__another_function(
+                    arg: *mut std::collections::HashSet<u8>
+                ) -> *mut std::collections::HashSet<u8>;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func some_function(_ arg: Optional<RustSet<UInt8>>) -> Optional<RustSet<UInt8>> {
+    { let val = __swift_bridge__$some_function({ if let val = arg { val.isOwned = false; return val.ptr } else { return nil } }()); if val != nil { return RustSet(ptr: val!) } else { return nil } }()
+}
+"#,
+            r#"
+func __swift_bridge__another_function (_ arg: UnsafeMutableRawPointer?) -> UnsafeMutableRawPointer? {
+    { if let val = another_function(arg: { let val = arg; if val != nil { return RustSet(ptr: val!) } else { return nil } }()) { val.isOwned = false; return val.ptr } else { return nil } }()
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$some_function(void* arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_and_swift_fn_option_hashset() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
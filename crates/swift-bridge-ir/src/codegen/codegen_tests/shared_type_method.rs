@@ -0,0 +1,389 @@
+//! See also: crates/swift-integration-tests/src/shared_types/shared_type_method.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we generate a Swift method that passes the shared struct by value for a
+/// `self: &SharedStruct` method.
+mod shared_struct_ref_self_method {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct Point {
+                    x: i32,
+                    y: i32,
+                }
+
+                extern "Rust" {
+                    fn is_origin(self: &Point) -> bool;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$Point$is_origin"]
+            pub extern "C" fn __swift_bridge__Point_is_origin(this: __swift_bridge__Point) -> bool {
+                this.into_rust_repr().is_origin()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension Point {
+    public func is_origin() -> Bool {
+        __swift_bridge__$Point$is_origin(self.intoFfiRepr())
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+bool __swift_bridge__$Point$is_origin(struct __swift_bridge__$Point this);
+"#,
+        )
+    }
+
+    #[test]
+    fn shared_struct_ref_self_method() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a `self: &mut SharedStruct` method passes a pointer to the shared struct's FFI
+/// representation and that Swift writes the mutated value back into `self`.
+mod shared_struct_ref_mut_self_method {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct Point {
+                    x: i32,
+                    y: i32,
+                }
+
+                extern "Rust" {
+                    fn translate(self: &mut Point, dx: i32);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$Point$translate"]
+            pub extern "C" fn __swift_bridge__Point_translate(this: *mut __swift_bridge__Point, dx: i32) {
+                {
+                    let mut this_val = unsafe { std::ptr::read(this) }.into_rust_repr();
+                    let val = this_val.translate(dx);
+                    unsafe { std::ptr::write(this, this_val.into_ffi_repr()) };
+                    val
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension Point {
+    public mutating func translate(_ dx: Int32) {
+        var this = self.intoFfiRepr()
+        defer { self = this.intoSwiftRepr() }
+        __swift_bridge__$Point$translate(&this, dx)
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$Point$translate(struct __swift_bridge__$Point* this, int32_t dx);
+"#,
+        )
+    }
+
+    #[test]
+    fn shared_struct_ref_mut_self_method() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a `self: &SharedStruct` method on a struct with a `String` field passes a pointer
+/// to the shared struct's FFI representation and that Swift writes `self` back after the call.
+/// Converting the FFI representation into the Rust struct takes ownership of the `String`, so
+/// Swift can't keep using the `RustString` that it passed to Rust.
+mod shared_struct_ref_self_method_with_string_field {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct User {
+                    name: String,
+                }
+
+                extern "Rust" {
+                    fn describe(self: &User) -> String;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$User$describe"]
+            pub extern "C" fn __swift_bridge__User_describe(this: *mut __swift_bridge__User) -> *mut swift_bridge::string::RustString {
+                swift_bridge::string::RustString({
+                    let this_val = unsafe { std::ptr::read(this) }.into_rust_repr();
+                    let val = this_val.describe();
+                    unsafe { std::ptr::write(this, this_val.into_ffi_repr()) };
+                    val
+                })
+                .box_into_raw()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension User {
+    public mutating func describe() -> RustString {
+        var this = self.intoFfiRepr()
+        defer { self = this.intoSwiftRepr() }
+        RustString(ptr: __swift_bridge__$User$describe(&this))
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$User$describe(struct __swift_bridge__$User* this);
+"#,
+        )
+    }
+
+    #[test]
+    fn shared_struct_ref_self_method_with_string_field() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate owned `self` methods, associated functions and initializers for a
+/// shared struct.
+mod shared_struct_owned_self_and_associated_functions {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct Point {
+                    x: i32,
+                    y: i32,
+                }
+
+                extern "Rust" {
+                    fn sum(self: Point) -> i32;
+
+                    #[swift_bridge(associated_to = Point)]
+                    fn origin() -> Point;
+
+                    #[swift_bridge(init)]
+                    fn new(x: i32, y: i32) -> Point;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub extern "C" fn __swift_bridge__Point_sum(this: __swift_bridge__Point) -> i32 {
+                    this.into_rust_repr().sum()
+                }
+            },
+            quote! {
+                pub extern "C" fn __swift_bridge__Point_origin() -> __swift_bridge__Point {
+                    Point::origin().into_ffi_repr()
+                }
+            },
+            quote! {
+                pub extern "C" fn __swift_bridge__Point_new(x: i32, y: i32) -> __swift_bridge__Point {
+                    Point::new(x, y).into_ffi_repr()
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension Point {
+    public func sum() -> Int32 {
+        __swift_bridge__$Point$sum(self.intoFfiRepr())
+    }
+
+    static public func origin() -> Point {
+        __swift_bridge__$Point$origin().intoSwiftRepr()
+    }
+
+    public init(_ x: Int32, _ y: Int32) {
+        self = __swift_bridge__$Point$new(x, y).intoSwiftRepr()
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "int32_t __swift_bridge__$Point$sum(struct __swift_bridge__$Point this);",
+            "struct __swift_bridge__$Point __swift_bridge__$Point$origin(void);",
+            "struct __swift_bridge__$Point __swift_bridge__$Point$new(int32_t x, int32_t y);",
+        ])
+    }
+
+    #[test]
+    fn shared_struct_owned_self_and_associated_functions() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate methods and failable initializers for a shared enum.
+mod shared_enum_methods {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                enum Shape {
+                    Circle(f64),
+                    Empty,
+                }
+
+                extern "Rust" {
+                    fn area(self: &Shape) -> f64;
+                    fn shrink(self: &mut Shape);
+
+                    #[swift_bridge(init)]
+                    fn try_new(radius: f64) -> Option<Shape>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub extern "C" fn __swift_bridge__Shape_area(this: __swift_bridge__Shape) -> f64 {
+                    this.into_rust_repr().area()
+                }
+            },
+            quote! {
+                pub extern "C" fn __swift_bridge__Shape_shrink(this: *mut __swift_bridge__Shape) {
+                    {
+                        let mut this_val = unsafe { std::ptr::read(this) }.into_rust_repr();
+                        let val = this_val.shrink();
+                        unsafe { std::ptr::write(this, this_val.into_ffi_repr()) };
+                        val
+                    }
+                }
+            },
+            quote! {
+                pub extern "C" fn __swift_bridge__Shape_try_new(radius: f64) -> __swift_bridge__Option_Shape {
+                    __swift_bridge__Option_Shape::from_rust_repr(Shape::try_new(radius))
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension Shape {
+    public func area() -> Double {
+        __swift_bridge__$Shape$area(self.intoFfiRepr())
+    }
+
+    public mutating func shrink() {
+        var this = self.intoFfiRepr()
+        defer { self = this.intoSwiftRepr() }
+        __swift_bridge__$Shape$shrink(&this)
+    }
+
+    public init?(_ radius: Double) {
+        guard let val = __swift_bridge__$Shape$try_new(radius).intoSwiftRepr() else { return nil }; self = val
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "double __swift_bridge__$Shape$area(struct __swift_bridge__$Shape this);",
+            "void __swift_bridge__$Shape$shrink(struct __swift_bridge__$Shape* this);",
+            "struct __swift_bridge__$Option$Shape __swift_bridge__$Shape$try_new(double radius);",
+        ])
+    }
+
+    #[test]
+    fn shared_enum_methods() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,189 @@
+//! See also: crates/swift-integration-tests/src/swift_naming.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that `swift_naming = "camelCase"` gives functions, methods and arguments camelCase
+/// Swift names, while the Rust code and the C header keep using the Rust names.
+mod camel_case_functions {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge(swift_naming = "camelCase")]
+            mod ffi {
+                extern "Rust" {
+                    type HttpClient;
+
+                    #[swift_bridge(init)]
+                    fn new(base_url: &str) -> HttpClient;
+                    fn send_request(&self, request_path: &str, max_retries: u8) -> u16;
+                    #[swift_bridge(swift_name = "shutdown")]
+                    fn close_all_connections(&mut self);
+                }
+
+                extern "Swift" {
+                    fn log_response(status_code: u16);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$HttpClient$send_request"]
+                pub extern "C" fn __swift_bridge__HttpClient_send_request(
+                    this: *mut super::HttpClient,
+                    request_path: swift_bridge::string::RustStr,
+                    max_retries: u8
+                ) -> u16 {
+                    (unsafe { &*this }).send_request(request_path.to_str(), max_retries)
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$log_response"]
+                fn __swift_bridge__log_response(status_code: u16);
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+    public convenience init<GenericToRustStr: ToRustStr>(_ baseUrl: GenericToRustStr) {
+        self.init(ptr: baseUrl.toRustStr({ baseUrlAsRustStr in
+            __swift_bridge__$HttpClient$new(baseUrlAsRustStr)
+        }))
+    }
+"#,
+            r#"
+    public func shutdown() {
+        __swift_bridge__$HttpClient$close_all_connections(ptr)
+    }
+"#,
+            r#"
+    public func sendRequest<GenericToRustStr: ToRustStr>(_ requestPath: GenericToRustStr, _ maxRetries: UInt8) -> UInt16 {
+        return requestPath.toRustStr({ requestPathAsRustStr in
+            __swift_bridge__$HttpClient$send_request(ptr, requestPathAsRustStr, maxRetries)
+        })
+    }
+"#,
+            r#"
+@_cdecl("__swift_bridge__$log_response")
+func __swift_bridge__log_response (_ status_code: UInt16) {
+    logResponse(statusCode: status_code)
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+uint16_t __swift_bridge__$HttpClient$send_request(void* self, struct RustStr request_path, uint8_t max_retries);
+"#,
+        )
+    }
+
+    #[test]
+    fn camel_case_functions() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that `swift_naming = "camelCase"` gives shared struct fields and shared enum variants
+/// camelCase Swift names, and that an explicit `swift_name` takes precedence.
+mod camel_case_shared_types {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge(swift_naming = "camelCase")]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct RequestOptions {
+                    max_retries: u8,
+                    #[swift_bridge(swift_name = "timeout")]
+                    timeout_in_seconds: u16,
+                }
+
+                enum RequestError {
+                    NotFound,
+                    TooManyRequests { retry_after: u16 },
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub struct __swift_bridge__RequestOptions {
+                    max_retries: u8,
+                    timeout_in_seconds: u16
+                }
+            },
+            quote! {
+                pub enum __swift_bridge__RequestError {
+                    NotFound,
+                    TooManyRequests { retry_after: u16 }
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public struct RequestOptions {
+    public var maxRetries: UInt8
+    public var timeout: UInt16
+
+    public init(maxRetries: UInt8,timeout: UInt16) {
+        self.maxRetries = maxRetries
+        self.timeout = timeout
+    }
+"#,
+            r#"
+public enum RequestError {
+    case notFound
+    case tooManyRequests(retryAfter: UInt16)
+}
+"#,
+            r#"
+            case __swift_bridge__$RequestError$tooManyRequests:
+                return RequestError.tooManyRequests(retryAfter: self.payload.tooManyRequests.retryAfter)
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+typedef struct __swift_bridge__$RequestOptions { uint8_t maxRetries; uint16_t timeout; } __swift_bridge__$RequestOptions;
+"#,
+            r#"
+typedef enum __swift_bridge__$RequestErrorTag { __swift_bridge__$RequestError$notFound, __swift_bridge__$RequestError$tooManyRequests, } __swift_bridge__$RequestErrorTag;
+"#,
+        ])
+    }
+
+    #[test]
+    fn camel_case_shared_types() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
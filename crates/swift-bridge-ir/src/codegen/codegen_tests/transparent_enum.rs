@@ -346,6 +346,81 @@ mod shared_enum_swift_name_attribute {
     }
 }
 
+/// Verify that the `swift_name` attribute on an enum's variants and on their fields renames them
+/// in Swift and in the C header, while the Rust enum keeps the original names.
+/// Related: crates/swift-integration-tests/src/enum_attributes/swift_name.rs
+mod shared_enum_variant_swift_name_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                enum Shape {
+                    #[swift_bridge(swift_name = "circle")]
+                    Circle {
+                        #[swift_bridge(swift_name = "radiusInMeters")]
+                        radius: f64
+                    },
+                    Empty,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub enum Shape {
+                    Circle { radius: f64 },
+                    Empty
+                }
+            },
+            quote! {
+                Shape::Circle { radius } => __swift_bridge__Shape::Circle { radius: radius },
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public enum Shape {
+    case circle(radiusInMeters: Double)
+    case Empty
+}
+"#,
+            r#"
+            case Shape.circle(let radiusInMeters):
+                return __swift_bridge__$Shape(tag: __swift_bridge__$Shape$circle, payload: __swift_bridge__$ShapeFields(circle: __swift_bridge__$Shape$FieldOfcircle(radiusInMeters: radiusInMeters)))
+"#,
+            r#"
+            case __swift_bridge__$Shape$circle:
+                return Shape.circle(radiusInMeters: self.payload.circle.radiusInMeters)
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "typedef struct __swift_bridge__$Shape$FieldOfcircle {double radiusInMeters;} __swift_bridge__$Shape$FieldOfcircle;",
+            "union __swift_bridge__$ShapeFields { __swift_bridge__$Shape$FieldOfcircle circle;};",
+            "typedef enum __swift_bridge__$ShapeTag { __swift_bridge__$Shape$circle, __swift_bridge__$Shape$Empty, } __swift_bridge__$ShapeTag;",
+        ])
+    }
+
+    #[test]
+    fn shared_enum_variant_swift_name_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
 /// Verify that we generate an enum type that has a variant with one unnamed field and one with no fields.
 mod generates_enum_to_and_from_ffi_conversions_one_unnamed_data_and_no_fields {
     use super::*;
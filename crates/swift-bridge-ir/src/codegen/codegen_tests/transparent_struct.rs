@@ -582,6 +582,87 @@ mod shared_struct_swift_name_attribute {
     }
 }
 
+/// Verify that the `swift_name` attribute on a struct's field renames the field in Swift and in
+/// the C header, while the Rust struct keeps the original field name.
+/// Related: crates/swift-integration-tests/src/struct_attributes/swift_name.rs
+mod shared_struct_field_swift_name_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    #[swift_bridge(swift_name = "defaultValue")]
+                    default: u8,
+                    other: u8,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub struct SomeStruct {
+                    pub default: u8,
+                    pub other: u8
+                }
+            },
+            quote! {
+                pub struct __swift_bridge__SomeStruct {
+                    default: u8,
+                    other: u8
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public struct SomeStruct {
+    public var defaultValue: UInt8
+    public var other: UInt8
+
+    public init(defaultValue: UInt8,other: UInt8) {
+        self.defaultValue = defaultValue
+        self.other = other
+    }
+
+    @inline(__always)
+    func intoFfiRepr() -> __swift_bridge__$SomeStruct {
+        { let val = self; return __swift_bridge__$SomeStruct(defaultValue: val.defaultValue, other: val.other); }()
+    }
+}
+"#,
+            r#"
+        { let val = self; return SomeStruct(defaultValue: val.defaultValue, other: val.other); }()
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$SomeStruct { uint8_t defaultValue; uint8_t other; } __swift_bridge__$SomeStruct;
+"#,
+        )
+    }
+
+    #[test]
+    fn shared_struct_field_swift_name_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
 /// Verify that we can use `Option<Struct>` as Rust function arg and return type.
 mod extern_rust_option_struct {
     use super::*;
@@ -702,3 +783,237 @@ struct __swift_bridge__$Option$SomeStruct __swift_bridge__$some_function(struct
         .test();
     }
 }
+
+/// Verify that a struct can have a lifetime parameter and borrow its `&str` and `&[T]` fields.
+/// Swift sees the borrowed fields as views into the Rust data.
+mod struct_with_borrowed_fields {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct<'a> {
+                    text: &'a str,
+                    bytes: &'a [u8],
+                }
+
+                extern "Rust" {
+                    fn some_function(arg: SomeStruct<'_>) -> SomeStruct<'static>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub struct SomeStruct<'a> {
+                    pub text: &'a str,
+                    pub bytes: &'a [u8]
+                }
+            },
+            quote! {
+                pub struct __swift_bridge__SomeStruct {
+                    text: swift_bridge::string::RustStr,
+                    bytes: swift_bridge::FfiSlice<u8>
+                }
+            },
+            quote! {
+                impl<'a> swift_bridge::SharedStruct for SomeStruct<'a> {
+                    type FfiRepr = __swift_bridge__SomeStruct;
+                }
+            },
+            quote! {
+                impl<'a> SomeStruct<'a> {
+                    #[doc(hidden)]
+                    #[inline(always)]
+                    pub fn into_ffi_repr(self) -> __swift_bridge__SomeStruct {
+                        {
+                            let val = self;
+                            __swift_bridge__SomeStruct {
+                                text: swift_bridge::string::RustStr::from_str(val.text),
+                                bytes: swift_bridge::FfiSlice::from_slice(val.bytes)
+                            }
+                        }
+                    }
+                }
+            },
+            quote! {
+                impl __swift_bridge__SomeStruct {
+                    #[doc(hidden)]
+                    #[inline(always)]
+                    pub fn into_rust_repr<'a>(self) -> SomeStruct<'a> {
+                        {
+                            let val = self;
+                            SomeStruct {
+                                text: val.text.to_str(),
+                                bytes: val.bytes.as_slice()
+                            }
+                        }
+                    }
+                }
+            },
+            quote! {
+                pub extern "C" fn __swift_bridge__some_function(arg: __swift_bridge__SomeStruct) -> __swift_bridge__SomeStruct {
+                    super::some_function(arg.into_rust_repr()).into_ffi_repr()
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public struct SomeStruct {
+    public var text: RustStr
+    public var bytes: UnsafeBufferPointer<UInt8>
+"#,
+            r#"
+        { let val = self; return __swift_bridge__$SomeStruct(text: val.text, bytes: val.bytes.toFfiSlice()); }()
+"#,
+            r#"
+        { let val = self; return SomeStruct(text: val.text, bytes: { let slice = val.bytes; return UnsafeBufferPointer(start: slice.start.assumingMemoryBound(to: UInt8.self), count: Int(slice.len)); }()); }()
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$SomeStruct { struct RustStr text; struct __private__FfiSlice bytes; } __swift_bridge__$SomeStruct;
+"#,
+        )
+    }
+
+    #[test]
+    fn struct_with_borrowed_fields() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a `Vec` of a struct that has a lifetime parameter uses `'static` for the
+/// lifetime, since the `Vec` is stored behind a raw pointer.
+mod vec_of_struct_with_lifetime {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                #[derive(Clone)]
+                struct SomeStruct<'a> {
+                    text: &'a str,
+                }
+
+                extern "Rust" {
+                    fn some_function(text: &str) -> Vec<SomeStruct>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub extern "C" fn _new() -> *mut Vec<SomeStruct<'static> > {
+                    Box::into_raw(Box::new(Vec::new()))
+                }
+            },
+            quote! {
+                pub extern "C" fn __swift_bridge__some_function(text: swift_bridge::string::RustStr) -> *mut Vec<SomeStruct<'static> > {
+                    Box::into_raw(Box::new(super::some_function(text.to_str())))
+                }
+            },
+        ])
+    }
+
+    #[test]
+    fn vec_of_struct_with_lifetime() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: ExpectedSwiftCode::SkipTest,
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that a struct can have raw pointer fields.
+mod struct_with_pointer_fields {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    const_void: *const c_void,
+                    mut_u8: *mut u8,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub struct SomeStruct {
+                    pub const_void: *const super::c_void,
+                    pub mut_u8: *mut u8
+                }
+            },
+            quote! {
+                pub struct __swift_bridge__SomeStruct {
+                    const_void: *const super::c_void,
+                    mut_u8: *mut u8
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public struct SomeStruct {
+    public var const_void: UnsafeRawPointer
+    public var mut_u8: UnsafeMutablePointer<UInt8>
+"#,
+            r#"
+        { let val = self; return __swift_bridge__$SomeStruct(const_void: UnsafeMutableRawPointer(mutating: val.const_void), mut_u8: val.mut_u8); }()
+"#,
+            r#"
+        { let val = self; return SomeStruct(const_void: UnsafeRawPointer(val.const_void!), mut_u8: val.mut_u8); }()
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$SomeStruct { void* const_void; uint8_t* mut_u8; } __swift_bridge__$SomeStruct;
+"#,
+        )
+    }
+
+    #[test]
+    fn struct_with_pointer_fields() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -5,7 +5,7 @@ use crate::bridged_type::{BridgeableType, BridgedType, CFfiStruct, StdLibType, S
 use crate::codegen::CodegenConfig;
 use crate::parse::{SharedTypeDeclaration, TypeDeclaration, TypeDeclarations};
 use crate::parsed_extern_fn::ParsedExternFn;
-use crate::{SwiftBridgeModule, SWIFT_BRIDGE_PREFIX};
+use crate::SwiftBridgeModule;
 use std::collections::{BTreeSet, HashSet};
 use syn::{FnArg, ReturnType};
 
@@ -53,6 +53,11 @@ impl SwiftBridgeModule {
             slice_types: HashSet::new(),
         };
 
+        let mut c_ffi_struct_bookkeeping = CFfiStructDeclarationBookkeeping {
+            encountered_custom_type_declarations: HashSet::new(),
+            custom_type_declarations: Vec::new(),
+        };
+
         for ty in self.types.types() {
             match ty {
                 TypeDeclaration::Shared(ty) => match ty {
@@ -128,16 +133,33 @@ impl SwiftBridgeModule {
                             "".to_string()
                         };
 
+                        let maybe_vec_support = if ty_struct.is_vectorizable() {
+                            bookkeeping.includes.insert("stdint.h");
+                            vec_transparent_struct_c_support(&name)
+                        } else {
+                            "".to_string()
+                        };
+
+                        let derive_debug_impl =
+                            if ty_struct.has_swift_debug_description(&self.types) {
+                                format!("\nvoid* {ffi_name}$Debug({ffi_name} this);")
+                            } else {
+                                "".to_string()
+                            };
+
                         let ty_decl = format!(
-                            r#"typedef struct {prefix}${name} {{{maybe_fields}}} {prefix}${name};
-typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi_name};"#,
-                            prefix = SWIFT_BRIDGE_PREFIX,
+                            r#"typedef struct {ffi_name} {{{maybe_fields}}} {ffi_name};
+typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi_name};{maybe_vec_support}{derive_debug_impl}"#,
                             ffi_name = ffi_name,
                             option_ffi_name = option_ffi_name,
-                            name = name,
                             maybe_fields = maybe_fields
                         );
 
+                        header += &declare_field_custom_c_ffi_types(
+                            &ty_struct.fields,
+                            &self.types,
+                            &mut c_ffi_struct_bookkeeping,
+                        );
                         header += &ty_decl;
                         header += "\n";
                     }
@@ -159,11 +181,26 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
 
                         let mut variants = "".to_string();
 
-                        for variant in ty_enum.variants.iter() {
-                            let variant = format!("{}${}, ", ffi_name, variant.name);
+                        let discriminants = ty_enum.discriminant_values();
+                        for (variant, discriminant) in ty_enum.variants.iter().zip(discriminants) {
+                            let variant_name = variant.swift_name_string();
+                            let variant = if variant.discriminant.is_some() {
+                                format!("{}${} = {}, ", ffi_name, variant_name, discriminant)
+                            } else {
+                                format!("{}${}, ", ffi_name, variant_name)
+                            };
                             variants += &variant;
                         }
 
+                        // typedef enum __swift_bridge__$SomeEnum$Tag : uint8_t { ...
+                        let tag_type = match ty_enum.c_tag_type() {
+                            Some(c_tag_type) => {
+                                bookkeeping.includes.insert("stdint.h");
+                                format!(" : {c_tag_type}")
+                            }
+                            None => "".to_string(),
+                        };
+
                         let derive_debug_impl = if ty_enum.derive.debug {
                             format!("void* {ffi_name}$Debug({ffi_name} this);")
                         } else {
@@ -178,7 +215,7 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
                         let mut variant_fields = "".to_string();
                         if all_variants_empty {
                             let enum_decl = format!(
-                                r#"typedef enum {ffi_tag_name} {{ {variants}}} {ffi_tag_name};
+                                r#"typedef enum {ffi_tag_name}{tag_type} {{ {variants}}} {ffi_tag_name};
 typedef struct {ffi_name} {{ {ffi_tag_name} tag; }} {ffi_name};
 typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi_name};
 {derive_debug_impl}{maybe_vec_support}"#,
@@ -206,11 +243,11 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
                                                 }
                                             }
                                             let ty = ty.to_c(&self.types);
-                                            let field_name = named_field.name.to_string();
+                                            let field_name = named_field.swift_name_string();
                                             params.push(format!("{} {};", ty, field_name));
                                         }
                                         let params = params.join(" ");
-                                        let variant_field = format!("typedef struct {ffi_name}$FieldOf{variant_name} {{{params}}} {ffi_name}$FieldOf{variant_name};", ffi_name = ffi_name, variant_name = variant.name, params = params);
+                                        let variant_field = format!("typedef struct {ffi_name}$FieldOf{variant_name} {{{params}}} {ffi_name}$FieldOf{variant_name};", ffi_name = ffi_name, variant_name = variant.swift_name_string(), params = params);
                                         variant_fields += &variant_field;
                                         variant_fields += "\n";
                                     }
@@ -231,7 +268,7 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
                                             params.push(format!("{} _{};", ty, unnamed_field.idx));
                                         }
                                         let params = params.join(" ");
-                                        let variant_field = format!("typedef struct {ffi_name}$FieldOf{variant_name} {{{params}}} {ffi_name}$FieldOf{variant_name};", ffi_name = ffi_name, variant_name = variant.name, params = params);
+                                        let variant_field = format!("typedef struct {ffi_name}$FieldOf{variant_name} {{{params}}} {ffi_name}$FieldOf{variant_name};", ffi_name = ffi_name, variant_name = variant.swift_name_string(), params = params);
                                         variant_fields += &variant_field;
                                         variant_fields += "\n";
                                     }
@@ -240,7 +277,7 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
                             }
                             let enum_decl = format!(
                                 r#"{variant_fields}union {ffi_union_name} {union_fields};
-typedef enum {ffi_tag_name} {{ {variants}}} {ffi_tag_name};
+typedef enum {ffi_tag_name}{tag_type} {{ {variants}}} {ffi_tag_name};
 typedef struct {ffi_name} {{ {ffi_tag_name} tag; union {ffi_union_name} payload;}} {ffi_name};
 typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi_name};
 {derive_debug_impl}{maybe_vec_support}"#,
@@ -253,6 +290,13 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
                                 ffi_union_name = ffi_union_name,
                             );
 
+                            for variant in ty_enum.variants.iter() {
+                                header += &declare_field_custom_c_ffi_types(
+                                    &variant.fields,
+                                    &self.types,
+                                    &mut c_ffi_struct_bookkeeping,
+                                );
+                            }
                             header += &enum_decl;
                             header += "\n";
                         }
@@ -350,20 +394,20 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
 
                         header += &vec_functions;
                         header += "\n";
+
+                        if self.uses_arc_of(&ty.ty) {
+                            header += &arc_opaque_rust_type_c_support(&ty_name);
+                            header += "\n";
+                        }
                     }
                 }
             }
         }
-        let mut c_ffi_struct_bookkeeping = CFfiStructDeclarationBookkeeping {
-            encountered_custom_type_declarations: HashSet::new(),
-            custom_type_declarations: Vec::new(),
-        };
-
         for func in self.functions.iter() {
             declare_custom_c_ffi_types(func, &self.types, &mut c_ffi_struct_bookkeeping);
             if func.host_lang.is_swift() {
                 for (idx, boxed_fn) in func.args_filtered_to_boxed_fns(&self.types) {
-                    if boxed_fn.params.is_empty() && boxed_fn.ret.is_null() {
+                    if boxed_fn.uses_no_args_no_return_support() {
                         continue;
                     }
 
@@ -376,6 +420,20 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
 
             header += &declare_func(&func, &mut bookkeeping, &self.types);
         }
+        for trait_declaration in self.types.traits() {
+            for implementor in trait_declaration.implementors.iter() {
+                let implementor = self
+                    .types
+                    .get(&implementor.to_string())
+                    .and_then(|ty| ty.as_opaque())
+                    .unwrap();
+
+                for method in trait_declaration.implementor_methods(implementor) {
+                    declare_custom_c_ffi_types(&method, &self.types, &mut c_ffi_struct_bookkeeping);
+                    header += &declare_func(&method, &mut bookkeeping, &self.types);
+                }
+            }
+        }
 
         for slice_ty in bookkeeping.slice_types.iter() {
             header = format!(
@@ -403,6 +461,14 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
     }
 }
 
+fn arc_opaque_rust_type_c_support(ty_name: &str) -> String {
+    format!(
+        r#"void __swift_bridge__${ty_name}$_increment_strong_count(void* self);
+void __swift_bridge__${ty_name}$_decrement_strong_count(void* self);"#,
+        ty_name = ty_name
+    )
+}
+
 fn vec_opaque_rust_type_c_support(ty_name: &str) -> String {
     format!(
         r#"
@@ -435,6 +501,21 @@ void* __swift_bridge__$Vec_{enum_name}$as_ptr(void* vec_ptr);
     )
 }
 
+fn vec_transparent_struct_c_support(struct_name: &str) -> String {
+    format!(
+        r#"
+void* __swift_bridge__$Vec_{struct_name}$new(void);
+void __swift_bridge__$Vec_{struct_name}$drop(void* vec_ptr);
+void __swift_bridge__$Vec_{struct_name}$push(void* vec_ptr, __swift_bridge__${struct_name} item);
+__swift_bridge__$Option${struct_name} __swift_bridge__$Vec_{struct_name}$pop(void* vec_ptr);
+__swift_bridge__$Option${struct_name} __swift_bridge__$Vec_{struct_name}$get(void* vec_ptr, uintptr_t index);
+__swift_bridge__$Option${struct_name} __swift_bridge__$Vec_{struct_name}$get_mut(void* vec_ptr, uintptr_t index);
+uintptr_t __swift_bridge__$Vec_{struct_name}$len(void* vec_ptr);
+void* __swift_bridge__$Vec_{struct_name}$as_ptr(void* vec_ptr);"#,
+        struct_name = struct_name
+    )
+}
+
 fn push_custom_type_declarations(
     custom_type_declaration: &CFfiStruct,
     c_ffi_struct_bookkeeping: &mut CFfiStructDeclarationBookkeeping,
@@ -456,6 +537,25 @@ fn push_custom_type_declarations(
         .push(custom_type_declaration.c_ffi_type.clone());
 }
 
+/// Struct and enum fields are stored by value, so the custom types that they depend on need to
+/// be declared before the struct or enum.
+fn declare_field_custom_c_ffi_types(
+    fields: &StructFields,
+    types: &TypeDeclarations,
+    c_ffi_struct_bookkeeping: &mut CFfiStructDeclarationBookkeeping,
+) -> String {
+    for declaration in fields.generate_custom_c_ffi_types(types) {
+        push_custom_type_declarations(&declaration, c_ffi_struct_bookkeeping);
+    }
+
+    let mut declarations = "".to_string();
+    for declaration in c_ffi_struct_bookkeeping.custom_type_declarations.drain(..) {
+        declarations += &declaration;
+        declarations += "\n";
+    }
+    declarations
+}
+
 fn declare_custom_c_ffi_types(
     func: &ParsedExternFn,
     types: &TypeDeclarations,
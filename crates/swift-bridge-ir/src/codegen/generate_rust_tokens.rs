@@ -2,10 +2,11 @@
 
 use std::collections::HashMap;
 
-use proc_macro2::TokenStream;
+use proc_macro2::{Ident, TokenStream};
 use quote::ToTokens;
 use quote::{quote, quote_spanned};
 
+use self::arc_of_opaque_rust_type::generate_arc_of_opaque_rust_type_functions;
 use self::vec::vec_of_opaque_rust_type::generate_vec_of_opaque_rust_type_functions;
 use crate::bridge_module_attributes::CfgAttr;
 use crate::parse::{
@@ -13,6 +14,8 @@ use crate::parse::{
 };
 use crate::SwiftBridgeModule;
 
+mod arc_of_opaque_rust_type;
+mod bridged_trait;
 mod shared_enum;
 mod shared_struct;
 mod vec;
@@ -45,6 +48,8 @@ impl ToTokens for SwiftBridgeModule {
                         &self.types,
                         &mut custom_type_definitions,
                     ));
+                    callbacks_support
+                        .push(func.callbacks_support(&self.swift_bridge_path, &self.types));
                 }
                 HostLang::Swift => {
                     let tokens = func
@@ -85,8 +90,24 @@ impl ToTokens for SwiftBridgeModule {
                     if let Some(definition) = self.generate_shared_struct_tokens(shared_struct) {
                         shared_struct_definitions.push(definition);
                     }
+
+                    for tokens in shared_struct
+                        .fields
+                        .generate_custom_rust_ffi_types(&self.swift_bridge_path, &self.types)
+                    {
+                        custom_type_definitions.insert(tokens.to_string(), tokens);
+                    }
                 }
                 TypeDeclaration::Shared(SharedTypeDeclaration::Enum(shared_enum)) => {
+                    for variant in shared_enum.variants.iter() {
+                        for tokens in variant
+                            .fields
+                            .generate_custom_rust_ffi_types(&self.swift_bridge_path, &self.types)
+                        {
+                            custom_type_definitions.insert(tokens.to_string(), tokens);
+                        }
+                    }
+
                     if let Some(definition) =
                         self.generate_shared_enum_tokens(shared_enum, &self.types)
                     {
@@ -218,6 +239,12 @@ impl ToTokens for SwiftBridgeModule {
                                         let vec_functions =
                                             generate_vec_of_opaque_rust_type_functions(ty_name);
                                         extern_rust_fn_tokens.push(vec_functions);
+
+                                        if self.uses_arc_of(ty_name) {
+                                            let arc_functions =
+                                                generate_arc_of_opaque_rust_type_functions(ty_name);
+                                            extern_rust_fn_tokens.push(arc_functions);
+                                        }
                                     }
 
                                     if ty.attributes.sendable {
@@ -251,42 +278,32 @@ impl ToTokens for SwiftBridgeModule {
                                 }
                             };
 
-                            let maybe_impl_send_sync = if ty.attributes.sendable {
-                                quote! {
-                                    unsafe impl Send for #ty_name {}
-                                    unsafe impl Sync for #ty_name {}
-                                }
-                            } else {
-                                quote! {}
-                            };
-
-                            let struct_tokens = quote! {
-                                #[repr(C)]
-                                pub struct #ty_name(*mut std::ffi::c_void);
-
-                                #impls
-
-                                impl Drop for #ty_name {
-                                    fn drop (&mut self) {
-                                        unsafe { #free_mem_func_name(self.0) }
-                                    }
-                                }
-
-                                #maybe_impl_send_sync
-                            };
-                            structs_for_swift_classes.push(struct_tokens);
-
-                            let free = quote! {
-                                #[link_name = #link_name]
-                                fn #free_mem_func_name (this: *mut std::ffi::c_void);
+                            let swift_class = SwiftClassTokens {
+                                attributes: quote! {},
+                                ty_name,
+                                impls,
+                                free_mem_func_name: &free_mem_func_name,
+                                free_link_name: &link_name,
+                                send: ty.attributes.sendable,
+                                sync: ty.attributes.sendable,
                             };
-                            extern_swift_fn_tokens.push(free);
+                            structs_for_swift_classes.push(swift_class.struct_tokens());
+                            extern_swift_fn_tokens.push(swift_class.free_fn_tokens());
                         }
                     };
                 }
             }
         }
 
+        for trait_declaration in self.types.traits() {
+            let trait_tokens =
+                self.generate_trait_tokens(trait_declaration, &mut custom_type_definitions);
+
+            structs_for_swift_classes.push(trait_tokens.swift_object_wrapper);
+            extern_swift_fn_tokens.extend(trait_tokens.extern_swift_fns);
+            extern_rust_fn_tokens.extend(trait_tokens.implementor_fns);
+        }
+
         let extern_swift_fn_tokens = if extern_swift_fn_tokens.len() > 0 {
             generate_extern_c_block(extern_swift_fn_tokens)
         } else {
@@ -335,6 +352,73 @@ impl ToTokens for SwiftBridgeModule {
     }
 }
 
+/// The Rust type that owns an instance of a Swift class, and releases the instance when it gets
+/// dropped.
+///
+/// Opaque Swift types and the Swift objects that conform to a bridged trait's protocol are both
+/// held this way. Their methods are generated by `to_rust_impl_call_swift.rs`.
+struct SwiftClassTokens<'a> {
+    attributes: TokenStream,
+    ty_name: &'a Ident,
+    /// The `impl` blocks that call the Swift class's methods.
+    impls: TokenStream,
+    free_mem_func_name: &'a Ident,
+    free_link_name: &'a str,
+    send: bool,
+    sync: bool,
+}
+
+impl SwiftClassTokens<'_> {
+    fn struct_tokens(&self) -> TokenStream {
+        let attributes = &self.attributes;
+        let ty_name = self.ty_name;
+        let impls = &self.impls;
+        let free_mem_func_name = self.free_mem_func_name;
+
+        let maybe_impl_send = if self.send {
+            quote! {
+                unsafe impl Send for #ty_name {}
+            }
+        } else {
+            quote! {}
+        };
+        let maybe_impl_sync = if self.sync {
+            quote! {
+                unsafe impl Sync for #ty_name {}
+            }
+        } else {
+            quote! {}
+        };
+
+        quote! {
+            #attributes
+            #[repr(C)]
+            pub struct #ty_name(*mut std::ffi::c_void);
+
+            #impls
+
+            impl Drop for #ty_name {
+                fn drop (&mut self) {
+                    unsafe { #free_mem_func_name(self.0) }
+                }
+            }
+
+            #maybe_impl_send
+            #maybe_impl_sync
+        }
+    }
+
+    fn free_fn_tokens(&self) -> TokenStream {
+        let free_link_name = self.free_link_name;
+        let free_mem_func_name = self.free_mem_func_name;
+
+        quote! {
+            #[link_name = #free_link_name]
+            fn #free_mem_func_name (this: *mut std::ffi::c_void);
+        }
+    }
+}
+
 /// Generate an `extern "C"` block such as:
 ///
 /// ```no_run
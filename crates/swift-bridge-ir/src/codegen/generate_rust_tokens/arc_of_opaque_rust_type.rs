@@ -0,0 +1,30 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+/// Generate the functions that Swift uses to manage the strong count of an `Arc<MyRustType>`.
+///
+/// So inside of `public class MyRustTypeArc: MyRustTypeRef {}` on the Swift side.
+pub(super) fn generate_arc_of_opaque_rust_type_functions(ty: &Ident) -> TokenStream {
+    // examples:
+    // "__swift_bridge__$MyRustType$_increment_strong_count"
+    // "__swift_bridge__$MyRustType$_decrement_strong_count"
+    let make_export_name = |fn_name| format!("__swift_bridge__${}${}", ty, fn_name);
+    let export_name_increment = make_export_name("_increment_strong_count");
+    let export_name_decrement = make_export_name("_decrement_strong_count");
+
+    quote! {
+        const _: () = {
+            #[doc(hidden)]
+            #[export_name = #export_name_increment]
+            pub extern "C" fn _increment_strong_count(this: *const super::#ty) {
+                unsafe { std::sync::Arc::increment_strong_count(this) }
+            }
+
+            #[doc(hidden)]
+            #[export_name = #export_name_decrement]
+            pub extern "C" fn _decrement_strong_count(this: *const super::#ty) {
+                unsafe { std::sync::Arc::decrement_strong_count(this) }
+            }
+        };
+    }
+}
@@ -0,0 +1,152 @@
+//! More tests can be found in
+//! crates/swift-bridge-ir/src/codegen/codegen_tests/bridged_trait.rs
+
+use super::SwiftClassTokens;
+use crate::parse::TraitDeclaration;
+use crate::SwiftBridgeModule;
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::collections::HashMap;
+use syn::spanned::Spanned;
+
+/// The tokens that get generated for a trait that was declared in the bridge module.
+pub(super) struct TraitTokens {
+    /// The type that holds a Swift object that conforms to the trait's protocol, along with its
+    /// implementation of the trait.
+    pub swift_object_wrapper: TokenStream,
+    /// The Swift functions that call the protocol's methods and release the Swift object.
+    pub extern_swift_fns: Vec<TokenStream>,
+    /// The functions that Swift uses to call an opaque Rust type's implementation of the trait.
+    pub implementor_fns: Vec<TokenStream>,
+}
+
+impl SwiftBridgeModule {
+    /// Generate the tokens for a trait.
+    ///
+    /// ```no_run,ignore
+    /// #[doc(hidden)]
+    /// #[repr(C)]
+    /// pub struct __swift_bridge__Animal(*mut std::ffi::c_void);
+    ///
+    /// impl super::Animal for __swift_bridge__Animal {
+    ///     fn name(&self) -> String {
+    ///         unsafe { __swift_bridge__Animal_name(swift_bridge::PointerToSwiftType(self.0)) }
+    ///     }
+    /// }
+    ///
+    /// #[export_name = "__swift_bridge__$Dog$Animal$name"]
+    /// pub extern "C" fn __swift_bridge__Dog_Animal_name(this: *mut super::Dog) -> ... {
+    ///     <super::Dog as super::Animal>::name(unsafe { &*this })
+    /// }
+    /// ```
+    pub(super) fn generate_trait_tokens(
+        &self,
+        trait_declaration: &TraitDeclaration,
+        custom_type_definitions: &mut HashMap<String, TokenStream>,
+    ) -> TraitTokens {
+        let swift_bridge_path = &self.swift_bridge_path;
+        let types = &self.types;
+
+        let trait_name = &trait_declaration.name;
+        let wrapper = trait_declaration.swift_object_wrapper_ident();
+        let free_link_name = trait_declaration.free_swift_object_link_name();
+        let free_ident = trait_declaration.free_swift_object_ident();
+
+        let mut trait_methods = vec![];
+        let mut extern_swift_fns = vec![];
+        for method in trait_declaration.methods.iter() {
+            if !method.args_filtered_to_boxed_fns(types).is_empty() {
+                unreachable!("Trait methods can't take boxed functions")
+            }
+
+            trait_methods.push(
+                method.to_rust_trait_method_that_calls_a_swift_extern(swift_bridge_path, types),
+            );
+            extern_swift_fns.push(method.to_extern_c_function_tokens(
+                swift_bridge_path,
+                types,
+                custom_type_definitions,
+            ));
+        }
+        // The Swift object's protocol inherits from `Sendable`, so Swift guarantees that the object
+        // can be used from other threads.
+        let swift_class = SwiftClassTokens {
+            attributes: quote! { #[doc(hidden)] },
+            ty_name: &wrapper,
+            impls: quote! {
+                impl super::#trait_name for #wrapper {
+                    #(#trait_methods)*
+                }
+            },
+            free_mem_func_name: &free_ident,
+            free_link_name: &free_link_name,
+            send: trait_declaration.send,
+            sync: trait_declaration.sync,
+        };
+        extern_swift_fns.push(swift_class.free_fn_tokens());
+        let swift_object_wrapper = swift_class.struct_tokens();
+
+        let mut implementor_fns = vec![];
+        for implementor in trait_declaration.implementors.iter() {
+            let implementor_declaration = types
+                .get(&implementor.to_string())
+                .and_then(|ty| ty.as_opaque())
+                .unwrap();
+
+            for method in trait_declaration.implementor_methods(implementor_declaration) {
+                let link_name = method.link_name();
+                let fn_ident = method.prefixed_fn_name();
+                let method_name = &method.func.sig.ident;
+
+                let params = method.to_extern_c_param_names_and_types(
+                    swift_bridge_path,
+                    types,
+                    custom_type_definitions,
+                );
+                let ret = method.rust_fn_sig_return_tokens(
+                    swift_bridge_path,
+                    types,
+                    custom_type_definitions,
+                );
+
+                let this = if method.self_mutability().is_some() {
+                    quote! { unsafe { &mut *this } }
+                } else {
+                    quote! { unsafe { &*this } }
+                };
+                let call_args = method.to_call_rust_args(swift_bridge_path, types);
+                let call_args = if call_args.is_empty() {
+                    this
+                } else {
+                    quote! { #this, #call_args }
+                };
+
+                let call_fn = quote! {
+                    <super::#implementor as super::#trait_name>::#method_name(#call_args)
+                };
+                let call_fn = method
+                    .return_ty_built_in(types)
+                    .unwrap()
+                    .convert_rust_expression_to_ffi_type(
+                        &call_fn,
+                        swift_bridge_path,
+                        types,
+                        method.func.span(),
+                    );
+
+                implementor_fns.push(quote! {
+                    #[export_name = #link_name]
+                    pub extern "C" fn #fn_ident ( #params ) #ret {
+                        #call_fn
+                    }
+                });
+            }
+        }
+
+        TraitTokens {
+            swift_object_wrapper,
+            extern_swift_fns,
+            implementor_fns,
+        }
+    }
+}
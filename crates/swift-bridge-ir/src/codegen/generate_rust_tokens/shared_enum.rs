@@ -4,10 +4,9 @@
 use crate::bridged_type::{BridgedType, SharedEnum, StructFields};
 use crate::codegen::generate_rust_tokens::vec::vec_of_transparent_enum::generate_vec_of_transparent_enum_functions;
 use crate::parse::TypeDeclarations;
-use crate::{SwiftBridgeModule, SWIFT_BRIDGE_PREFIX};
+use crate::SwiftBridgeModule;
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
-use syn::Ident;
 
 impl SwiftBridgeModule {
     /// Generate the tokens for a shared enum.
@@ -23,45 +22,67 @@ impl SwiftBridgeModule {
         let enum_name = &shared_enum.name;
         let swift_bridge_path = &self.swift_bridge_path;
 
-        let enum_ffi_name = format!("{}{}", SWIFT_BRIDGE_PREFIX, enum_name);
-        let enum_ffi_name = Ident::new(&enum_ffi_name, enum_name.span());
+        let enum_ffi_name = shared_enum.ffi_name_ident();
 
         let option_enum = shared_enum.ffi_option_name_tokens();
 
+        let generics = &shared_enum.generics;
+        let ty_generics = shared_enum.ty_generics(types);
+
+        // A generic enum gets declared once, alongside its first instantiation.
+        let declared_variants = if generics.is_empty() {
+            Some(&shared_enum.variants)
+        } else {
+            shared_enum.declared_variants.as_ref()
+        };
+
+        // `#[serde(...)]` attributes are an error unless the enum derives a serde trait.
+        let forward_serde_attrs = !shared_enum.derive.serde.is_empty();
+
         let mut enum_variants = vec![];
         let mut enum_ffi_variants = vec![];
 
-        for variant in shared_enum.variants.iter() {
+        for variant in declared_variants.into_iter().flatten() {
             let variant_name = &variant.name;
+            let variant_serde_attrs = if forward_serde_attrs {
+                variant.serde_attrs.as_slice()
+            } else {
+                &[]
+            };
+            let maybe_discriminant = variant.discriminant.as_ref().map(|d| quote! { = #d });
             let enum_variant = match &variant.fields {
                 StructFields::Named(named_fields) => {
                     let mut names = vec![];
                     for named_field in named_fields {
                         let field_name = &named_field.name;
-                        let ty = BridgedType::new_with_type(&named_field.ty, &self.types)
-                            .unwrap()
-                            .to_rust_type_path(types);
-                        let field = quote! {#field_name : #ty};
+                        let ty = generics.declared_field_rust_type(&named_field.ty, types);
+                        let field_serde_attrs = if forward_serde_attrs {
+                            named_field.serde_attrs.as_slice()
+                        } else {
+                            &[]
+                        };
+                        let field = quote! {#(#field_serde_attrs)* #field_name : #ty};
                         names.push(field);
                     }
                     quote! {
-                        #variant_name {#(#names),*}
+                        #(#variant_serde_attrs)*
+                        #variant_name {#(#names),*} #maybe_discriminant
                     }
                 }
                 StructFields::Unnamed(unamed_fields) => {
                     let mut names = vec![];
                     for unnamed_field in unamed_fields {
-                        let ty =
-                            BridgedType::new_with_type(&unnamed_field.ty, &self.types).unwrap();
-                        names.push(ty.to_rust_type_path(types));
+                        names.push(generics.declared_field_rust_type(&unnamed_field.ty, types));
                     }
                     quote! {
-                        #variant_name (#(#names),*)
+                        #(#variant_serde_attrs)*
+                        #variant_name (#(#names),*) #maybe_discriminant
                     }
                 }
                 StructFields::Unit => {
                     quote! {
-                        #variant_name
+                        #(#variant_serde_attrs)*
+                        #variant_name #maybe_discriminant
                     }
                 }
             };
@@ -70,6 +91,7 @@ impl SwiftBridgeModule {
 
         for variant in shared_enum.variants.iter() {
             let variant_name = &variant.name;
+            let maybe_discriminant = variant.discriminant.as_ref().map(|d| quote! { = #d });
             let enum_ffi_variant = match &variant.fields {
                 StructFields::Named(named_fields) => {
                     let mut names = vec![];
@@ -82,7 +104,7 @@ impl SwiftBridgeModule {
                         names.push(field);
                     }
                     quote! {
-                        #variant_name {#(#names),*}
+                        #variant_name {#(#names),*} #maybe_discriminant
                     }
                 }
                 StructFields::Unnamed(unamed_fields) => {
@@ -95,12 +117,12 @@ impl SwiftBridgeModule {
                         );
                     }
                     quote! {
-                        #variant_name (#(#names),*)
+                        #variant_name (#(#names),*) #maybe_discriminant
                     }
                 }
                 StructFields::Unit => {
                     quote! {
-                        #variant_name
+                        #variant_name #maybe_discriminant
                     }
                 }
             };
@@ -138,6 +160,42 @@ impl SwiftBridgeModule {
         };
 
         // User derives
+        if shared_enum.has_one_or_more_variants_with_data() {
+            if shared_enum.derive.copy {
+                derives.push(quote! {Copy});
+            }
+            if shared_enum.derive.clone {
+                derives.push(quote! {Clone});
+            }
+        }
+        if shared_enum.derive.partial_eq {
+            derives.push(quote! {::std::cmp::PartialEq});
+        }
+        if shared_enum.derive.eq {
+            derives.push(quote! {::std::cmp::Eq});
+        }
+        if shared_enum.derive.hash {
+            derives.push(quote! {::std::hash::Hash});
+        }
+        if shared_enum.derive.partial_ord {
+            derives.push(quote! {::std::cmp::PartialOrd});
+        }
+        if shared_enum.derive.ord {
+            derives.push(quote! {::std::cmp::Ord});
+        }
+        for serde_derive in shared_enum.derive.serde.iter() {
+            derives.push(quote! {#serde_derive});
+        }
+        let serde_attrs = if forward_serde_attrs {
+            shared_enum.serde_attrs.as_slice()
+        } else {
+            &[]
+        };
+        let maybe_repr = shared_enum
+            .repr
+            .as_ref()
+            .map(|repr| quote! { #[repr(#repr)] });
+
         let mut derive_impl_ffi_bridges = vec![];
 
         // We currently only allow derive(Debug) on non data carrying enums in order
@@ -165,23 +223,41 @@ impl SwiftBridgeModule {
             generate_vec_of_transparent_enum_functions(&shared_enum)
         };
 
-        let definition = quote! {
-            #[derive(#(#derives),*)]
-            pub enum #enum_name {
-                #(#enum_variants),*
+        let enum_declaration = if declared_variants.is_some() {
+            let params = generics.angle_bracketed_params_tokens();
+            quote! {
+                #[derive(#(#derives),*)]
+                #maybe_repr
+                #(#serde_attrs)*
+                pub enum #enum_name #params {
+                    #(#enum_variants),*
+                }
             }
+        } else {
+            quote! {}
+        };
 
-            #[repr(C)]
+        // The FFI representation's tag has the same integer type as the C header's tag.
+        let ffi_repr = match shared_enum.repr_integer_type() {
+            Some(repr) if shared_enum.all_variants_empty() => quote! { #[repr(#repr)] },
+            Some(repr) => quote! { #[repr(C, #repr)] },
+            None => quote! { #[repr(C)] },
+        };
+
+        let definition = quote! {
+            #enum_declaration
+
+            #ffi_repr
             #[doc(hidden)]
             pub enum #enum_ffi_name {
                 #(#enum_ffi_variants),*
             }
 
-            impl #swift_bridge_path::SharedEnum for #enum_name {
+            impl #swift_bridge_path::SharedEnum for #enum_name #ty_generics {
                 type FfiRepr = #enum_ffi_name;
             }
 
-            impl #enum_name {
+            impl #enum_name #ty_generics {
                 #[doc(hidden)]
                 #[inline(always)]
                 pub fn into_ffi_repr(self) -> #enum_ffi_name {
@@ -194,7 +270,7 @@ impl SwiftBridgeModule {
             impl #enum_ffi_name {
                 #[doc(hidden)]
                 #[inline(always)]
-                pub fn into_rust_repr(self) -> #enum_name {
+                pub fn into_rust_repr(self) -> #enum_name #ty_generics {
                     match self {
                         #(#convert_ffi_variants_to_rust),*
                     }
@@ -211,7 +287,7 @@ impl SwiftBridgeModule {
             impl #option_enum {
                 #[doc(hidden)]
                 #[inline(always)]
-                pub fn into_rust_repr(self) -> Option<#enum_name> {
+                pub fn into_rust_repr(self) -> Option<#enum_name #ty_generics> {
                     if self.is_some {
                         Some(unsafe { self.val.assume_init().into_rust_repr() })
                     } else {
@@ -221,7 +297,7 @@ impl SwiftBridgeModule {
 
                 #[doc(hidden)]
                 #[inline(always)]
-                pub fn from_rust_repr(val: Option<#enum_name>) -> #option_enum {
+                pub fn from_rust_repr(val: Option<#enum_name #ty_generics>) -> #option_enum {
                     if let Some(val) = val {
                         #option_enum {
                             is_some: true,
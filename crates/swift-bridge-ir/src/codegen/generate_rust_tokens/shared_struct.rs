@@ -1,11 +1,11 @@
 //! More tests can be found in
 //! crates/swift-bridge-ir/src/codegen/codegen_tests/shared_struct_codegen_tests.rs
 
-use crate::bridged_type::{BridgedType, SharedStruct};
-use crate::{SwiftBridgeModule, SWIFT_BRIDGE_PREFIX};
+use crate::bridged_type::{BridgedType, SharedStruct, StdLibType, StructFields};
+use crate::codegen::generate_rust_tokens::vec::vec_of_transparent_struct::generate_vec_of_transparent_struct_functions;
+use crate::SwiftBridgeModule;
 use proc_macro2::{Span, TokenStream};
-use quote::quote;
-use syn::Ident;
+use quote::{format_ident, quote};
 
 impl SwiftBridgeModule {
     /// Generate the tokens for a shared struct.
@@ -20,25 +20,45 @@ impl SwiftBridgeModule {
         let struct_name = &shared_struct.name;
         let swift_bridge_path = &self.swift_bridge_path;
 
-        let struct_ffi_name = format!("{}{}", SWIFT_BRIDGE_PREFIX, struct_name);
-        let struct_ffi_name = Ident::new(&struct_ffi_name, struct_name.span());
+        let struct_ffi_name = shared_struct.ffi_name_ident();
 
         let option_struct = shared_struct.ffi_option_name_tokens();
 
-        let struct_fields: Vec<TokenStream> = shared_struct
-            .fields
-            .normalized_fields()
-            .iter()
-            .map(|norm_field| {
-                let maybe_name_and_colon = norm_field.maybe_name_and_colon();
-                let ty = &norm_field.ty;
+        // `#[serde(...)]` attributes are an error unless the struct derives a serde trait.
+        let forward_serde_attrs = !shared_struct.derives.serde.is_empty();
+
+        let declare_fields = |fields: &StructFields| {
+            let struct_fields: Vec<TokenStream> = fields
+                .normalized_fields()
+                .iter()
+                .enumerate()
+                .map(|(idx, norm_field)| {
+                    let maybe_name_and_colon = norm_field.maybe_name_and_colon();
+                    let ty = &norm_field.ty;
+
+                    // Pointee types such as `c_void` are resolved in the parent module.
+                    let ty = match BridgedType::new_with_type(ty, &self.types) {
+                        Some(pointer @ BridgedType::StdLib(StdLibType::Pointer(_))) => {
+                            pointer.to_rust_type_path(&self.types)
+                        }
+                        _ => quote! { #ty },
+                    };
 
-                quote! {
-                    pub #maybe_name_and_colon #ty
-                }
-            })
-            .collect();
-        let struct_fields = shared_struct.fields.wrap_declaration_fields(&struct_fields);
+                    let serde_attrs = match fields {
+                        StructFields::Named(named) if forward_serde_attrs => {
+                            named[idx].serde_attrs.as_slice()
+                        }
+                        _ => &[],
+                    };
+
+                    quote! {
+                        #(#serde_attrs)*
+                        pub #maybe_name_and_colon #ty
+                    }
+                })
+                .collect();
+            fields.wrap_declaration_fields(&struct_fields)
+        };
 
         let repr_c_struct_fields: Vec<TokenStream> = shared_struct
             .fields
@@ -98,14 +118,77 @@ impl SwiftBridgeModule {
         if shared_struct.derives.clone {
             derives.push(quote! {Clone});
         }
+        if shared_struct.derives.partial_eq {
+            derives.push(quote! {::std::cmp::PartialEq});
+        }
+        if shared_struct.derives.eq {
+            derives.push(quote! {::std::cmp::Eq});
+        }
+        if shared_struct.derives.hash {
+            derives.push(quote! {::std::hash::Hash});
+        }
+        if shared_struct.derives.debug {
+            derives.push(quote! {::std::fmt::Debug});
+        }
+        for serde_derive in shared_struct.derives.serde.iter() {
+            derives.push(quote! {#serde_derive});
+        }
+        let serde_attrs = if forward_serde_attrs {
+            shared_struct.serde_attrs.as_slice()
+        } else {
+            &[]
+        };
+
+        let derive_debug_ffi_bridge = if shared_struct.has_swift_debug_description(&self.types) {
+            // __swift_bridge__$SomeStruct$Debug
+            let export_name = format!("{}$Debug", shared_struct.ffi_name_string());
+            // __swift_bridge__SomeStruct_Debug
+            let fn_name = format_ident!("{}_Debug", struct_ffi_name);
+            quote! {
+                #[export_name = #export_name]
+                pub extern "C" fn #fn_name(this: #struct_ffi_name) -> *mut swift_bridge::string::RustString {
+                    swift_bridge::string::RustString(format!("{:?}", this.into_rust_repr())).box_into_raw()
+                }
+            }
+        } else {
+            quote! {}
+        };
+
+        let vec_support = if shared_struct.is_vectorizable() {
+            generate_vec_of_transparent_struct_functions(shared_struct)
+        } else {
+            quote! {}
+        };
+
+        let generics = shared_struct.lifetime_generics();
+        let ty_generics = shared_struct.ty_generics(&self.types);
+
+        // A generic struct gets declared once, alongside its first instantiation.
+        let struct_declaration = if shared_struct.generics.is_empty() {
+            let struct_fields = declare_fields(&shared_struct.fields);
+            quote! {
+                #[derive(#(#derives),*)]
+                #(#serde_attrs)*
+                pub struct #struct_name #generics #struct_fields
+            }
+        } else if let Some(declared_fields) = &shared_struct.declared_fields {
+            let params = shared_struct.generics.angle_bracketed_params_tokens();
+            let struct_fields = declare_fields(declared_fields);
+            quote! {
+                #[derive(#(#derives),*)]
+                #(#serde_attrs)*
+                pub struct #struct_name #params #struct_fields
+            }
+        } else {
+            quote! {}
+        };
 
         let definition = quote! {
-            #[derive(#(#derives),*)]
-            pub struct #struct_name #struct_fields
+            #struct_declaration
 
             #struct_ffi_repr
 
-            impl #swift_bridge_path::SharedStruct for #struct_name {
+            impl #generics #swift_bridge_path::SharedStruct for #struct_name #ty_generics {
                 type FfiRepr = #struct_ffi_name;
             }
 
@@ -114,7 +197,7 @@ impl SwiftBridgeModule {
             impl #struct_ffi_name {
                 #[doc(hidden)]
                 #[inline(always)]
-                pub fn into_rust_repr(self) -> #struct_name {
+                pub fn into_rust_repr #generics (self) -> #struct_name #ty_generics {
                     #convert_ffi_to_rust
                 }
             }
@@ -129,7 +212,7 @@ impl SwiftBridgeModule {
             impl #option_struct {
                 #[doc(hidden)]
                 #[inline(always)]
-                pub fn into_rust_repr(self) -> Option<#struct_name> {
+                pub fn into_rust_repr #generics (self) -> Option<#struct_name #ty_generics> {
                     if self.is_some {
                         Some(unsafe { self.val.assume_init().into_rust_repr() })
                     } else {
@@ -139,7 +222,7 @@ impl SwiftBridgeModule {
 
                 #[doc(hidden)]
                 #[inline(always)]
-                pub fn from_rust_repr(val: Option<#struct_name>) -> #option_struct {
+                pub fn from_rust_repr #generics (val: Option<#struct_name #ty_generics>) -> #option_struct {
                     if let Some(val) = val {
                         #option_struct {
                             is_some: true,
@@ -153,6 +236,10 @@ impl SwiftBridgeModule {
                     }
                 }
             }
+
+            #vec_support
+
+            #derive_debug_ffi_bridge
         };
 
         Some(definition)
@@ -96,7 +96,8 @@ pub(in super::super) fn generate_vec_of_transparent_enum_functions(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{bridged_type::DeriveAttrs, test_utils::assert_tokens_eq};
+    use crate::bridged_type::{DeriveAttrs, SharedTypeGenerics};
+    use crate::test_utils::assert_tokens_eq;
     use proc_macro2::{Ident, Span};
 
     /// Verify that we can generate the functions for an opaque Rust type that get exposed to Swift
@@ -169,6 +170,13 @@ mod tests {
             already_declared: false,
             swift_name: None,
             derive: DeriveAttrs::default(),
+            codable: false,
+            serde_attrs: vec![],
+            case_iterable: false,
+            raw_value_type: None,
+            repr: None,
+            generics: SharedTypeGenerics::default(),
+            declared_variants: None,
         };
         assert_tokens_eq(
             &generate_vec_of_transparent_enum_functions(&shared_enum),
@@ -1,7 +1,9 @@
 use std::collections::HashMap;
 use syn::Path;
 
+use crate::bridged_type::boxed_fn::BoxedFnKind;
 use crate::bridged_type::{BridgeableType, BridgedType, BuiltInResult, TypePosition};
+use crate::codegen::generate_swift::arc::generate_arc_class;
 use crate::codegen::generate_swift::generate_function_swift_calls_rust::gen_func_swift_calls_rust;
 use crate::codegen::generate_swift::opaque_copy_type::generate_opaque_copy_struct;
 use crate::codegen::generate_swift::swift_class::generate_swift_class;
@@ -14,6 +16,9 @@ use crate::parse::{
 use crate::parsed_extern_fn::ParsedExternFn;
 use crate::SwiftBridgeModule;
 
+mod arc;
+mod bridged_trait;
+mod codable;
 mod vec;
 
 mod generate_function_swift_calls_rust;
@@ -39,11 +44,19 @@ impl SwiftBridgeModule {
 
         for function in &self.functions {
             if function.host_lang.is_rust() {
+                swift += &gen_swift_closures_exposed_to_rust(
+                    function,
+                    &self.types,
+                    &self.swift_bridge_path,
+                );
+
                 if let Some(ty) = function.associated_type.as_ref() {
                     match ty {
-                        TypeDeclaration::Shared(_) => {
-                            //
-                            todo!("Think about what to do here..")
+                        TypeDeclaration::Shared(shared_ty) => {
+                            associated_funcs_and_methods
+                                .entry(shared_ty.name().to_string())
+                                .or_default()
+                                .push(function);
                         }
                         TypeDeclaration::Opaque(opaque_ty) => {
                             associated_funcs_and_methods
@@ -53,7 +66,11 @@ impl SwiftBridgeModule {
 
                             if function.is_swift_identifiable {
                                 let identifiable_protocol = IdentifiableProtocol {
-                                    func_name: function.func.sig.ident.to_string(),
+                                    func_name: function
+                                        .swift_name_override
+                                        .as_ref()
+                                        .map(|swift_name| swift_name.value())
+                                        .unwrap_or_else(|| function.func.sig.ident.to_string()),
                                     return_ty: BridgedType::new_with_return_type(
                                         &function.func.sig.output,
                                         &self.types,
@@ -89,17 +106,36 @@ impl SwiftBridgeModule {
             swift += "\n";
         }
 
+        for trait_declaration in self.types.traits() {
+            swift += &self.generate_trait_string(trait_declaration);
+            swift += "\n";
+        }
+
         for ty in self.types.types() {
             match ty {
-                TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)) => {
-                    if let Some(swift_struct) = self.generate_shared_struct_string(shared_struct) {
-                        swift += &swift_struct;
+                TypeDeclaration::Shared(shared_ty) => {
+                    let swift_shared_ty = match shared_ty {
+                        SharedTypeDeclaration::Struct(shared_struct) => {
+                            self.generate_shared_struct_string(shared_struct)
+                        }
+                        SharedTypeDeclaration::Enum(shared_enum) => {
+                            self.generate_shared_enum_string(shared_enum)
+                        }
+                    };
+                    if let Some(swift_shared_ty) = swift_shared_ty {
+                        swift += &swift_shared_ty;
                         swift += "\n";
                     }
-                }
-                TypeDeclaration::Shared(SharedTypeDeclaration::Enum(shared_enum)) => {
-                    if let Some(swift_enum) = self.generate_shared_enum_string(shared_enum) {
-                        swift += &swift_enum;
+
+                    if let Some(functions) =
+                        associated_funcs_and_methods.get(&shared_ty.name().to_string())
+                    {
+                        swift += &generate_shared_type_methods_extension(
+                            &shared_ty.swift_name_string(),
+                            functions,
+                            &self.types,
+                            &self.swift_bridge_path,
+                        );
                         swift += "\n";
                     }
                 }
@@ -136,10 +172,28 @@ impl SwiftBridgeModule {
                                 if ty.attributes.copy.is_none() && ty.generics.len() == 0 {
                                     swift += &generate_vectorizable_extension(&ty);
                                     swift += "\n";
+
+                                    if self.uses_arc_of(&ty.ty) {
+                                        swift += &generate_arc_class(ty);
+                                        swift += "\n";
+
+                                        if ty.attributes.sendable
+                                            && !self.types.implements_sendable_trait(&ty.ty)
+                                        {
+                                            let ty_name = ty.ty_name_string();
+                                            swift += &format!(
+                                                "extension {ty_name}Arc: @unchecked Sendable {{}}\n"
+                                            );
+                                        }
+                                    }
                                 }
                             }
 
-                            if ty.attributes.sendable {
+                            // A type that implements a sendable trait inherits `Sendable` from
+                            // its `Ref` class.
+                            if ty.attributes.sendable
+                                && !self.types.implements_sendable_trait(&ty.ty)
+                            {
                                 let ty_name = ty.ty_name_string();
                                 swift += &format!("extension {ty_name}: @unchecked Sendable {{}}")
                             }
@@ -212,12 +266,87 @@ fn gen_function_exposes_swift_to_rust(
     swift_bridge_path: &Path,
 ) -> String {
     let is_async = func.sig.asyncness.is_some();
+    let maybe_availability = func.maybe_swift_availability("", types);
 
-    if is_async {
+    let generated_func = if is_async {
         gen_async_function_exposes_swift_to_rust(func, types, swift_bridge_path)
     } else {
         gen_sync_function_exposes_swift_to_rust(func, types, swift_bridge_path)
+    };
+
+    format!("{maybe_availability}{generated_func}")
+}
+
+/// Generate the functions that Rust uses to call and release the Swift closures that get passed
+/// to a Rust function.
+///
+/// Swift passes Rust a retained `__private__SwiftCallback` that holds the closure. Rust releases
+/// it when the `Box<dyn Fn>` is dropped.
+fn gen_swift_closures_exposed_to_rust(
+    func: &ParsedExternFn,
+    types: &TypeDeclarations,
+    swift_bridge_path: &Path,
+) -> String {
+    let mut generated = "".to_string();
+    let prefixed_fn_name = func.prefixed_fn_name();
+
+    for (idx, boxed_fn) in func.args_filtered_to_boxed_fns(types) {
+        let call_link_name = func.call_boxed_fn_link_name(idx);
+        let free_link_name = func.free_boxed_fn_link_name(idx);
+        let closure_ty = boxed_fn.to_swift_closure_type(types, swift_bridge_path);
+
+        let mut params = vec!["_ ptr: UnsafeMutableRawPointer".to_string()];
+        let mut args = vec![];
+        for (param_idx, param) in boxed_fn.params.iter().enumerate() {
+            let param_pos = TypePosition::FnArg(HostLang::Swift, param_idx);
+            let param_ty = param.to_swift_type(param_pos, types, swift_bridge_path);
+
+            params.push(format!("_ arg{param_idx}: {param_ty}"));
+            args.push(param.convert_ffi_expression_to_swift_type(
+                &format!("arg{param_idx}"),
+                param_pos,
+                types,
+                swift_bridge_path,
+            ));
+        }
+        let params = params.join(", ");
+        let args = args.join(", ");
+
+        let call_closure = format!(
+            "Unmanaged<__private__SwiftCallback<{closure_ty}>>.fromOpaque(ptr).takeUnretainedValue().callback({args})"
+        );
+        let call_closure = boxed_fn.ret.convert_swift_expression_to_ffi_type(
+            &call_closure,
+            types,
+            TypePosition::FnReturn(HostLang::Swift),
+        );
+        let maybe_ret = if boxed_fn.ret.is_null() {
+            "".to_string()
+        } else {
+            let ret = boxed_fn.ret.to_swift_type(
+                TypePosition::FnReturn(HostLang::Swift),
+                types,
+                swift_bridge_path,
+            );
+            format!(" -> {ret}")
+        };
+
+        generated += &format!(
+            r#"@_cdecl("{call_link_name}")
+func {prefixed_fn_name}$param{idx} ({params}){maybe_ret} {{
+    {call_closure}
+}}
+
+@_cdecl("{free_link_name}")
+func {prefixed_fn_name}$_free$param{idx} (_ ptr: UnsafeMutableRawPointer) {{
+    Unmanaged<AnyObject>.fromOpaque(ptr).release()
+}}
+
+"#
+        );
     }
+
+    generated
 }
 
 /// Common metadata extracted from a ParsedExternFn for generating Swift wrappers.
@@ -252,19 +381,29 @@ impl SwiftFnMetadata {
 /// For methods, this generates code like:
 /// `Unmanaged<TypeName>.fromOpaque(this).takeUnretainedValue().fn_name(args)`
 ///
+/// For methods of a bridged trait, `this` is any object that conforms to the trait's protocol:
+/// `(Unmanaged<AnyObject>.fromOpaque(this).takeUnretainedValue() as! any TraitName).fn_name(args)`
+///
 /// For static methods:
 /// `TypeName::fn_name(args)`
 ///
 /// For freestanding functions:
 /// `fn_name(args)`
-fn build_swift_call_expression(func: &ParsedExternFn, fn_name: &str, args: &str) -> String {
+fn build_swift_call_expression(
+    func: &ParsedExternFn,
+    fn_name: &str,
+    args: &str,
+    types: &TypeDeclarations,
+) -> String {
     if let Some(associated_type) = func.associated_type.as_ref() {
         let ty_name = match associated_type {
             TypeDeclaration::Shared(_) => todo!(),
             TypeDeclaration::Opaque(associated_type) => associated_type.to_string(),
         };
 
-        if func.is_method() {
+        if func.is_method() && types.get_trait(&ty_name).is_some() {
+            format!("(Unmanaged<AnyObject>.fromOpaque(this).takeUnretainedValue() as! any {ty_name}).{fn_name}({args})")
+        } else if func.is_method() {
             format!("Unmanaged<{ty_name}>.fromOpaque(this).takeUnretainedValue().{fn_name}({args})")
         } else {
             format!("{ty_name}::{fn_name}({args})")
@@ -299,7 +438,7 @@ fn gen_async_function_exposes_swift_to_rust(
     let maybe_result = return_ty.as_ref().and_then(|ty| ty.as_result());
 
     // Build the async call expression
-    let call_expression = build_swift_call_expression(func, fn_name, &args);
+    let call_expression = build_swift_call_expression(func, fn_name, &args, types);
 
     // Build params_str, task_body, optional typed_throws_check, and pre_task_bindings based on whether this is a Result type or not
     let (params_str, task_body, typed_throws_check, pre_task_bindings) = if let Some(result) =
@@ -382,9 +521,10 @@ fn gen_async_function_exposes_swift_to_rust(
         } else {
             original_params.clone() + ", _: " + &err_swift_ty + ".Type"
         };
+        let maybe_availability = func.maybe_swift_availability("", types);
         let typed_throws_check = format!(
             r#"
-func {prefixed_fn_name}__TypedThrowsCheck({checker_params}) async throws({err_swift_ty}) {{
+{maybe_availability}func {prefixed_fn_name}__TypedThrowsCheck({checker_params}) async throws({err_swift_ty}) {{
     _ = try await {call_expression}
 }}"#
         );
@@ -567,11 +707,7 @@ fn gen_sync_function_exposes_swift_to_rust(
             };
 
             if func.is_method() {
-                call_fn = format!(
-                    "Unmanaged<{ty_name}>.fromOpaque(this).takeUnretainedValue().{call_fn}",
-                    ty_name = ty_name,
-                    call_fn = call_fn
-                );
+                call_fn = build_swift_call_expression(func, fn_name, &args, types);
                 call_fn = built_in.convert_swift_expression_to_ffi_type(
                     &call_fn,
                     types,
@@ -593,7 +729,7 @@ fn gen_sync_function_exposes_swift_to_rust(
         todo!("Push to ParsedErrors")
     };
 
-    let mut rust_fn_once_callback_classes = "".to_string();
+    let mut rust_callback_classes = "".to_string();
 
     let maybe_associated_ty = if let Some(ty) = func.associated_type.as_ref() {
         format!("${}", ty.as_opaque().unwrap().ty.to_string())
@@ -602,7 +738,7 @@ fn gen_sync_function_exposes_swift_to_rust(
     };
 
     for (idx, boxed_fn) in func.args_filtered_to_boxed_fns(types) {
-        if boxed_fn.does_not_have_params_or_return() {
+        if boxed_fn.uses_no_args_no_return_support() {
             continue;
         }
 
@@ -613,7 +749,7 @@ fn gen_sync_function_exposes_swift_to_rust(
             "".to_string()
         } else {
             let ret = boxed_fn.ret.to_swift_type(
-                TypePosition::FnArg(HostLang::Rust, idx),
+                TypePosition::FnReturn(HostLang::Rust),
                 types,
                 swift_bridge_path,
             );
@@ -631,10 +767,44 @@ fn gen_sync_function_exposes_swift_to_rust(
         );
 
         let maybe_generics = boxed_fn.maybe_swift_generics(types);
+        let class_name = format!(
+            "{}{maybe_associated_ty}${fn_name}$param{idx}",
+            boxed_fn.swift_callback_class_prefix()
+        );
+
+        if boxed_fn.kind != BoxedFnKind::FnOnce {
+            // Swift can call a `Box<dyn Fn>` or `Box<dyn FnMut>` any number of times. The boxed
+            // function gets freed when the last Swift closure that captured it is released.
+            let maybe_sendable = if boxed_fn.rust_callback_is_sendable() {
+                ": @unchecked Sendable"
+            } else {
+                ""
+            };
+
+            rust_callback_classes += &format!(
+                r#"
+class {class_name}{maybe_sendable} {{
+    let ptr: UnsafeMutableRawPointer
+
+    init(ptr: UnsafeMutableRawPointer) {{
+        self.ptr = ptr
+    }}
+
+    deinit {{
+        __swift_bridge__{maybe_associated_ty}${fn_name}$_free$param{idx}(ptr)
+    }}
+
+    func call{maybe_generics}({params_as_swift}){maybe_ret} {{
+        return {ret_value}
+    }}
+}}"#
+            );
+            continue;
+        }
 
-        rust_fn_once_callback_classes += &format!(
+        rust_callback_classes += &format!(
             r#"
-class __private__RustFnOnceCallback{maybe_associated_ty}${fn_name}$param{idx} {{
+class {class_name} {{
     var ptr: UnsafeMutableRawPointer
     var called = false
 
@@ -660,7 +830,7 @@ class __private__RustFnOnceCallback{maybe_associated_ty}${fn_name}$param{idx} {{
     }
 
     let callback_initializers =
-        func.fnonce_callback_initializers(&fn_name, &maybe_associated_ty, types);
+        func.boxed_fn_callback_initializers(&fn_name, &maybe_associated_ty, types);
     if !callback_initializers.is_empty() {
         let maybe_ret = if ret.is_empty() {
             "let _ = "
@@ -675,7 +845,7 @@ class __private__RustFnOnceCallback{maybe_associated_ty}${fn_name}$param{idx} {{
         r#"@_cdecl("{link_name}")
 func {prefixed_fn_name} ({params}){ret} {{
     {call_fn}
-}}{rust_fn_once_callback_classes}
+}}{rust_callback_classes}
 "#,
         link_name = link_name,
         prefixed_fn_name = prefixed_fn_name,
@@ -721,20 +891,7 @@ fn gen_sync_result_function_exposes_swift_to_rust(
     );
 
     // Build the call expression
-    let call_expr = if let Some(associated_type) = func.associated_type.as_ref() {
-        let ty_name = match associated_type {
-            TypeDeclaration::Shared(_) => todo!(),
-            TypeDeclaration::Opaque(associated_type) => associated_type.to_string(),
-        };
-
-        if func.is_method() {
-            format!("Unmanaged<{ty_name}>.fromOpaque(this).takeUnretainedValue().{fn_name}({args})")
-        } else {
-            format!("{ty_name}::{fn_name}({args})")
-        }
-    } else {
-        format!("{fn_name}({args})")
-    };
+    let call_expr = build_swift_call_expression(func, fn_name, args, types);
 
     // Use ResultFfiReturnType to get the FFI return type (not the Ok type)
     let ret_ty = result.to_swift_type(TypePosition::ResultFfiReturnType, types, swift_bridge_path);
@@ -793,9 +950,10 @@ fn gen_sync_result_function_exposes_swift_to_rust(
     } else {
         format!("{params}, _: {err_swift_ty}.Type")
     };
+    let maybe_availability = func.maybe_swift_availability("", types);
     let typed_throws_check = format!(
         r#"
-func {prefixed_fn_name}__TypedThrowsCheck({checker_params}) throws({err_swift_ty}) {{
+{maybe_availability}func {prefixed_fn_name}__TypedThrowsCheck({checker_params}) throws({err_swift_ty}) {{
     _ = try {call_expr}
 }}"#
     );
@@ -861,6 +1019,32 @@ fn generate_swift_class_methods(
     }
 }
 
+/// The methods and associated functions of a shared struct or enum.
+///
+/// ```swift
+/// extension SomeStruct {
+///     public func some_method() { ... }
+/// }
+/// ```
+fn generate_shared_type_methods_extension(
+    swift_name: &str,
+    functions: &[&ParsedExternFn],
+    types: &TypeDeclarations,
+    swift_bridge_path: &Path,
+) -> String {
+    let methods: Vec<String> = functions
+        .iter()
+        .map(|function| gen_func_swift_calls_rust(function, types, swift_bridge_path))
+        .collect();
+
+    format!(
+        r#"extension {swift_name} {{
+{methods}
+}}"#,
+        methods = methods.join("\n\n")
+    )
+}
+
 /// A Swift protocol that inherits from Swift's `Sendable` protocol.
 /// We use this to validate at compile time that a Swift type is `Sendable`.
 fn create_swift_sendable_protocol_check() -> &'static str {
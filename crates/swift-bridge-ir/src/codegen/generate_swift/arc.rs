@@ -0,0 +1,50 @@
+use proc_macro2::Ident;
+
+/// Generate the `public class MyRustTypeArc: MyRustTypeRef {}` for the Swift side.
+///
+/// An instance of the class owns one strong count of an `Arc<MyRustType>`, which gets released
+/// when the instance is deinitialized.
+/// Since an `Arc<T>` only gives out shared references the class only exposes `&self` methods.
+pub(super) fn generate_arc_class(ty: &Ident) -> String {
+    format!(
+        r#"public class {ty}Arc: {ty}Ref {{
+    public override init(ptr: UnsafeMutableRawPointer) {{
+        super.init(ptr: ptr)
+    }}
+
+    deinit {{
+        __swift_bridge__${ty}$_decrement_strong_count(ptr)
+    }}
+}}
+"#,
+        ty = ty.to_string()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::assert_trimmed_generated_equals_trimmed_expected;
+    use proc_macro2::Span;
+
+    /// Verify that we generate the class that holds an `Arc<MyRustType>` for the Swift side.
+    #[test]
+    fn generates_arc_class() {
+        let expected = r#"
+public class ARustTypeArc: ARustTypeRef {
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+
+    deinit {
+        __swift_bridge__$ARustType$_decrement_strong_count(ptr)
+    }
+}
+"#;
+
+        assert_trimmed_generated_equals_trimmed_expected(
+            &generate_arc_class(&Ident::new("ARustType", Span::call_site())),
+            &expected,
+        );
+    }
+}
@@ -0,0 +1,120 @@
+//! More tests can be found in
+//! crates/swift-bridge-ir/src/codegen/codegen_tests/bridged_trait.rs
+
+use crate::codegen::generate_swift::gen_function_exposes_swift_to_rust;
+use crate::codegen::generate_swift::generate_function_swift_calls_rust::gen_func_swift_calls_rust;
+use crate::parse::TraitDeclaration;
+use crate::SwiftBridgeModule;
+
+impl SwiftBridgeModule {
+    /// Generate the Swift code for a trait that was declared in the bridge module.
+    ///
+    /// ```no_run,ignore
+    /// public protocol Animal: AnyObject {
+    ///     func name() -> RustString
+    /// }
+    ///
+    /// extension DogRef: Animal {
+    ///     public func name() -> RustString {
+    ///         RustString(ptr: __swift_bridge__$Dog$Animal$name(ptr))
+    ///     }
+    /// }
+    ///
+    /// @_cdecl("__swift_bridge__$Animal$name")
+    /// func __swift_bridge__Animal_name (_ this: UnsafeMutableRawPointer) -> UnsafeMutableRawPointer {
+    ///     (Unmanaged<AnyObject>.fromOpaque(this).takeUnretainedValue() as! any Animal).name().intoRustString().ptr
+    /// }
+    ///
+    /// @_cdecl("__swift_bridge__$Animal$_free")
+    /// func __swift_bridge__Animal__free (_ ptr: UnsafeMutableRawPointer) {
+    ///     Unmanaged<AnyObject>.fromOpaque(ptr).release()
+    /// }
+    /// ```
+    pub(super) fn generate_trait_string(&self, trait_declaration: &TraitDeclaration) -> String {
+        let types = &self.types;
+        let swift_bridge_path = &self.swift_bridge_path;
+        let trait_name = &trait_declaration.name;
+
+        let mut requirements = "".to_string();
+        for method in trait_declaration.protocol_requirements() {
+            let params = method.to_swift_param_names_and_types(false, types, swift_bridge_path);
+            let ret = method.to_swift_return_type(types, swift_bridge_path);
+            let maybe_generics = method.maybe_swift_generics(types);
+
+            requirements += &format!(
+                "    func {}{maybe_generics}({params}){ret}\n",
+                method.sig.ident
+            );
+        }
+
+        let maybe_sendable = if trait_declaration.is_sendable() {
+            ", Sendable"
+        } else {
+            ""
+        };
+        let mut swift = format!(
+            r#"public protocol {trait_name}: AnyObject{maybe_sendable} {{
+{requirements}}}
+"#
+        );
+
+        let conforming_class_suffix = if trait_declaration.has_mutable_methods() {
+            "RefMut"
+        } else {
+            "Ref"
+        };
+        for implementor in trait_declaration.implementors.iter() {
+            let implementor_declaration = types
+                .get(&implementor.to_string())
+                .and_then(|ty| ty.as_opaque())
+                .unwrap();
+
+            let mut methods = vec![];
+            for method in trait_declaration.implementor_methods(implementor_declaration) {
+                methods.push(gen_func_swift_calls_rust(&method, types, swift_bridge_path));
+            }
+            let methods = methods.join("\n\n");
+
+            swift += &format!(
+                r#"extension {implementor}{conforming_class_suffix}: {trait_name} {{
+{methods}
+}}
+"#
+            );
+        }
+
+        // The trait's `Send` and `Sync` supertraits guarantee that the Rust type can be used from
+        // other threads.
+        // The implementor's `Ref` class is `Sendable` even if it implements multiple sendable
+        // traits, so we only declare it for the first one.
+        if trait_declaration.is_sendable() {
+            for implementor in trait_declaration.implementors.iter() {
+                let first_sendable_trait = types
+                    .traits()
+                    .iter()
+                    .find(|t| t.is_sendable() && t.implementors.contains(implementor))
+                    .unwrap();
+                if first_sendable_trait.name == *trait_name {
+                    swift += &format!("extension {implementor}Ref: @unchecked Sendable {{}}\n");
+                }
+            }
+        }
+
+        for method in trait_declaration.methods.iter() {
+            swift += &gen_function_exposes_swift_to_rust(method, types, swift_bridge_path);
+            swift += "\n";
+        }
+
+        let free_link_name = trait_declaration.free_swift_object_link_name();
+        let free_fn_name = trait_declaration.free_swift_object_ident();
+        swift += &format!(
+            r#"@_cdecl("{free_link_name}")
+func {free_fn_name} (_ ptr: UnsafeMutableRawPointer) {{
+    Unmanaged<AnyObject>.fromOpaque(ptr).release()
+}}
+"#
+        );
+
+        swift
+    }
+}
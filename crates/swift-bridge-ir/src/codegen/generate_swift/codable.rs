@@ -0,0 +1,376 @@
+//! More tests can be found in
+//! crates/swift-bridge-ir/src/codegen/codegen_tests/codable.rs
+
+use crate::bridged_type::shared_struct::StructField;
+use crate::bridged_type::{
+    EnumVariant, SharedEnum, SharedStruct, SharedTypeGenerics, StructFields,
+};
+use crate::SwiftBridgeModule;
+
+impl SwiftBridgeModule {
+    /// `#[swift_bridge(Codable)]` on a shared struct.
+    ///
+    /// The JSON matches serde's default representation of the Rust struct, so a struct with
+    /// named fields gets synthesized `Codable` conformance with `CodingKeys` that follow the
+    /// fields' `#[serde(rename = "...")]` attributes.
+    /// A tuple struct is a JSON array, a newtype struct is its field's JSON and a unit struct is
+    /// `null`.
+    pub(super) fn generate_shared_struct_codable_conformance(
+        &self,
+        shared_struct: &SharedStruct,
+        declared_fields: &StructFields,
+    ) -> String {
+        let struct_name = shared_struct.swift_name_string();
+        let generics = &shared_struct.generics;
+        let bounds = generics.swift_conditional_conformance_bounds("Codable");
+
+        let body = match declared_fields {
+            // Swift synthesizes the conformance of a struct that doesn't have any fields.
+            StructFields::Named(named) if named.is_empty() => "".to_string(),
+            StructFields::Named(named) => {
+                let coding_keys: Vec<String> = named
+                    .iter()
+                    .map(|field| coding_key(&field.swift_name_string(), &field.serde_name_string()))
+                    .collect();
+
+                format!(
+                    r#"    enum CodingKeys: String, CodingKey {{
+{coding_keys}
+    }}"#,
+                    coding_keys = coding_keys.join("\n")
+                )
+            }
+            StructFields::Unnamed(unnamed) if unnamed.len() == 1 => {
+                let field = &unnamed[0];
+                let ty = self.codable_field_swift_type(field, generics);
+                let name = field.swift_name_string();
+
+                format!(
+                    r#"    public init(from decoder: Decoder) throws {{
+        let container = try decoder.singleValueContainer()
+        self.init({name}: try container.decode({ty}.self))
+    }}
+
+    public func encode(to encoder: Encoder) throws {{
+        var container = encoder.singleValueContainer()
+        try container.encode(self.{name})
+    }}"#
+                )
+            }
+            StructFields::Unnamed(unnamed) => {
+                let decode_fields: Vec<String> = unnamed
+                    .iter()
+                    .map(|field| {
+                        format!(
+                            "{}: try container.decode({}.self)",
+                            field.swift_name_string(),
+                            self.codable_field_swift_type(field, generics)
+                        )
+                    })
+                    .collect();
+                let encode_fields: Vec<String> = unnamed
+                    .iter()
+                    .map(|field| {
+                        format!(
+                            "        try container.encode(self.{})",
+                            field.swift_name_string()
+                        )
+                    })
+                    .collect();
+
+                format!(
+                    r#"    public init(from decoder: Decoder) throws {{
+        var container = try decoder.unkeyedContainer()
+        self.init({decode_fields})
+    }}
+
+    public func encode(to encoder: Encoder) throws {{
+        var container = encoder.unkeyedContainer()
+{encode_fields}
+    }}"#,
+                    decode_fields = decode_fields.join(", "),
+                    encode_fields = encode_fields.join("\n")
+                )
+            }
+            StructFields::Unit => r#"    public init(from decoder: Decoder) throws {
+        self.init()
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        try container.encodeNil()
+    }"#
+            .to_string(),
+        };
+
+        if body.is_empty() {
+            return format!("\nextension {struct_name}: Codable{bounds} {{}}");
+        }
+
+        format!(
+            r#"
+extension {struct_name}: Codable{bounds} {{
+{body}
+}}"#
+        )
+    }
+
+    /// `#[swift_bridge(Codable)]` on a shared enum.
+    ///
+    /// The JSON matches serde's default externally tagged representation of the Rust enum.
+    /// A unit variant is its name, and a variant with data is an object whose only key is the
+    /// variant's name.
+    ///
+    /// ```json
+    /// "UnitVariant"
+    /// {"NewtypeVariant": 1}
+    /// {"TupleVariant": [1, "two"]}
+    /// {"StructVariant": {"field": 1}}
+    /// ```
+    pub(super) fn generate_shared_enum_codable_conformance(
+        &self,
+        shared_enum: &SharedEnum,
+        declared_variants: &[EnumVariant],
+    ) -> String {
+        let enum_name = shared_enum.swift_name_string();
+        let generics = &shared_enum.generics;
+        let bounds = generics.swift_conditional_conformance_bounds("Codable");
+
+        let coding_keys: Vec<String> = declared_variants
+            .iter()
+            .map(|variant| coding_key(&variant.swift_name_string(), &variant.serde_name_string()))
+            .collect();
+
+        let mut variant_coding_keys = "".to_string();
+        let mut decode_unit_variants = "".to_string();
+        let mut decode_data_variants = "".to_string();
+        let mut encode_variants = "".to_string();
+
+        for variant in declared_variants {
+            let name = variant.swift_name_string();
+
+            match &variant.fields {
+                StructFields::Named(named) => {
+                    let keys: Vec<String> = named
+                        .iter()
+                        .map(|field| {
+                            coding_key(&field.swift_name_string(), &field.serde_name_string())
+                        })
+                        .collect();
+                    variant_coding_keys += &format!(
+                        r#"
+
+    enum {name}CodingKeys: String, CodingKey {{
+{keys}
+    }}"#,
+                        keys = keys.join("\n")
+                    );
+
+                    let decode_fields: Vec<String> = named
+                        .iter()
+                        .map(|field| {
+                            format!(
+                                "{field_name}: try values.decode({ty}.self, forKey: .{field_name})",
+                                field_name = field.swift_name_string(),
+                                ty = self.codable_field_swift_type(field, generics)
+                            )
+                        })
+                        .collect();
+                    decode_data_variants += &format!(
+                        r#"
+        case .{name}:
+            let values = try container.nestedContainer(keyedBy: {name}CodingKeys.self, forKey: .{name})
+            self = .{name}({decode_fields})"#,
+                        decode_fields = decode_fields.join(", ")
+                    );
+
+                    let bindings: Vec<String> = named
+                        .iter()
+                        .map(|field| format!("let {}", field.swift_name_string()))
+                        .collect();
+                    let encode_fields: Vec<String> = named
+                        .iter()
+                        .map(|field| {
+                            format!(
+                                "            try values.encode({field_name}, forKey: .{field_name})",
+                                field_name = field.swift_name_string()
+                            )
+                        })
+                        .collect();
+                    encode_variants += &format!(
+                        r#"
+        case .{name}({bindings}):
+            var container = encoder.container(keyedBy: CodingKeys.self)
+            var values = container.nestedContainer(keyedBy: {name}CodingKeys.self, forKey: .{name})
+{encode_fields}"#,
+                        bindings = bindings.join(", "),
+                        encode_fields = encode_fields.join("\n")
+                    );
+                }
+                StructFields::Unnamed(unnamed) if unnamed.len() == 1 => {
+                    let ty = self.codable_field_swift_type(&unnamed[0], generics);
+                    decode_data_variants += &format!(
+                        r#"
+        case .{name}:
+            self = .{name}(try container.decode({ty}.self, forKey: .{name}))"#
+                    );
+                    encode_variants += &format!(
+                        r#"
+        case .{name}(let _0):
+            var container = encoder.container(keyedBy: CodingKeys.self)
+            try container.encode(_0, forKey: .{name})"#
+                    );
+                }
+                StructFields::Unnamed(unnamed) => {
+                    let decode_fields: Vec<String> = unnamed
+                        .iter()
+                        .map(|field| {
+                            format!(
+                                "try values.decode({}.self)",
+                                self.codable_field_swift_type(field, generics)
+                            )
+                        })
+                        .collect();
+                    decode_data_variants += &format!(
+                        r#"
+        case .{name}:
+            var values = try container.nestedUnkeyedContainer(forKey: .{name})
+            self = .{name}({decode_fields})"#,
+                        decode_fields = decode_fields.join(", ")
+                    );
+
+                    let bindings: Vec<String> = unnamed
+                        .iter()
+                        .map(|field| format!("let {}", field.swift_name_string()))
+                        .collect();
+                    let encode_fields: Vec<String> = unnamed
+                        .iter()
+                        .map(|field| {
+                            format!(
+                                "            try values.encode({})",
+                                field.swift_name_string()
+                            )
+                        })
+                        .collect();
+                    encode_variants += &format!(
+                        r#"
+        case .{name}({bindings}):
+            var container = encoder.container(keyedBy: CodingKeys.self)
+            var values = container.nestedUnkeyedContainer(forKey: .{name})
+{encode_fields}"#,
+                        bindings = bindings.join(", "),
+                        encode_fields = encode_fields.join("\n")
+                    );
+                }
+                StructFields::Unit => {
+                    decode_unit_variants += &format!(
+                        r#"
+            case .{name}:
+                self = .{name}"#
+                    );
+                    encode_variants += &format!(
+                        r#"
+        case .{name}:
+            var container = encoder.singleValueContainer()
+            try container.encode(CodingKeys.{name}.rawValue)"#
+                    );
+                }
+            }
+        }
+
+        let has_unit_variants = !decode_unit_variants.is_empty();
+        let has_data_variants = !decode_data_variants.is_empty();
+
+        let decode_unit_variants = if has_unit_variants {
+            format!(
+                r#"
+        if let tag = try? decoder.singleValueContainer().decode(String.self) {{
+            switch CodingKeys(rawValue: tag) {{{decode_unit_variants}
+            default:
+                throw DecodingError.dataCorrupted(DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Unknown variant \(tag)"))
+            }}
+            return
+        }}
+"#
+            )
+        } else {
+            "".to_string()
+        };
+
+        let decode_data_variants = if has_data_variants {
+            // Unit variants are strings, so they can't be the key of a data variant.
+            let maybe_default = if has_unit_variants {
+                r#"
+        default:
+            throw DecodingError.dataCorrupted(DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Expected a variant with data"))"#
+            } else {
+                ""
+            };
+
+            format!(
+                r#"
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        guard container.allKeys.count == 1 else {{
+            throw DecodingError.dataCorrupted(DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Expected exactly one variant"))
+        }}
+        switch container.allKeys[0] {{{decode_data_variants}{maybe_default}
+        }}
+"#
+            )
+        } else if has_unit_variants {
+            r#"
+        throw DecodingError.dataCorrupted(DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Expected a variant name"))
+"#
+            .to_string()
+        } else {
+            r#"
+        throw DecodingError.dataCorrupted(DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Enum has no variants"))
+"#
+            .to_string()
+        };
+
+        let maybe_coding_keys = if coding_keys.is_empty() {
+            "".to_string()
+        } else {
+            format!(
+                r#"    enum CodingKeys: String, CodingKey {{
+{coding_keys}
+    }}{variant_coding_keys}
+
+"#,
+                coding_keys = coding_keys.join("\n")
+            )
+        };
+
+        format!(
+            r#"
+extension {enum_name}: Codable{bounds} {{
+{maybe_coding_keys}    public init(from decoder: Decoder) throws {{{decode_unit_variants}{decode_data_variants}    }}
+
+    public func encode(to encoder: Encoder) throws {{
+        switch self {{{encode_variants}
+        }}
+    }}
+}}"#
+        )
+    }
+
+    /// The Swift type that a field gets encoded and decoded as.
+    /// This uses the generic type parameters of a generic struct or enum, such as `T`.
+    fn codable_field_swift_type(
+        &self,
+        field: &impl StructField,
+        generics: &SharedTypeGenerics,
+    ) -> String {
+        generics.declared_field_swift_type(field.field_type(), &self.types, &self.swift_bridge_path)
+    }
+}
+
+/// `case field = "renamed"`, or `case field` if the serialized name is the same as the Swift name.
+fn coding_key(swift_name: &str, serde_name: &str) -> String {
+    if swift_name == serde_name {
+        format!("        case {swift_name}")
+    } else {
+        format!("        case {swift_name} = \"{serde_name}\"")
+    }
+}
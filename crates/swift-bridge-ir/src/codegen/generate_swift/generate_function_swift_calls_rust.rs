@@ -12,6 +12,12 @@ pub(super) fn gen_func_swift_calls_rust(
     swift_bridge_path: &Path,
 ) -> String {
     let fn_name = function.sig.ident.to_string();
+    // Each instantiation of a generic function has its own link name, but they all share the
+    // same Swift function name, so Swift sees them as overloads.
+    let link_fn_name = match function.instantiation_name() {
+        Some(instantiation) => format!("{}${}", fn_name, instantiation),
+        None => fn_name.clone(),
+    };
     let params = function.to_swift_param_names_and_types(false, types, swift_bridge_path);
     let call_args = function.to_swift_call_args(true, false, types, swift_bridge_path);
     let call_fn = if function.sig.asyncness.is_some() {
@@ -21,29 +27,28 @@ pub(super) fn gen_func_swift_calls_rust(
             format!(", {}", call_args)
         };
 
-        format!("{}(wrapperPtr, onComplete{})", fn_name, maybe_args)
+        format!("{}(wrapperPtr, onComplete{})", link_fn_name, maybe_args)
     } else {
-        format!("{}({})", fn_name, call_args)
+        format!("{}({})", link_fn_name, call_args)
     };
 
     let maybe_type_name_segment = if let Some(ty) = function.associated_type.as_ref() {
-        match ty {
-            TypeDeclaration::Shared(_) => {
-                //
-                todo!()
-            }
-            TypeDeclaration::Opaque(ty) => {
-                format!("${}", ty.to_string())
-            }
-        }
+        format!("${}", ty.name_string())
     } else {
         "".to_string()
     };
+    // Shared structs and enums are Swift value types.
+    let shared_ty = function.associated_shared_type();
+    let maybe_trait_name_segment = function
+        .trait_name
+        .as_ref()
+        .map(|trait_name| format!("${}", trait_name))
+        .unwrap_or_default();
 
     let maybe_static_class_func = if function.associated_type.is_some()
         && (!function.is_method() && !function.is_swift_initializer)
     {
-        if function.is_copy_method_on_opaque_type() {
+        if function.is_copy_method_on_opaque_type() || shared_ty.is_some() {
             "static "
         } else {
             "class "
@@ -53,7 +58,13 @@ pub(super) fn gen_func_swift_calls_rust(
     };
 
     let public_func_fn_name = if function.is_swift_initializer {
-        if function.is_copy_method_on_opaque_type() {
+        if shared_ty.is_some() {
+            if let Some(FailableInitializerType::Option) = function.swift_failable_initializer {
+                "public init?".to_string()
+            } else {
+                "public init".to_string()
+            }
+        } else if function.is_copy_method_on_opaque_type() {
             "public init".to_string()
         } else {
             if let Some(crate::parsed_extern_fn::FailableInitializerType::Throwing) =
@@ -69,10 +80,16 @@ pub(super) fn gen_func_swift_calls_rust(
             }
         }
     } else {
+        // A method on a shared type that writes back `self` replaces `self` with the new value.
+        let maybe_mutating = if function.writes_back_shared_self(types) {
+            "mutating "
+        } else {
+            ""
+        };
         if let Some(swift_name) = &function.swift_name_override {
-            format!("public func {}", swift_name.value())
+            format!("public {}func {}", maybe_mutating, swift_name.value())
         } else {
-            format!("public func {}", fn_name.as_str())
+            format!("public {}func {}", maybe_mutating, fn_name.as_str())
         }
     };
 
@@ -89,13 +106,22 @@ pub(super) fn gen_func_swift_calls_rust(
     };
 
     let call_rust = format!(
-        "{prefix}{type_name_segment}${call_fn}",
+        "{prefix}{type_name_segment}{trait_name_segment}${call_fn}",
         prefix = SWIFT_BRIDGE_PREFIX,
         type_name_segment = maybe_type_name_segment,
+        trait_name_segment = maybe_trait_name_segment,
         call_fn = call_fn
     );
     let mut call_rust = if function.sig.asyncness.is_some() {
         call_rust
+    } else if function.is_swift_initializer && shared_ty.is_some() {
+        let built_in = function.return_ty_built_in(types).unwrap();
+        built_in.convert_ffi_value_to_swift_value(
+            &call_rust,
+            TypePosition::FnReturn(function.host_lang),
+            types,
+            swift_bridge_path,
+        )
     } else if function.is_swift_initializer {
         if let Some(FailableInitializerType::Throwing) = function.swift_failable_initializer {
             let built_in = function.return_ty_built_in(types).unwrap();
@@ -172,7 +198,7 @@ pub(super) fn gen_func_swift_calls_rust(
         }
         let bridged_arg = bridged_arg.unwrap();
 
-        let arg_name = fn_arg_name(arg).unwrap().to_string();
+        let arg_name = function.swift_param_name(&fn_arg_name(arg).unwrap().to_string());
 
         // TODO: Refactor to make less duplicative
         match bridged_arg {
@@ -203,7 +229,16 @@ pub(super) fn gen_func_swift_calls_rust(
     }
 
     if function.is_swift_initializer {
-        if function.is_copy_method_on_opaque_type() {
+        if shared_ty.is_some() {
+            if let Some(FailableInitializerType::Option) = function.swift_failable_initializer {
+                call_rust = format!(
+                    "guard let val = {} else {{ return nil }}; self = val",
+                    call_rust
+                )
+            } else {
+                call_rust = format!("self = {}", call_rust)
+            }
+        } else if function.is_copy_method_on_opaque_type() {
             call_rust = format!("self.bytes = {}", call_rust)
         } else {
             if let Some(FailableInitializerType::Option) = function.swift_failable_initializer {
@@ -217,6 +252,28 @@ pub(super) fn gen_func_swift_calls_rust(
         }
     }
 
+    // Rust writes the value of `self` back to `this`, such as after a `&mut self` method.
+    if let Some(shared_ty) = shared_ty.as_ref() {
+        if function.writes_back_shared_self(types) && !shared_ty.can_be_encoded_with_zero_bytes() {
+            let this = shared_ty.convert_swift_expression_to_ffi_type(
+                "self",
+                types,
+                TypePosition::FnArg(function.host_lang, 0),
+            );
+            let mutated_self = shared_ty.convert_ffi_value_to_swift_value(
+                "this",
+                TypePosition::FnReturn(function.host_lang),
+                types,
+                swift_bridge_path,
+            );
+            call_rust = format!(
+                r#"var this = {this}
+{indentation}    defer {{ self = {mutated_self} }}
+{indentation}    {call_rust}"#
+            );
+        }
+    }
+
     let maybe_return = if function.is_swift_initializer {
         "".to_string()
     } else {
@@ -224,6 +281,7 @@ pub(super) fn gen_func_swift_calls_rust(
     };
 
     let maybe_generics = function.maybe_swift_generics(types);
+    let maybe_availability = function.maybe_swift_availability(indentation, types);
 
     let func_definition = if function.sig.asyncness.is_some() {
         let func_ret_ty = function.return_ty_built_in(types).unwrap();
@@ -244,7 +302,7 @@ pub(super) fn gen_func_swift_calls_rust(
                 )
             )
         };
-        let callback_wrapper_ty = format!("CbWrapper{}${}", maybe_type_name_segment, fn_name);
+        let callback_wrapper_ty = format!("CbWrapper{}${}", maybe_type_name_segment, link_fn_name);
         let (run_wrapper_cb, error, maybe_try, with_checked_continuation_function_name) =
             if let Some(result) = func_ret_ty.as_result() {
                 let run_wrapper_cb = result.generate_swift_calls_async_rust_callback(
@@ -323,10 +381,11 @@ return{maybe_try}await {with_checked_continuation_function_name}({{ (continuatio
         let fn_body_indented = fn_body_indented.trim_end();
 
         format!(
-            r#"{indentation}{maybe_static_class_func}{swift_class_func_name}{maybe_generics}({params}) async{maybe_ret} {{
+            r#"{maybe_availability}{indentation}{maybe_static_class_func}{swift_class_func_name}{maybe_generics}({params}) async{maybe_ret} {{
 {fn_body_indented}
 {indentation}}}
 {callback_wrapper}"#,
+            maybe_availability = maybe_availability,
             indentation = indentation,
             maybe_static_class_func = maybe_static_class_func,
             swift_class_func_name = public_func_fn_name,
@@ -338,9 +397,10 @@ return{maybe_try}await {with_checked_continuation_function_name}({{ (continuatio
         )
     } else {
         format!(
-            r#"{indentation}{maybe_static_class_func}{swift_class_func_name}{maybe_generics}({params}){maybe_throws}{maybe_ret} {{
+            r#"{maybe_availability}{indentation}{maybe_static_class_func}{swift_class_func_name}{maybe_generics}({params}){maybe_throws}{maybe_ret} {{
 {indentation}    {call_rust}
 {indentation}}}"#,
+            maybe_availability = maybe_availability,
             indentation = indentation,
             maybe_static_class_func = maybe_static_class_func,
             swift_class_func_name = public_func_fn_name,
@@ -1,4 +1,5 @@
-use crate::bridged_type::{BridgedType, SharedEnum, StructFields, TypePosition};
+use crate::bridged_type::shared_struct::StructField;
+use crate::bridged_type::{BridgedBox, SharedEnum, StructFields};
 use crate::SwiftBridgeModule;
 
 impl SwiftBridgeModule {
@@ -9,60 +10,82 @@ impl SwiftBridgeModule {
         }
 
         let enum_name = shared_enum.swift_name_string();
+        let swift_type = shared_enum.swift_type_string(&self.types, &self.swift_bridge_path);
         let enum_ffi_name = shared_enum.ffi_name_string();
         let option_ffi_name = shared_enum.ffi_option_name_string();
+        let generics = &shared_enum.generics;
+
+        // A generic enum gets declared once, alongside its first instantiation.
+        let declared_variants = if generics.is_empty() {
+            Some(&shared_enum.variants)
+        } else {
+            shared_enum.declared_variants.as_ref()
+        };
 
         let mut variants = "".to_string();
         let mut convert_swift_to_ffi_repr = "\n".to_string();
         let mut convert_ffi_repr_to_swift = "\n".to_string();
         let all_variants_empty = shared_enum.all_variants_empty();
-        for variant in shared_enum.variants.iter() {
+        let raw_values = shared_enum.swift_raw_values();
+        for (idx, variant) in declared_variants.into_iter().flatten().enumerate() {
+            // A case that holds a `Box<T>` is stored on the heap, which lets an enum contain
+            // itself.
+            let maybe_indirect = if variant
+                .fields
+                .normalized_fields()
+                .iter()
+                .any(|field| BridgedBox::is_boxed_field_type(&field.ty))
+            {
+                "indirect "
+            } else {
+                ""
+            };
             let v = match &variant.fields {
                 StructFields::Named(named_fields) => {
                     let mut params = vec![];
                     for named_field in named_fields {
-                        let ty = BridgedType::new_with_type(&named_field.ty, &self.types)
-                            .unwrap()
-                            .to_swift_type(
-                                TypePosition::SharedStructField,
-                                &self.types,
-                                &self.swift_bridge_path,
-                            );
-                        params.push(format!("{}: {}", named_field.name, ty))
+                        let ty = generics.declared_field_swift_type(
+                            &named_field.ty,
+                            &self.types,
+                            &self.swift_bridge_path,
+                        );
+                        params.push(format!("{}: {}", named_field.swift_name_string(), ty))
                     }
                     let params = params.join(", ");
                     format!(
                         r#"
-    case {name}({params})"#,
-                        name = variant.name,
+    {maybe_indirect}case {name}({params})"#,
+                        name = variant.swift_name_string(),
                         params = params,
                     )
                 }
                 StructFields::Unnamed(unnamed_fields) => {
                     let mut params = vec![];
                     for unnamed_field in unnamed_fields {
-                        let ty = BridgedType::new_with_type(&unnamed_field.ty, &self.types)
-                            .unwrap()
-                            .to_swift_type(
-                                TypePosition::SharedStructField,
-                                &self.types,
-                                &self.swift_bridge_path,
-                            );
+                        let ty = generics.declared_field_swift_type(
+                            &unnamed_field.ty,
+                            &self.types,
+                            &self.swift_bridge_path,
+                        );
                         params.push(ty);
                     }
                     let params = params.join(", ");
                     format!(
                         r#"
-    case {name}({params})"#,
-                        name = variant.name,
+    {maybe_indirect}case {name}({params})"#,
+                        name = variant.swift_name_string(),
                         params = params,
                     )
                 }
                 StructFields::Unit => {
+                    let maybe_raw_value = match raw_values.as_ref() {
+                        Some(raw_values) => format!(" = {}", raw_values[idx]),
+                        None => "".to_string(),
+                    };
                     format!(
                         r#"
-    case {name}"#,
-                        name = variant.name
+    case {name}{maybe_raw_value}"#,
+                        name = variant.swift_name_string()
                     )
                 }
             };
@@ -75,7 +98,7 @@ impl SwiftBridgeModule {
         for variant in shared_enum.variants.iter() {
             let convert_swift_variant_to_ffi_repr = variant.convert_swift_to_ffi_repr(
                 &self.types,
-                format!("{}", enum_name),
+                format!("{}", swift_type),
                 format!("{}", enum_ffi_name),
                 all_variants_empty,
             );
@@ -88,7 +111,8 @@ impl SwiftBridgeModule {
         for variant in shared_enum.variants.iter() {
             let convert_ffi_variant_to_swift = variant.convert_ffi_expression_to_swift(
                 &self.types,
-                format!("{}", enum_name),
+                format!("{}", swift_type),
+                format!("{}", enum_ffi_name),
                 &self.swift_bridge_path,
             );
             convert_ffi_repr_to_swift += &convert_ffi_variant_to_swift;
@@ -158,21 +182,84 @@ extension {enum_name}: CustomDebugStringConvertible {{
             "".to_string()
         };
 
+        // Swift synthesizes the protocol conformances, so each associated value needs to conform
+        // to the protocol as well.
+        let mut derive_conformances = "".to_string();
+        if declared_variants.is_some() {
+            for protocol in shared_enum.derive.swift_protocols() {
+                // Swift's synthesized `Comparable` uses the order that the cases are declared in,
+                // but Rust's `PartialOrd` uses the discriminants.
+                if protocol == "Comparable"
+                    && generics.is_empty()
+                    && shared_enum.has_explicit_discriminants()
+                {
+                    derive_conformances +=
+                        &self.generate_shared_enum_discriminant_comparable_impl(shared_enum);
+                    continue;
+                }
+
+                derive_conformances += &format!(
+                    "\nextension {enum_name}: {protocol}{bounds} {{}}",
+                    bounds = generics.swift_conditional_conformance_bounds(protocol)
+                );
+            }
+            if shared_enum.case_iterable {
+                derive_conformances += &format!("\nextension {enum_name}: CaseIterable {{}}");
+            }
+        }
+
+        let discriminant_impl = if generics.is_empty() && shared_enum.has_explicit_discriminants() {
+            self.generate_shared_enum_discriminant_impl(shared_enum)
+        } else {
+            "".to_string()
+        };
+
+        let codable_impl = match declared_variants {
+            Some(declared_variants) if shared_enum.codable => {
+                self.generate_shared_enum_codable_conformance(shared_enum, declared_variants)
+            }
+            _ => "".to_string(),
+        };
+
+        let maybe_enum_declaration = if declared_variants.is_some() {
+            let declared_field_types: Vec<_> = declared_variants
+                .into_iter()
+                .flatten()
+                .flat_map(|v| v.fields.normalized_fields())
+                .map(|f| f.ty)
+                .collect();
+            let params = generics.angle_bracketed_swift_params_string(declared_field_types.iter());
+            let raw_value_type = shared_enum.swift_raw_value_type_string();
+            format!("public enum {enum_name}{params}{raw_value_type} {{{variants}}}\n")
+        } else {
+            "".to_string()
+        };
+
+        // Each instantiation of a generic enum gets converted to and from its own FFI
+        // representation.
+        let maybe_generic_bounds = if generics.is_empty() {
+            "".to_string()
+        } else {
+            format!(
+                " where {}",
+                generics.swift_generic_bounds(&self.types, &self.swift_bridge_path)
+            )
+        };
+
         let swift_enum = format!(
-            r#"public enum {enum_name} {{{variants}}}
-extension {enum_name} {{
+            r#"{maybe_enum_declaration}extension {enum_name}{maybe_generic_bounds} {{
     func intoFfiRepr() -> {ffi_repr_name} {{
         switch self {{{convert_swift_to_ffi_repr}}}
     }}
 }}
 extension {enum_ffi_name} {{
-    func intoSwiftRepr() -> {enum_name} {{
+    func intoSwiftRepr() -> {swift_type} {{
         switch self.tag {{{convert_ffi_repr_to_swift}}}
     }}
 }}
 extension {option_ffi_name} {{
     @inline(__always)
-    func intoSwiftRepr() -> Optional<{enum_name}> {{
+    func intoSwiftRepr() -> Optional<{swift_type}> {{
         if self.is_some {{
             return self.val.intoSwiftRepr()
         }} else {{
@@ -180,23 +267,152 @@ extension {option_ffi_name} {{
         }}
     }}
     @inline(__always)
-    static func fromSwiftRepr(_ val: Optional<{enum_name}>) -> {option_ffi_name} {{
+    static func fromSwiftRepr(_ val: Optional<{swift_type}>) -> {option_ffi_name} {{
         if let v = val {{
             return {option_ffi_name}(is_some: true, val: v.intoFfiRepr())
         }} else {{
             return {option_ffi_name}(is_some: false, val: {ffi_repr_name}())
         }}
     }}
-}}{vectorizable_impl}{derive_debug_impl}"#,
+}}{vectorizable_impl}{derive_debug_impl}{derive_conformances}{discriminant_impl}{codable_impl}"#,
             enum_name = enum_name,
             enum_ffi_name = enum_ffi_name,
             option_ffi_name = option_ffi_name,
             ffi_repr_name = shared_enum.ffi_name_string(),
-            variants = variants,
             convert_swift_to_ffi_repr = convert_swift_to_ffi_repr,
             convert_ffi_repr_to_swift = convert_ffi_repr_to_swift
         );
 
         Some(swift_enum)
     }
+
+    /// Lets Swift read the numeric value of an enum that has a `#[repr(...)]` or explicit
+    /// discriminants, and build a fieldless variant from its numeric value.
+    ///
+    /// ```swift
+    /// extension SomeEnum {
+    ///     public var discriminant: UInt8 { ... }
+    ///     public init?(discriminant: UInt8) { ... }
+    /// }
+    /// ```
+    fn generate_shared_enum_discriminant_impl(&self, shared_enum: &SharedEnum) -> String {
+        let enum_name = shared_enum.swift_name_string();
+        let discriminant_ty = shared_enum.discriminant_swift_type();
+        let discriminants = shared_enum.discriminant_values();
+
+        let mut read_discriminants = "".to_string();
+        let mut build_from_discriminants = "".to_string();
+        for (variant, discriminant) in shared_enum.variants.iter().zip(discriminants) {
+            let name = variant.swift_name_string();
+            read_discriminants += &format!(
+                r#"
+        case .{name}:
+            return {discriminant}"#
+            );
+            build_from_discriminants += &format!(
+                r#"
+        case {discriminant}:
+            self = .{name}"#
+            );
+        }
+
+        // Variants with fields can't be built from only their discriminant.
+        let maybe_init = if shared_enum.all_variants_empty() {
+            format!(
+                r#"
+
+    public init?(discriminant: {discriminant_ty}) {{
+        switch discriminant {{{build_from_discriminants}
+        default:
+            return nil
+        }}
+    }}"#
+            )
+        } else {
+            "".to_string()
+        };
+
+        format!(
+            r#"
+extension {enum_name} {{
+    public var discriminant: {discriminant_ty} {{
+        switch self {{{read_discriminants}
+        }}
+    }}{maybe_init}
+}}"#
+        )
+    }
+
+    /// Compares an enum that has explicit discriminants the same way that Rust's derived
+    /// `PartialOrd` does. Variants are ordered by their discriminants, and two values of the same
+    /// variant are ordered by their fields.
+    ///
+    /// ```swift
+    /// extension SomeEnum: Comparable {
+    ///     public static func < (lhs: SomeEnum, rhs: SomeEnum) -> Bool { ... }
+    /// }
+    /// ```
+    fn generate_shared_enum_discriminant_comparable_impl(
+        &self,
+        shared_enum: &SharedEnum,
+    ) -> String {
+        let enum_name = shared_enum.swift_name_string();
+
+        if shared_enum.all_variants_empty() {
+            return format!(
+                r#"
+extension {enum_name}: Comparable {{
+    public static func < (lhs: {enum_name}, rhs: {enum_name}) -> Bool {{
+        lhs.discriminant < rhs.discriminant
+    }}
+}}"#
+            );
+        }
+
+        let mut compare_fields = "".to_string();
+        for variant in shared_enum.variants.iter() {
+            let field_count = variant.fields.normalized_fields().len();
+            if field_count == 0 {
+                continue;
+            }
+
+            let name = variant.swift_name_string();
+            let lhs_fields: Vec<String> =
+                (0..field_count).map(|idx| format!("let l{idx}")).collect();
+            let rhs_fields: Vec<String> =
+                (0..field_count).map(|idx| format!("let r{idx}")).collect();
+
+            let mut comparisons = "".to_string();
+            for idx in 0..field_count - 1 {
+                comparisons += &format!(
+                    r#"
+            if l{idx} != r{idx} {{ return l{idx} < r{idx} }}"#
+                );
+            }
+            let last = field_count - 1;
+
+            compare_fields += &format!(
+                r#"
+        case (.{name}({lhs_fields}), .{name}({rhs_fields})):{comparisons}
+            return l{last} < r{last}"#,
+                lhs_fields = lhs_fields.join(", "),
+                rhs_fields = rhs_fields.join(", "),
+            );
+        }
+
+        format!(
+            r#"
+extension {enum_name}: Comparable {{
+    public static func < (lhs: {enum_name}, rhs: {enum_name}) -> Bool {{
+        if lhs.discriminant != rhs.discriminant {{
+            return lhs.discriminant < rhs.discriminant
+        }}
+        switch (lhs, rhs) {{{compare_fields}
+        default:
+            return false
+        }}
+    }}
+}}"#
+        )
+    }
 }
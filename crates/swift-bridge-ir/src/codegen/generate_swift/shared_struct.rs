@@ -1,5 +1,7 @@
 use crate::bridged_type::shared_struct::StructField;
-use crate::bridged_type::{BridgedType, SharedStruct, StructFields, StructSwiftRepr, TypePosition};
+use crate::bridged_type::{
+    BridgedBox, SharedStruct, SharedTypeGenerics, StructFields, StructSwiftRepr,
+};
 use crate::SwiftBridgeModule;
 
 impl SwiftBridgeModule {
@@ -13,22 +15,36 @@ impl SwiftBridgeModule {
         }
 
         let struct_name = &shared_struct.swift_name_string();
+        let swift_type = shared_struct.swift_type_string(&self.types, &self.swift_bridge_path);
         let option_ffi_name = shared_struct.ffi_option_name_string();
+        let generics = &shared_struct.generics;
+
+        // A generic struct gets declared once, alongside its first instantiation.
+        let declared_fields = if generics.is_empty() {
+            &shared_struct.fields
+        } else {
+            shared_struct
+                .declared_fields
+                .as_ref()
+                .unwrap_or(&StructFields::Unit)
+        };
 
         match shared_struct.swift_repr {
             StructSwiftRepr::Class => {
                 todo!()
             }
             StructSwiftRepr::Structure => {
-                let initializer_params = match &shared_struct.fields {
-                    StructFields::Named(named) => self.convert_fields_to_initializer_params(named),
+                let initializer_params = match declared_fields {
+                    StructFields::Named(named) => {
+                        self.convert_fields_to_initializer_params(named, generics)
+                    }
                     StructFields::Unnamed(unnamed) => {
-                        self.convert_fields_to_initializer_params(unnamed)
+                        self.convert_fields_to_initializer_params(unnamed, generics)
                     }
                     StructFields::Unit => "".to_string(),
                 };
 
-                let initializer_body = match &shared_struct.fields {
+                let initializer_body = match declared_fields {
                     StructFields::Named(named) => self.convert_fields_to_initializer_body(named),
                     StructFields::Unnamed(unnamed) => {
                         self.convert_fields_to_initializer_body(unnamed)
@@ -36,9 +52,9 @@ impl SwiftBridgeModule {
                     StructFields::Unit => "".to_string(),
                 };
 
-                let fields = match &shared_struct.fields {
-                    StructFields::Named(named) => self.declare_fields(named),
-                    StructFields::Unnamed(unnamed) => self.declare_fields(unnamed),
+                let fields = match declared_fields {
+                    StructFields::Named(named) => self.declare_fields(named, generics),
+                    StructFields::Unnamed(unnamed) => self.declare_fields(unnamed, generics),
                     StructFields::Unit => "".to_string(),
                 };
 
@@ -50,26 +66,152 @@ impl SwiftBridgeModule {
                     &self.swift_bridge_path,
                 );
 
-                // No need to generate any code. Swift will automatically generate a
-                //  struct from our C header typedef that we generate for this struct.
-                let swift_struct = format!(
-                    r#"public struct {struct_name} {{{fields}
-    public init({initializer_params}) {{{initializer_body}}}
+                let vectorizable_impl = if shared_struct.is_vectorizable() {
+                    // `as_ptr()` views the Rust `Vec`'s elements as Swift structs, which only
+                    // works when the fields are plain data. A `String` field is a pointer to a
+                    // boxed Rust value, not a `RustString`.
+                    let as_ptr = if shared_struct.fields.ffi_repr_owns_rust_values(&self.types) {
+                        format!(
+                            r#"fatalError("RustVec<{struct_name}>.as_ptr() is not supported since {struct_name} has fields that own Rust values")"#
+                        )
+                    } else {
+                        format!(
+                            "UnsafePointer<Self>(OpaquePointer(__swift_bridge__$Vec_{struct_name}$as_ptr(vecPtr)))"
+                        )
+                    };
 
-    @inline(__always)
+                    format!(
+                        r#"
+extension {struct_name}: Vectorizable {{
+    public static func vecOfSelfNew() -> UnsafeMutableRawPointer {{
+        __swift_bridge__$Vec_{struct_name}$new()
+    }}
+
+    public static func vecOfSelfFree(vecPtr: UnsafeMutableRawPointer) {{
+        __swift_bridge__$Vec_{struct_name}$drop(vecPtr)
+    }}
+
+    public static func vecOfSelfPush(vecPtr: UnsafeMutableRawPointer, value: Self) {{
+        __swift_bridge__$Vec_{struct_name}$push(vecPtr, value.intoFfiRepr())
+    }}
+
+    public static func vecOfSelfPop(vecPtr: UnsafeMutableRawPointer) -> Optional<Self> {{
+        let maybeStruct = __swift_bridge__$Vec_{struct_name}$pop(vecPtr)
+        return maybeStruct.intoSwiftRepr()
+    }}
+
+    public static func vecOfSelfGet(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<Self> {{
+        let maybeStruct = __swift_bridge__$Vec_{struct_name}$get(vecPtr, index)
+        return maybeStruct.intoSwiftRepr()
+    }}
+
+    public static func vecOfSelfGetMut(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<Self> {{
+        let maybeStruct = __swift_bridge__$Vec_{struct_name}$get_mut(vecPtr, index)
+        return maybeStruct.intoSwiftRepr()
+    }}
+
+    public static func vecOfSelfAsPtr(vecPtr: UnsafeMutableRawPointer) -> UnsafePointer<Self> {{
+        {as_ptr}
+    }}
+
+    public static func vecOfSelfLen(vecPtr: UnsafeMutableRawPointer) -> UInt {{
+        __swift_bridge__$Vec_{struct_name}$len(vecPtr)
+    }}
+}}"#
+                    )
+                } else {
+                    "".to_string()
+                };
+
+                let derive_debug_impl = if shared_struct.has_swift_debug_description(&self.types) {
+                    format!(
+                        r#"
+extension {struct_name}: CustomDebugStringConvertible {{
+    public var debugDescription: String {{
+        RustString(ptr: {ffi_repr_name}$Debug(self.intoFfiRepr())).toString()
+    }}
+}}"#,
+                        ffi_repr_name = shared_struct.ffi_name_string(),
+                    )
+                } else {
+                    "".to_string()
+                };
+
+                // Swift synthesizes the protocol conformances, so each field needs to conform to
+                // the protocol as well.
+                let mut derive_conformances = "".to_string();
+                if generics.is_empty() || shared_struct.declared_fields.is_some() {
+                    for protocol in shared_struct.derives.swift_protocols() {
+                        derive_conformances += &format!(
+                            "\nextension {struct_name}: {protocol}{bounds} {{}}",
+                            bounds = generics.swift_conditional_conformance_bounds(protocol)
+                        );
+                    }
+                }
+
+                let codable_impl = if shared_struct.codable
+                    && (generics.is_empty() || shared_struct.declared_fields.is_some())
+                {
+                    self.generate_shared_struct_codable_conformance(shared_struct, declared_fields)
+                } else {
+                    "".to_string()
+                };
+
+                let into_ffi_repr = format!(
+                    r#"    @inline(__always)
     func intoFfiRepr() -> {ffi_repr_name} {{
         {convert_swift_to_ffi_repr}
-    }}
+    }}"#,
+                    ffi_repr_name = shared_struct.ffi_name_string(),
+                );
+
+                // Each instantiation of a generic struct gets converted to and from its own FFI
+                // representation.
+                let swift_struct_declaration = if generics.is_empty() {
+                    format!(
+                        r#"public struct {struct_name} {{{fields}
+    public init({initializer_params}) {{{initializer_body}}}
+
+{into_ffi_repr}
+}}"#
+                    )
+                } else {
+                    let maybe_generic_struct = if shared_struct.declared_fields.is_some() {
+                        let params = generics.angle_bracketed_swift_params_string(
+                            declared_fields.normalized_fields().iter().map(|f| &f.ty),
+                        );
+                        format!(
+                            r#"public struct {struct_name}{params} {{{fields}
+    public init({initializer_params}) {{{initializer_body}}}
 }}
+"#
+                        )
+                    } else {
+                        "".to_string()
+                    };
+                    let bounds =
+                        generics.swift_generic_bounds(&self.types, &self.swift_bridge_path);
+
+                    format!(
+                        r#"{maybe_generic_struct}extension {struct_name} where {bounds} {{
+{into_ffi_repr}
+}}"#
+                    )
+                };
+
+                // No need to generate any code. Swift will automatically generate a
+                //  struct from our C header typedef that we generate for this struct.
+                let swift_struct = format!(
+                    r#"{swift_struct_declaration}
 extension {ffi_repr_name} {{
     @inline(__always)
-    func intoSwiftRepr() -> {struct_name} {{
+    func intoSwiftRepr() -> {swift_type} {{
         {convert_ffi_repr_to_swift}
     }}
 }}
 extension {option_ffi_name} {{
     @inline(__always)
-    func intoSwiftRepr() -> Optional<{struct_name}> {{
+    func intoSwiftRepr() -> Optional<{swift_type}> {{
         if self.is_some {{
             return self.val.intoSwiftRepr()
         }} else {{
@@ -78,22 +220,18 @@ extension {option_ffi_name} {{
     }}
 
     @inline(__always)
-    static func fromSwiftRepr(_ val: Optional<{struct_name}>) -> {option_ffi_name} {{
+    static func fromSwiftRepr(_ val: Optional<{swift_type}>) -> {option_ffi_name} {{
         if let v = val {{
             return {option_ffi_name}(is_some: true, val: v.intoFfiRepr())
         }} else {{
             return {option_ffi_name}(is_some: false, val: {ffi_repr_name}())
         }}
     }}
-}}"#,
-                    struct_name = struct_name,
-                    initializer_params = initializer_params,
-                    initializer_body = initializer_body,
-                    fields = fields,
+}}{vectorizable_impl}{derive_debug_impl}{derive_conformances}{codable_impl}"#,
                     ffi_repr_name = shared_struct.ffi_name_string(),
                     option_ffi_name = option_ffi_name,
-                    convert_swift_to_ffi_repr = convert_swift_to_ffi_repr,
-                    convert_ffi_repr_to_swift = convert_ffi_repr_to_swift
+                    convert_ffi_repr_to_swift = convert_ffi_repr_to_swift,
+                    vectorizable_impl = vectorizable_impl
                 );
 
                 Some(swift_struct)
@@ -104,6 +242,7 @@ extension {option_ffi_name} {{
     fn convert_fields_to_initializer_params<'a, T>(
         &self,
         struct_fields: impl IntoIterator<Item = &'a T>,
+        generics: &SharedTypeGenerics,
     ) -> String
     where
         T: StructField + 'a,
@@ -111,13 +250,11 @@ extension {option_ffi_name} {{
         let mut params = "".to_string();
 
         for field in struct_fields.into_iter() {
-            let bridged_ty = BridgedType::new_with_type(field.field_type(), &self.types).unwrap();
-
             params += &format!(
                 "{}: {},",
                 field.swift_name_string(),
-                bridged_ty.to_swift_type(
-                    TypePosition::SharedStructField,
+                generics.declared_field_swift_type(
+                    field.field_type(),
                     &self.types,
                     &self.swift_bridge_path
                 )
@@ -155,20 +292,29 @@ extension {option_ffi_name} {{
         body
     }
 
-    fn declare_fields<'a, T>(&self, struct_fields: impl IntoIterator<Item = &'a T>) -> String
+    fn declare_fields<'a, T>(
+        &self,
+        struct_fields: impl IntoIterator<Item = &'a T>,
+        generics: &SharedTypeGenerics,
+    ) -> String
     where
         T: StructField + 'a,
     {
         let mut fields = "".to_string();
 
         for field in struct_fields.into_iter() {
-            let bridged_ty = BridgedType::new_with_type(field.field_type(), &self.types).unwrap();
-
+            // Swift structs can't contain themselves, so a `Box<T>` field is stored on the heap.
+            let maybe_indirect = if BridgedBox::is_boxed_field_type(field.field_type()) {
+                "@Indirect "
+            } else {
+                ""
+            };
             fields += &format!(
-                "    public var {}: {}\n",
+                "    {}public var {}: {}\n",
+                maybe_indirect,
                 field.swift_name_string(),
-                bridged_ty.to_swift_type(
-                    TypePosition::SharedStructField,
+                generics.declared_field_swift_type(
+                    field.field_type(),
                     &self.types,
                     &self.swift_bridge_path
                 )
@@ -1,8 +1,8 @@
 use proc_macro2::Ident;
 use quote::ToTokens;
-use syn::{Error, FnArg, Item, Receiver};
-use syn::{ForeignItemFn, ForeignItemType, LitStr};
-use syn::{Token, Type};
+use syn::{Error, FnArg, Item, ItemImpl, Receiver, Signature, TraitItem, TypeParamBound};
+use syn::{Expr, ForeignItemFn, ForeignItemType, LitStr};
+use syn::{Path, Token, Type};
 
 pub(crate) enum ParseError {
     ArgsIntoArgNotFound {
@@ -56,6 +56,112 @@ pub(crate) enum ParseError {
     InvalidModuleItem { item: Item },
     /// The associated_to attribute is used for only an associated method.
     InvalidAssociatedTo { self_: FnArg },
+    /// A trait item that isn't a method declaration, such as an associated type or a method with
+    /// a default implementation.
+    InvalidTraitItem { item: TraitItem },
+    /// A trait method that doesn't take `&self` or `&mut self`, or is generic or async.
+    InvalidTraitMethod { sig: Signature },
+    /// `impl SomeTrait for SomeType { ... }` that isn't empty, or that implements a trait that
+    /// wasn't declared in the module for a type that isn't an opaque Rust type.
+    InvalidTraitImpl { item_impl: ItemImpl },
+    /// A bridged trait's supertrait that isn't `Send` or `Sync`.
+    InvalidTraitSupertrait { supertrait: TypeParamBound },
+    /// A generic function that doesn't list a concrete type for each of its generic type
+    /// parameters, or an `instantiate` attribute on a function that isn't generic.
+    /// `#[swift_bridge(instantiate(T = u32))] fn parse<T>(s: &str) -> T;`
+    InvalidFnInstantiation { func: ForeignItemFn },
+    /// A generic shared struct or enum that doesn't list a concrete type for each of its generic
+    /// type parameters, or that uses a generic type parameter somewhere that we don't support.
+    /// `#[swift_bridge(instantiate(T = u32))] struct Page<T> { items: Vec<T> }`
+    InvalidSharedTypeInstantiation { ty: Ident },
+    /// A generic opaque type instantiation that names a generic type parameter that the type
+    /// doesn't have.
+    /// `#[swift_bridge(instantiate(A = Vec<u8>))] type SomeType<A>;`
+    InvalidOpaqueTypeInstantiation { ty: Ident },
+    /// `CaseIterable` or `RawRepresentable` on an enum that has a variant with fields.
+    /// `#[swift_bridge(CaseIterable)] enum SomeEnum { Variant(u8) }`
+    FieldlessEnumAttribute { attribute: Ident },
+    /// A `RawRepresentable` raw value type other than "Int" or "String".
+    InvalidEnumRawValueType { raw_value_type: LitStr },
+    /// An enum discriminant that isn't an integer literal.
+    /// `enum SomeEnum { Variant = SOME_CONSTANT }`
+    InvalidEnumDiscriminant { discriminant: Expr },
+    /// A method on a shared struct or enum that is declared in an `extern "Swift"` block.
+    /// Methods on shared types can only be implemented in Rust.
+    SharedTypeMethodInExternSwift { func: ForeignItemFn },
+    /// Two items that end up with the same Swift name after the module's `swift_naming`
+    /// convention was applied.
+    /// `struct SomeStruct { is_enabled: bool, isEnabled: bool }`
+    SwiftNamingCollision {
+        first: Ident,
+        second: Ident,
+        swift_name: String,
+    },
+    /// A default argument value on a function that is declared in an `extern "Swift"` block.
+    /// Rust doesn't have default argument values, so Rust always passes every argument.
+    ArgumentDefaultInExternSwift { arg: FnArg },
+    /// `default = None` on an argument that isn't an `Option`.
+    /// `fn some_function(#[swift_bridge(default = None)] arg: u8);`
+    ArgumentDefaultNoneOnNonOption { arg: FnArg },
+    /// A default argument value on an argument that the generated Swift function takes as a
+    /// generic type, such as a `String` or `&str`.
+    /// `fn some_function(#[swift_bridge(default = "\"hello\"")] arg: &str);`
+    ArgumentDefaultOnGenericSwiftArg { arg: FnArg },
+    /// A `HashMap` or `BTreeMap` key that is an opaque Rust type without the `Equatable` and
+    /// `Hashable` attributes, so it can't be used as a Swift `Dictionary` key.
+    /// `fn some_function(arg: HashMap<SomeOpaqueType, u8>);`
+    MapKeyNotHashable { key: Type },
+    /// A boxed function that isn't the type of a function argument, such as a boxed function
+    /// return value or an `Option<Box<dyn Fn()>>`.
+    /// `fn some_function() -> Box<dyn Fn(u8)>;`
+    BoxedFnNotFnArg { ty: Type },
+    /// A trait object that isn't the type of an `extern "Rust"` function argument, such as a
+    /// trait object return value or an `Option<Box<dyn SomeTrait>>`.
+    /// `fn some_function() -> Box<dyn SomeTrait>;`
+    TraitObjectNotRustFnArg { ty: Type },
+    /// A trait method that takes or returns a boxed function.
+    /// `trait SomeTrait { fn some_method(&self, callback: Box<dyn Fn(u8)>); }`
+    TraitMethodBoxedFn { ty: Type },
+    /// An `extern "Rust"` function that returns a shared struct that borrows from a `&str`
+    /// argument, which Swift only keeps alive for the duration of the call.
+    /// `fn some_function<'a>(arg: &'a str) -> SomeStruct<'a>;`
+    BorrowedStructReturnBorrowsStrArg { return_ty: Type, arg: Type },
+    /// A shared struct that doesn't derive `Clone` and is used in a `Vec<T>`.
+    /// `fn some_function() -> Vec<SomeStruct>;`
+    VecElementNotClone { ty: Type },
+    /// A field of a `#[swift_bridge(Codable)]` type whose Swift type isn't `Codable`.
+    /// `#[swift_bridge(Codable)] struct SomeStruct { field: Vec<u8> }`
+    CodableFieldNotCodable { ty: Type },
+    /// A `#[serde(...)]` option on a `#[swift_bridge(Codable)]` type that the generated Swift
+    /// `Codable` conformance doesn't follow.
+    /// `#[swift_bridge(Codable)] #[serde(tag = "type")] enum SomeEnum { ... }`
+    CodableUnsupportedSerdeOption { option: Path },
+    /// An `Arc<T>` that is borrowed or put in a collection.
+    /// `fn some_function(arg: &Arc<SomeType>) -> Vec<Arc<SomeType>>;`
+    UnsupportedArc { ty: Type },
+    /// A struct that derives `Debug` and has fields that own Rust values.
+    /// `#[derive(Debug)] struct SomeStruct { field: String }`
+    DeriveDebugOwnsRustValues { name: Ident },
+    /// A generic struct that derives `Debug`.
+    /// `#[derive(Debug)] struct SomeStruct<T> { field: T }`
+    DeriveDebugGenericStruct { name: Ident },
+    /// The error type of a returned `Result` that Swift can't throw.
+    /// `fn some_function() -> Result<u8, Option<String>>;`
+    ResultErrNotThrowable { ty: Type },
+    /// A trait object that is `Send` or `Sync`, where the trait wasn't declared with that
+    /// supertrait.
+    /// `trait SomeTrait {} fn some_function(arg: Arc<dyn SomeTrait + Send + Sync>);`
+    TraitObjectMissingSupertraits {
+        ty: Type,
+        trait_name: Ident,
+        supertraits: String,
+    },
+    /// The element type of a fixed-size array that can't be stored in a C array.
+    /// `fn some_function(arg: [String; 2]);`
+    UnsupportedArrayElement { elem: Type },
+    /// The length of a fixed-size array that isn't a non-zero integer literal.
+    /// `fn some_function(arg: [u8; LEN]);`
+    UnsupportedArrayLength { len: Expr },
 }
 
 /// An error while parsing a function attribute.
@@ -201,7 +307,9 @@ struct {struct_name};
                 Error::new_spanned(arg, message)
             }
             ParseError::InvalidModuleItem { item } => {
-                let message = format!(r#"Only `extern` blocks, structs and enums are supported."#);
+                let message = format!(
+                    r#"Only `extern` blocks, structs, enums, traits and trait impls are supported."#
+                );
                 Error::new_spanned(item, message)
             }
             ParseError::InvalidAssociatedTo { self_ } => {
@@ -209,6 +317,193 @@ struct {struct_name};
                     format!(r#"The associated_to attribute can only be used on static methods."#);
                 Error::new_spanned(self_, message)
             }
+            ParseError::InvalidTraitItem { item } => {
+                let message = format!(
+                    r#"Bridged traits can only contain method declarations without a default implementation."#
+                );
+                Error::new_spanned(item, message)
+            }
+            ParseError::InvalidTraitMethod { sig } => {
+                let message = format!(
+                    r#"Bridged trait methods must take `&self` or `&mut self`, and can't be generic or async."#
+                );
+                Error::new_spanned(sig, message)
+            }
+            ParseError::InvalidTraitImpl { item_impl } => {
+                let message = format!(
+                    r#"Trait impls must be empty and implement a trait that was declared in this module for an opaque Rust type, such as `impl SomeTrait for SomeType {{}}`."#
+                );
+                Error::new_spanned(item_impl, message)
+            }
+            ParseError::InvalidTraitSupertrait { supertrait } => {
+                let message =
+                    format!(r#"Bridged traits can only have `Send` and `Sync` as supertraits."#);
+                Error::new_spanned(supertrait, message)
+            }
+            ParseError::InvalidFnInstantiation { func } => {
+                let message = format!(
+                    r#"Generic functions must be declared in an `extern "Rust"` block and list a concrete type for each of their generic type parameters, such as `#[swift_bridge(instantiate(T = u32))]`."#
+                );
+                Error::new_spanned(func.sig, message)
+            }
+            ParseError::InvalidSharedTypeInstantiation { ty } => {
+                let message = format!(
+                    r#"Generic shared structs and enums must list a concrete type for each of their generic type parameters, such as `#[swift_bridge(instantiate(T = u32))]`, and can only use a generic type parameter directly or inside of a `Vec` or `Option`."#
+                );
+                Error::new_spanned(ty, message)
+            }
+            ParseError::InvalidOpaqueTypeInstantiation { ty } => {
+                let message = format!(
+                    r#"Each generic type parameter in `instantiate(...)` must be one of the type's generic arguments, such as `#[swift_bridge(instantiate(A = Vec<u8>))] type {ty}<A>;`."#
+                );
+                Error::new_spanned(ty, message)
+            }
+            ParseError::FieldlessEnumAttribute { attribute } => {
+                let message = format!(
+                    r#"`{attribute}` can only be used on enums whose variants don't have any fields."#
+                );
+                Error::new_spanned(attribute, message)
+            }
+            ParseError::InvalidEnumRawValueType { raw_value_type } => {
+                let message = r#"`RawRepresentable` must be "Int" or "String"."#;
+                Error::new_spanned(raw_value_type, message)
+            }
+            ParseError::InvalidEnumDiscriminant { discriminant } => {
+                let message =
+                    "Enum discriminants must be integer literals, such as `Variant = 404`.";
+                Error::new_spanned(discriminant, message)
+            }
+            ParseError::SharedTypeMethodInExternSwift { func } => {
+                let message = r#"Methods on shared structs and enums must be declared in an `extern "Rust"` block."#;
+                Error::new_spanned(func.sig, message)
+            }
+            ParseError::SwiftNamingCollision {
+                first,
+                second,
+                swift_name,
+            } => {
+                let message = format!(
+                    r#"`{second}` and `{first}` both have the Swift name "{swift_name}". Give one of them a different name using `#[swift_bridge(swift_name = "...")]`."#
+                );
+                Error::new_spanned(second, message)
+            }
+            ParseError::ArgumentDefaultInExternSwift { arg } => {
+                let message = r#"Default argument values can only be used on functions in an `extern "Rust"` block."#;
+                Error::new_spanned(arg, message)
+            }
+            ParseError::ArgumentDefaultNoneOnNonOption { arg } => {
+                let message = "`default = None` can only be used on an `Option` argument.";
+                Error::new_spanned(arg, message)
+            }
+            ParseError::ArgumentDefaultOnGenericSwiftArg { arg } => {
+                let message = "Default argument values can't be used on `String` or `&str` arguments, since Swift can't infer their generic type when the argument is left out.";
+                Error::new_spanned(arg, message)
+            }
+            ParseError::MapKeyNotHashable { key } => {
+                let message = format!(
+                    "Map keys must be Hashable in Swift. Add `#[swift_bridge(Equatable, Hashable)]` to `type {}`.",
+                    key.to_token_stream()
+                );
+                Error::new_spanned(key, message)
+            }
+            ParseError::BoxedFnNotFnArg { ty } => {
+                let message = "Boxed functions can only be used as the type of a function argument, such as `fn some_function(callback: Box<dyn Fn(u8)>)`.";
+                Error::new_spanned(ty, message)
+            }
+            ParseError::TraitObjectNotRustFnArg { ty } => {
+                let message = "Trait objects can only be used as the type of an `extern \"Rust\"` function argument, such as `fn some_function(arg: Box<dyn SomeTrait>)`.";
+                Error::new_spanned(ty, message)
+            }
+            ParseError::TraitMethodBoxedFn { ty } => {
+                let message = "Trait methods can't take or return boxed functions.";
+                Error::new_spanned(ty, message)
+            }
+            ParseError::BorrowedStructReturnBorrowsStrArg { return_ty, arg } => {
+                let message = format!(
+                    "`{}` can't borrow from a `{}` argument, since Swift only keeps the string that it passes to Rust alive until the function returns. Borrow from Rust data instead, such as an opaque Rust type's `&self`.",
+                    return_ty.to_token_stream(),
+                    arg.to_token_stream()
+                );
+                Error::new_spanned(arg, message)
+            }
+            ParseError::VecElementNotClone { ty } => {
+                let message = format!(
+                    "`{}` must `#[derive(Clone)]` to be used in a `Vec<T>`, since Swift gets a copy of an element when it reads from a `RustVec<T>`.",
+                    ty.to_token_stream()
+                );
+                Error::new_spanned(ty, message)
+            }
+            ParseError::CodableFieldNotCodable { ty } => {
+                let message = format!(
+                    "`{}` isn't Codable in Swift, so it can't be used in a `#[swift_bridge(Codable)]` type. Codable types can contain integers, floats, `bool`, `String`, `Option<T>` and other Codable shared types.",
+                    ty.to_token_stream()
+                );
+                Error::new_spanned(ty, message)
+            }
+            ParseError::CodableUnsupportedSerdeOption { option } => {
+                let message = format!(
+                    "`#[serde({})]` can't be used on a `#[swift_bridge(Codable)]` type, since the Swift Codable conformance only follows serde's default representation and `#[serde(rename = \"...\")]`.",
+                    option.to_token_stream()
+                );
+                Error::new_spanned(option, message)
+            }
+            ParseError::UnsupportedArc { ty } => {
+                let message = format!(
+                    "`{}` is not supported. An `Arc<T>` can only be passed by value, such as `Arc<SomeType>` or `Option<Arc<SomeType>>`. It can't be borrowed or put in a collection such as a `Vec<T>`.",
+                    ty.to_token_stream()
+                );
+                Error::new_spanned(ty, message)
+            }
+            ParseError::DeriveDebugOwnsRustValues { name } => {
+                let message = format!(
+                    "`{}` can't derive `Debug` since it has fields that own Rust values, such as a `String`, and Swift gives up ownership of them when it passes the struct to Rust. Implement `Debug` for the struct outside of the bridge module instead.",
+                    name
+                );
+                Error::new_spanned(name, message)
+            }
+            ParseError::DeriveDebugGenericStruct { name } => {
+                let message = format!(
+                    "`{}` can't derive `Debug` since it is generic, and a generic Swift struct can only conform to `CustomDebugStringConvertible` once. Implement `Debug` for the struct outside of the bridge module instead.",
+                    name
+                );
+                Error::new_spanned(name, message)
+            }
+            ParseError::ResultErrNotThrowable { ty } => {
+                let message = format!(
+                    "`{}` can't be the error type of a returned `Result`, since Swift throws the error and Optionals and tuples can't conform to `Error`. Use a type such as a `String` or a shared enum instead.",
+                    ty.to_token_stream()
+                );
+                Error::new_spanned(ty, message)
+            }
+            ParseError::TraitObjectMissingSupertraits {
+                ty,
+                trait_name,
+                supertraits,
+            } => {
+                let message = format!(
+                    "`{}` requires `{}` to be declared with `{}` supertraits, such as `trait {}: {} {{ ... }}`, since the Swift object's protocol needs to be `Sendable`.",
+                    ty.to_token_stream(),
+                    trait_name,
+                    supertraits,
+                    trait_name,
+                    supertraits
+                );
+                Error::new_spanned(ty, message)
+            }
+            ParseError::UnsupportedArrayElement { elem } => {
+                let message = format!(
+                    "`{}` can't be the element type of a fixed-size array. The elements can only be numbers, `bool`s, transparent structs, transparent enums or other fixed-size arrays.",
+                    elem.to_token_stream()
+                );
+                Error::new_spanned(elem, message)
+            }
+            ParseError::UnsupportedArrayLength { len } => {
+                let message = format!(
+                    "`{}` can't be the length of a fixed-size array. The length needs to be an integer literal that isn't zero, such as `[u8; 32]`.",
+                    len.to_token_stream()
+                );
+                Error::new_spanned(len, message)
+            }
         }
     }
 }
@@ -11,10 +11,13 @@ use proc_macro2::Ident;
 use syn::{Path, Visibility};
 
 use crate::bridge_module_attributes::CfgAttr;
+use crate::errors::ParseErrors;
 use crate::parse::TypeDeclarations;
 use crate::parsed_extern_fn::ParsedExternFn;
 
-pub use self::bridge_macro_attributes::{SwiftBridgeModuleAttr, SwiftBridgeModuleAttrs};
+pub use self::bridge_macro_attributes::{
+    SwiftBridgeModuleAttr, SwiftBridgeModuleAttrs, SwiftNaming,
+};
 pub use self::codegen::CodegenConfig;
 
 mod errors;
@@ -32,6 +35,12 @@ mod test_utils;
 
 const SWIFT_BRIDGE_PREFIX: &'static str = "__swift_bridge__";
 
+/// The `@available` attribute of Swift code that uses `Int128` or `UInt128`.
+///
+/// `Int128` and `UInt128` were added in Swift 6.0, but they are only available on newer OSes.
+pub const SWIFT_INT128_AVAILABILITY: &str =
+    "@available(macOS 15.0, iOS 18.0, watchOS 11.0, tvOS 18.0, visionOS 2.0, *)";
+
 /// Represents a type definition within an `extern "Rust"` module, as well as all of its methods.
 ///
 /// ```no_run,ignore
@@ -71,6 +80,15 @@ impl SwiftBridgeModule {
     pub fn set_swift_bridge_path(&mut self, path: Path) {
         self.swift_bridge_path = path;
     }
+
+    /// Set the naming convention for the names of the module's functions, arguments, struct
+    /// fields and enum variants in the generated Swift code.
+    /// Returns an error if two items end up with the same Swift name.
+    pub fn set_swift_naming(&mut self, naming: SwiftNaming) -> syn::Result<()> {
+        let mut errors = ParseErrors::new();
+        self.apply_swift_naming(naming, &mut errors);
+        errors.combine_all()
+    }
 }
 
 #[cfg(test)]
@@ -4,18 +4,28 @@ use crate::errors::{ParseError, ParseErrors};
 use crate::parse::parse_enum::SharedEnumDeclarationParser;
 use crate::parse::parse_extern_mod::ForeignModParser;
 use crate::parse::parse_struct::SharedStructDeclarationParser;
-use crate::SwiftBridgeModule;
+use crate::parse::parse_trait::{resolve_trait_impls, TraitDeclarationParser};
+use crate::{SwiftBridgeModule, SwiftBridgeModuleAttr, SwiftBridgeModuleAttrs};
 use proc_macro2::TokenTree;
 use quote::{quote, ToTokens};
 use syn::parse::{Parse, ParseStream};
 use syn::{Item, ItemMod, Token};
 
+mod codable;
+mod field_attributes;
+mod instantiation;
+mod optional_args_default;
 mod parse_enum;
 mod parse_extern_mod;
 mod parse_struct;
+mod parse_trait;
+mod swift_naming;
+mod unsupported_types;
 
 mod type_declarations;
+pub(crate) use self::field_attributes::FieldAttributes;
 pub(crate) use self::type_declarations::*;
+pub(crate) use self::unsupported_types::visit_nested_types;
 
 impl Parse for SwiftBridgeModule {
     fn parse(input: ParseStream) -> syn::Result<Self> {
@@ -62,7 +72,9 @@ impl Parse for SwiftBridgeModuleAndErrors {
             let mut functions = vec![];
             let mut type_declarations = TypeDeclarations::default();
             let mut unresolved_types = vec![];
+            let mut trait_impls = vec![];
             let mut cfg_attrs = vec![];
+            let mut bridge_module_attrs = vec![];
 
             for attr in item_mod.attrs {
                 match attr.path.to_token_stream().to_string().as_str() {
@@ -70,6 +82,12 @@ impl Parse for SwiftBridgeModuleAndErrors {
                         let cfg: CfgAttr = syn::parse2(attr.tokens)?;
                         cfg_attrs.push(cfg);
                     }
+                    "swift_bridge :: bridge" | "swift_bridge_macro :: bridge"
+                        if !attr.tokens.is_empty() =>
+                    {
+                        let bridge_attrs: SwiftBridgeModuleAttrs = attr.parse_args()?;
+                        bridge_module_attrs = bridge_attrs.attributes;
+                    }
                     _ => {}
                 };
             }
@@ -86,26 +104,47 @@ impl Parse for SwiftBridgeModuleAndErrors {
                         .parse(foreign_mod)?;
                     }
                     Item::Struct(item_struct) => {
-                        let shared_struct = SharedStructDeclarationParser {
+                        let shared_structs = SharedStructDeclarationParser {
                             item_struct,
                             errors: &mut errors,
                         }
                         .parse()?;
-                        type_declarations.insert(
-                            shared_struct.name.to_string(),
-                            TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)),
-                        );
+                        for shared_struct in shared_structs {
+                            type_declarations.insert(
+                                shared_struct
+                                    .generics
+                                    .type_declaration_key(&shared_struct.name),
+                                TypeDeclaration::Shared(SharedTypeDeclaration::Struct(
+                                    shared_struct,
+                                )),
+                            );
+                        }
                     }
                     Item::Enum(item_enum) => {
-                        let shared_enum = SharedEnumDeclarationParser {
+                        let shared_enums = SharedEnumDeclarationParser {
                             item_enum,
                             errors: &mut errors,
                         }
                         .parse()?;
-                        type_declarations.insert(
-                            shared_enum.name.to_string(),
-                            TypeDeclaration::Shared(SharedTypeDeclaration::Enum(shared_enum)),
-                        );
+                        for shared_enum in shared_enums {
+                            type_declarations.insert(
+                                shared_enum.generics.type_declaration_key(&shared_enum.name),
+                                TypeDeclaration::Shared(SharedTypeDeclaration::Enum(shared_enum)),
+                            );
+                        }
+                    }
+                    Item::Trait(item_trait) => {
+                        let trait_declaration = TraitDeclarationParser {
+                            item_trait,
+                            errors: &mut errors,
+                            type_declarations: &type_declarations,
+                            unresolved_types: &mut unresolved_types,
+                        }
+                        .parse();
+                        type_declarations.insert_trait(trait_declaration);
+                    }
+                    Item::Impl(item_impl) if item_impl.trait_.is_some() => {
+                        trait_impls.push(item_impl);
                     }
                     invalid_item => {
                         let error = ParseError::InvalidModuleItem { item: invalid_item };
@@ -114,17 +153,26 @@ impl Parse for SwiftBridgeModuleAndErrors {
                 };
             }
 
+            resolve_trait_impls(trait_impls, &mut type_declarations, &mut errors);
+
             for unresolved_type in unresolved_types.into_iter() {
                 if BridgedType::new_with_type(&unresolved_type, &type_declarations).is_some() {
                     continue;
                 }
 
+                if let Some(error) =
+                    unsupported_types::unresolved_type_error(&unresolved_type, &type_declarations)
+                {
+                    errors.push(error);
+                    continue;
+                }
+
                 errors.push(ParseError::UndeclaredType {
                     ty: unresolved_type.clone(),
                 });
             }
 
-            let module = SwiftBridgeModule {
+            let mut module = SwiftBridgeModule {
                 name: module_name,
                 vis,
                 types: type_declarations,
@@ -132,6 +180,23 @@ impl Parse for SwiftBridgeModuleAndErrors {
                 swift_bridge_path: syn::parse2(quote! { swift_bridge }).unwrap(),
                 cfg_attrs,
             };
+            module.push_unsupported_type_errors(&mut errors);
+            module.push_codable_errors(&mut errors);
+
+            for attr in bridge_module_attrs {
+                match attr {
+                    // The `#[swift_bridge::bridge]` macro sets the path when it generates the Rust
+                    // code.
+                    SwiftBridgeModuleAttr::SwiftBridgePath(_) => {}
+                    SwiftBridgeModuleAttr::SwiftNaming(naming) => {
+                        module.apply_swift_naming(naming, &mut errors);
+                    }
+                    SwiftBridgeModuleAttr::OptionalArgsDefaultNone => {
+                        module.set_optional_args_default_none();
+                    }
+                }
+            }
+
             Ok(SwiftBridgeModuleAndErrors { module, errors })
         } else {
             return Err(syn::Error::new_spanned(
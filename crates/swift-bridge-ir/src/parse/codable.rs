@@ -0,0 +1,242 @@
+use crate::bridged_type::serde_attributes::unsupported_codable_serde_option;
+use crate::bridged_type::{EnumVariant, SharedTypeGenerics, StructFields};
+use crate::errors::{ParseError, ParseErrors};
+use crate::parse::unsupported_types::generic_type_args;
+use crate::parse::{SharedTypeDeclaration, TypeDeclaration, TypeDeclarations};
+use crate::SwiftBridgeModule;
+use syn::Type;
+
+impl SwiftBridgeModule {
+    /// Push an error for each field of a `#[swift_bridge(Codable)]` type that isn't `Codable` in
+    /// Swift, and for each `#[serde(...)]` option that the generated `Codable` conformance
+    /// doesn't follow.
+    pub(super) fn push_codable_errors(&self, errors: &mut ParseErrors) {
+        for declaration in self.types.types() {
+            match declaration {
+                TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct))
+                    if shared_struct.codable =>
+                {
+                    // Each instantiation of a generic struct gets parsed as its own struct, but
+                    // only the first one declares the Swift struct.
+                    let fields = if shared_struct.generics.is_empty() {
+                        &shared_struct.fields
+                    } else {
+                        match shared_struct.declared_fields.as_ref() {
+                            Some(fields) => fields,
+                            None => continue,
+                        }
+                    };
+
+                    push_unsupported_serde_option_error(&shared_struct.serde_attrs, errors);
+                    self.push_codable_field_errors(fields, &shared_struct.generics, errors);
+                }
+                TypeDeclaration::Shared(SharedTypeDeclaration::Enum(shared_enum))
+                    if shared_enum.codable =>
+                {
+                    let variants: &[EnumVariant] = if shared_enum.generics.is_empty() {
+                        &shared_enum.variants
+                    } else {
+                        match shared_enum.declared_variants.as_ref() {
+                            Some(variants) => variants,
+                            None => continue,
+                        }
+                    };
+
+                    push_unsupported_serde_option_error(&shared_enum.serde_attrs, errors);
+                    for variant in variants {
+                        push_unsupported_serde_option_error(&variant.serde_attrs, errors);
+                        self.push_codable_field_errors(
+                            &variant.fields,
+                            &shared_enum.generics,
+                            errors,
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn push_codable_field_errors(
+        &self,
+        fields: &StructFields,
+        generics: &SharedTypeGenerics,
+        errors: &mut ParseErrors,
+    ) {
+        if let StructFields::Named(named) = fields {
+            for field in named {
+                push_unsupported_serde_option_error(&field.serde_attrs, errors);
+            }
+        }
+
+        for field in fields.normalized_fields() {
+            if !is_codable(&field.ty, &self.types, generics) {
+                errors.push(ParseError::CodableFieldNotCodable { ty: field.ty });
+            }
+        }
+    }
+}
+
+fn push_unsupported_serde_option_error(attrs: &[syn::Attribute], errors: &mut ParseErrors) {
+    if let Some(option) = unsupported_codable_serde_option(attrs) {
+        errors.push(ParseError::CodableUnsupportedSerdeOption { option });
+    }
+}
+
+/// Whether or not the Swift type of a field is `Codable`.
+///
+/// The integer, float and `bool` types, `String`, `Option<T>` of a `Codable` type, and shared
+/// types that are also `#[swift_bridge(Codable)]` are `Codable`.
+/// A generic type parameter is `Codable` since the conformance requires it to be.
+fn is_codable(ty: &Type, types: &TypeDeclarations, generics: &SharedTypeGenerics) -> bool {
+    const CODABLE: &[&str] = &[
+        "u8", "i8", "u16", "i16", "u32", "i32", "u64", "i64", "usize", "isize", "f32", "f64",
+        "bool", "String",
+    ];
+
+    let path = match ty {
+        Type::Path(path) => path,
+        _ => return false,
+    };
+
+    if CODABLE.iter().any(|codable| path.path.is_ident(codable))
+        || generics
+            .params
+            .iter()
+            .any(|param| path.path.is_ident(param))
+    {
+        return true;
+    }
+
+    if let Some(inner) = generic_type_args(ty, &["Option"]).first() {
+        return is_codable(inner, types, generics);
+    }
+
+    match types.get_with_type_path(path) {
+        Some(TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct))) => {
+            shared_struct.codable
+        }
+        Some(TypeDeclaration::Shared(SharedTypeDeclaration::Enum(shared_enum))) => {
+            shared_enum.codable
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{parse_errors, parse_ok};
+    use quote::{quote, ToTokens};
+
+    /// Verify that we push an error for a field of a `Codable` type that isn't `Codable` in
+    /// Swift.
+    #[test]
+    fn error_if_codable_field_is_not_codable() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct NotCodable {
+                    field: u8,
+                }
+
+                #[swift_bridge(swift_repr = "struct", Codable)]
+                struct SomeStruct {
+                    field: Vec<u8>,
+                    another_field: Option<NotCodable>,
+                }
+
+                #[swift_bridge(Codable)]
+                enum SomeEnum {
+                    Variant(HashMap<String, u8>),
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 3);
+
+        for (idx, expected_ty) in [
+            "Vec < u8 >",
+            "Option < NotCodable >",
+            "HashMap < String , u8 >",
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            match &errors[idx] {
+                ParseError::CodableFieldNotCodable { ty } => {
+                    assert_eq!(ty.to_token_stream().to_string(), expected_ty);
+                }
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that we push an error for a serde option that `Codable` types don't support.
+    #[test]
+    fn error_if_codable_type_uses_unsupported_serde_option() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", Codable)]
+                #[derive(serde::Serialize, serde::Deserialize)]
+                #[serde(rename_all = "camelCase")]
+                struct SomeStruct {
+                    #[serde(skip)]
+                    field: u8,
+                }
+
+                #[swift_bridge(Codable)]
+                #[derive(serde::Serialize, serde::Deserialize)]
+                #[serde(tag = "type", content = "value")]
+                enum SomeEnum {
+                    #[serde(untagged)]
+                    Variant(u8),
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 4);
+
+        for (idx, expected_option) in ["rename_all", "skip", "tag", "untagged"]
+            .into_iter()
+            .enumerate()
+        {
+            match &errors[idx] {
+                ParseError::CodableUnsupportedSerdeOption { option } => {
+                    assert!(option.is_ident(expected_option));
+                }
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that a `Codable` type can contain other `Codable` types.
+    #[test]
+    fn codable_fields() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(Codable)]
+                enum SomeEnum {
+                    Variant(u8, String),
+                }
+
+                #[swift_bridge(swift_repr = "struct", Codable, instantiate(T = u8))]
+                #[derive(serde::Serialize, serde::Deserialize)]
+                struct SomeStruct<T> {
+                    #[serde(rename = "renamed")]
+                    generic: T,
+                    maybe: Option<f64>,
+                    nested: SomeEnum,
+                    flag: bool,
+                }
+            }
+        };
+
+        parse_ok(tokens);
+    }
+}
@@ -0,0 +1,193 @@
+use proc_macro2::Ident;
+use quote::ToTokens;
+use syn::parse::{Parse, ParseStream};
+use syn::{Attribute, LitStr, Token};
+
+/// The `#[swift_bridge(...)]` attributes on a shared struct's field or a shared enum's variant.
+#[derive(Default)]
+pub(crate) struct FieldAttributes {
+    /// `#[swift_bridge(swift_name = "...")]`
+    pub swift_name: Option<LitStr>,
+    /// `#[swift_bridge(rename = "...")]`, an enum variant's `String` raw value.
+    pub rename: Option<LitStr>,
+}
+
+enum FieldAttr {
+    SwiftName(LitStr),
+    Rename(LitStr),
+}
+
+impl FieldAttributes {
+    /// Parse the `#[swift_bridge(...)]` attributes, ignoring any other attributes.
+    pub fn from_attributes(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut attributes = FieldAttributes::default();
+
+        for attr in attrs {
+            if attr.path.to_token_stream().to_string() != "swift_bridge" {
+                continue;
+            }
+
+            let parsed: FieldAttributes = attr.parse_args()?;
+            if parsed.swift_name.is_some() {
+                attributes.swift_name = parsed.swift_name;
+            }
+            if parsed.rename.is_some() {
+                attributes.rename = parsed.rename;
+            }
+        }
+
+        Ok(attributes)
+    }
+}
+
+impl Parse for FieldAttributes {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut attributes = FieldAttributes::default();
+        let punctuated =
+            syn::punctuated::Punctuated::<FieldAttr, syn::Token![,]>::parse_terminated(input)?;
+        for attr in punctuated.into_iter() {
+            match attr {
+                FieldAttr::SwiftName(name) => {
+                    attributes.swift_name = Some(name);
+                }
+                FieldAttr::Rename(name) => {
+                    attributes.rename = Some(name);
+                }
+            }
+        }
+        Ok(attributes)
+    }
+}
+
+impl Parse for FieldAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let key: Ident = input.parse()?;
+        let attribute = match key.to_string().as_str() {
+            "swift_name" => {
+                input.parse::<Token![=]>()?;
+                let value: LitStr = input.parse()?;
+                FieldAttr::SwiftName(value)
+            }
+            "rename" => {
+                input.parse::<Token![=]>()?;
+                let value: LitStr = input.parse()?;
+                FieldAttr::Rename(value)
+            }
+            _ => {
+                let attrib = key.to_string();
+                Err(syn::Error::new_spanned(
+                    key,
+                    format!(r#"Unrecognized attribute "{}"."#, attrib,),
+                ))?
+            }
+        };
+        Ok(attribute)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bridged_type::StructFields;
+    use crate::test_utils::parse_ok;
+    use crate::SwiftBridgeModule;
+    use quote::quote;
+
+    /// Verify that we can parse the `swift_name` of a shared struct's field and of a shared
+    /// enum's variant.
+    #[test]
+    fn parse_field_and_variant_swift_name() {
+        let tokens = quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    #[swift_bridge(swift_name = "defaultValue")]
+                    default: u8,
+                    other: u8,
+                }
+
+                enum SomeEnum {
+                    #[swift_bridge(swift_name = "someVariant")]
+                    SomeVariant,
+                    Other,
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_struct();
+        match &ty.fields {
+            StructFields::Named(fields) => {
+                assert_eq!(
+                    fields[0].swift_name.as_ref().unwrap().value(),
+                    "defaultValue"
+                );
+                assert!(fields[1].swift_name.is_none());
+            }
+            _ => panic!(),
+        };
+
+        let ty = module.types.types()[1].unwrap_shared_enum();
+        assert_eq!(ty.variants[0].swift_name_string(), "someVariant");
+        assert_eq!(ty.variants[1].swift_name_string(), "Other");
+    }
+
+    /// Verify that we return an error for an unrecognized field attribute.
+    #[test]
+    fn error_if_unrecognized_field_attribute() {
+        let tokens = quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    #[swift_bridge(unknown_attribute)]
+                    field: u8,
+                }
+            }
+        };
+
+        let error = syn::parse2::<SwiftBridgeModule>(tokens).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            r#"Unrecognized attribute "unknown_attribute"."#
+        );
+    }
+
+    /// Verify that we return an error for a `swift_name` on an unnamed field, since Swift
+    /// accesses those fields by their position.
+    #[test]
+    fn error_if_swift_name_on_unnamed_field() {
+        let tokens = quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct(#[swift_bridge(swift_name = "field")] u8);
+            }
+        };
+
+        let error = syn::parse2::<SwiftBridgeModule>(tokens).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "The swift_name attribute can only be used on named fields."
+        );
+    }
+
+    /// Verify that we return an error for a `rename` on a field, since only enum variants have
+    /// raw values.
+    #[test]
+    fn error_if_rename_on_field() {
+        let tokens = quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    #[swift_bridge(rename = "renamed")]
+                    field: u8,
+                }
+            }
+        };
+
+        let error = syn::parse2::<SwiftBridgeModule>(tokens).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "The rename attribute can only be used on enum variants."
+        );
+    }
+}
@@ -0,0 +1,226 @@
+use crate::bridged_type::SharedTypeGenerics;
+use proc_macro2::Ident;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{
+    FnArg, ForeignItemFn, GenericArgument, GenericParam, Generics, Path, PathArguments, ReturnType,
+    Token, Type, TypeParamBound,
+};
+
+/// The concrete types that a generic function, struct or enum gets instantiated with.
+///
+/// `#[swift_bridge(instantiate(T = u32))]`
+#[derive(Clone)]
+pub(crate) struct Instantiation {
+    pub types: Vec<(Ident, Type)>,
+}
+
+impl Instantiation {
+    /// Whether or not every one of the generic type parameters, and nothing else, was given a
+    /// concrete type.
+    pub fn is_valid_for(&self, generics: &Generics) -> bool {
+        let type_params = type_params(generics);
+
+        self.types.len() == type_params.len()
+            && type_params
+                .iter()
+                .all(|param| self.types.iter().any(|(generic, _)| generic == *param))
+    }
+
+    /// The function's signature, with each generic type parameter replaced by its concrete type.
+    ///
+    /// `fn parse<T>(s: &str) -> T` instantiated with `T = u32` gives us
+    /// `fn parse(s: &str) -> u32`.
+    pub fn instantiate_fn(&self, func: &ForeignItemFn) -> ForeignItemFn {
+        let mut func = func.clone();
+
+        for arg in func.sig.inputs.iter_mut() {
+            if let FnArg::Typed(pat_ty) = arg {
+                self.substitute(&mut pat_ty.ty);
+            }
+        }
+        if let ReturnType::Type(_, return_ty) = &mut func.sig.output {
+            self.substitute(return_ty);
+        }
+
+        func.sig.generics.params = func
+            .sig
+            .generics
+            .params
+            .into_iter()
+            .filter(|param| !matches!(param, GenericParam::Type(_)))
+            .collect();
+        if func.sig.generics.params.is_empty() {
+            func.sig.generics.lt_token = None;
+            func.sig.generics.gt_token = None;
+            func.sig.generics.where_clause = None;
+        }
+
+        func
+    }
+
+    /// The concrete types, in the order that the generic type parameters were declared.
+    pub fn ordered_types(&self, generics: &Generics) -> Vec<Type> {
+        type_params(generics)
+            .into_iter()
+            .filter_map(|param| {
+                self.types
+                    .iter()
+                    .find(|(generic, _)| generic == param)
+                    .map(|(_, ty)| ty.clone())
+            })
+            .collect()
+    }
+
+    /// The generics of the shared struct or enum instantiation.
+    pub fn shared_type_generics(&self, generics: &Generics) -> SharedTypeGenerics {
+        SharedTypeGenerics {
+            params: type_params(generics).into_iter().cloned().collect(),
+            args: self.ordered_types(generics),
+        }
+    }
+
+    /// The generic arguments of an opaque type, with each generic type parameter replaced by its
+    /// concrete type.
+    ///
+    /// `type Cache<K, V>` instantiated with `K = String, V = Vec<u8>` gives us
+    /// `Cache<String, Vec<u8>>`.
+    ///
+    /// Returns `None` if the instantiation names a generic type parameter that the type doesn't
+    /// have.
+    pub fn instantiate_opaque_type_generics(&self, generics: &[Type]) -> Option<Vec<Type>> {
+        let names_every_param = self.types.iter().all(|(generic, _)| {
+            generics.iter().any(|ty| match ty {
+                Type::Path(path) => path.path.is_ident(generic),
+                _ => false,
+            })
+        });
+        if !names_every_param {
+            return None;
+        }
+
+        let mut generics = generics.to_vec();
+        for ty in generics.iter_mut() {
+            self.substitute(ty);
+        }
+
+        Some(generics)
+    }
+
+    fn concrete_type(&self, ty: &Type) -> Option<&Type> {
+        let ident = match ty {
+            Type::Path(path) if path.qself.is_none() => path.path.get_ident()?,
+            _ => return None,
+        };
+
+        self.types
+            .iter()
+            .find(|(generic, _)| generic == ident)
+            .map(|(_, concrete)| concrete)
+    }
+
+    /// Replace each of the generic type parameters inside of the type with its concrete type.
+    ///
+    /// `Vec<T>` instantiated with `T = u32` gives us `Vec<u32>`.
+    pub fn substitute(&self, ty: &mut Type) {
+        if let Some(concrete) = self.concrete_type(ty) {
+            *ty = concrete.clone();
+            return;
+        }
+
+        match ty {
+            Type::Array(array) => self.substitute(&mut array.elem),
+            Type::Group(group) => self.substitute(&mut group.elem),
+            Type::Paren(paren) => self.substitute(&mut paren.elem),
+            Type::Path(path) => self.substitute_in_path(&mut path.path),
+            Type::Ptr(ptr) => self.substitute(&mut ptr.elem),
+            Type::Reference(reference) => self.substitute(&mut reference.elem),
+            Type::Slice(slice) => self.substitute(&mut slice.elem),
+            Type::TraitObject(trait_object) => self.substitute_in_bounds(&mut trait_object.bounds),
+            Type::Tuple(tuple) => {
+                for elem in tuple.elems.iter_mut() {
+                    self.substitute(elem);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn substitute_in_path(&self, path: &mut Path) {
+        for segment in path.segments.iter_mut() {
+            match &mut segment.arguments {
+                PathArguments::AngleBracketed(args) => {
+                    for arg in args.args.iter_mut() {
+                        if let GenericArgument::Type(ty) = arg {
+                            self.substitute(ty);
+                        }
+                    }
+                }
+                // `Box<dyn Fn(T) -> T>`
+                PathArguments::Parenthesized(args) => {
+                    for input in args.inputs.iter_mut() {
+                        self.substitute(input);
+                    }
+                    if let ReturnType::Type(_, output) = &mut args.output {
+                        self.substitute(output);
+                    }
+                }
+                PathArguments::None => {}
+            }
+        }
+    }
+
+    fn substitute_in_bounds<P>(&self, bounds: &mut Punctuated<TypeParamBound, P>) {
+        for bound in bounds.iter_mut() {
+            if let TypeParamBound::Trait(bound) = bound {
+                self.substitute_in_path(&mut bound.path);
+            }
+        }
+    }
+}
+
+/// `(T = u32, U = String)`
+impl Parse for Instantiation {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        syn::parenthesized!(content in input);
+
+        let types = Punctuated::<_, Token![,]>::parse_terminated_with(&content, |input| {
+            let generic: Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+            let ty: Type = input.parse()?;
+            Ok((generic, ty))
+        })?;
+
+        Ok(Instantiation {
+            types: types.into_iter().collect(),
+        })
+    }
+}
+
+/// Whether or not a shared struct or enum lists a concrete type for each of its generic type
+/// parameters, and only uses its generic type parameters in fields that we can declare in Swift.
+///
+/// Shared structs and enums with lifetime parameters can't be generic.
+pub(super) fn is_valid_shared_type_instantiation<'a>(
+    generics: &Generics,
+    instantiations: &[Instantiation],
+    mut field_types: impl Iterator<Item = &'a Type>,
+) -> bool {
+    let shared_type_generics = SharedTypeGenerics {
+        params: type_params(generics).into_iter().cloned().collect(),
+        args: vec![],
+    };
+
+    !shared_type_generics.is_empty()
+        && generics.lifetimes().next().is_none()
+        && !instantiations.is_empty()
+        && instantiations
+            .iter()
+            .all(|instantiation| instantiation.is_valid_for(generics))
+        && field_types.all(|ty| shared_type_generics.is_supported_field_type(ty))
+}
+
+fn type_params(generics: &Generics) -> Vec<&Ident> {
+    generics.type_params().map(|ty| &ty.ident).collect()
+}
@@ -0,0 +1,42 @@
+use crate::bridged_type::{fn_arg_name, BridgeableType, BridgedType};
+use crate::SwiftBridgeModule;
+
+impl SwiftBridgeModule {
+    /// Give every `Option` argument of the module's `extern "Rust"` functions a default value of
+    /// `nil` in the generated Swift code, unless the argument has its own
+    /// `#[swift_bridge(default = ...)]`.
+    ///
+    /// `Option<String>` and `Option<&str>` arguments are skipped, since the generated Swift
+    /// function takes them as a generic type that Swift can't infer when the argument is left
+    /// out.
+    pub fn set_optional_args_default_none(&mut self) {
+        let types = &self.types;
+
+        for func in self.functions.iter_mut() {
+            if func.host_lang.is_swift() {
+                continue;
+            }
+
+            for arg in func.func.sig.inputs.iter() {
+                let arg_name = match fn_arg_name(arg) {
+                    Some(arg_name) if arg_name != "self" => arg_name,
+                    _ => continue,
+                };
+                if func.argument_defaults.contains_key(arg_name) {
+                    continue;
+                }
+
+                let bridged_ty = match BridgedType::new_with_fn_arg(arg, types) {
+                    Some(bridged_ty) => bridged_ty,
+                    None => continue,
+                };
+                if bridged_ty.as_option().is_none() || bridged_ty.is_generic_swift_arg(types) {
+                    continue;
+                }
+
+                func.argument_defaults
+                    .insert(arg_name.clone(), "nil".to_string());
+            }
+        }
+    }
+}
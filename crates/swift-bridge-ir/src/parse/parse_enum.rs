@@ -1,6 +1,11 @@
-use crate::bridged_type::{EnumVariant, SharedEnum, StructFields};
-use crate::errors::ParseErrors;
-use syn::ItemEnum;
+use crate::bridged_type::serde_attributes::is_serde_attribute;
+use crate::bridged_type::{
+    integer_discriminant_value, EnumVariant, SharedEnum, SharedTypeGenerics, StructFields,
+};
+use crate::errors::{ParseError, ParseErrors};
+use crate::parse::instantiation::is_valid_shared_type_instantiation;
+use crate::parse::FieldAttributes;
+use syn::{Attribute, ItemEnum};
 
 use self::enum_attributes::SharedEnumAllAttributes;
 
@@ -12,7 +17,9 @@ pub(crate) struct SharedEnumDeclarationParser<'a> {
 }
 
 impl<'a> SharedEnumDeclarationParser<'a> {
-    pub fn parse(self) -> Result<SharedEnum, syn::Error> {
+    /// A generic enum gets parsed once for each of its instantiations, as if each instantiation
+    /// were its own enum.
+    pub fn parse(self) -> Result<Vec<SharedEnum>, syn::Error> {
         let item_enum = self.item_enum;
 
         let attribs = SharedEnumAllAttributes::from_attributes(&item_enum.attrs)?;
@@ -20,29 +27,141 @@ impl<'a> SharedEnumDeclarationParser<'a> {
 
         let mut variants = vec![];
 
-        for v in item_enum.variants {
+        for v in item_enum.variants.iter() {
+            let attributes = FieldAttributes::from_attributes(&v.attrs)?;
             let variant = EnumVariant {
-                name: v.ident,
-                fields: StructFields::from_syn_fields(v.fields),
+                name: v.ident.clone(),
+                fields: StructFields::from_syn_fields(v.fields.clone())?,
+                serde_attrs: serde_attrs(&v.attrs),
+                discriminant: v.discriminant.as_ref().map(|(_, d)| d.clone()),
+                swift_name: attributes.swift_name,
+                rename: attributes.rename,
             };
             variants.push(variant);
         }
 
+        for (_, discriminant) in item_enum
+            .variants
+            .iter()
+            .filter_map(|v| v.discriminant.as_ref())
+        {
+            if integer_discriminant_value(discriminant).is_none() {
+                self.errors.push(ParseError::InvalidEnumDiscriminant {
+                    discriminant: discriminant.clone(),
+                });
+            }
+        }
+
+        // Swift can only list the cases of, or give raw values to, enums without associated
+        // values.
+        if variants.iter().any(|v| !v.fields.is_empty()) {
+            let fieldless_attributes = attribs.swift_bridge.case_iterable.iter().chain(
+                attribs
+                    .swift_bridge
+                    .raw_representable
+                    .iter()
+                    .map(|(a, _)| a),
+            );
+            for attribute in fieldless_attributes {
+                self.errors.push(ParseError::FieldlessEnumAttribute {
+                    attribute: attribute.clone(),
+                });
+            }
+        }
+
         let shared_enum = SharedEnum {
-            name: item_enum.ident,
+            name: item_enum.ident.clone(),
             variants,
             already_declared: attribs.swift_bridge.already_declared,
             swift_name: attribs.swift_bridge.swift_name,
             derive: attribs.derive,
+            codable: attribs.swift_bridge.codable,
+            serde_attrs: attribs.serde_attrs,
+            case_iterable: attribs.swift_bridge.case_iterable.is_some(),
+            raw_value_type: attribs
+                .swift_bridge
+                .raw_representable
+                .as_ref()
+                .map(|(_, raw_value_type)| *raw_value_type),
+            repr: attribs.repr,
+            generics: SharedTypeGenerics::default(),
+            declared_variants: None,
         };
 
-        Ok(shared_enum)
+        let instantiations = attribs.swift_bridge.instantiations;
+        let is_generic = item_enum.generics.type_params().next().is_some();
+        if !is_generic && instantiations.is_empty() {
+            return Ok(vec![shared_enum]);
+        }
+
+        if !is_valid_shared_type_instantiation(
+            &item_enum.generics,
+            &instantiations,
+            item_enum
+                .variants
+                .iter()
+                .flat_map(|v| v.fields.iter().map(|field| &field.ty)),
+        ) {
+            self.errors
+                .push(ParseError::InvalidSharedTypeInstantiation {
+                    ty: item_enum.ident,
+                });
+            return Ok(vec![]);
+        }
+
+        let instantiations = instantiations
+            .iter()
+            .enumerate()
+            .map(|(idx, instantiation)| {
+                let variants = item_enum
+                    .variants
+                    .iter()
+                    .map(|v| {
+                        let mut fields = v.fields.clone();
+                        for field in fields.iter_mut() {
+                            instantiation.substitute(&mut field.ty);
+                        }
+
+                        let attributes = FieldAttributes::from_attributes(&v.attrs)?;
+                        Ok(EnumVariant {
+                            name: v.ident.clone(),
+                            fields: StructFields::from_syn_fields(fields)?,
+                            serde_attrs: serde_attrs(&v.attrs),
+                            discriminant: v.discriminant.as_ref().map(|(_, d)| d.clone()),
+                            swift_name: attributes.swift_name,
+                            rename: attributes.rename,
+                        })
+                    })
+                    .collect::<syn::Result<Vec<_>>>()?;
+
+                Ok(SharedEnum {
+                    variants,
+                    generics: instantiation.shared_type_generics(&item_enum.generics),
+                    declared_variants: if idx == 0 {
+                        Some(shared_enum.variants.clone())
+                    } else {
+                        None
+                    },
+                    ..shared_enum.clone()
+                })
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        Ok(instantiations)
     }
 }
 
+fn serde_attrs(attrs: &[Attribute]) -> Vec<Attribute> {
+    attrs
+        .iter()
+        .filter(|attr| is_serde_attribute(attr))
+        .cloned()
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::bridged_type::StructFields;
+    use crate::bridged_type::{EnumRawValueType, StructFields};
     use crate::errors::ParseError;
     use crate::test_utils::{parse_errors, parse_ok};
     use quote::{quote, ToTokens};
@@ -202,4 +321,245 @@ mod tests {
         let ty = module.types.types()[0].unwrap_shared_enum();
         assert!(ty.derive.debug);
     }
+
+    /// Verify that we can parse the standard derives on enums.
+    #[test]
+    fn derive_standard_traits() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+                enum Foo {
+                    Variant1
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_enum();
+        assert!(ty.derive.copy);
+        assert!(ty.derive.clone);
+        assert!(ty.derive.partial_eq);
+        assert!(ty.derive.eq);
+        assert!(ty.derive.hash);
+        assert!(ty.derive.partial_ord);
+        assert!(ty.derive.ord);
+        assert!(!ty.derive.debug);
+    }
+
+    /// Verify that we parse the `#[swift_bridge(Codable)]` attribute, the serde derives and the
+    /// `#[serde(...)]` attributes on the enum and its variants.
+    #[test]
+    fn parse_codable_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(Codable)]
+                #[derive(serde::Serialize, Deserialize)]
+                #[serde(deny_unknown_fields)]
+                enum SomeEnum {
+                    #[serde(rename = "renamed")]
+                    Variant1,
+                    Variant2 {
+                        #[serde(rename = "renamedField")]
+                        field: u8
+                    }
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_enum();
+        assert!(ty.codable);
+        assert_eq!(ty.derive.serde.len(), 2);
+        assert_eq!(ty.serde_attrs.len(), 1);
+
+        assert_eq!(ty.variants[0].serde_name_string(), "renamed");
+        assert_eq!(ty.variants[1].serde_name_string(), "Variant2");
+        match &ty.variants[1].fields {
+            StructFields::Named(fields) => {
+                assert_eq!(fields[0].serde_name_string(), "renamedField");
+            }
+            _ => panic!(),
+        }
+    }
+
+    /// Verify that we can parse the `CaseIterable` and `RawRepresentable` attributes along with
+    /// the enum's `#[repr]` and explicit discriminants.
+    #[test]
+    fn parse_case_iterable_and_raw_representable() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(CaseIterable, RawRepresentable = "Int")]
+                #[repr(u16)]
+                enum SomeEnum {
+                    Variant1 = 5,
+                    Variant2,
+                    Variant3 = -1,
+                }
+
+                #[swift_bridge(RawRepresentable = "String")]
+                enum AnotherEnum {
+                    #[swift_bridge(rename = "renamed")]
+                    Variant1,
+                    Variant2,
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_enum();
+        assert!(ty.case_iterable);
+        assert_eq!(ty.raw_value_type, Some(EnumRawValueType::Int));
+        assert_eq!(ty.repr.as_ref().unwrap().to_string(), "u16");
+        assert_eq!(ty.discriminant_values(), vec![5, 6, -1]);
+
+        let ty = module.types.types()[1].unwrap_shared_enum();
+        assert!(!ty.case_iterable);
+        assert_eq!(ty.raw_value_type, Some(EnumRawValueType::String));
+        assert!(ty.repr.is_none());
+        assert_eq!(ty.discriminant_values(), vec![0, 1]);
+        assert_eq!(ty.variants[0].raw_value_string(), "renamed");
+        assert_eq!(ty.variants[1].raw_value_string(), "Variant2");
+    }
+
+    /// Verify that we return an error if the raw value type isn't supported.
+    #[test]
+    fn error_if_invalid_raw_value_type() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(RawRepresentable = "Float")]
+                enum SomeEnum {
+                    Variant
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            ParseError::InvalidEnumRawValueType { raw_value_type } => {
+                assert_eq!(raw_value_type.value(), "Float");
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we return an error if `CaseIterable` or `RawRepresentable` is used on an enum
+    /// that has a variant with fields.
+    #[test]
+    fn error_if_fieldless_enum_attribute_on_enum_with_fields() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(CaseIterable, RawRepresentable = "Int")]
+                enum SomeEnum {
+                    Variant1,
+                    Variant2(u8)
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 2);
+        match &errors[0] {
+            ParseError::FieldlessEnumAttribute { attribute } => {
+                assert_eq!(&attribute.to_string(), "CaseIterable");
+            }
+            _ => panic!(),
+        };
+        match &errors[1] {
+            ParseError::FieldlessEnumAttribute { attribute } => {
+                assert_eq!(&attribute.to_string(), "RawRepresentable");
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we return an error if a discriminant isn't an integer literal.
+    #[test]
+    fn error_if_discriminant_not_integer_literal() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                enum SomeEnum {
+                    Variant = SOME_CONST
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            ParseError::InvalidEnumDiscriminant { discriminant } => {
+                assert_eq!(discriminant.to_token_stream().to_string(), "SOME_CONST");
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we parse a generic enum once for each of its instantiations.
+    #[test]
+    fn parse_generic_enum_instantiations() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(instantiate(T = u8), instantiate(T = String))]
+                enum Event<T> {
+                    Data(T),
+                    Closed,
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(module.types.types().len(), 2);
+
+        let event_u8 = module.types.get("Event<u8>").unwrap().unwrap_shared_enum();
+        assert_eq!(event_u8.generics.params[0].to_string(), "T");
+        assert!(event_u8.declared_variants.is_some());
+
+        let event_string = module
+            .types
+            .get("Event<String>")
+            .unwrap()
+            .unwrap_shared_enum();
+        assert_eq!(
+            event_string.generics.dollar_prefixed_args_string(),
+            "$String"
+        );
+        assert!(event_string.declared_variants.is_none());
+    }
+
+    /// Verify that we push an error if a generic enum is missing an instantiation.
+    #[test]
+    fn error_if_generic_enum_missing_instantiation() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                enum Event<T> {
+                    Data(T),
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            ParseError::InvalidSharedTypeInstantiation { ty } => {
+                assert_eq!(ty.to_string(), "Event");
+            }
+            _ => panic!(),
+        };
+    }
 }
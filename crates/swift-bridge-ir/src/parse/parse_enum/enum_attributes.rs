@@ -1,5 +1,7 @@
-use crate::bridged_type::DeriveAttrs;
+use crate::bridged_type::serde_attributes::is_serde_derive;
+use crate::bridged_type::{DeriveAttrs, EnumRawValueType};
 use crate::errors::ParseError;
+use crate::parse::instantiation::Instantiation;
 use crate::parse::move_input_cursor_to_next_comma;
 use proc_macro2::Ident;
 use quote::ToTokens;
@@ -11,6 +13,9 @@ pub(super) struct SharedEnumAllAttributes {
     pub errors: Vec<ParseError>,
     pub swift_bridge: SharedEnumSwiftBridgeAttributes,
     pub derive: DeriveAttrs,
+    pub serde_attrs: Vec<Attribute>,
+    /// The `u8` in `#[repr(u8)]`.
+    pub repr: Option<Ident>,
 }
 
 impl SharedEnumAllAttributes {
@@ -26,8 +31,20 @@ impl SharedEnumAllAttributes {
                         syn::punctuated::Punctuated::<Path, syn::Token![,]>::parse_terminated;
                     let args = attr.parse_args_with(parser)?;
                     for arg in args.into_iter() {
+                        if is_serde_derive(&arg) {
+                            attributes.derive.serde.push(arg);
+                            continue;
+                        }
+
                         match arg.get_ident().unwrap().to_string().as_str() {
                             "Debug" => attributes.derive.debug = true,
+                            "Clone" => attributes.derive.clone = true,
+                            "Copy" => attributes.derive.copy = true,
+                            "PartialEq" => attributes.derive.partial_eq = true,
+                            "Eq" => attributes.derive.eq = true,
+                            "Hash" => attributes.derive.hash = true,
+                            "PartialOrd" => attributes.derive.partial_ord = true,
+                            "Ord" => attributes.derive.ord = true,
                             _ => todo!("Unsupported derive macro; please see https://github.com/chinedufn/swift-bridge/issues/190#issuecomment-1463234027"),
                         }
                     }
@@ -38,6 +55,8 @@ impl SharedEnumAllAttributes {
                         .errors
                         .append(&mut attributes.swift_bridge.errors);
                 }
+                "serde" => attributes.serde_attrs.push(attr.clone()),
+                "repr" => attributes.repr = Some(attr.parse_args()?),
                 _ => todo!("Push unsupported attribute error."),
             };
         }
@@ -48,7 +67,11 @@ impl SharedEnumAllAttributes {
 
 pub(super) enum EnumAttr {
     AlreadyDeclared,
+    CaseIterable(Ident),
+    Codable,
     Error(ParseError),
+    Instantiate(Instantiation),
+    RawRepresentable(Ident, LitStr),
     SwiftName(LitStr),
 }
 
@@ -56,14 +79,32 @@ pub(super) enum EnumAttr {
 pub(super) struct SharedEnumSwiftBridgeAttributes {
     pub errors: Vec<ParseError>,
     pub already_declared: bool,
+    pub case_iterable: Option<Ident>,
+    pub codable: bool,
+    pub raw_representable: Option<(Ident, EnumRawValueType)>,
     pub swift_name: Option<LitStr>,
+    pub instantiations: Vec<Instantiation>,
 }
 
 impl SharedEnumSwiftBridgeAttributes {
     pub(super) fn store_attrib(&mut self, attrib: EnumAttr) -> syn::Result<()> {
         match attrib {
             EnumAttr::AlreadyDeclared => self.already_declared = true,
+            EnumAttr::CaseIterable(attribute) => self.case_iterable = Some(attribute),
+            EnumAttr::Codable => self.codable = true,
+            EnumAttr::RawRepresentable(attribute, raw_value_type) => {
+                match raw_value_type.value().as_str() {
+                    "Int" => self.raw_representable = Some((attribute, EnumRawValueType::Int)),
+                    "String" => {
+                        self.raw_representable = Some((attribute, EnumRawValueType::String))
+                    }
+                    _ => self
+                        .errors
+                        .push(ParseError::InvalidEnumRawValueType { raw_value_type }),
+                }
+            }
             EnumAttr::Error(error) => self.errors.push(error),
+            EnumAttr::Instantiate(instantiation) => self.instantiations.push(instantiation),
             EnumAttr::SwiftName(name) => self.swift_name = Some(name),
         };
         Ok(())
@@ -91,6 +132,15 @@ impl Parse for EnumAttr {
 
         let attr = match key.to_string().as_str() {
             "already_declared" => EnumAttr::AlreadyDeclared,
+            "CaseIterable" => EnumAttr::CaseIterable(key),
+            "Codable" => EnumAttr::Codable,
+            "RawRepresentable" => {
+                input.parse::<Token![=]>()?;
+
+                let raw_value_type = input.parse()?;
+                EnumAttr::RawRepresentable(key, raw_value_type)
+            }
+            "instantiate" => EnumAttr::Instantiate(input.parse()?),
             "swift_name" => {
                 input.parse::<Token![=]>()?;
 
@@ -1,4 +1,4 @@
-use self::argument_attributes::ArgumentAttributes;
+use self::argument_attributes::{ArgumentAttributes, ArgumentDefault};
 pub(crate) use self::opaque_type_attributes::OpaqueTypeAllAttributes;
 use crate::bridged_type::{
     bridgeable_type_from_fn_arg, pat_type_pat_is_self, BridgeableType, BridgedType,
@@ -17,9 +17,7 @@ use quote::{format_ident, ToTokens};
 use std::cmp::Ordering;
 use std::collections::{HashMap, HashSet};
 use std::ops::Deref;
-use syn::{
-    FnArg, ForeignItem, ForeignItemFn, GenericParam, ItemForeignMod, LitStr, Pat, ReturnType, Type,
-};
+use syn::{FnArg, ForeignItem, ForeignItemFn, ItemForeignMod, LitStr, Pat, ReturnType, Type};
 
 mod argument_attributes;
 mod function_attributes;
@@ -101,86 +99,112 @@ impl<'a> ForeignModParser<'a> {
                         attributes = attr.parse_args()?;
                     }
 
-                    for arg in func.sig.inputs.iter() {
-                        if let FnArg::Typed(pat_ty) = arg {
-                            let ty = &pat_ty.ty;
-                            if BridgedType::new_with_type(&ty, &self.type_declarations).is_none() {
-                                self.unresolved_types.push(ty.deref().clone());
-                            }
-                        }
-                    }
+                    for (func, instantiation) in self.instantiate_fn(func, &attributes, host_lang) {
+                        let attributes = attributes.clone();
 
-                    let return_type = &func.sig.output;
-                    let mut swift_failable_initializer: Option<FailableInitializerType> = None;
-                    if let ReturnType::Type(_, return_ty) = return_type {
-                        let bridged_return_type =
-                            BridgedType::new_with_type(return_ty.deref(), &self.type_declarations);
-
-                        if let Some(ty) = &bridged_return_type {
-                            if ty.as_option().is_some() && attributes.is_swift_initializer {
-                                swift_failable_initializer = Some(FailableInitializerType::Option);
-                            } else if ty.as_result().is_some() && attributes.is_swift_initializer {
-                                swift_failable_initializer =
-                                    Some(FailableInitializerType::Throwing);
+                        for arg in func.sig.inputs.iter() {
+                            if let FnArg::Typed(pat_ty) = arg {
+                                let ty = &pat_ty.ty;
+                                if BridgedType::new_with_type(&ty, &self.type_declarations)
+                                    .is_none()
+                                {
+                                    self.unresolved_types.push(ty.deref().clone());
+                                }
                             }
                         }
-                        if bridged_return_type.is_none() {
-                            self.unresolved_types.push(return_ty.deref().clone());
-                        }
-                    }
-                    let first_input = func.sig.inputs.iter().next();
-
-                    let associated_type = self.get_associated_type(
-                        first_input,
-                        func.clone(),
-                        &attributes,
-                        &mut local_type_declarations,
-                        swift_failable_initializer.clone(),
-                    )?;
-
-                    if attributes.is_swift_identifiable {
-                        let args = &func.sig.inputs;
-
-                        let mut is_ref_self_no_args = args.len() == 1;
-                        if is_ref_self_no_args {
-                            is_ref_self_no_args = match args.iter().next().unwrap() {
-                                FnArg::Receiver(receiver) => {
-                                    receiver.reference.is_some() && receiver.mutability.is_none()
-                                }
-                                FnArg::Typed(pat_ty) => {
-                                    pat_type_pat_is_self(pat_ty)
-                                        && pat_ty.ty.to_token_stream().to_string().starts_with("&")
+
+                        let return_type = &func.sig.output;
+                        let mut swift_failable_initializer: Option<FailableInitializerType> = None;
+                        if let ReturnType::Type(_, return_ty) = return_type {
+                            let bridged_return_type = BridgedType::new_with_type(
+                                return_ty.deref(),
+                                &self.type_declarations,
+                            );
+
+                            if let Some(ty) = &bridged_return_type {
+                                if ty.as_option().is_some() && attributes.is_swift_initializer {
+                                    swift_failable_initializer =
+                                        Some(FailableInitializerType::Option);
+                                } else if ty.as_result().is_some()
+                                    && attributes.is_swift_initializer
+                                {
+                                    swift_failable_initializer =
+                                        Some(FailableInitializerType::Throwing);
                                 }
-                            };
+                            }
+                            if bridged_return_type.is_none() {
+                                self.unresolved_types.push(return_ty.deref().clone());
+                            }
                         }
+                        let first_input = func.sig.inputs.iter().next();
 
-                        let has_return_type = matches!(&func.sig.output, ReturnType::Type(_, _));
+                        let associated_type = self.get_associated_type(
+                            first_input,
+                            func.clone(),
+                            &attributes,
+                            &mut local_type_declarations,
+                            swift_failable_initializer.clone(),
+                        )?;
 
-                        if !is_ref_self_no_args {
-                            self.errors.push(ParseError::FunctionAttribute(
-                                FunctionAttributeParseError::Identifiable(
-                                    IdentifiableParseError::MustBeRefSelf {
-                                        fn_ident: func.sig.ident.clone(),
-                                    },
-                                ),
-                            ));
+                        if host_lang.is_swift() {
+                            if let Some(TypeDeclaration::Shared(_)) = &associated_type {
+                                self.errors.push(ParseError::SharedTypeMethodInExternSwift {
+                                    func: func.clone(),
+                                });
+                            }
                         }
-                        if !has_return_type {
-                            self.errors.push(ParseError::FunctionAttribute(
-                                FunctionAttributeParseError::Identifiable(
-                                    IdentifiableParseError::MissingReturnType {
-                                        fn_ident: func.sig.ident.clone(),
-                                    },
-                                ),
-                            ));
+
+                        if attributes.is_swift_identifiable {
+                            let args = &func.sig.inputs;
+
+                            let mut is_ref_self_no_args = args.len() == 1;
+                            if is_ref_self_no_args {
+                                is_ref_self_no_args = match args.iter().next().unwrap() {
+                                    FnArg::Receiver(receiver) => {
+                                        receiver.reference.is_some()
+                                            && receiver.mutability.is_none()
+                                    }
+                                    FnArg::Typed(pat_ty) => {
+                                        pat_type_pat_is_self(pat_ty)
+                                            && pat_ty
+                                                .ty
+                                                .to_token_stream()
+                                                .to_string()
+                                                .starts_with("&")
+                                    }
+                                };
+                            }
+
+                            let has_return_type =
+                                matches!(&func.sig.output, ReturnType::Type(_, _));
+
+                            if !is_ref_self_no_args {
+                                self.errors.push(ParseError::FunctionAttribute(
+                                    FunctionAttributeParseError::Identifiable(
+                                        IdentifiableParseError::MustBeRefSelf {
+                                            fn_ident: func.sig.ident.clone(),
+                                        },
+                                    ),
+                                ));
+                            }
+                            if !has_return_type {
+                                self.errors.push(ParseError::FunctionAttribute(
+                                    FunctionAttributeParseError::Identifiable(
+                                        IdentifiableParseError::MissingReturnType {
+                                            fn_ident: func.sig.ident.clone(),
+                                        },
+                                    ),
+                                ));
+                            }
                         }
-                    }
-                    let mut argument_labels: HashMap<Ident, LitStr> = HashMap::new();
-                    for arg in func.sig.inputs.iter() {
-                        let is_mutable_ref = fn_arg_is_mutable_reference(arg);
+                        let mut argument_labels: HashMap<Ident, LitStr> = HashMap::new();
+                        let mut argument_defaults: HashMap<Ident, String> = HashMap::new();
+                        for arg in func.sig.inputs.iter() {
+                            let is_mutable_ref = fn_arg_is_mutable_reference(arg);
 
-                        let is_copy_opaque_type =
-                            if let Some(TypeDeclaration::Opaque(o)) = associated_type.as_ref() {
+                            let is_copy_opaque_type = if let Some(TypeDeclaration::Opaque(o)) =
+                                associated_type.as_ref()
+                            {
                                 o.attributes.copy.is_some()
                             } else if let Some(ty) =
                                 bridgeable_type_from_fn_arg(arg, &self.type_declarations)
@@ -190,107 +214,133 @@ impl<'a> ForeignModParser<'a> {
                                 false
                             };
 
-                        if is_mutable_ref && is_copy_opaque_type {
-                            self.errors
-                                .push(ParseError::ArgCopyAndRefMut { arg: arg.clone() });
-                        }
-                        match arg {
-                            syn::FnArg::Typed(ty) => {
-                                for attr in ty.attrs.iter() {
-                                    let attribute: ArgumentAttributes = attr.parse_args()?;
-                                    if let Some(label) = attribute.label {
-                                        argument_labels.insert(
-                                            format_ident!(
-                                                "{}",
-                                                ty.pat.to_token_stream().to_string()
-                                            ),
-                                            label,
-                                        );
+                            if is_mutable_ref && is_copy_opaque_type {
+                                self.errors
+                                    .push(ParseError::ArgCopyAndRefMut { arg: arg.clone() });
+                            }
+                            match arg {
+                                syn::FnArg::Typed(ty) => {
+                                    for attr in ty.attrs.iter() {
+                                        let attribute: ArgumentAttributes = attr.parse_args()?;
+                                        if let Some(label) = attribute.label {
+                                            argument_labels.insert(
+                                                format_ident!(
+                                                    "{}",
+                                                    ty.pat.to_token_stream().to_string()
+                                                ),
+                                                label,
+                                            );
+                                        }
+                                        if let Some(default) = attribute.default {
+                                            if let Some(default) =
+                                                self.parse_argument_default(arg, default, host_lang)
+                                            {
+                                                argument_defaults.insert(
+                                                    format_ident!(
+                                                        "{}",
+                                                        ty.pat.to_token_stream().to_string()
+                                                    ),
+                                                    default,
+                                                );
+                                            }
+                                        }
                                     }
                                 }
+                                _ => {}
                             }
-                            _ => {}
                         }
-                    }
-                    if let Some(ref args) = attributes.args_into {
-                        let mut func_sig_args = HashSet::with_capacity(args.len());
-                        for fn_arg in func.sig.inputs.iter() {
-                            match fn_arg {
-                                FnArg::Receiver(_) => {}
-                                FnArg::Typed(pat_ty) => {
-                                    let fn_arg_name = pat_ty.pat.to_token_stream().to_string();
-                                    func_sig_args.insert(fn_arg_name);
+                        if let Some(ref args) = attributes.args_into {
+                            let mut func_sig_args = HashSet::with_capacity(args.len());
+                            for fn_arg in func.sig.inputs.iter() {
+                                match fn_arg {
+                                    FnArg::Receiver(_) => {}
+                                    FnArg::Typed(pat_ty) => {
+                                        let fn_arg_name = pat_ty.pat.to_token_stream().to_string();
+                                        func_sig_args.insert(fn_arg_name);
+                                    }
                                 }
                             }
-                        }
 
-                        for arg in args.iter() {
-                            let arg_name = arg.to_token_stream().to_string();
+                            for arg in args.iter() {
+                                let arg_name = arg.to_token_stream().to_string();
 
-                            if !func_sig_args.contains(&arg_name) {
-                                self.errors.push(ParseError::ArgsIntoArgNotFound {
-                                    func: func.clone(),
-                                    missing_arg: arg.clone(),
-                                })
+                                if !func_sig_args.contains(&arg_name) {
+                                    self.errors.push(ParseError::ArgsIntoArgNotFound {
+                                        func: func.clone(),
+                                        missing_arg: arg.clone(),
+                                    })
+                                }
                             }
                         }
-                    }
 
-                    let func = ParsedExternFn {
-                        func,
-                        associated_type,
-                        is_swift_initializer: attributes.is_swift_initializer,
-                        swift_failable_initializer,
-                        is_swift_identifiable: attributes.is_swift_identifiable,
-                        host_lang,
-                        rust_name_override: attributes.rust_name,
-                        swift_name_override: attributes.swift_name,
-                        return_into: attributes.return_into,
-                        return_with: attributes.return_with,
-                        args_into: attributes.args_into,
-                        get_field: attributes.get_field,
-                        argument_labels: argument_labels,
-                    };
-                    self.functions.push(func);
+                        let func = ParsedExternFn {
+                            func,
+                            associated_type,
+                            is_swift_initializer: attributes.is_swift_initializer,
+                            swift_failable_initializer,
+                            is_swift_identifiable: attributes.is_swift_identifiable,
+                            host_lang,
+                            rust_name_override: attributes.rust_name,
+                            swift_name_override: attributes.swift_name,
+                            return_into: attributes.return_into,
+                            return_with: attributes.return_with,
+                            args_into: attributes.args_into,
+                            get_field: attributes.get_field,
+                            argument_labels: argument_labels,
+                            swift_param_names: HashMap::new(),
+                            argument_defaults,
+                            trait_name: None,
+                            instantiation,
+                        };
+                        self.functions.push(func);
+                    }
                 }
                 ForeignItem::Verbatim(foreign_item_verbatim) => {
                     if let Ok(generic_foreign_type) =
                         syn::parse2::<GenericOpaqueType>(foreign_item_verbatim)
                     {
-                        let ty_name = generic_foreign_type.ident.to_string();
+                        let attributes = OpaqueTypeAllAttributes::from_attributes(
+                            &generic_foreign_type.attributes,
+                        )?;
 
-                        let foreign_ty = OpaqueForeignTypeDeclaration {
-                            ty: generic_foreign_type.ident,
-                            host_lang,
-                            attributes: OpaqueTypeAllAttributes::from_attributes(
-                                &generic_foreign_type.attributes,
-                            )?,
-                            generics: OpaqueRustTypeGenerics {
-                                generics: generic_foreign_type
-                                    .generics
-                                    .params
-                                    .clone()
-                                    .into_iter()
-                                    .map(|p| match p {
-                                        GenericParam::Type(generic_ty) => generic_ty,
-                                        _ => todo!(
-                                            "Push a ParseError for non-concrete generic types"
-                                        ),
-                                    })
-                                    .collect(),
-                            },
-                        };
-                        let generics: Vec<String> = foreign_ty
-                            .generics
-                            .generics
-                            .iter()
-                            .map(|g| g.ident.to_string())
-                            .collect();
-                        let generics: String = generics.join(",");
-                        let ty_name = format!("{}<{}>", ty_name, generics);
-                        self.type_declarations
-                            .insert(ty_name.clone(), TypeDeclaration::Opaque(foreign_ty.clone()));
-                        local_type_declarations.insert(ty_name, foreign_ty);
+                        // `Vec<u8>` can't be written inside of `type SomeType<...>;`, so generic
+                        // arguments like it get declared using `instantiate(A = Vec<u8>)`.
+                        let mut all_generics = vec![];
+                        if attributes.instantiations.is_empty() {
+                            all_generics.push(generic_foreign_type.generics);
+                        } else {
+                            for instantiation in attributes.instantiations.iter() {
+                                match instantiation.instantiate_opaque_type_generics(
+                                    &generic_foreign_type.generics,
+                                ) {
+                                    Some(generics) => all_generics.push(generics),
+                                    None => self.errors.push(
+                                        ParseError::InvalidOpaqueTypeInstantiation {
+                                            ty: generic_foreign_type.ident.clone(),
+                                        },
+                                    ),
+                                }
+                            }
+                        }
+
+                        for generics in all_generics {
+                            let foreign_ty = OpaqueForeignTypeDeclaration {
+                                ty: generic_foreign_type.ident.clone(),
+                                host_lang,
+                                attributes: attributes.clone(),
+                                generics: OpaqueRustTypeGenerics { generics },
+                            };
+                            let ty_name = format!(
+                                "{}<{}>",
+                                foreign_ty.ty,
+                                foreign_ty.generics.comma_separated_generics_string()
+                            );
+                            self.type_declarations.insert(
+                                ty_name.clone(),
+                                TypeDeclaration::Opaque(foreign_ty.clone()),
+                            );
+                            local_type_declarations.insert(ty_name, foreign_ty);
+                        }
                     }
                 }
                 _ => {}
@@ -300,6 +350,97 @@ impl<'a> ForeignModParser<'a> {
         Ok(())
     }
 
+    /// A generic function gets parsed once for each of its instantiations, as if each
+    /// instantiation were its own function.
+    ///
+    /// We return the function's instantiated signatures, along with the concrete types that each
+    /// one was instantiated with.
+    fn instantiate_fn(
+        &mut self,
+        func: ForeignItemFn,
+        attributes: &FunctionAttributes,
+        host_lang: HostLang,
+    ) -> Vec<(ForeignItemFn, Vec<Type>)> {
+        let is_generic = func.sig.generics.type_params().next().is_some();
+        if !is_generic && attributes.instantiations.is_empty() {
+            return vec![(func, vec![])];
+        }
+
+        let is_valid = is_generic
+            && host_lang.is_rust()
+            && !attributes.instantiations.is_empty()
+            && attributes
+                .instantiations
+                .iter()
+                .all(|instantiation| instantiation.is_valid_for(&func.sig.generics));
+        if !is_valid {
+            self.errors
+                .push(ParseError::InvalidFnInstantiation { func });
+            return vec![];
+        }
+
+        attributes
+            .instantiations
+            .iter()
+            .map(|instantiation| {
+                (
+                    instantiation.instantiate_fn(&func),
+                    instantiation.ordered_types(&func.sig.generics),
+                )
+            })
+            .collect()
+    }
+
+    /// Turn an argument's `#[swift_bridge(default = ...)]` into the Swift expression that the
+    /// generated Swift function uses as the argument's default value.
+    fn parse_argument_default(
+        &mut self,
+        arg: &FnArg,
+        default: ArgumentDefault,
+        host_lang: HostLang,
+    ) -> Option<String> {
+        let pat_ty = match arg {
+            FnArg::Typed(pat_ty) => pat_ty,
+            FnArg::Receiver(_) => return None,
+        };
+
+        if host_lang.is_swift() {
+            self.errors
+                .push(ParseError::ArgumentDefaultInExternSwift { arg: arg.clone() });
+            return None;
+        }
+
+        if let Some(bridged_ty) = BridgedType::new_with_type(&pat_ty.ty, self.type_declarations) {
+            if bridged_ty.is_generic_swift_arg(self.type_declarations) {
+                self.errors
+                    .push(ParseError::ArgumentDefaultOnGenericSwiftArg { arg: arg.clone() });
+                return None;
+            }
+        }
+
+        match default {
+            ArgumentDefault::Value(value) => Some(value.value()),
+            ArgumentDefault::None => {
+                let is_option = match pat_ty.ty.deref() {
+                    Type::Path(path) => path
+                        .path
+                        .segments
+                        .last()
+                        .map(|segment| segment.ident == "Option")
+                        .unwrap_or(false),
+                    _ => false,
+                };
+                if !is_option {
+                    self.errors
+                        .push(ParseError::ArgumentDefaultNoneOnNonOption { arg: arg.clone() });
+                    return None;
+                }
+
+                Some("nil".to_string())
+            }
+        }
+    }
+
     fn get_associated_type(
         &mut self,
         first: Option<&FnArg>,
@@ -861,4 +1002,70 @@ mod tests {
             }
         }
     }
+
+    /// Verify that we can parse methods on shared structs and enums.
+    #[test]
+    fn parses_shared_type_methods() {
+        let tokens = quote! {
+            mod foo {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    field: u8,
+                }
+
+                enum SomeEnum {
+                    Variant,
+                }
+
+                extern "Rust" {
+                    fn a(self: SomeStruct);
+                    fn b(self: &SomeStruct);
+                    fn c(self: &mut SomeEnum);
+                    #[swift_bridge(associated_to = SomeEnum)]
+                    fn d() -> SomeEnum;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let associated_types: Vec<String> = module
+            .functions
+            .iter()
+            .map(|f| f.associated_type.as_ref().unwrap().name_string())
+            .collect();
+        assert_eq!(
+            associated_types,
+            vec!["SomeStruct", "SomeStruct", "SomeEnum", "SomeEnum"]
+        );
+    }
+
+    /// Verify that we push an error for methods on shared types in an `extern "Swift"` block.
+    #[test]
+    fn error_if_shared_type_method_in_extern_swift() {
+        let tokens = quote! {
+            mod foo {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    field: u8,
+                }
+
+                extern "Swift" {
+                    fn a(self: &SomeStruct);
+                    #[swift_bridge(associated_to = SomeStruct)]
+                    fn b() -> SomeStruct;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 2);
+        for error in errors.iter() {
+            match error {
+                ParseError::SharedTypeMethodInExternSwift { func: _ } => {}
+                _ => panic!(),
+            }
+        }
+    }
 }
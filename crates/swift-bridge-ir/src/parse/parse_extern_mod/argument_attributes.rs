@@ -6,11 +6,22 @@ use syn::{LitStr, Token};
 pub(super) struct ArgumentAttributes {
     /// LitStr: argument_name
     pub label: Option<LitStr>,
+    /// `#[swift_bridge(default = "10")]` or `#[swift_bridge(default = None)]`
+    pub default: Option<ArgumentDefault>,
+}
+
+/// The default value of an argument in the generated Swift function.
+pub(super) enum ArgumentDefault {
+    /// `default = "10"`, a Swift expression that gets used as written.
+    Value(LitStr),
+    /// `default = None`, which becomes Swift's `nil`.
+    None,
 }
 
 enum ArgumentAttr {
     /// LitStr: argument_name
     ArgumentLabel(LitStr),
+    Default(ArgumentDefault),
 }
 
 impl Parse for ArgumentAttributes {
@@ -23,6 +34,9 @@ impl Parse for ArgumentAttributes {
                 ArgumentAttr::ArgumentLabel(label) => {
                     attributes.label = Some(label);
                 }
+                ArgumentAttr::Default(default) => {
+                    attributes.default = Some(default);
+                }
             }
         }
         Ok(attributes)
@@ -38,6 +52,22 @@ impl Parse for ArgumentAttr {
                 let value: LitStr = input.parse()?;
                 ArgumentAttr::ArgumentLabel(value)
             }
+            "default" => {
+                input.parse::<Token![=]>()?;
+                let default = if input.peek(LitStr) {
+                    ArgumentDefault::Value(input.parse()?)
+                } else {
+                    let value: Ident = input.parse()?;
+                    if value != "None" {
+                        return Err(syn::Error::new_spanned(
+                            value,
+                            r#"The default must be a Swift expression such as "10", or None."#,
+                        ));
+                    }
+                    ArgumentDefault::None
+                };
+                ArgumentAttr::Default(default)
+            }
             _ => {
                 let attrib = key.to_string();
                 Err(syn::Error::new_spanned(
@@ -52,7 +82,8 @@ impl Parse for ArgumentAttr {
 
 #[cfg(test)]
 mod tests {
-    use crate::test_utils::parse_ok;
+    use crate::errors::ParseError;
+    use crate::test_utils::{parse_errors, parse_ok};
     use quote::{format_ident, quote};
 
     /// Verify that we can parse a function that has a argument label.
@@ -78,4 +109,59 @@ mod tests {
             .unwrap();
         assert_eq!(argument_label.value().to_string(), "argumentLabel1");
     }
+
+    /// Verify that we can parse an argument's default value, and that `default = None` becomes
+    /// Swift's `nil`.
+    #[test]
+    fn parse_argument_default() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function(
+                        #[swift_bridge(default = "10")] arg1: u8,
+                        #[swift_bridge(label = "arg", default = None)] arg2: Option<u8>,
+                        arg3: Option<u8>,
+                    );
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+        let defaults = &module.functions[0].argument_defaults;
+        assert_eq!(defaults.len(), 2);
+        assert_eq!(defaults.get(&format_ident!("arg1")).unwrap(), "10");
+        assert_eq!(defaults.get(&format_ident!("arg2")).unwrap(), "nil");
+    }
+
+    /// Verify that we push errors for default values that the generated Swift code can't use.
+    #[test]
+    fn error_if_invalid_argument_default() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function(#[swift_bridge(default = None)] arg: u8);
+                    fn another_function(#[swift_bridge(default = r#""hello""#)] arg: &str);
+                }
+
+                extern "Swift" {
+                    fn swift_function(#[swift_bridge(default = "10")] arg: u8);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 3);
+        assert!(matches!(
+            errors[0],
+            ParseError::ArgumentDefaultNoneOnNonOption { .. }
+        ));
+        assert!(matches!(
+            errors[1],
+            ParseError::ArgumentDefaultOnGenericSwiftArg { .. }
+        ));
+        assert!(matches!(
+            errors[2],
+            ParseError::ArgumentDefaultInExternSwift { .. }
+        ));
+    }
 }
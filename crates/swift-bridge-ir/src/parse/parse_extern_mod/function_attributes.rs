@@ -1,9 +1,10 @@
+use crate::parse::instantiation::Instantiation;
 use crate::parsed_extern_fn::{GetField, GetFieldDirect, GetFieldWith};
 use proc_macro2::Ident;
 use syn::parse::{Parse, ParseStream};
 use syn::{LitStr, Path, Token};
 
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub(super) struct FunctionAttributes {
     pub associated_to: Option<Ident>,
     pub is_swift_initializer: bool,
@@ -14,6 +15,7 @@ pub(super) struct FunctionAttributes {
     pub return_with: Option<Path>,
     pub args_into: Option<Vec<Ident>>,
     pub get_field: Option<GetField>,
+    pub instantiations: Vec<Instantiation>,
 }
 
 impl FunctionAttributes {
@@ -43,6 +45,7 @@ impl FunctionAttributes {
             FunctionAttr::GetFieldWith(get_field) => {
                 self.get_field = Some(GetField::With(get_field))
             }
+            FunctionAttr::Instantiate(instantiation) => self.instantiations.push(instantiation),
         }
     }
 }
@@ -58,6 +61,7 @@ pub(super) enum FunctionAttr {
     ArgsInto(Vec<Ident>),
     GetField(GetFieldDirect),
     GetFieldWith(GetFieldWith),
+    Instantiate(Instantiation),
 }
 
 impl Parse for FunctionAttributes {
@@ -149,6 +153,7 @@ impl Parse for FunctionAttr {
                     path,
                 })
             }
+            "instantiate" => FunctionAttr::Instantiate(input.parse()?),
             _ => {
                 let attrib = key.to_string();
                 Err(syn::Error::new_spanned(
@@ -583,4 +588,80 @@ mod tests {
         assert_eq!(func.args_into.as_ref().unwrap().len(), 1);
         assert_eq!(func.return_into, true);
     }
+
+    /// Verify that we parse a generic function once for each of its instantiations.
+    #[test]
+    fn parses_instantiate_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(instantiate(T = u32), instantiate(T = String))]
+                    fn parse<T>(s: &str) -> T;
+
+                    #[swift_bridge(instantiate(B = u8, A = Vec<u8>))]
+                    fn convert<A, B>(a: A) -> Option<B>;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let funcs = &module.functions;
+        assert_eq!(funcs.len(), 3);
+
+        assert_eq!(funcs[0].func.sig.ident, "parse");
+        assert!(funcs[0].func.sig.generics.params.is_empty());
+        assert_eq!(
+            funcs[0].func.sig.output.to_token_stream().to_string(),
+            "-> u32"
+        );
+        assert_eq!(funcs[0].instantiation_name().unwrap(), "u32");
+        assert_eq!(funcs[1].instantiation_name().unwrap(), "String");
+
+        assert_eq!(
+            funcs[2].func.sig.inputs.to_token_stream().to_string(),
+            "a : Vec < u8 >"
+        );
+        assert_eq!(
+            funcs[2].func.sig.output.to_token_stream().to_string(),
+            "-> Option < u8 >"
+        );
+        assert_eq!(funcs[2].instantiation_name().unwrap(), "Vec_u8_u8");
+    }
+
+    /// Verify that we push an error for a generic function that doesn't list a concrete type for
+    /// each of its generic type parameters.
+    #[test]
+    fn error_if_invalid_instantiation() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    fn a<T>(arg: T);
+
+                    #[swift_bridge(instantiate(T = u32))]
+                    fn b<T, U>(arg: T) -> U;
+
+                    #[swift_bridge(instantiate(U = u32))]
+                    fn c<T>(arg: T);
+
+                    #[swift_bridge(instantiate(T = u32))]
+                    fn d(arg: u32);
+                }
+
+                extern "Swift" {
+                    #[swift_bridge(instantiate(T = u32))]
+                    fn e<T>(arg: T);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 5);
+        for error in errors.iter() {
+            assert!(matches!(error, ParseError::InvalidFnInstantiation { .. }));
+        }
+    }
 }
@@ -5,9 +5,10 @@ mod generic_opaque_type;
 #[cfg(test)]
 mod tests {
     use quote::quote;
-    use syn::TypeParam;
+    use syn::Type;
 
-    use crate::test_utils::parse_ok;
+    use crate::errors::ParseError;
+    use crate::test_utils::{parse_errors, parse_ok};
     use crate::SwiftBridgeModule;
 
     /// Verify that we can parse generic extern "Rust" types
@@ -51,7 +52,88 @@ mod tests {
         assert_eq!(get_generics(&module, "SomeType<u64>").len(), 1);
     }
 
-    fn get_generics<'a>(module: &'a SwiftBridgeModule, type_name: &str) -> &'a Vec<TypeParam> {
+    /// Verify that we can parse generic types with more than 8 generic arguments, as well as
+    /// non-primitive generic arguments such as `String` and `Vec<u8>`.
+    #[test]
+    fn parse_non_primitive_generic_arguments() {
+        let tokens = quote! {
+            #[swift_bridge:bridge]
+            mod foo {
+                extern "Rust" {
+                    type User;
+
+                    #[swift_bridge(declare_generic)]
+                    type Cache<A, B>;
+                    type Cache<String, User>;
+                    #[swift_bridge(instantiate(A = Vec<u8>, B = Option<u32>))]
+                    type Cache<A, B>;
+
+                    #[swift_bridge(declare_generic)]
+                    type Many<A, B, C, D, E, F, G, H, I, J>;
+                    type Many<u8, u16, u32, u64, i8, i16, i32, i64, f32, f64>;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(get_generics(&module, "Cache<String,User>").len(), 2);
+        assert_eq!(get_generics(&module, "Cache<Vec<u8>,Option<u32>>").len(), 2);
+        assert_eq!(
+            get_generics(&module, "Many<u8,u16,u32,u64,i8,i16,i32,i64,f32,f64>").len(),
+            10
+        );
+    }
+
+    /// Verify that `instantiate(...)` declares the same instantiation as writing the generic
+    /// arguments directly.
+    #[test]
+    fn instantiate_attribute_is_same_as_generic_arguments() {
+        let tokens = quote! {
+            #[swift_bridge:bridge]
+            mod foo {
+                extern "Rust" {
+                    type User;
+
+                    #[swift_bridge(declare_generic)]
+                    type Cache<A, B>;
+                    #[swift_bridge(instantiate(A = String, B = User))]
+                    type Cache<A, B>;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(get_generics(&module, "Cache<String,User>").len(), 2);
+    }
+
+    /// Verify that we push an error if an instantiation names a generic type parameter that the
+    /// type doesn't have.
+    #[test]
+    fn error_if_invalid_opaque_type_instantiation() {
+        let tokens = quote! {
+            #[swift_bridge:bridge]
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(instantiate(B = Vec<u8>))]
+                    type Cache<A>;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            ParseError::InvalidOpaqueTypeInstantiation { ty } => {
+                assert_eq!(ty.to_string(), "Cache");
+            }
+            _ => panic!(),
+        };
+    }
+
+    fn get_generics<'a>(module: &'a SwiftBridgeModule, type_name: &str) -> &'a Vec<Type> {
         &module
             .types
             .get(type_name)
@@ -1,14 +1,18 @@
 use proc_macro2::Ident;
 use syn::parse::{Parse, ParseStream};
-use syn::{Attribute, Generics, Token};
+use syn::punctuated::Punctuated;
+use syn::{Attribute, Token, Type};
 
 pub(crate) struct GenericOpaqueType {
     pub attributes: Vec<Attribute>,
     #[allow(unused)]
     pub type_token: Token![type],
     pub ident: Ident,
-    #[allow(unused)]
-    pub generics: Generics,
+    /// The generic arguments, such as the `String` and `User` in `type SomeType<String, User>`.
+    ///
+    /// `syn` only parses single names inside of the module's `type` declarations, so generic
+    /// arguments such as `Vec<u8>` get declared using `#[swift_bridge(instantiate(...))]`.
+    pub generics: Vec<Type>,
     #[allow(unused)]
     pub semicolon: Token![;],
 }
@@ -25,7 +29,12 @@ impl Parse for GenericOpaqueType {
             attributes,
             type_token: input.parse()?,
             ident: input.parse()?,
-            generics: input.parse()?,
+            generics: {
+                input.parse::<Token![<]>()?;
+                let generics = Punctuated::<Type, Token![,]>::parse_separated_nonempty(input)?;
+                input.parse::<Token![>]>()?;
+                generics.into_iter().collect()
+            },
             semicolon: input.parse()?,
         })
     }
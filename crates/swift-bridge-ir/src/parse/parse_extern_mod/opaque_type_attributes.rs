@@ -1,3 +1,4 @@
+use crate::parse::instantiation::Instantiation;
 use crate::parse::OpaqueCopy;
 use proc_macro2::Ident;
 use quote::ToTokens;
@@ -26,6 +27,10 @@ pub(crate) struct OpaqueTypeSwiftBridgeAttributes {
     /// `#[swift_bridge(declare_generic)]`
     /// Used to declare a generic type.
     pub declare_generic: bool,
+    /// `#[swift_bridge(instantiate(A = Vec<u8>))]`
+    /// The concrete generic arguments to declare the type with, for generic arguments such as
+    /// `Vec<u8>` that can't be written inside of a `type SomeType<...>;` declaration.
+    pub instantiations: Vec<Instantiation>,
     /// `#[swift_bridge(Equatable)]`
     /// Used to determine if Equatable need to be implemented.
     pub equatable: bool,
@@ -91,6 +96,7 @@ impl OpaqueTypeSwiftBridgeAttributes {
             OpaqueTypeAttr::AlreadyDeclared => self.already_declared = true,
             OpaqueTypeAttr::Copy { size } => self.copy = Some(OpaqueCopy { size_bytes: size }),
             OpaqueTypeAttr::DeclareGeneric => self.declare_generic = true,
+            OpaqueTypeAttr::Instantiate(instantiation) => self.instantiations.push(instantiation),
             OpaqueTypeAttr::Equatable => self.equatable = true,
             OpaqueTypeAttr::Hashable => self.hashable = true,
             OpaqueTypeAttr::Sendable => self.sendable = true,
@@ -103,6 +109,7 @@ pub(crate) enum OpaqueTypeAttr {
     AlreadyDeclared,
     Copy { size: usize },
     DeclareGeneric,
+    Instantiate(Instantiation),
     Equatable,
     Hashable,
     Sendable,
@@ -142,6 +149,7 @@ impl Parse for OpaqueTypeAttr {
                 }
             }
             "declare_generic" => OpaqueTypeAttr::DeclareGeneric,
+            "instantiate" => OpaqueTypeAttr::Instantiate(input.parse()?),
             "Equatable" => OpaqueTypeAttr::Equatable,
             "Hashable" => OpaqueTypeAttr::Hashable,
             "Sendable" => OpaqueTypeAttr::Sendable,
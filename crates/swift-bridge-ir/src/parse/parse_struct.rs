@@ -1,12 +1,14 @@
+use crate::bridged_type::serde_attributes::is_serde_derive;
 use crate::bridged_type::{
-    shared_struct::StructDerives, SharedStruct, StructFields, StructSwiftRepr,
+    shared_struct::StructDerives, SharedStruct, SharedTypeGenerics, StructFields, StructSwiftRepr,
 };
 use crate::errors::{ParseError, ParseErrors};
+use crate::parse::instantiation::{is_valid_shared_type_instantiation, Instantiation};
 use crate::parse::move_input_cursor_to_next_comma;
 use proc_macro2::Ident;
 use quote::ToTokens;
 use syn::parse::{Parse, ParseStream};
-use syn::{ItemStruct, LitStr, Meta, Token};
+use syn::{Attribute, ItemStruct, LitStr, Meta, NestedMeta, Token};
 
 pub(crate) struct SharedStructDeclarationParser<'a> {
     pub item_struct: ItemStruct,
@@ -18,6 +20,8 @@ enum StructAttr {
     SwiftName(LitStr),
     Error(StructAttrParseError),
     AlreadyDeclared,
+    Codable,
+    Instantiate(Instantiation),
 }
 
 enum StructAttrParseError {
@@ -31,6 +35,9 @@ struct StructAttribs {
     swift_name: Option<LitStr>,
     already_declared: bool,
     derives: StructDerives,
+    codable: bool,
+    serde_attrs: Vec<Attribute>,
+    instantiations: Vec<Instantiation>,
 }
 
 impl Default for StructDerives {
@@ -38,6 +45,11 @@ impl Default for StructDerives {
         StructDerives {
             copy: false,
             clone: false,
+            debug: false,
+            partial_eq: false,
+            eq: false,
+            hash: false,
+            serde: vec![],
         }
     }
 }
@@ -77,6 +89,8 @@ impl Parse for StructAttr {
                 StructAttr::SwiftName(name)
             }
             "already_declared" => StructAttr::AlreadyDeclared,
+            "Codable" => StructAttr::Codable,
+            "instantiate" => StructAttr::Instantiate(input.parse()?),
             _ => {
                 move_input_cursor_to_next_comma(input);
                 StructAttr::Error(StructAttrParseError::UnrecognizedAttribute(key))
@@ -88,7 +102,9 @@ impl Parse for StructAttr {
 }
 
 impl<'a> SharedStructDeclarationParser<'a> {
-    pub fn parse(self) -> Result<SharedStruct, syn::Error> {
+    /// A generic struct gets parsed once for each of its instantiations, as if each instantiation
+    /// were its own struct.
+    pub fn parse(self) -> Result<Vec<SharedStruct>, syn::Error> {
         let item_struct = self.item_struct;
 
         let mut attribs = StructAttribs::default();
@@ -124,21 +140,39 @@ impl<'a> SharedStructDeclarationParser<'a> {
                             StructAttr::AlreadyDeclared => {
                                 attribs.already_declared = true;
                             }
+                            StructAttr::Codable => {
+                                attribs.codable = true;
+                            }
+                            StructAttr::Instantiate(instantiation) => {
+                                attribs.instantiations.push(instantiation);
+                            }
                         };
                     }
                 }
                 "derive" => match attr.parse_meta()? {
                     Meta::List(meta_list) => {
                         for derive in meta_list.nested {
+                            if let NestedMeta::Meta(Meta::Path(path)) = &derive {
+                                if is_serde_derive(path) {
+                                    attribs.derives.serde.push(path.clone());
+                                    continue;
+                                }
+                            }
+
                             match derive.to_token_stream().to_string().as_str() {
                                 "Copy" => attribs.derives.copy = true,
                                 "Clone" => attribs.derives.clone = true,
+                                "Debug" => attribs.derives.debug = true,
+                                "PartialEq" => attribs.derives.partial_eq = true,
+                                "Eq" => attribs.derives.eq = true,
+                                "Hash" => attribs.derives.hash = true,
                                 _ => {}
                             }
                         }
                     }
                     _ => todo!("Push parse error that derive attribute is in incorrect format"),
                 },
+                "serde" => attribs.serde_attrs.push(attr.clone()),
                 attr_name => {
                     todo!(
                         "Push unsupported attribute error. Found unsupported attribute \"{}\" on struct \"{}\". Consult the swift-bridge manual for supported struct attributes.",
@@ -171,15 +205,64 @@ impl<'a> SharedStructDeclarationParser<'a> {
         };
 
         let shared_struct = SharedStruct {
-            name: item_struct.ident,
+            name: item_struct.ident.clone(),
             swift_repr,
-            fields: StructFields::from_syn_fields(item_struct.fields),
+            fields: StructFields::from_syn_fields(item_struct.fields.clone())?,
             swift_name: attribs.swift_name,
             already_declared: attribs.already_declared,
             derives: attribs.derives,
+            codable: attribs.codable,
+            serde_attrs: attribs.serde_attrs,
+            lifetimes: item_struct
+                .generics
+                .lifetimes()
+                .map(|l| l.lifetime.clone())
+                .collect(),
+            generics: SharedTypeGenerics::default(),
+            declared_fields: None,
         };
 
-        Ok(shared_struct)
+        let is_generic = item_struct.generics.type_params().next().is_some();
+        if !is_generic && attribs.instantiations.is_empty() {
+            return Ok(vec![shared_struct]);
+        }
+
+        if !is_valid_shared_type_instantiation(
+            &item_struct.generics,
+            &attribs.instantiations,
+            item_struct.fields.iter().map(|field| &field.ty),
+        ) {
+            self.errors
+                .push(ParseError::InvalidSharedTypeInstantiation {
+                    ty: item_struct.ident,
+                });
+            return Ok(vec![]);
+        }
+
+        let instantiations = attribs
+            .instantiations
+            .iter()
+            .enumerate()
+            .map(|(idx, instantiation)| {
+                let mut fields = item_struct.fields.clone();
+                for field in fields.iter_mut() {
+                    instantiation.substitute(&mut field.ty);
+                }
+
+                Ok(SharedStruct {
+                    fields: StructFields::from_syn_fields(fields)?,
+                    generics: instantiation.shared_type_generics(&item_struct.generics),
+                    declared_fields: if idx == 0 {
+                        Some(shared_struct.fields.clone())
+                    } else {
+                        None
+                    },
+                    ..shared_struct.clone()
+                })
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        Ok(instantiations)
     }
 }
 
@@ -325,6 +408,33 @@ mod tests {
         };
     }
 
+    /// Verify that we parse a struct's lifetime parameters, and that functions can refer to the
+    /// struct with or without its lifetimes.
+    #[test]
+    fn parse_struct_with_lifetime() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct Foo<'a> {
+                    bar: &'a str
+                }
+
+                extern "Rust" {
+                    fn a(arg: Foo<'_>) -> Foo;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_struct();
+        assert_eq!(ty.lifetimes.len(), 1);
+        assert_eq!(ty.lifetimes[0].to_string(), "'a");
+
+        assert_eq!(module.functions.len(), 1);
+    }
+
     /// Verify that we parse the swift_name = "..."
     #[test]
     fn parse_swift_name_attribute() {
@@ -369,6 +479,82 @@ mod tests {
         assert_eq!(ty2.derives.clone, true);
     }
 
+    /// Verify that we parse the `#[swift_bridge(Codable)]` attribute, the serde derives and the
+    /// `#[serde(...)]` attributes on the struct and its fields.
+    #[test]
+    fn parse_codable_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", Codable)]
+                #[derive(Clone, serde::Serialize, serde::Deserialize)]
+                #[serde(deny_unknown_fields)]
+                struct Foo {
+                    #[serde(rename = "renamedField")]
+                    field: u8,
+                    another_field: u8
+                }
+
+                #[swift_bridge(swift_repr = "struct")]
+                struct Bar {
+                    field: u8
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_struct();
+        assert!(ty.codable);
+        assert!(ty.derives.clone);
+        assert_eq!(ty.derives.serde.len(), 2);
+        assert_eq!(ty.serde_attrs.len(), 1);
+        match &ty.fields {
+            StructFields::Named(fields) => {
+                assert_eq!(fields[0].serde_name_string(), "renamedField");
+                assert_eq!(fields[1].serde_name_string(), "another_field");
+            }
+            _ => panic!(),
+        }
+
+        let ty2 = module.types.types()[1].unwrap_shared_struct();
+        assert!(!ty2.codable);
+        assert!(ty2.derives.serde.is_empty());
+    }
+
+    /// Verify that we parse the standard derives that map to Swift protocols.
+    #[test]
+    fn parse_derive_standard_traits() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[derive(PartialEq, Eq, Hash, Debug)]
+                struct Foo {
+                    field: u8
+                }
+
+                #[derive(PartialEq)]
+                struct Bar {
+                    field: u8
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_struct();
+        assert!(ty.derives.partial_eq);
+        assert!(ty.derives.eq);
+        assert!(ty.derives.hash);
+        assert!(ty.derives.debug);
+
+        let ty2 = module.types.types()[1].unwrap_shared_struct();
+        assert!(ty2.derives.partial_eq);
+        assert!(!ty2.derives.eq);
+        assert!(!ty2.derives.hash);
+        assert!(!ty2.derives.debug);
+    }
+
     /// Verify that we properly parse multiple comma separated struct attributes.
     #[test]
     fn parses_multiple_struct_attributes() {
@@ -457,4 +643,89 @@ mod tests {
             _ => panic!(),
         };
     }
+
+    /// Verify that we parse a generic struct once for each of its instantiations.
+    #[test]
+    fn parse_generic_struct_instantiations() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", instantiate(T = u32), instantiate(T = String))]
+                struct Page<T> {
+                    items: Vec<T>,
+                    next: Option<String>,
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(module.types.types().len(), 2);
+
+        let page_u32 = module
+            .types
+            .get("Page<u32>")
+            .unwrap()
+            .unwrap_shared_struct();
+        assert_eq!(page_u32.generics.params[0].to_string(), "T");
+        assert!(page_u32.declared_fields.is_some());
+
+        let page_string = module
+            .types
+            .get("Page<String>")
+            .unwrap()
+            .unwrap_shared_struct();
+        assert_eq!(
+            page_string.generics.dollar_prefixed_args_string(),
+            "$String"
+        );
+        assert!(page_string.declared_fields.is_none());
+    }
+
+    /// Verify that we push an error if a generic struct is missing an instantiation, has a lifetime
+    /// or uses its generic type parameter in a way that we can't declare in Swift.
+    #[test]
+    fn error_if_invalid_generic_struct_instantiation() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct MissingInstantiation<T> {
+                    field: T,
+                }
+
+                #[swift_bridge(swift_repr = "struct", instantiate(U = u8))]
+                struct WrongTypeParameter<T> {
+                    field: T,
+                }
+
+                #[swift_bridge(swift_repr = "struct", instantiate(T = u8))]
+                struct HasLifetime<'a, T> {
+                    field: &'a T,
+                }
+
+                #[swift_bridge(swift_repr = "struct", instantiate(T = u8))]
+                struct UnsupportedField<T> {
+                    field: (T, u8),
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 4);
+        for (error, expected) in errors.iter().zip([
+            "MissingInstantiation",
+            "WrongTypeParameter",
+            "HasLifetime",
+            "UnsupportedField",
+        ]) {
+            match error {
+                ParseError::InvalidSharedTypeInstantiation { ty } => {
+                    assert_eq!(ty.to_string(), expected);
+                }
+                _ => panic!(),
+            }
+        }
+    }
 }
@@ -0,0 +1,421 @@
+use crate::bridged_type::BridgedType;
+use crate::errors::{ParseError, ParseErrors};
+use crate::parse::type_declarations::{
+    OpaqueForeignTypeDeclaration, TraitDeclaration, TypeDeclaration, TypeDeclarations,
+};
+use crate::parse::unsupported_types::is_boxed_fn;
+use crate::parse::{visit_nested_types, HostLang, OpaqueRustTypeGenerics};
+use crate::parsed_extern_fn::ParsedExternFn;
+use std::collections::HashMap;
+use std::ops::Deref;
+use syn::{
+    FnArg, ForeignItemFn, ItemImpl, ItemTrait, LitStr, Pat, ReturnType, Token, TraitItem, Type,
+    TypeParamBound, Visibility,
+};
+
+pub(super) struct TraitDeclarationParser<'a> {
+    pub item_trait: ItemTrait,
+    pub errors: &'a mut ParseErrors,
+    pub type_declarations: &'a TypeDeclarations,
+    pub unresolved_types: &'a mut Vec<Type>,
+}
+
+impl<'a> TraitDeclarationParser<'a> {
+    pub fn parse(self) -> TraitDeclaration {
+        let name = self.item_trait.ident;
+
+        // The trait's methods get called on Swift objects, so they're associated to a Swift
+        // type that has the same name as the trait.
+        let associated_type = TypeDeclaration::Opaque(OpaqueForeignTypeDeclaration {
+            ty: name.clone(),
+            host_lang: HostLang::Swift,
+            attributes: Default::default(),
+            generics: OpaqueRustTypeGenerics::new(),
+        });
+
+        let mut send = false;
+        let mut sync = false;
+        for supertrait in self.item_trait.supertraits {
+            let supertrait_name = match &supertrait {
+                TypeParamBound::Trait(bound) => {
+                    bound.path.get_ident().map(|ident| ident.to_string())
+                }
+                _ => None,
+            };
+
+            match supertrait_name.as_deref() {
+                Some("Send") => send = true,
+                Some("Sync") => sync = true,
+                _ => {
+                    self.errors
+                        .push(ParseError::InvalidTraitSupertrait { supertrait });
+                }
+            }
+        }
+
+        let mut methods = vec![];
+        for item in self.item_trait.items {
+            let method = match item {
+                TraitItem::Method(method) if method.default.is_none() => method,
+                item => {
+                    self.errors.push(ParseError::InvalidTraitItem { item });
+                    continue;
+                }
+            };
+
+            let sig = method.sig;
+            let takes_ref_self = matches!(
+                sig.inputs.first(),
+                Some(FnArg::Receiver(receiver)) if receiver.reference.is_some()
+            );
+            if !takes_ref_self || !sig.generics.params.is_empty() || sig.asyncness.is_some() {
+                self.errors.push(ParseError::InvalidTraitMethod { sig });
+                continue;
+            }
+
+            for arg in sig.inputs.iter() {
+                if let FnArg::Typed(pat_ty) = arg {
+                    if BridgedType::new_with_type(&pat_ty.ty, self.type_declarations).is_none() {
+                        self.unresolved_types.push(pat_ty.ty.deref().clone());
+                    }
+                }
+            }
+            if let ReturnType::Type(_, return_ty) = &sig.output {
+                if BridgedType::new_with_type(return_ty, self.type_declarations).is_none() {
+                    self.unresolved_types.push(return_ty.deref().clone());
+                }
+            }
+
+            // Rust calls a Swift object's trait methods and Swift calls an opaque Rust type's
+            // trait methods, so a trait method's boxed functions would need to be bridged in
+            // both directions. We don't support this.
+            let arg_types = sig.inputs.iter().filter_map(|arg| match arg {
+                FnArg::Typed(pat_ty) => Some(pat_ty.ty.as_ref()),
+                FnArg::Receiver(_) => None,
+            });
+            let return_type = match &sig.output {
+                ReturnType::Type(_, ty) => Some(ty.as_ref()),
+                ReturnType::Default => None,
+            };
+            for ty in arg_types.chain(return_type) {
+                visit_nested_types(ty, &mut |ty| {
+                    if is_boxed_fn(ty) {
+                        self.errors
+                            .push(ParseError::TraitMethodBoxedFn { ty: ty.clone() });
+                    }
+                });
+            }
+
+            let func = ForeignItemFn {
+                attrs: method.attrs,
+                vis: Visibility::Inherited,
+                sig,
+                semi_token: <Token![;]>::default(),
+            };
+            methods.push(trait_method(func, associated_type.clone(), HostLang::Swift));
+        }
+
+        TraitDeclaration {
+            name,
+            methods,
+            implementors: vec![],
+            send,
+            sync,
+        }
+    }
+}
+
+/// Record the `impl SomeTrait for SomeType {}` items in the module.
+///
+/// This happens after all of the module's items have been parsed, since a trait impl can appear
+/// before the trait or the type.
+pub(super) fn resolve_trait_impls(
+    impls: Vec<ItemImpl>,
+    type_declarations: &mut TypeDeclarations,
+    errors: &mut ParseErrors,
+) {
+    for item_impl in impls {
+        let trait_name = item_impl
+            .trait_
+            .as_ref()
+            .filter(|(bang, _, _)| bang.is_none())
+            .and_then(|(_, path, _)| path.get_ident())
+            .map(|ident| ident.to_string());
+        let implementor = match item_impl.self_ty.deref() {
+            Type::Path(path) => path.path.get_ident().cloned(),
+            _ => None,
+        };
+
+        let is_opaque_rust_type = implementor
+            .as_ref()
+            .and_then(|ty| type_declarations.get(&ty.to_string()))
+            .and_then(|ty| ty.as_opaque())
+            .map(|ty| ty.host_lang.is_rust() && ty.generics.is_empty())
+            .unwrap_or(false);
+        let is_valid = item_impl.items.is_empty()
+            && item_impl.generics.params.is_empty()
+            && is_opaque_rust_type;
+
+        match trait_name.and_then(|name| type_declarations.get_trait_mut(&name)) {
+            Some(trait_declaration) if is_valid => {
+                trait_declaration.implementors.push(implementor.unwrap());
+            }
+            _ => {
+                errors.push(ParseError::InvalidTraitImpl { item_impl });
+            }
+        }
+    }
+}
+
+/// A trait method.
+///
+/// Protocol requirements don't have argument labels, so that Swift calls a trait method the same
+/// way that it calls a method on an opaque Rust type.
+fn trait_method(
+    func: ForeignItemFn,
+    associated_type: TypeDeclaration,
+    host_lang: HostLang,
+) -> ParsedExternFn {
+    let argument_labels = func
+        .sig
+        .inputs
+        .iter()
+        .filter_map(|arg| match arg {
+            FnArg::Typed(pat_ty) => match pat_ty.pat.deref() {
+                Pat::Ident(pat_ident) => Some((
+                    pat_ident.ident.clone(),
+                    LitStr::new("_", pat_ident.ident.span()),
+                )),
+                _ => None,
+            },
+            FnArg::Receiver(_) => None,
+        })
+        .collect();
+
+    ParsedExternFn {
+        func,
+        associated_type: Some(associated_type),
+        host_lang,
+        is_swift_initializer: false,
+        swift_failable_initializer: None,
+        is_swift_identifiable: false,
+        rust_name_override: None,
+        swift_name_override: None,
+        return_into: false,
+        return_with: None,
+        args_into: None,
+        get_field: None,
+        argument_labels,
+        swift_param_names: HashMap::new(),
+        argument_defaults: HashMap::new(),
+        trait_name: None,
+        instantiation: vec![],
+    }
+}
+
+impl TraitDeclaration {
+    /// The trait's methods, associated to one of the opaque Rust types that implement the trait.
+    ///
+    /// Swift calls these to use the opaque Rust type's implementation of the trait.
+    pub(crate) fn implementor_methods(
+        &self,
+        implementor: &OpaqueForeignTypeDeclaration,
+    ) -> Vec<ParsedExternFn> {
+        self.methods
+            .iter()
+            .map(|method| {
+                let mut implementor_method = trait_method(
+                    method.func.clone(),
+                    TypeDeclaration::Opaque(implementor.clone()),
+                    HostLang::Rust,
+                );
+                implementor_method.trait_name = Some(self.name.clone());
+                implementor_method
+            })
+            .collect()
+    }
+
+    /// The trait's methods, as they get called from Swift on any object that conforms to the
+    /// trait's protocol.
+    pub(crate) fn protocol_requirements(&self) -> Vec<ParsedExternFn> {
+        self.methods
+            .iter()
+            .map(|method| {
+                trait_method(
+                    method.func.clone(),
+                    method.associated_type.clone().unwrap(),
+                    HostLang::Rust,
+                )
+            })
+            .collect()
+    }
+
+    /// Whether or not any of the trait's methods take `&mut self`.
+    pub(crate) fn has_mutable_methods(&self) -> bool {
+        self.methods
+            .iter()
+            .any(|method| method.self_mutability().is_some())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{parse_errors, parse_ok};
+    use quote::quote;
+
+    /// Verify that we can parse a trait and the opaque Rust types that implement it.
+    #[test]
+    fn parse_trait_and_implementors() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                trait Animal {
+                    fn name(&self) -> String;
+                    fn rename(&mut self, name: String);
+                }
+
+                impl Animal for Dog {}
+
+                extern "Rust" {
+                    type Dog;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let animal = module.types.get_trait("Animal").unwrap();
+        assert_eq!(animal.methods.len(), 2);
+        assert_eq!(animal.methods[0].func.sig.ident, "name");
+        assert_eq!(animal.methods[1].func.sig.ident, "rename");
+        assert!(animal.has_mutable_methods());
+
+        assert_eq!(animal.implementors.len(), 1);
+        assert_eq!(animal.implementors[0], "Dog");
+    }
+
+    /// Verify that we can parse a trait's `Send` and `Sync` supertraits, and that we push an error
+    /// for any other supertrait.
+    #[test]
+    fn parse_trait_supertraits() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                trait Storage: Send + Sync {
+                    fn get(&self, key: String) -> Option<String>;
+                }
+
+                trait Clock: Send {
+                    fn now(&self) -> u64;
+                }
+            }
+        };
+        let module = parse_ok(tokens);
+
+        let storage = module.types.get_trait("Storage").unwrap();
+        assert!(storage.send);
+        assert!(storage.sync);
+
+        let clock = module.types.get_trait("Clock").unwrap();
+        assert!(clock.send);
+        assert!(!clock.sync);
+
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                trait Storage: Clone + Send {
+                    fn get(&self, key: String) -> Option<String>;
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            errors[0],
+            ParseError::InvalidTraitSupertrait { .. }
+        ));
+    }
+
+    /// Verify that we push an error for trait items that we don't support.
+    #[test]
+    fn error_for_invalid_trait_items() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                trait Animal {
+                    type Food;
+                    fn name(&self) -> String { String::new() }
+                    fn new() -> Self;
+                    fn eat<T>(&self, food: T);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 4);
+        assert!(matches!(errors[0], ParseError::InvalidTraitItem { .. }));
+        assert!(matches!(errors[1], ParseError::InvalidTraitItem { .. }));
+        assert!(matches!(errors[2], ParseError::InvalidTraitMethod { .. }));
+        assert!(matches!(errors[3], ParseError::InvalidTraitMethod { .. }));
+    }
+
+    /// Verify that we push an error for a trait impl that isn't empty, that implements an
+    /// undeclared trait, or that is for a type that isn't an opaque Rust type.
+    #[test]
+    fn error_for_invalid_trait_impls() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                trait Animal {
+                    fn name(&self) -> String;
+                }
+
+                impl Animal for Dog {
+                    fn name(&self) -> String {
+                        String::new()
+                    }
+                }
+                impl Vehicle for Dog {}
+                impl Animal for Cat {}
+
+                extern "Rust" {
+                    type Dog;
+                }
+                extern "Swift" {
+                    type Cat;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 3);
+        for error in errors.iter() {
+            assert!(matches!(error, ParseError::InvalidTraitImpl { .. }));
+        }
+    }
+
+    /// Verify that we push an error for a trait method that takes or returns a boxed function.
+    #[test]
+    fn error_for_trait_method_boxed_fn() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                trait Animal {
+                    fn on_speak(&self, callback: Box<dyn Fn(String)>);
+                    fn speaker(&self) -> Box<dyn Fn() -> String>;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 2);
+        for error in errors.iter() {
+            assert!(matches!(error, ParseError::TraitMethodBoxedFn { .. }));
+        }
+    }
+}
@@ -0,0 +1,392 @@
+use crate::bridged_type::{fn_arg_name, EnumVariant, StructFields};
+use crate::errors::{ParseError, ParseErrors};
+use crate::parse::{SharedTypeDeclaration, TypeDeclaration};
+use crate::{SwiftBridgeModule, SwiftNaming};
+use proc_macro2::Ident;
+use std::collections::HashMap;
+use syn::LitStr;
+
+impl SwiftBridgeModule {
+    /// Give each of the module's functions, methods, arguments, shared struct fields and shared
+    /// enum variants a Swift name that follows the naming convention, unless it already has an
+    /// explicit `swift_name`.
+    ///
+    /// We push an error for any two items that end up with the same Swift name.
+    pub(crate) fn apply_swift_naming(&mut self, naming: SwiftNaming, errors: &mut ParseErrors) {
+        let convert = |name: &Ident| match naming {
+            SwiftNaming::CamelCase => to_lower_camel_case(&name.to_string()),
+        };
+
+        self.types.for_each_type_mut(|ty| {
+            apply_to_type_declaration(ty, &convert, errors);
+        });
+
+        let mut fn_names: HashMap<(String, bool), SwiftNames> = HashMap::new();
+        for func in self.functions.iter_mut() {
+            // Associated types are copies of the module's type declarations, so they need the
+            // same Swift names.
+            if let Some(associated_type) = func.associated_type.as_mut() {
+                apply_to_type_declaration(associated_type, &convert, &mut ParseErrors::new());
+            }
+
+            let mut param_names = SwiftNames::default();
+            for arg in func.func.sig.inputs.iter() {
+                let arg_name = match fn_arg_name(arg) {
+                    Some(arg_name) if arg_name != "self" => arg_name.clone(),
+                    _ => continue,
+                };
+                let swift_name = convert(&arg_name);
+                param_names.insert(&arg_name, &swift_name, true, errors);
+                if arg_name != swift_name {
+                    func.swift_param_names.insert(arg_name, swift_name);
+                }
+            }
+
+            if func.is_swift_initializer {
+                continue;
+            }
+
+            let scope = (
+                func.associated_type
+                    .as_ref()
+                    .map(|ty| ty.name_string())
+                    .unwrap_or_default(),
+                func.host_lang.is_rust(),
+            );
+            let fn_ident = func.func.sig.ident.clone();
+            let is_converted = func.swift_name_override.is_none();
+            let swift_name = match func.swift_name_override.as_ref() {
+                Some(swift_name) => swift_name.value(),
+                None => convert(&fn_ident),
+            };
+            fn_names
+                .entry(scope)
+                .or_default()
+                .insert(&fn_ident, &swift_name, is_converted, errors);
+
+            if is_converted && fn_ident != swift_name {
+                func.swift_name_override = Some(LitStr::new(&swift_name, fn_ident.span()));
+            }
+        }
+    }
+}
+
+fn apply_to_type_declaration(
+    ty: &mut TypeDeclaration,
+    convert: &impl Fn(&Ident) -> String,
+    errors: &mut ParseErrors,
+) {
+    match ty {
+        TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)) => {
+            if shared_struct.already_declared {
+                return;
+            }
+            apply_to_fields(&mut shared_struct.fields, convert, errors);
+            if let Some(declared_fields) = shared_struct.declared_fields.as_mut() {
+                apply_to_fields(declared_fields, convert, &mut ParseErrors::new());
+            }
+        }
+        TypeDeclaration::Shared(SharedTypeDeclaration::Enum(shared_enum)) => {
+            if shared_enum.already_declared {
+                return;
+            }
+            apply_to_variants(&mut shared_enum.variants, convert, errors);
+            if let Some(declared_variants) = shared_enum.declared_variants.as_mut() {
+                apply_to_variants(declared_variants, convert, &mut ParseErrors::new());
+            }
+        }
+        TypeDeclaration::Opaque(_) => {}
+    }
+}
+
+fn apply_to_fields(
+    fields: &mut StructFields,
+    convert: &impl Fn(&Ident) -> String,
+    errors: &mut ParseErrors,
+) {
+    let fields = match fields {
+        StructFields::Named(fields) => fields,
+        StructFields::Unnamed(_) | StructFields::Unit => return,
+    };
+
+    let mut names = SwiftNames::default();
+    for field in fields.iter_mut() {
+        let is_converted = field.swift_name.is_none();
+        let swift_name = apply_to_name(&field.name, &mut field.swift_name, convert);
+        names.insert(&field.name, &swift_name, is_converted, errors);
+    }
+}
+
+fn apply_to_variants(
+    variants: &mut [EnumVariant],
+    convert: &impl Fn(&Ident) -> String,
+    errors: &mut ParseErrors,
+) {
+    let mut names = SwiftNames::default();
+    for variant in variants.iter_mut() {
+        let is_converted = variant.swift_name.is_none();
+        let swift_name = apply_to_name(&variant.name, &mut variant.swift_name, convert);
+        names.insert(&variant.name, &swift_name, is_converted, errors);
+
+        apply_to_fields(&mut variant.fields, convert, errors);
+    }
+}
+
+/// Fill in an item's Swift name if it doesn't have an explicit one, and return its Swift name.
+fn apply_to_name(
+    name: &Ident,
+    swift_name: &mut Option<LitStr>,
+    convert: &impl Fn(&Ident) -> String,
+) -> String {
+    if let Some(swift_name) = swift_name.as_ref() {
+        return swift_name.value();
+    }
+
+    let converted = convert(name);
+    if *name != converted {
+        *swift_name = Some(LitStr::new(&converted, name.span()));
+    }
+    converted
+}
+
+/// The Swift names of the items within one scope, such as a struct's fields.
+#[derive(Default)]
+struct SwiftNames {
+    /// Swift name -> (Rust name, whether the Swift name came from the naming convention)
+    names: HashMap<String, (Ident, bool)>,
+}
+
+impl SwiftNames {
+    /// Record an item's Swift name, pushing an error if a differently named item in the same
+    /// scope already has that Swift name and either of the names came from the naming
+    /// convention.
+    fn insert(
+        &mut self,
+        rust_name: &Ident,
+        swift_name: &str,
+        is_converted: bool,
+        errors: &mut ParseErrors,
+    ) {
+        match self.names.get(swift_name) {
+            Some((existing, existing_is_converted)) => {
+                if existing != rust_name && (is_converted || *existing_is_converted) {
+                    errors.push(ParseError::SwiftNamingCollision {
+                        first: existing.clone(),
+                        second: rust_name.clone(),
+                        swift_name: swift_name.to_string(),
+                    });
+                }
+            }
+            None => {
+                self.names
+                    .insert(swift_name.to_string(), (rust_name.clone(), is_converted));
+            }
+        }
+    }
+}
+
+/// Convert a Rust name to Swift's `lowerCamelCase`.
+///
+/// `make_request` -> `makeRequest`, `HttpError` -> `httpError`, `HTTPError` -> `httpError`.
+///
+/// Leading underscores are kept, so that `_private` stays `_private`.
+fn to_lower_camel_case(name: &str) -> String {
+    let name = name.strip_prefix("r#").unwrap_or(name);
+    let trimmed = name.trim_start_matches('_');
+    let leading_underscores = &name[..name.len() - trimmed.len()];
+
+    let mut words: Vec<String> = vec![];
+    for segment in trimmed.split('_').filter(|segment| !segment.is_empty()) {
+        let chars: Vec<char> = segment.chars().collect();
+        let mut word = String::new();
+
+        for (idx, c) in chars.iter().enumerate() {
+            let starts_word = idx > 0
+                && c.is_uppercase()
+                && (chars[idx - 1].is_lowercase()
+                    || chars[idx - 1].is_ascii_digit()
+                    || chars
+                        .get(idx + 1)
+                        .map(|next| next.is_lowercase())
+                        .unwrap_or(false));
+            if starts_word {
+                words.push(std::mem::take(&mut word));
+            }
+            word.push(*c);
+        }
+        words.push(word);
+    }
+
+    let mut camel_case = leading_underscores.to_string();
+    for (idx, word) in words.iter().enumerate() {
+        let word = word.to_lowercase();
+        if idx == 0 {
+            camel_case += &word;
+        } else {
+            let mut chars = word.chars();
+            if let Some(first) = chars.next() {
+                camel_case.extend(first.to_uppercase());
+                camel_case += chars.as_str();
+            }
+        }
+    }
+
+    camel_case
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bridged_type::StructFields;
+    use crate::test_utils::{parse_errors, parse_ok};
+    use quote::{format_ident, quote};
+
+    /// Verify that we convert Rust names to lowerCamelCase.
+    #[test]
+    fn lower_camel_case() {
+        for (rust_name, expected) in [
+            ("make_request", "makeRequest"),
+            ("value", "value"),
+            ("is_2d", "is2d"),
+            ("r#type", "type"),
+            ("_private_value", "_privateValue"),
+            ("SomeVariant", "someVariant"),
+            ("HttpError", "httpError"),
+            ("HTTPError", "httpError"),
+            ("URL", "url"),
+            ("Version2", "version2"),
+            ("SOME_CONSTANT", "someConstant"),
+        ] {
+            assert_eq!(to_lower_camel_case(rust_name), expected);
+        }
+    }
+
+    /// Verify that `swift_naming = "camelCase"` gives functions, arguments, fields and variants a
+    /// camelCase Swift name, and that an explicit `swift_name` takes precedence.
+    #[test]
+    fn parse_camel_case_swift_naming() {
+        let tokens = quote! {
+            #[swift_bridge::bridge(swift_naming = "camelCase")]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    max_retries: u8,
+                    #[swift_bridge(swift_name = "timeout")]
+                    timeout_in_seconds: u8,
+                }
+
+                enum SomeEnum {
+                    NotFound { status_code: u16 },
+                    #[swift_bridge(swift_name = "serverError")]
+                    InternalServerError,
+                }
+
+                extern "Rust" {
+                    fn make_request(max_retries: u8);
+                    #[swift_bridge(swift_name = "send")]
+                    fn send_request();
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_struct();
+        match &ty.fields {
+            StructFields::Named(fields) => {
+                assert_eq!(fields[0].swift_name.as_ref().unwrap().value(), "maxRetries");
+                assert_eq!(fields[1].swift_name.as_ref().unwrap().value(), "timeout");
+            }
+            _ => panic!(),
+        };
+
+        let ty = module.types.types()[1].unwrap_shared_enum();
+        assert_eq!(ty.variants[0].swift_name_string(), "notFound");
+        assert_eq!(ty.variants[1].swift_name_string(), "serverError");
+        match &ty.variants[0].fields {
+            StructFields::Named(fields) => {
+                assert_eq!(fields[0].swift_name.as_ref().unwrap().value(), "statusCode");
+            }
+            _ => panic!(),
+        };
+
+        let func = &module.functions[0];
+        assert_eq!(
+            func.swift_name_override.as_ref().unwrap().value(),
+            "makeRequest"
+        );
+        assert_eq!(
+            func.swift_param_names
+                .get(&format_ident!("max_retries"))
+                .unwrap(),
+            "maxRetries"
+        );
+        assert_eq!(
+            module.functions[1]
+                .swift_name_override
+                .as_ref()
+                .unwrap()
+                .value(),
+            "send"
+        );
+    }
+
+    /// Verify that we push an error when two items end up with the same Swift name.
+    #[test]
+    fn error_if_swift_names_collide() {
+        let tokens = quote! {
+            #[swift_bridge::bridge(swift_naming = "camelCase")]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    is_enabled: bool,
+                    isEnabled: bool,
+                }
+
+                extern "Rust" {
+                    fn get_url();
+                    fn get_URL();
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 2);
+
+        for (idx, (first, second, swift_name)) in [
+            ("is_enabled", "isEnabled", "isEnabled"),
+            ("get_url", "get_URL", "getUrl"),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            match &errors[idx] {
+                ParseError::SwiftNamingCollision {
+                    first: actual_first,
+                    second: actual_second,
+                    swift_name: actual_swift_name,
+                } => {
+                    assert_eq!(actual_first, first);
+                    assert_eq!(actual_second, second);
+                    assert_eq!(actual_swift_name, swift_name);
+                }
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that we return an error for a naming convention that we don't support.
+    #[test]
+    fn error_if_unsupported_swift_naming() {
+        let tokens = quote! {
+            #[swift_bridge::bridge(swift_naming = "snake_case")]
+            mod ffi {}
+        };
+
+        let error = syn::parse2::<SwiftBridgeModule>(tokens).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            r#"The only supported swift_naming is "camelCase"."#
+        );
+    }
+}
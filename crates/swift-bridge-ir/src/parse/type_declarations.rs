@@ -3,12 +3,13 @@ use crate::bridged_type::{
 };
 use crate::parse::parse_extern_mod::OpaqueTypeAllAttributes;
 use crate::parse::HostLang;
+use crate::parsed_extern_fn::ParsedExternFn;
 use crate::SWIFT_BRIDGE_PREFIX;
 use proc_macro2::{Ident, TokenStream};
 use quote::ToTokens;
 use std::collections::HashMap;
 use std::ops::Deref;
-use syn::{PatType, Type, TypePath};
+use syn::{GenericArgument, PatType, Path, PathArguments, Type, TypePath};
 
 mod generics;
 pub(crate) use self::generics::*;
@@ -17,6 +18,7 @@ pub(crate) use self::generics::*;
 pub(crate) struct TypeDeclarations {
     decls: HashMap<String, TypeDeclaration>,
     order: Vec<String>,
+    traits: Vec<TraitDeclaration>,
 }
 
 #[derive(Clone)]
@@ -57,6 +59,7 @@ impl TypeDeclaration {
                 host_lang: opaque.host_lang,
                 reference,
                 mutable,
+                arc: false,
                 has_swift_bridge_copy_annotation: opaque.attributes.copy.is_some(),
                 generics: opaque.generics.clone(),
             }),
@@ -177,6 +180,67 @@ impl OpaqueForeignTypeDeclaration {
     }
 }
 
+/// A trait that was declared in the bridge module.
+///
+/// ```no_run,ignore
+/// #[swift_bridge::bridge]
+/// mod ffi {
+///     trait Animal {
+///         fn name(&self) -> String;
+///     }
+///
+///     extern "Rust" {
+///         type Dog;
+///     }
+///
+///     impl Animal for Dog {}
+/// }
+/// ```
+pub(crate) struct TraitDeclaration {
+    pub name: Ident,
+    /// The trait's methods, which get called on Swift objects that conform to the generated
+    /// Swift protocol.
+    /// Each method is associated to a Swift type with the same name as the trait.
+    pub methods: Vec<ParsedExternFn>,
+    /// The opaque Rust types that implement the trait.
+    pub implementors: Vec<Ident>,
+    /// Whether the trait has a `Send` supertrait, such as `trait Animal: Send { ... }`.
+    pub send: bool,
+    /// Whether the trait has a `Sync` supertrait, such as `trait Animal: Sync { ... }`.
+    pub sync: bool,
+}
+
+impl TraitDeclaration {
+    /// The Rust type that holds a Swift object that conforms to the trait's protocol.
+    ///
+    /// For `trait Animal` this would be `__swift_bridge__Animal`.
+    pub(crate) fn swift_object_wrapper_ident(&self) -> Ident {
+        Ident::new(
+            &format!("{}{}", SWIFT_BRIDGE_PREFIX, self.name),
+            self.name.span(),
+        )
+    }
+
+    /// Whether Swift objects that conform to the trait's protocol get used from other threads, in
+    /// which case the protocol inherits from Swift's `Sendable` protocol.
+    pub(crate) fn is_sendable(&self) -> bool {
+        self.send || self.sync
+    }
+
+    /// "__swift_bridge__$Animal$_free"
+    pub(crate) fn free_swift_object_link_name(&self) -> String {
+        format!("{}${}$_free", SWIFT_BRIDGE_PREFIX, self.name)
+    }
+
+    /// "__swift_bridge__Animal__free"
+    pub(crate) fn free_swift_object_ident(&self) -> Ident {
+        Ident::new(
+            &format!("{}{}__free", SWIFT_BRIDGE_PREFIX, self.name),
+            self.name.span(),
+        )
+    }
+}
+
 #[derive(Copy, Clone)]
 pub(crate) struct OpaqueCopy {
     /// The size of the opaque type, in bytes.
@@ -231,6 +295,7 @@ impl TypeDeclarations {
         let ty = ty.replace(" ", "");
 
         self.get(&ty)
+            .or_else(|| self.get(&path_without_lifetimes(&type_path.path)))
     }
 
     pub(crate) fn get_with_type(&self, ty: &Type) -> Option<&TypeDeclaration> {
@@ -247,12 +312,66 @@ impl TypeDeclarations {
         self.order.push(type_name);
     }
 
+    /// Call a function on each type declaration, in the order that they were declared.
+    pub(crate) fn for_each_type_mut(&mut self, mut f: impl FnMut(&mut TypeDeclaration)) {
+        for ty in self.order.iter() {
+            f(self.decls.get_mut(ty).unwrap());
+        }
+    }
+
     pub fn types(&self) -> Vec<&TypeDeclaration> {
         self.order
             .iter()
             .map(|ty| self.decls.get(ty).unwrap())
             .collect()
     }
+
+    pub(crate) fn get_trait(&self, trait_name: &str) -> Option<&TraitDeclaration> {
+        self.traits.iter().find(|t| t.name == trait_name)
+    }
+
+    pub(crate) fn get_trait_mut(&mut self, trait_name: &str) -> Option<&mut TraitDeclaration> {
+        self.traits.iter_mut().find(|t| t.name == trait_name)
+    }
+
+    pub(crate) fn insert_trait(&mut self, trait_declaration: TraitDeclaration) {
+        self.traits.push(trait_declaration);
+    }
+
+    pub fn traits(&self) -> &[TraitDeclaration] {
+        &self.traits
+    }
+
+    /// Whether an opaque Rust type implements a trait that has `Send` or `Sync` supertraits, in
+    /// which case its Swift `Ref` class is `Sendable`.
+    pub(crate) fn implements_sendable_trait(&self, ty: &Ident) -> bool {
+        self.traits.iter().any(|trait_declaration| {
+            trait_declaration.is_sendable() && trait_declaration.implementors.contains(ty)
+        })
+    }
+}
+
+/// Types that have lifetime parameters are declared without them, so `SomeStruct<'a>` is looked
+/// up as `SomeStruct`.
+fn path_without_lifetimes(path: &Path) -> String {
+    let mut path = path.clone();
+
+    for segment in path.segments.iter_mut() {
+        if let PathArguments::AngleBracketed(args) = &mut segment.arguments {
+            args.args = args
+                .args
+                .iter()
+                .filter(|arg| !matches!(arg, GenericArgument::Lifetime(_)))
+                .cloned()
+                .collect();
+
+            if args.args.is_empty() {
+                segment.arguments = PathArguments::None;
+            }
+        }
+    }
+
+    path.to_token_stream().to_string().replace(" ", "")
 }
 
 impl TypeDeclaration {
@@ -262,6 +381,49 @@ impl TypeDeclaration {
             _ => None,
         }
     }
+
+    pub(crate) fn as_shared(&self) -> Option<&SharedTypeDeclaration> {
+        match self {
+            TypeDeclaration::Shared(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// The name of the type in Rust.
+    ///
+    /// `SomeType`
+    pub(crate) fn name_string(&self) -> String {
+        match self {
+            TypeDeclaration::Shared(shared) => shared.name().to_string(),
+            TypeDeclaration::Opaque(opaque) => opaque.to_string(),
+        }
+    }
+}
+
+impl SharedTypeDeclaration {
+    /// The name of the shared struct or enum in Rust.
+    pub(crate) fn name(&self) -> &Ident {
+        match self {
+            SharedTypeDeclaration::Struct(shared_struct) => &shared_struct.name,
+            SharedTypeDeclaration::Enum(shared_enum) => &shared_enum.name,
+        }
+    }
+
+    /// The name of the shared struct or enum in Swift.
+    pub(crate) fn swift_name_string(&self) -> String {
+        match self {
+            SharedTypeDeclaration::Struct(shared_struct) => shared_struct.swift_name_string(),
+            SharedTypeDeclaration::Enum(shared_enum) => shared_enum.swift_name_string(),
+        }
+    }
+
+    /// Whether the shared struct or enum was declared in a different bridge module.
+    pub(crate) fn already_declared(&self) -> bool {
+        match self {
+            SharedTypeDeclaration::Struct(shared_struct) => shared_struct.already_declared,
+            SharedTypeDeclaration::Enum(shared_enum) => shared_enum.already_declared,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1,16 +1,32 @@
+use crate::bridged_type::shared_type_generics::alpha_numeric_type_name;
 use crate::bridged_type::{BridgedType, TypePosition};
 use crate::parse::HostLang;
 use crate::TypeDeclarations;
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{quote, ToTokens};
 use std::ops::Deref;
-use syn::{Path, TypeParam};
-
-pub(crate) const GENERIC_PLACEHOLDERS: [&'static str; 8] = ["A", "B", "C", "D", "E", "F", "G", "H"];
+use syn::{Path, Type};
+
+/// The name of the generic placeholder at the given index in the Swift class declaration of a
+/// generic opaque Rust type.
+///
+/// 0 -> "A", 25 -> "Z", 26 -> "A1", 27 -> "B1"
+pub(crate) fn generic_placeholder(idx: usize) -> String {
+    let letter = (b'A' + (idx % 26) as u8) as char;
+    let round = idx / 26;
+
+    if round == 0 {
+        letter.to_string()
+    } else {
+        format!("{}{}", letter, round)
+    }
+}
 
 #[derive(Clone)]
 pub(crate) struct OpaqueRustTypeGenerics {
-    pub generics: Vec<TypeParam>,
+    /// The generic arguments, such as the `String` and `Vec<u8>` in
+    /// `type SomeType<String, Vec<u8>>`.
+    pub generics: Vec<Type>,
 }
 
 impl OpaqueRustTypeGenerics {
@@ -36,8 +52,8 @@ impl OpaqueRustTypeGenerics {
             .map(|(idx, g)| {
                 format!(
                     "{} == {}",
-                    GENERIC_PLACEHOLDERS[idx],
-                    BridgedType::new_with_str(&g.ident.to_string(), types)
+                    generic_placeholder(idx),
+                    BridgedType::new_with_type(g, types)
                         .unwrap()
                         // TODO: FnReturn isn't the real position.. Add a
                         //  new variant that makes more sense for our use case (generic bounds).
@@ -64,7 +80,7 @@ impl OpaqueRustTypeGenerics {
             .generics
             .iter()
             .enumerate()
-            .map(|(idx, _)| GENERIC_PLACEHOLDERS[idx].to_string())
+            .map(|(idx, _)| generic_placeholder(idx))
             .collect();
         let generics = generics.join(", ");
         format!("<{}>", generics)
@@ -87,7 +103,7 @@ impl OpaqueRustTypeGenerics {
             .map(|g| {
                 format!(
                     "{}",
-                    BridgedType::new_with_str(&g.ident.to_string(), types)
+                    BridgedType::new_with_type(g, types)
                         .unwrap()
                         // TODO: FnReturn isn't the real position.. Add a
                         //  new variant that makes more sense for our use case (generic bounds).
@@ -117,7 +133,7 @@ impl OpaqueRustTypeGenerics {
             .generics
             .iter()
             .map(|g| {
-                let ty = BridgedType::new_with_str(&g.ident.to_string(), types).unwrap();
+                let ty = BridgedType::new_with_type(g, types).unwrap();
                 let path = ty.to_rust_type_path(types);
                 quote! { #path }
             })
@@ -125,7 +141,7 @@ impl OpaqueRustTypeGenerics {
         quote! {<#(#generics),*>}
     }
 
-    /// "$A$B$C" if there are generics.
+    /// "$u32$String$Vec_u8" if there are generics.
     /// "" if there are no generics.
     pub(crate) fn dollar_prefixed_generics_string(&self) -> String {
         let mut generics = String::with_capacity(self.generics.len() * 2);
@@ -134,13 +150,13 @@ impl OpaqueRustTypeGenerics {
         }
 
         for generic in self.generics.iter() {
-            generics += &format!("${}", generic.ident);
+            generics += &format!("${}", alpha_numeric_type_name(generic));
         }
 
         generics
     }
 
-    /// "_A_B_C" if there are generics.
+    /// "_u32_String_Vec_u8" if there are generics.
     /// "" if there are no generics.
     pub(crate) fn underscore_prefixed_generics_string(&self) -> String {
         let mut generics = String::with_capacity(self.generics.len() * 2);
@@ -149,15 +165,28 @@ impl OpaqueRustTypeGenerics {
         }
 
         for generic in self.generics.iter() {
-            generics += &format!("_{}", generic.ident);
+            generics += &format!("_{}", alpha_numeric_type_name(generic));
         }
 
         generics
     }
+
+    /// "String,Vec<u8>" if there are generics.
+    /// "" if there are no generics.
+    ///
+    /// Used to key the generic type's instantiation in the [`TypeDeclarations`].
+    pub(crate) fn comma_separated_generics_string(&self) -> String {
+        let generics: Vec<String> = self
+            .generics
+            .iter()
+            .map(|generic| generic.to_token_stream().to_string().replace(' ', ""))
+            .collect();
+        generics.join(",")
+    }
 }
 
 impl Deref for OpaqueRustTypeGenerics {
-    type Target = Vec<TypeParam>;
+    type Target = Vec<Type>;
 
     fn deref(&self) -> &Self::Target {
         &self.generics
@@ -0,0 +1,1051 @@
+use crate::bridged_type::boxed_fn::BridgeableBoxedFn;
+use crate::bridged_type::bridged_trait_object::BridgedTraitObject;
+use crate::bridged_type::built_in_array::BuiltInArray;
+use crate::bridged_type::shared_struct::StructSwiftRepr;
+use crate::bridged_type::{BridgeableType, BridgedType};
+use crate::errors::{ParseError, ParseErrors};
+use crate::parse::{HostLang, SharedTypeDeclaration, TypeDeclaration, TypeDeclarations};
+use crate::SwiftBridgeModule;
+use quote::ToTokens;
+use syn::{
+    FnArg, GenericArgument, Path, PathArguments, ReturnType, Type, TypeArray, TypeParamBound,
+    TypeTraitObject,
+};
+
+impl SwiftBridgeModule {
+    /// Push an error for each type in the module's function signatures that parses successfully
+    /// but that we can't generate code for.
+    pub(crate) fn push_unsupported_type_errors(&self, errors: &mut ParseErrors) {
+        for func in self.functions.iter() {
+            let sig = &func.func.sig;
+
+            let arg_types = sig.inputs.iter().filter_map(|arg| match arg {
+                FnArg::Typed(pat_ty) => Some(pat_ty.ty.as_ref()),
+                FnArg::Receiver(_) => None,
+            });
+            let return_type = match &sig.output {
+                ReturnType::Type(_, ty) => Some(ty.as_ref()),
+                ReturnType::Default => None,
+            };
+
+            for ty in arg_types.clone().chain(return_type) {
+                visit_nested_types(ty, &mut |ty| {
+                    push_map_key_errors(ty, &self.types, errors);
+                });
+            }
+
+            // A boxed function can only be passed as an argument. We don't support returning
+            // one, or putting one inside of another type such as an `Option`.
+            for arg_ty in arg_types {
+                visit_nested_types(arg_ty, &mut |ty| {
+                    if !std::ptr::eq(ty, arg_ty) && is_boxed_fn(ty) {
+                        errors.push(ParseError::BoxedFnNotFnArg { ty: ty.clone() });
+                    }
+                });
+            }
+            if let Some(return_type) = return_type {
+                visit_nested_types(return_type, &mut |ty| {
+                    if is_boxed_fn(ty) {
+                        errors.push(ParseError::BoxedFnNotFnArg { ty: ty.clone() });
+                    }
+                });
+            }
+        }
+
+        self.push_trait_object_errors(errors);
+        self.push_borrowed_struct_return_errors(errors);
+        self.push_vec_element_errors(errors);
+        self.push_arc_in_collection_errors(errors);
+        self.push_derive_debug_errors(errors);
+        self.push_result_err_type_errors(errors);
+    }
+
+    /// A function that returns a `Result` throws its error in Swift, and Swift can only throw a
+    /// type that conforms to `Error`. An `Optional` or a tuple can't conform to `Error`.
+    fn push_result_err_type_errors(&self, errors: &mut ParseErrors) {
+        let trait_methods = self.types.traits().iter().flat_map(|t| t.methods.iter());
+        for func in self.functions.iter().chain(trait_methods) {
+            let return_type = match &func.func.sig.output {
+                ReturnType::Type(_, return_type) => return_type.as_ref(),
+                ReturnType::Default => continue,
+            };
+            let err_ty = match generic_type_args(return_type, &["Result"]).get(1) {
+                Some(err_ty) => *err_ty,
+                None => continue,
+            };
+
+            let is_throwable = match err_ty {
+                Type::Tuple(tuple) => tuple.elems.is_empty(),
+                _ => generic_type_args(err_ty, &["Option"]).is_empty(),
+            };
+            if !is_throwable {
+                errors.push(ParseError::ResultErrNotThrowable { ty: err_ty.clone() });
+            }
+        }
+    }
+
+    /// A struct's `debugDescription` passes a copy of the struct to its Rust `Debug` impl, which
+    /// only works for non-generic structs whose fields don't own Rust values.
+    fn push_derive_debug_errors(&self, errors: &mut ParseErrors) {
+        for declaration in self.types.types() {
+            let shared_struct = match declaration {
+                TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)) => {
+                    shared_struct
+                }
+                _ => continue,
+            };
+            if !shared_struct.derives.debug
+                || !matches!(shared_struct.swift_repr, StructSwiftRepr::Structure)
+            {
+                continue;
+            }
+
+            if !shared_struct.generics.is_empty() {
+                // Each instantiation of a generic struct gets parsed as its own struct, but only
+                // the first one declares the Swift struct.
+                if shared_struct.declared_fields.is_some() {
+                    errors.push(ParseError::DeriveDebugGenericStruct {
+                        name: shared_struct.name.clone(),
+                    });
+                }
+            } else if shared_struct.fields.ffi_repr_owns_rust_values(&self.types) {
+                errors.push(ParseError::DeriveDebugOwnsRustValues {
+                    name: shared_struct.name.clone(),
+                });
+            }
+        }
+    }
+
+    /// Swift holds an `Arc<T>` in a class that owns one strong count, which can't be put in a
+    /// `RustVec<T>` or in another collection.
+    fn push_arc_in_collection_errors(&self, errors: &mut ParseErrors) {
+        for ty in self.signature_and_field_types() {
+            visit_nested_types(&ty, &mut |ty| {
+                let elements =
+                    generic_type_args(ty, &["Vec", "HashMap", "BTreeMap", "HashSet", "BTreeSet"]);
+                let has_arc_element = match ty {
+                    Type::Array(array) => is_arc_of_opaque_type(&array.elem),
+                    _ => elements.into_iter().any(is_arc_of_opaque_type),
+                };
+                if has_arc_element {
+                    errors.push(ParseError::UnsupportedArc { ty: ty.clone() });
+                }
+            });
+        }
+    }
+
+    /// Swift gets a copy of an element when it reads from a `RustVec<T>`, so a shared struct
+    /// needs to be `Clone` to be put in a `Vec<T>`.
+    fn push_vec_element_errors(&self, errors: &mut ParseErrors) {
+        for ty in self.signature_and_field_types() {
+            visit_nested_types(&ty, &mut |ty| {
+                let element = match generic_type_args(ty, &["Vec"]).first() {
+                    Some(Type::Path(element)) => element,
+                    _ => return,
+                };
+                if let Some(TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct))) =
+                    self.types.get_with_type_path(element)
+                {
+                    if !shared_struct.derives.clone {
+                        errors.push(ParseError::VecElementNotClone {
+                            ty: Type::Path(element.clone()),
+                        });
+                    }
+                }
+            });
+        }
+    }
+
+    /// The types of the arguments and return values of the module's functions and trait
+    /// methods, and the types of its shared structs' and enums' fields.
+    fn signature_and_field_types(&self) -> Vec<Type> {
+        let mut types = vec![];
+
+        let trait_methods = self.types.traits().iter().flat_map(|t| t.methods.iter());
+        for func in self.functions.iter().chain(trait_methods) {
+            let sig = &func.func.sig;
+            for arg in sig.inputs.iter() {
+                if let FnArg::Typed(pat_ty) = arg {
+                    types.push(pat_ty.ty.as_ref().clone());
+                }
+            }
+            if let ReturnType::Type(_, return_type) = &sig.output {
+                types.push(return_type.as_ref().clone());
+            }
+        }
+
+        for declaration in self.types.types() {
+            let fields = match declaration {
+                TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)) => {
+                    shared_struct.fields.normalized_fields()
+                }
+                TypeDeclaration::Shared(SharedTypeDeclaration::Enum(shared_enum)) => shared_enum
+                    .variants
+                    .iter()
+                    .flat_map(|variant| variant.fields.normalized_fields())
+                    .collect(),
+                TypeDeclaration::Opaque(_) => continue,
+            };
+            types.extend(fields.into_iter().map(|field| field.ty));
+        }
+
+        types
+    }
+
+    /// Swift passes a `&str` argument to Rust as a view into a temporary copy of the Swift
+    /// string that is only alive for the duration of the call. So an `extern "Rust"` function
+    /// can't return a shared struct that borrows from one of its `&str` arguments.
+    fn push_borrowed_struct_return_errors(&self, errors: &mut ParseErrors) {
+        for func in self
+            .functions
+            .iter()
+            .filter(|func| func.host_lang.is_rust())
+        {
+            let sig = &func.func.sig;
+            let return_type = match &sig.output {
+                ReturnType::Type(_, ty) => ty.as_ref(),
+                ReturnType::Default => continue,
+            };
+
+            let mut borrowed_struct = None;
+            let mut return_lifetimes = vec![];
+            visit_nested_types(return_type, &mut |ty| {
+                if let Some(lifetimes) = self.borrowed_struct_lifetimes(ty) {
+                    borrowed_struct.get_or_insert(ty);
+                    return_lifetimes.extend(lifetimes);
+                }
+            });
+            let borrowed_struct = match borrowed_struct {
+                Some(borrowed_struct) => borrowed_struct,
+                None => continue,
+            };
+
+            // Elided return lifetimes come from the `&self` receiver when there is one, and
+            // otherwise from the function's only reference argument.
+            let borrows_elided_arg =
+                return_lifetimes.iter().any(|l| l == "_") && func.self_reference().is_none();
+
+            let mut str_args = vec![];
+            for arg in sig.inputs.iter() {
+                if let FnArg::Typed(pat_ty) = arg {
+                    visit_nested_types(&pat_ty.ty, &mut |ty| {
+                        if let Type::Reference(reference) = ty {
+                            if matches!(reference.elem.as_ref(), Type::Path(path) if path.path.is_ident("str"))
+                            {
+                                str_args.push(reference);
+                            }
+                        }
+                    });
+                }
+            }
+
+            for str_arg in str_args {
+                let borrows = match &str_arg.lifetime {
+                    Some(lifetime) if lifetime.ident != "_" => {
+                        return_lifetimes.contains(&lifetime.ident.to_string())
+                    }
+                    _ => borrows_elided_arg,
+                };
+                if borrows {
+                    errors.push(ParseError::BorrowedStructReturnBorrowsStrArg {
+                        return_ty: borrowed_struct.clone(),
+                        arg: Type::Reference(str_arg.clone()),
+                    });
+                }
+            }
+        }
+    }
+
+    /// The lifetimes of a shared struct that has lifetime parameters, such as `["a"]` for
+    /// `SomeStruct<'a>`. An elided lifetime, such as in `SomeStruct<'_>` or `SomeStruct`, is `"_"`.
+    fn borrowed_struct_lifetimes(&self, ty: &Type) -> Option<Vec<String>> {
+        let path = match ty {
+            Type::Path(path) => path,
+            _ => return None,
+        };
+        match self.types.get_with_type_path(path) {
+            Some(TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)))
+                if !shared_struct.lifetimes.is_empty() => {}
+            _ => return None,
+        };
+
+        let mut lifetimes: Vec<String> = match &path.path.segments.last()?.arguments {
+            PathArguments::AngleBracketed(args) => args
+                .args
+                .iter()
+                .filter_map(|arg| match arg {
+                    GenericArgument::Lifetime(lifetime) => Some(lifetime.ident.to_string()),
+                    _ => None,
+                })
+                .collect(),
+            _ => vec![],
+        };
+        if lifetimes.is_empty() {
+            lifetimes.push("_".to_string());
+        }
+
+        Some(lifetimes)
+    }
+
+    /// Swift can pass a trait object to Rust, but we don't support passing one from Rust to
+    /// Swift or putting one inside of another type such as an `Option`. So a trait object can
+    /// only be the type of an `extern "Rust"` function argument.
+    fn push_trait_object_errors(&self, errors: &mut ParseErrors) {
+        let mut push_error = |ty: &Type| {
+            if BridgedTraitObject::from_type(ty, &self.types).is_some() {
+                errors.push(ParseError::TraitObjectNotRustFnArg { ty: ty.clone() });
+            }
+        };
+
+        let trait_methods = self.types.traits().iter().flat_map(|t| t.methods.iter());
+        for func in self.functions.iter().chain(trait_methods) {
+            let sig = &func.func.sig;
+
+            for arg in sig.inputs.iter() {
+                if let FnArg::Typed(pat_ty) = arg {
+                    let arg_ty = pat_ty.ty.as_ref();
+                    visit_nested_types(arg_ty, &mut |ty| {
+                        if !(func.host_lang.is_rust() && std::ptr::eq(ty, arg_ty)) {
+                            push_error(ty);
+                        }
+                    });
+                }
+            }
+            if let ReturnType::Type(_, return_type) = &sig.output {
+                visit_nested_types(return_type, &mut push_error);
+            }
+        }
+
+        for declaration in self.types.types() {
+            let fields = match declaration {
+                TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)) => {
+                    shared_struct.fields.normalized_fields()
+                }
+                TypeDeclaration::Shared(SharedTypeDeclaration::Enum(shared_enum)) => shared_enum
+                    .variants
+                    .iter()
+                    .flat_map(|variant| variant.fields.normalized_fields())
+                    .collect(),
+                TypeDeclaration::Opaque(_) => continue,
+            };
+            for field in fields.iter() {
+                visit_nested_types(&field.ty, &mut push_error);
+            }
+        }
+    }
+}
+
+/// A more specific error than `ParseError::UndeclaredType` for a type that can't be parsed since
+/// it uses a supported type in a way that we don't support, such as a `&Arc<T>`.
+pub(super) fn unresolved_type_error(ty: &Type, types: &TypeDeclarations) -> Option<ParseError> {
+    let mut error = None;
+    visit_nested_types(ty, &mut |ty| {
+        if error.is_some() {
+            return;
+        }
+
+        if let Type::Reference(reference) = ty {
+            if is_arc_of_opaque_type(&reference.elem) {
+                error = Some(ParseError::UnsupportedArc { ty: ty.clone() });
+            }
+        }
+        if let Type::TraitObject(trait_object) = ty {
+            error = trait_object_missing_supertraits_error(trait_object, types);
+        }
+        if let Type::Array(array) = ty {
+            error = unsupported_array_error(array, types);
+        }
+    });
+
+    error
+}
+
+/// A `[T; N]` whose length isn't a non-zero integer literal, or whose elements can't be stored
+/// in a C array.
+fn unsupported_array_error(array: &TypeArray, types: &TypeDeclarations) -> Option<ParseError> {
+    if BuiltInArray::len(array).is_none() {
+        return Some(ParseError::UnsupportedArrayLength {
+            len: array.len.clone(),
+        });
+    }
+
+    match BridgedType::new_with_type(&array.elem, types) {
+        Some(elem) if !BuiltInArray::is_supported_element(&elem) => {
+            Some(ParseError::UnsupportedArrayElement {
+                elem: array.elem.as_ref().clone(),
+            })
+        }
+        Some(_) => None,
+        None => unresolved_type_error(&array.elem, types).or_else(|| {
+            Some(ParseError::UndeclaredType {
+                ty: array.elem.as_ref().clone(),
+            })
+        }),
+    }
+}
+
+/// `dyn SomeTrait + Send + Sync`, where `SomeTrait` was declared without the `Send` or `Sync`
+/// supertrait.
+/// The Swift object wrapper only implements `Send` and `Sync` if the trait has them as
+/// supertraits.
+fn trait_object_missing_supertraits_error(
+    trait_object: &TypeTraitObject,
+    types: &TypeDeclarations,
+) -> Option<ParseError> {
+    let mut trait_declaration = None;
+    let mut send = false;
+    let mut sync = false;
+    for bound in trait_object.bounds.iter() {
+        let bound = match bound {
+            TypeParamBound::Trait(bound) => bound.path.get_ident()?,
+            _ => continue,
+        };
+
+        if bound == "Send" {
+            send = true;
+        } else if bound == "Sync" {
+            sync = true;
+        } else {
+            trait_declaration = types.get_trait(&bound.to_string());
+        }
+    }
+    let trait_declaration = trait_declaration?;
+
+    let mut missing = vec![];
+    if send && !trait_declaration.send {
+        missing.push("Send");
+    }
+    if sync && !trait_declaration.sync {
+        missing.push("Sync");
+    }
+    if missing.is_empty() {
+        return None;
+    }
+
+    Some(ParseError::TraitObjectMissingSupertraits {
+        ty: Type::TraitObject(trait_object.clone()),
+        trait_name: trait_declaration.name.clone(),
+        supertraits: missing.join(" + "),
+    })
+}
+
+/// `Arc<T>`, where `T` isn't a trait object.
+fn is_arc_of_opaque_type(ty: &Type) -> bool {
+    match generic_type_args(ty, &["Arc"]).first() {
+        Some(inner) => !matches!(inner, Type::TraitObject(_)),
+        None => false,
+    }
+}
+
+/// `Box<dyn FnOnce(A) -> B>`, `Box<dyn FnMut(A) -> B>` or `Box<dyn Fn(A) -> B>`
+pub(super) fn is_boxed_fn(ty: &Type) -> bool {
+    BridgeableBoxedFn::can_parse_str_tokens(&ty.to_token_stream().to_string())
+}
+
+/// Swift `Dictionary` keys need to be `Hashable`, so a map's opaque Rust type keys must use the
+/// `Equatable` and `Hashable` attributes.
+fn push_map_key_errors(ty: &Type, types: &TypeDeclarations, errors: &mut ParseErrors) {
+    let key = match generic_type_args(ty, &["HashMap", "BTreeMap"]).first() {
+        Some(key) => *key,
+        None => return,
+    };
+
+    if let Some(TypeDeclaration::Opaque(opaque)) = types.get_with_type(key) {
+        if opaque.host_lang == HostLang::Rust
+            && !(opaque.attributes.equatable && opaque.attributes.hashable)
+        {
+            errors.push(ParseError::MapKeyNotHashable { key: key.clone() });
+        }
+    }
+}
+
+/// The generic type arguments of a type whose name is one of the given names.
+///
+/// `HashMap<u8, String>` -> `[u8, String]`
+pub(super) fn generic_type_args<'a>(ty: &'a Type, names: &[&str]) -> Vec<&'a Type> {
+    let last = match ty {
+        Type::Path(path) => match path.path.segments.last() {
+            Some(last) => last,
+            None => return vec![],
+        },
+        _ => return vec![],
+    };
+    if !names.iter().any(|name| last.ident == name) {
+        return vec![];
+    }
+
+    match &last.arguments {
+        PathArguments::AngleBracketed(args) => args
+            .args
+            .iter()
+            .filter_map(|arg| match arg {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// Call `visit` with the type and with every type that is nested inside of it, such as the `u8`
+/// and the `String` in `Result<Vec<u8>, String>`, or the `u8` in `Box<dyn Fn(u8)>`.
+pub(crate) fn visit_nested_types<'a>(ty: &'a Type, visit: &mut impl FnMut(&'a Type)) {
+    visit(ty);
+
+    match ty {
+        Type::Path(path) => visit_path_types(&path.path, visit),
+        Type::TraitObject(trait_object) => {
+            for bound in trait_object.bounds.iter() {
+                if let TypeParamBound::Trait(bound) = bound {
+                    visit_path_types(&bound.path, visit);
+                }
+            }
+        }
+        Type::Tuple(tuple) => {
+            for ty in tuple.elems.iter() {
+                visit_nested_types(ty, visit);
+            }
+        }
+        Type::Array(array) => visit_nested_types(&array.elem, visit),
+        Type::Slice(slice) => visit_nested_types(&slice.elem, visit),
+        Type::Reference(reference) => visit_nested_types(&reference.elem, visit),
+        Type::Ptr(ptr) => visit_nested_types(&ptr.elem, visit),
+        Type::Paren(paren) => visit_nested_types(&paren.elem, visit),
+        Type::Group(group) => visit_nested_types(&group.elem, visit),
+        _ => {}
+    }
+}
+
+/// Visit the generic arguments of `Vec<T>`, and the parameters and return type of `Fn(A) -> B`.
+fn visit_path_types<'a>(path: &'a Path, visit: &mut impl FnMut(&'a Type)) {
+    for segment in path.segments.iter() {
+        match &segment.arguments {
+            PathArguments::AngleBracketed(args) => {
+                for arg in args.args.iter() {
+                    if let GenericArgument::Type(ty) = arg {
+                        visit_nested_types(ty, visit);
+                    }
+                }
+            }
+            PathArguments::Parenthesized(args) => {
+                for ty in args.inputs.iter() {
+                    visit_nested_types(ty, visit);
+                }
+                if let ReturnType::Type(_, ty) = &args.output {
+                    visit_nested_types(ty, visit);
+                }
+            }
+            PathArguments::None => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{parse_errors, parse_ok};
+    use quote::quote;
+
+    /// Verify that we push an error for a map whose keys are an opaque Rust type that doesn't
+    /// use the `Equatable` and `Hashable` attributes.
+    #[test]
+    fn error_if_map_key_is_not_hashable() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                    #[swift_bridge(Equatable)]
+                    type AnotherType;
+
+                    fn some_function(arg: HashMap<SomeType, u8>);
+                    fn another_function() -> Option<BTreeMap<AnotherType, u8>>;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 2);
+
+        for (idx, expected_key) in ["SomeType", "AnotherType"].into_iter().enumerate() {
+            match &errors[idx] {
+                ParseError::MapKeyNotHashable { key } => {
+                    assert_eq!(key.to_token_stream().to_string(), expected_key);
+                }
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that a map can have `Hashable` opaque Rust type keys.
+    #[test]
+    fn map_with_hashable_key() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Equatable, Hashable)]
+                    type SomeType;
+
+                    fn some_function(arg: HashMap<SomeType, SomeType>);
+                }
+            }
+        };
+
+        parse_ok(tokens);
+    }
+
+    /// Verify that we push an error for a boxed function that isn't a function argument.
+    #[test]
+    fn error_if_boxed_fn_is_not_fn_arg() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    fn some_function() -> Box<dyn Fn(u8)>;
+                    fn another_function(arg: Vec<Box<dyn FnMut()>>);
+                    fn third_function(arg: Box<dyn Fn(Box<dyn FnOnce()>)>);
+                }
+
+                extern "Swift" {
+                    fn fourth_function() -> Box<dyn Fn() -> u8>;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 4);
+
+        for (idx, expected_ty) in [
+            "Box < dyn Fn (u8) >",
+            "Box < dyn FnMut () >",
+            "Box < dyn FnOnce () >",
+            "Box < dyn Fn () -> u8 >",
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            match &errors[idx] {
+                ParseError::BoxedFnNotFnArg { ty } => {
+                    assert_eq!(ty.to_token_stream().to_string(), expected_ty);
+                }
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that we push an error for a trait object that isn't an `extern "Rust"` function
+    /// argument.
+    #[test]
+    fn error_if_trait_object_is_not_rust_fn_arg() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                trait Animal {
+                    fn name(&self) -> String;
+                    fn mate(&self, other: &dyn Animal);
+                }
+
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    field: Box<dyn Animal>,
+                }
+
+                extern "Rust" {
+                    fn some_function(arg: &dyn Animal) -> Box<dyn Animal>;
+                    fn another_function(arg: Option<Box<dyn Animal>>);
+                }
+
+                extern "Swift" {
+                    fn third_function(arg: Arc<dyn Animal>);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 5);
+
+        for (idx, expected_ty) in [
+            "Box < dyn Animal >",
+            "Box < dyn Animal >",
+            "Arc < dyn Animal >",
+            "& dyn Animal",
+            "Box < dyn Animal >",
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            match &errors[idx] {
+                ParseError::TraitObjectNotRustFnArg { ty } => {
+                    assert_eq!(ty.to_token_stream().to_string(), expected_ty);
+                }
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that we push an error for an `extern "Rust"` function that returns a shared struct
+    /// that borrows from a `&str` argument.
+    #[test]
+    fn error_if_borrowed_struct_borrows_from_str_arg() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct Token<'a> {
+                    text: &'a str,
+                }
+
+                extern "Rust" {
+                    fn some_function<'a>(arg: &'a str) -> Token<'a>;
+                    fn another_function(arg: &str) -> Option<Token<'_>>;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 2);
+
+        for (idx, (expected_return_ty, expected_arg)) in
+            [("Token < 'a >", "& 'a str"), ("Token < '_ >", "& str")]
+                .into_iter()
+                .enumerate()
+        {
+            match &errors[idx] {
+                ParseError::BorrowedStructReturnBorrowsStrArg { return_ty, arg } => {
+                    assert_eq!(return_ty.to_token_stream().to_string(), expected_return_ty);
+                    assert_eq!(arg.to_token_stream().to_string(), expected_arg);
+                }
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that a function can return a shared struct that borrows from Rust data while
+    /// taking a `&str` argument.
+    #[test]
+    fn borrowed_struct_that_does_not_borrow_from_str_arg() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct Token<'a> {
+                    text: &'a str,
+                }
+
+                extern "Rust" {
+                    type Document;
+
+                    fn find(&self, text: &str) -> Option<Token<'_>>;
+                    fn some_function<'a>(document: &'a Document, text: &str) -> Token<'a>;
+                    fn another_function(arg: Token<'_>) -> Token<'_>;
+                }
+            }
+        };
+
+        parse_ok(tokens);
+    }
+
+    /// Verify that we push an error for a `Vec<T>` of a shared struct that isn't `Clone`.
+    #[test]
+    fn error_if_vec_element_is_not_clone() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    field: u8,
+                }
+
+                #[swift_bridge(swift_repr = "struct")]
+                struct AnotherStruct {
+                    field: Vec<SomeStruct>,
+                }
+
+                extern "Rust" {
+                    fn some_function(arg: Vec<SomeStruct>) -> Option<Vec<SomeStruct>>;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 3);
+
+        for error in errors.iter() {
+            match error {
+                ParseError::VecElementNotClone { ty } => {
+                    assert_eq!(ty.to_token_stream().to_string(), "SomeStruct");
+                }
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that a shared struct that is `Clone` can be used in a `Vec<T>`.
+    #[test]
+    fn vec_element_is_clone() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                #[derive(Clone)]
+                struct SomeStruct {
+                    field: u8,
+                }
+
+                extern "Rust" {
+                    fn some_function(arg: Vec<SomeStruct>) -> Vec<SomeStruct>;
+                }
+            }
+        };
+
+        parse_ok(tokens);
+    }
+
+    /// Verify that we push an error for a shared struct that contains itself through a
+    /// `Vec<Self>` without being `Clone`.
+    #[test]
+    fn error_if_recursive_vec_element_is_not_clone() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct TreeNode {
+                    value: u32,
+                    children: Vec<TreeNode>,
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            ParseError::VecElementNotClone { ty } => {
+                assert_eq!(ty.to_token_stream().to_string(), "TreeNode");
+            }
+            _ => panic!(),
+        }
+    }
+
+    /// Verify that we push an error for an `Arc<T>` that is borrowed or put in a collection.
+    #[test]
+    fn error_if_arc_is_borrowed_or_in_collection() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type Session;
+
+                    fn some_function(arg: &Arc<Session>);
+                    fn another_function() -> Vec<Arc<Session>>;
+                    fn third_function(arg: Option<HashMap<String, Arc<Session>>>);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 3);
+
+        let mut tys: Vec<String> = errors
+            .iter()
+            .map(|error| match error {
+                ParseError::UnsupportedArc { ty } => ty.to_token_stream().to_string(),
+                _ => panic!(),
+            })
+            .collect();
+        tys.sort();
+        assert_eq!(
+            tys,
+            vec![
+                "& Arc < Session >",
+                "HashMap < String , Arc < Session > >",
+                "Vec < Arc < Session > >",
+            ]
+        );
+    }
+
+    /// Verify that we push an error for a struct that derives `Debug` and is generic or has
+    /// fields that own Rust values.
+    #[test]
+    fn error_if_derive_debug_struct_owns_rust_values_or_is_generic() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                #[derive(Debug)]
+                struct Owning {
+                    field: Option<String>,
+                }
+
+                #[swift_bridge(swift_repr = "struct", instantiate(T = u8), instantiate(T = u16))]
+                #[derive(Debug)]
+                struct Generic<T> {
+                    field: T,
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 2);
+
+        match &errors[0] {
+            ParseError::DeriveDebugOwnsRustValues { name } => assert_eq!(name, "Owning"),
+            _ => panic!(),
+        }
+        match &errors[1] {
+            ParseError::DeriveDebugGenericStruct { name } => assert_eq!(name, "Generic"),
+            _ => panic!(),
+        }
+    }
+
+    /// Verify that a non-generic struct whose fields don't own Rust values can derive `Debug`.
+    #[test]
+    fn derive_debug_plain_data_struct() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                #[derive(Debug)]
+                struct Inner {
+                    field: u8,
+                }
+
+                #[swift_bridge(swift_repr = "struct")]
+                #[derive(Clone, Debug)]
+                struct Outer {
+                    inner: Inner,
+                    maybe: Option<u32>,
+                }
+            }
+        };
+
+        parse_ok(tokens);
+    }
+
+    /// Verify that we push an error for a returned `Result` whose error type Swift can't throw.
+    #[test]
+    fn error_if_result_err_is_option_or_tuple() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    fn some_function() -> Result<u8, Option<String>>;
+                    fn another_function() -> Result<u8, (u8, u16)>;
+                    fn unit_error() -> Result<u8, ()>;
+                    fn nested(arg: Option<Result<u8, Option<String>>>);
+                }
+
+                extern "Swift" {
+                    fn swift_function() -> Result<u8, Option<String>>;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 3);
+
+        for (idx, expected_ty) in ["Option < String >", "(u8 , u16)", "Option < String >"]
+            .into_iter()
+            .enumerate()
+        {
+            match &errors[idx] {
+                ParseError::ResultErrNotThrowable { ty } => {
+                    assert_eq!(ty.to_token_stream().to_string(), expected_ty);
+                }
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that we push an error for a `Send` or `Sync` trait object whose trait wasn't
+    /// declared with that supertrait.
+    #[test]
+    fn error_if_trait_object_is_send_sync_but_trait_is_not() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                trait Storage {
+                    fn get(&self) -> u8;
+                }
+
+                trait Clock: Send {
+                    fn now(&self) -> u64;
+                }
+
+                extern "Rust" {
+                    fn set_storage(storage: Arc<dyn Storage + Send + Sync>);
+                    fn set_clock(clock: Box<dyn Clock + Send + Sync>);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 2);
+
+        for (idx, (expected_trait, expected_supertraits)) in
+            [("Storage", "Send + Sync"), ("Clock", "Sync")]
+                .into_iter()
+                .enumerate()
+        {
+            match &errors[idx] {
+                ParseError::TraitObjectMissingSupertraits {
+                    trait_name,
+                    supertraits,
+                    ..
+                } => {
+                    assert_eq!(trait_name, expected_trait);
+                    assert_eq!(supertraits, expected_supertraits);
+                }
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that we push an error for a fixed-size array whose elements or length aren't
+    /// supported.
+    #[test]
+    fn error_if_array_element_or_length_is_unsupported() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: [String; 2]);
+                    fn another_function() -> Option<[Vec<u8>; 4]>;
+                    fn third_function(arg: [u8; LEN]);
+                    fn fourth_function(arg: [u8; 0]);
+                    fn fifth_function(arg: [Undeclared; 2]);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 5);
+
+        let mut messages: Vec<String> = errors
+            .iter()
+            .map(|error| match error {
+                ParseError::UnsupportedArrayElement { elem } => {
+                    format!("element {}", elem.to_token_stream())
+                }
+                ParseError::UnsupportedArrayLength { len } => {
+                    format!("length {}", len.to_token_stream())
+                }
+                ParseError::UndeclaredType { ty } => {
+                    format!("undeclared {}", ty.to_token_stream())
+                }
+                _ => panic!(),
+            })
+            .collect();
+        messages.sort();
+        assert_eq!(
+            messages,
+            vec![
+                "element String",
+                "element Vec < u8 >",
+                "length 0",
+                "length LEN",
+                "undeclared Undeclared",
+            ]
+        );
+    }
+}
@@ -1,9 +1,10 @@
-use crate::bridged_type::boxed_fn::BridgeableBoxedFnOnce;
+use crate::bridged_type::boxed_fn::BridgeableBoxedFn;
+use crate::bridged_type::shared_type_generics::alpha_numeric_type_name;
 use crate::bridged_type::{pat_type_pat_is_self, BridgeableType, BridgedType, StdLibType};
 use crate::parse::{HostLang, SharedTypeDeclaration, TypeDeclaration, TypeDeclarations};
-use crate::SWIFT_BRIDGE_PREFIX;
+use crate::{SWIFT_BRIDGE_PREFIX, SWIFT_INT128_AVAILABILITY};
 use proc_macro2::{Ident, Span, TokenStream};
-use quote::{quote, quote_spanned, ToTokens};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
 use std::collections::{HashMap, HashSet};
 use std::ops::Deref;
 use syn::spanned::Spanned;
@@ -104,19 +105,73 @@ pub(crate) struct ParsedExternFn {
     /// Get one of the associated type's fields
     pub get_field: Option<GetField>,
     pub argument_labels: HashMap<Ident, LitStr>,
+    /// The names that the generated Swift code uses for arguments whose Swift name is different
+    /// from their Rust name, such as when the module uses `swift_naming = "camelCase"`.
+    pub swift_param_names: HashMap<Ident, String>,
+    /// The default values of arguments in the generated Swift function, as Swift expressions.
+    ///
+    /// ```no_run,ignore
+    /// // Declaration
+    /// fn some_function(#[swift_bridge(default = "10")] arg: u8);
+    ///
+    /// // Generated Swift
+    /// public func some_function(_ arg: UInt8 = 10) { ... }
+    /// ```
+    pub argument_defaults: HashMap<Ident, String>,
+    /// The bridged trait that this method belongs to, when the method calls an opaque Rust
+    /// type's implementation of a trait that was declared in the bridge module.
+    ///
+    /// ```
+    /// # const  _: &str = stringify!(
+    /// #[swift_bridge::bridge]
+    /// mod ffi {
+    ///     trait Animal {
+    ///         fn name(&self) -> String;
+    ///     }
+    ///
+    ///     // Swift calls `Dog`'s `name` method through "__swift_bridge__$Dog$Animal$name".
+    ///     impl Animal for Dog {}
+    ///
+    ///     extern "Rust" {
+    ///         type Dog;
+    ///     }
+    /// }
+    /// # );
+    /// ```
+    pub trait_name: Option<Ident>,
+    /// The concrete types that a generic function was instantiated with, in the order that the
+    /// function declared its generic type parameters.
+    ///
+    /// ```
+    /// # const  _: &str = stringify!(
+    /// #[swift_bridge::bridge]
+    /// mod ffi {
+    ///     extern "Rust" {
+    ///         // Gets parsed as two functions, one with an instantiation of `[u32]` and the
+    ///         // other with an instantiation of `[String]`.
+    ///         #[swift_bridge(instantiate(T = u32), instantiate(T = String))]
+    ///         fn parse<T>(s: &str) -> T;
+    ///     }
+    /// }
+    /// # );
+    /// ```
+    pub instantiation: Vec<Type>,
 }
 
+#[derive(Clone)]
 pub(crate) enum GetField {
     Direct(GetFieldDirect),
     With(GetFieldWith),
 }
 
+#[derive(Clone)]
 pub struct GetFieldDirect {
     pub(crate) maybe_ref: Option<Token![&]>,
     pub(crate) maybe_mut: Option<Token![mut]>,
     pub(crate) field_name: Ident,
 }
 
+#[derive(Clone)]
 pub struct GetFieldWith {
     pub(crate) maybe_ref: Option<Token![&]>,
     pub(crate) maybe_mut: Option<Token![mut]>,
@@ -146,6 +201,32 @@ impl ParsedExternFn {
         self.func.sig.receiver().is_some()
     }
 
+    /// The shared struct or enum that this function is a method or an associated function of.
+    pub(crate) fn associated_shared_type(&self) -> Option<BridgedType> {
+        let associated_type = self.associated_type.as_ref()?;
+        associated_type.as_shared()?;
+
+        Some(associated_type.to_bridged_type(false, false))
+    }
+
+    /// Whether or not a method on a shared type receives a pointer to `self`, which Rust writes
+    /// the value of `self` back to after the call.
+    ///
+    /// `&mut self` methods write back the mutated value. `&self` methods on a type whose FFI
+    /// representation owns Rust values, such as a struct with a `String` field, also write back
+    /// `self`, since converting it into the Rust type takes ownership of those values.
+    pub(crate) fn writes_back_shared_self(&self, types: &TypeDeclarations) -> bool {
+        let shared_ty = match self.associated_shared_type() {
+            Some(shared_ty) => shared_ty,
+            None => return false,
+        };
+        if self.self_reference().is_none() {
+            return false;
+        }
+
+        self.self_mutability().is_some() || shared_ty.ffi_repr_owns_rust_values(types)
+    }
+
     pub fn self_reference(&self) -> Option<(Token![&], Option<Lifetime>)> {
         match self.func.sig.receiver()? {
             FnArg::Receiver(receiver) => receiver.reference.clone(),
@@ -213,14 +294,7 @@ impl ParsedExternFn {
         let sig = &self.func.sig;
 
         let prefix = if let Some(associated_ty) = self.associated_type.as_ref() {
-            match associated_ty {
-                TypeDeclaration::Shared(_) => {
-                    todo!()
-                }
-                TypeDeclaration::Opaque(associated_ty) => {
-                    format!("{}_", associated_ty.ty)
-                }
-            }
+            format!("{}_", associated_ty.name_string())
         } else {
             "".to_string()
         };
@@ -231,6 +305,14 @@ impl ParsedExternFn {
         )
     }
 
+    /// The name that the generated Swift code uses for an argument.
+    pub(crate) fn swift_param_name(&self, arg_name: &str) -> String {
+        self.swift_param_names
+            .get(&format_ident!("{}", arg_name))
+            .cloned()
+            .unwrap_or_else(|| arg_name.to_string())
+    }
+
     pub fn args_into_contains_arg(&self, arg: &FnArg) -> bool {
         if self.args_into.is_none() {
             return false;
@@ -267,7 +349,7 @@ impl ParsedExternFn {
     ) -> TokenStream {
         let mut args = vec![];
         let inputs = &self.func.sig.inputs;
-        for fn_arg in inputs.into_iter() {
+        for (idx, fn_arg) in inputs.into_iter().enumerate() {
             match fn_arg {
                 FnArg::Receiver(_receiver) => {
                     if self.host_lang.is_swift() {
@@ -291,6 +373,14 @@ impl ParsedExternFn {
                         if self.host_lang.is_rust() {
                             arg = if let Some(repr) = built_in.only_encoding() {
                                 repr.rust
+                            } else if let Some(boxed_fn) = built_in.as_boxed_fn() {
+                                boxed_fn.convert_swift_callback_to_rust_boxed_fn(
+                                    &arg,
+                                    &self.call_boxed_fn_ident(idx),
+                                    &self.free_boxed_fn_ident(idx),
+                                    swift_bridge_path,
+                                    types,
+                                )
                             } else {
                                 built_in.convert_ffi_expression_to_rust_type(
                                     &arg,
@@ -341,13 +431,13 @@ impl ParsedExternFn {
         for arg in inputs {
             match arg {
                 FnArg::Receiver(_receiver) => {
-                    self.push_self_param(&mut params);
+                    self.push_self_param(&mut params, types);
                 }
                 FnArg::Typed(pat_ty) => {
                     let pat = &pat_ty.pat;
 
                     if pat_type_pat_is_self(pat_ty) {
-                        self.push_self_param(&mut params);
+                        self.push_self_param(&mut params, types);
                     } else {
                         let built_in = BridgedType::new_with_type(&pat_ty.ty, types).unwrap();
 
@@ -437,7 +527,22 @@ impl ParsedExternFn {
         }
     }
 
-    fn push_self_param(&self, params: &mut Vec<String>) {
+    fn push_self_param(&self, params: &mut Vec<String>, types: &TypeDeclarations) {
+        if let Some(shared_ty) = self.associated_shared_type() {
+            if shared_ty.can_be_encoded_with_zero_bytes() {
+                return;
+            }
+
+            // Methods that write back `self` receive a pointer to it.
+            let maybe_pointer = if self.writes_back_shared_self(types) {
+                "*"
+            } else {
+                ""
+            };
+            params.push(format!("{}{} this", shared_ty.to_c(types), maybe_pointer));
+            return;
+        }
+
         let param = if self.is_copy_method_on_opaque_type() {
             format!(
                 "struct {}${} this",
@@ -463,26 +568,43 @@ impl ParsedExternFn {
         let host_type = self
             .associated_type
             .as_ref()
-            .map(|h| {
-                match h {
-                    TypeDeclaration::Shared(_) => {
-                        //
-                        todo!()
-                    }
-                    TypeDeclaration::Opaque(h) => {
-                        format!("${}", h.to_string())
-                    }
-                }
-            })
+            .map(|h| format!("${}", h.name_string()))
             .unwrap_or("".to_string());
+        let maybe_trait = self
+            .trait_name
+            .as_ref()
+            .map(|trait_name| format!("${}", trait_name))
+            .unwrap_or_default();
+        let maybe_instantiation = self
+            .instantiation_name()
+            .map(|instantiation| format!("${}", instantiation))
+            .unwrap_or_default();
         format!(
-            "{}{}${}",
+            "{}{}{}${}{}",
             SWIFT_BRIDGE_PREFIX,
             host_type,
-            self.func.sig.ident.to_string()
+            maybe_trait,
+            self.func.sig.ident.to_string(),
+            maybe_instantiation
         )
     }
 
+    /// Distinguishes the instantiations of a generic function from each other.
+    ///
+    /// `fn parse<T>(s: &str) -> T` instantiated with `T = Vec<u8>` gives us "Vec_u8".
+    pub(crate) fn instantiation_name(&self) -> Option<String> {
+        if self.instantiation.is_empty() {
+            return None;
+        }
+
+        let names: Vec<String> = self
+            .instantiation
+            .iter()
+            .map(alpha_numeric_type_name)
+            .collect();
+        Some(names.join("_"))
+    }
+
     pub fn call_boxed_fn_link_name(&self, boxed_fn_idx: usize) -> String {
         format!("{}$param{}", self.link_name(), boxed_fn_idx)
     }
@@ -490,13 +612,54 @@ impl ParsedExternFn {
         format!("{}$_free$param{}", self.link_name(), boxed_fn_idx)
     }
 
+    /// The Rust function that calls the boxed function at the given argument index.
+    ///
+    /// `fn some_method(&self, callback: Box<dyn Fn()>)` on `SomeType` gives us
+    /// `SomeType_some_method_param1`.
+    pub fn call_boxed_fn_ident(&self, boxed_fn_idx: usize) -> Ident {
+        let fn_name = &self.func.sig.ident;
+        Ident::new(
+            &format!(
+                "{}{}_param{}",
+                self.maybe_associated_ty_underscore_prefix(),
+                fn_name,
+                boxed_fn_idx
+            ),
+            fn_name.span(),
+        )
+    }
+
+    /// The Rust function that frees the boxed function at the given argument index.
+    ///
+    /// `fn some_method(&self, callback: Box<dyn Fn()>)` on `SomeType` gives us
+    /// `free_SomeType_some_method_param1`.
+    pub fn free_boxed_fn_ident(&self, boxed_fn_idx: usize) -> Ident {
+        let fn_name = &self.func.sig.ident;
+        Ident::new(
+            &format!(
+                "free_{}{}_param{}",
+                self.maybe_associated_ty_underscore_prefix(),
+                fn_name,
+                boxed_fn_idx
+            ),
+            fn_name.span(),
+        )
+    }
+
+    fn maybe_associated_ty_underscore_prefix(&self) -> String {
+        self.associated_type
+            .as_ref()
+            .map(|t| format!("{}_", t.name_string()))
+            .unwrap_or("".to_string())
+    }
+
     /// Generates something like:
     /// void __swift_bridge__$some_function$param0(void* boxed_fn, uint8_t arg);
     /// void __swift_bridge__$some_function$_free$param0(void* boxed_fn);
     pub fn boxed_fn_to_c_header_fns(
         &self,
         idx: usize,
-        boxed_fn: &BridgeableBoxedFnOnce,
+        boxed_fn: &BridgeableBoxedFn,
         types: &TypeDeclarations,
     ) -> String {
         let call_boxed_fn_link_name = self.call_boxed_fn_link_name(idx);
@@ -525,25 +688,26 @@ void {free_boxed_fn_link_name}(void* {boxed_fn_arg_name});"#
         let host_type_prefix = self
             .associated_type
             .as_ref()
-            .map(|h| {
-                match h {
-                    TypeDeclaration::Shared(_) => {
-                        //
-                        todo!()
-                    }
-                    TypeDeclaration::Opaque(h) => {
-                        format!("{}_", h.to_token_stream().to_string())
-                    }
-                }
-            })
+            .map(|h| format!("{}_", h.name_string()))
+            .unwrap_or_default();
+        let maybe_trait_prefix = self
+            .trait_name
+            .as_ref()
+            .map(|trait_name| format!("{}_", trait_name))
+            .unwrap_or_default();
+        let maybe_instantiation_suffix = self
+            .instantiation_name()
+            .map(|instantiation| format!("__{}", instantiation))
             .unwrap_or_default();
         let fn_name = &self.func.sig.ident;
         let prefixed_fn_name = Ident::new(
             &format!(
-                "{}{}{}",
+                "{}{}{}{}{}",
                 SWIFT_BRIDGE_PREFIX,
                 host_type_prefix,
-                fn_name.to_string()
+                maybe_trait_prefix,
+                fn_name.to_string(),
+                maybe_instantiation_suffix
             ),
             fn_name.span(),
         );
@@ -556,7 +720,7 @@ void {free_boxed_fn_link_name}(void* {boxed_fn_arg_name});"#
     pub fn args_filtered_to_boxed_fns(
         &self,
         type_decls: &TypeDeclarations,
-    ) -> Vec<(usize, BridgeableBoxedFnOnce)> {
+    ) -> Vec<(usize, BridgeableBoxedFn)> {
         self.func
             .sig
             .inputs
@@ -566,7 +730,7 @@ void {free_boxed_fn_link_name}(void* {boxed_fn_arg_name});"#
                 let ty = BridgedType::new_with_fn_arg(arg, type_decls)?;
 
                 match ty {
-                    BridgedType::StdLib(StdLibType::BoxedFnOnce(boxed_fn)) => Some((idx, boxed_fn)),
+                    BridgedType::StdLib(StdLibType::BoxedFn(boxed_fn)) => Some((idx, boxed_fn)),
                     _ => None,
                 }
             })
@@ -574,7 +738,7 @@ void {free_boxed_fn_link_name}(void* {boxed_fn_arg_name});"#
     }
 
     /// `let cb1 = __private__RustFnOnceCallback$some_function$param0(ptr: callback); let cb0 = ...`
-    pub fn fnonce_callback_initializers(
+    pub fn boxed_fn_callback_initializers(
         &self,
         fn_name: &str,
         maybe_associated_ty: &str,
@@ -583,15 +747,16 @@ void {free_boxed_fn_link_name}(void* {boxed_fn_arg_name});"#
         let mut initializers = "".to_string();
         let mut maybe_space = "";
 
-        for (idx, fn_once) in self.args_filtered_to_boxed_fns(types) {
+        for (idx, boxed_fn) in self.args_filtered_to_boxed_fns(types) {
             let arg_name = self.arg_name_at_idx(idx).unwrap();
 
-            if fn_once.params.is_empty() && fn_once.ret.is_null() {
+            if boxed_fn.uses_no_args_no_return_support() {
                 initializers += &format!(
                 "{maybe_space}let cb{idx} = __private__RustFnOnceCallbackNoArgsNoRet(ptr: {arg_name});"
             );
             } else {
-                initializers += &format!("{maybe_space}let cb{idx} = __private__RustFnOnceCallback{maybe_associated_ty}${fn_name}$param{idx}(ptr: {arg_name});");
+                let class_prefix = boxed_fn.swift_callback_class_prefix();
+                initializers += &format!("{maybe_space}let cb{idx} = {class_prefix}{maybe_associated_ty}${fn_name}$param{idx}(ptr: {arg_name});");
             }
 
             maybe_space = " ";
@@ -624,6 +789,29 @@ void {free_boxed_fn_link_name}(void* {boxed_fn_arg_name});"#
         self.arg_name_tokens_at_idx(idx).map(|a| a.to_string())
     }
 
+    /// The `@available` attribute that a Swift function needs, if any.
+    ///
+    /// Swift's `Int128` and `UInt128` are only available on newer OS versions, so functions that
+    /// use them in their signature get annotated.
+    /// For example:
+    /// "@available(macOS 15.0, iOS 18.0, watchOS 11.0, tvOS 18.0, visionOS 2.0, *)\n"
+    pub fn maybe_swift_availability(&self, indentation: &str, types: &TypeDeclarations) -> String {
+        let return_has_128_bit_integer = BridgedType::new_with_return_type(&self.sig.output, types)
+            .map(|ty| ty.contains_128_bit_integer_recursive())
+            .unwrap_or(false);
+        let arg_has_128_bit_integer = self.sig.inputs.iter().any(|arg| {
+            BridgedType::new_with_fn_arg(arg, types)
+                .map(|ty| ty.contains_128_bit_integer_recursive())
+                .unwrap_or(false)
+        });
+
+        if return_has_128_bit_integer || arg_has_128_bit_integer {
+            format!("{}{}\n", indentation, SWIFT_INT128_AVAILABILITY)
+        } else {
+            "".to_string()
+        }
+    }
+
     /// Generate the generate bounds for a Swift function.
     /// For example:
     /// "<GenericRustString: IntoRustString>"
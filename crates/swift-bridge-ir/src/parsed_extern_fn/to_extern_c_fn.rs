@@ -184,11 +184,25 @@ impl ParsedExternFn {
 
         let call_args = self.to_call_rust_args(swift_bridge_path, types);
 
+        // `parse::<u32>`
+        let maybe_turbofish = if self.instantiation.is_empty() {
+            quote! {}
+        } else {
+            let instantiation = self.instantiation.iter().map(|ty| {
+                BridgedType::new_with_type(ty, types)
+                    .unwrap()
+                    .to_rust_type_path(types)
+            });
+            quote! { ::< #(#instantiation),* > }
+        };
+
         let call_fn = quote! {
-            #fn_name ( #call_args )
+            #fn_name #maybe_turbofish ( #call_args )
         };
 
-        let mut call_fn = if self.is_method() {
+        let mut call_fn = if self.is_method() && self.associated_shared_type().is_some() {
+            self.call_shared_type_method_tokens(&call_fn, swift_bridge_path, types)
+        } else if self.is_method() {
             self.call_method_tokens(&call_fn)
         } else {
             self.call_function_tokens(&call_fn)
@@ -197,7 +211,7 @@ impl ParsedExternFn {
         let return_ty = self.return_ty_built_in(types).unwrap();
 
         if self.return_into {
-            call_fn = return_ty.rust_expression_into(&call_fn);
+            call_fn = return_ty.rust_expression_into(&call_fn, types);
         }
 
         if let Some(return_with) = self.return_with.as_ref() {
@@ -271,23 +285,83 @@ impl ParsedExternFn {
         }
     }
 
+    /// Generate tokens for calling a method on a shared struct or enum.
+    ///
+    /// The FFI representation of `self` gets converted into the Rust type. A method that writes
+    /// back `self`, such as a `&mut self` method, is called on a local copy, which is then written
+    /// back to the FFI representation.
+    fn call_shared_type_method_tokens(
+        &self,
+        call_fn: &TokenStream,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let shared_ty = self.associated_shared_type().unwrap();
+        let span = self.func.span();
+
+        if let Some(only) = shared_ty.only_encoding() {
+            let this = only.rust;
+            let maybe_mut = self.self_mutability();
+            return quote! {
+                { let #maybe_mut this = #this; this.#call_fn }
+            };
+        }
+
+        if !self.writes_back_shared_self(types) {
+            let this = shared_ty.convert_ffi_expression_to_rust_type(
+                &quote! { this },
+                span,
+                swift_bridge_path,
+                types,
+            );
+            return quote! {
+                #this.#call_fn
+            };
+        }
+
+        let this = shared_ty.convert_ffi_expression_to_rust_type(
+            &quote! { unsafe { std::ptr::read(this) } },
+            span,
+            swift_bridge_path,
+            types,
+        );
+        let write_back = shared_ty.convert_rust_expression_to_ffi_type(
+            &quote! { this_val },
+            swift_bridge_path,
+            types,
+            span,
+        );
+        let maybe_mut = self.self_mutability();
+        quote! {
+            {
+                let #maybe_mut this_val = #this;
+                let val = this_val.#call_fn;
+                unsafe { std::ptr::write(this, #write_back) };
+                val
+            }
+        }
+    }
+
     /// Generate tokens for calling a freestanding or an associated function.
     fn call_function_tokens(&self, call_fn: &TokenStream) -> TokenStream {
-        let maybe_associated_type = self.associated_type.as_ref().map(|ty| {
-            match ty {
-                TypeDeclaration::Shared(_) => {
-                    //
-                    todo!()
-                }
-                TypeDeclaration::Opaque(ty) => {
-                    let ty = &ty.ty;
-                    quote! {#ty::}
+        match self.associated_type.as_ref() {
+            // Shared structs and enums are defined inside of the bridge module, unless they were
+            // already declared in another bridge module.
+            Some(TypeDeclaration::Shared(shared)) => {
+                let ty = shared.name();
+                if shared.already_declared() {
+                    quote! { super:: #ty :: #call_fn }
+                } else {
+                    quote! { #ty :: #call_fn }
                 }
             }
-        });
-
-        quote! {
-            super:: #maybe_associated_type #call_fn
+            Some(TypeDeclaration::Opaque(opaque)) => {
+                let ty = &opaque.ty;
+                quote! { super:: #ty :: #call_fn }
+            }
+            None => {
+                quote! { super:: #call_fn }
+            }
         }
     }
 
@@ -27,7 +27,10 @@ impl ParsedExternFn {
                                 quote! { this: #opaque_ty_ffi_repr }
                             }
                             TypeDeclaration::Shared(_) => {
-                                todo!("Methods on shared types are not yet supported.")
+                                match self.shared_type_self_param(swift_bridge_path, types) {
+                                    Some(this) => this,
+                                    None => continue,
+                                }
                             }
                         };
 
@@ -55,7 +58,13 @@ impl ParsedExternFn {
                             }
 
                             let pat = &pat_ty.pat;
-                            let ty = built_in.to_ffi_compatible_rust_type(swift_bridge_path, types);
+                            let ty = if self.host_lang.is_rust() && built_in.as_boxed_fn().is_some()
+                            {
+                                // Swift passes us a pointer to a retained Swift closure.
+                                quote! { *mut std::ffi::c_void }
+                            } else {
+                                built_in.to_ffi_compatible_rust_type(swift_bridge_path, types)
+                            };
 
                             params.push(quote! { #pat: #ty});
 
@@ -90,7 +99,12 @@ impl ParsedExternFn {
                         .unwrap()
                     {
                         TypeDeclaration::Shared(_) => {
-                            todo!("Support methods on shared types.")
+                            if let Some(this) =
+                                self.shared_type_self_param(swift_bridge_path, types)
+                            {
+                                params.push(this);
+                            }
+                            continue;
                         }
                         TypeDeclaration::Opaque(opaque) => opaque.ffi_repr_type_tokens(),
                     };
@@ -106,6 +120,32 @@ impl ParsedExternFn {
             #(#params),*
         }
     }
+
+    /// The `this` parameter of a method on a shared struct or enum.
+    ///
+    /// `self` and `&self` receive the FFI representation. `&mut self`, and `&self` on a type whose
+    /// FFI representation owns Rust values, receive a pointer to it so that `self` can be written
+    /// back.
+    /// A type that can be encoded with zero bytes isn't passed at all.
+    fn shared_type_self_param(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> Option<TokenStream> {
+        let shared_ty = self.associated_shared_type()?;
+        if shared_ty.can_be_encoded_with_zero_bytes() {
+            return None;
+        }
+
+        let ffi_ty = shared_ty.to_ffi_compatible_rust_type(swift_bridge_path, types);
+        let this = if self.writes_back_shared_self(types) {
+            quote! { this: *mut #ffi_ty }
+        } else {
+            quote! { this: #ffi_ty }
+        };
+
+        Some(this)
+    }
 }
 
 #[cfg(test)]
@@ -33,6 +33,28 @@ impl ParsedExternFn {
         &self,
         swift_bridge_path: &Path,
         types: &TypeDeclarations,
+    ) -> TokenStream {
+        self.rust_fn_that_calls_a_swift_extern(&quote! { pub }, swift_bridge_path, types)
+    }
+
+    /// Generates a method in an `impl SomeTrait for __swift_bridge__SomeTrait` block, which
+    /// calls a method on a Swift object that conforms to `SomeTrait`'s protocol.
+    ///
+    /// Trait methods can't have a visibility, so this is the same as
+    /// `to_rust_fn_that_calls_a_swift_extern` without the `pub`.
+    pub fn to_rust_trait_method_that_calls_a_swift_extern(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        self.rust_fn_that_calls_a_swift_extern(&quote! {}, swift_bridge_path, types)
+    }
+
+    fn rust_fn_that_calls_a_swift_extern(
+        &self,
+        vis: &TokenStream,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
     ) -> TokenStream {
         let sig = &self.func.sig;
         let fn_name = &sig.ident;
@@ -62,6 +84,7 @@ impl ParsedExternFn {
 
         if is_async {
             self.generate_async_rust_fn_that_calls_swift(
+                vis,
                 fn_name,
                 &ret,
                 &params,
@@ -88,7 +111,7 @@ impl ParsedExternFn {
                 );
 
                 quote! {
-                    pub fn #fn_name(#params) #ret {
+                    #vis fn #fn_name(#params) #ret {
                         #convert_result
                     }
                 }
@@ -109,7 +132,7 @@ impl ParsedExternFn {
                 }
 
                 quote! {
-                    pub fn #fn_name(#params) #ret {
+                    #vis fn #fn_name(#params) #ret {
                         #inner
                     }
                 }
@@ -126,6 +149,7 @@ impl ParsedExternFn {
     /// 4. Await the receiver and convert result to Rust type
     fn generate_async_rust_fn_that_calls_swift(
         &self,
+        vis: &TokenStream,
         fn_name: &Ident,
         ret: &TokenStream,
         params: &TokenStream,
@@ -205,7 +229,7 @@ impl ParsedExternFn {
                 };
 
             quote! {
-                pub async fn #fn_name(#params) #ret {
+                #vis async fn #fn_name(#params) #ret {
                     let (future, callback_wrapper) = #swift_bridge_path::async_swift_support::create_swift_async_call::<
                         std::result::Result<#rust_ok_ty, #rust_err_ty>
                     >();
@@ -304,7 +328,7 @@ impl ParsedExternFn {
                 };
 
             quote! {
-                pub async fn #fn_name(#params) #ret {
+                #vis async fn #fn_name(#params) #ret {
                     let (future, callback_wrapper) = #swift_bridge_path::async_swift_support::create_swift_async_call::<
                         #ffi_ty_for_channel
                     >();
@@ -327,6 +351,8 @@ impl ParsedExternFn {
         }
     }
 
+    /// When Rust passes a boxed function to Swift:
+    ///
     /// #\[export_name = "__swift_bridge__$SomeType$some_method$param1"]
     /// pub extern "C" fn SomeType_some_method_param1(boxed_fn: *mut dyn FnOnce(u8) -> (), arg0: u8) {
     ///     unsafe { Box::from_raw(boxed_fn) }(arg0)
@@ -335,37 +361,32 @@ impl ParsedExternFn {
     /// pub extern "C" fn free_SomeType_some_method_param1(boxed_fn: *mut dyn FnOnce(u8) -> ()) {
     ///     unsafe { Box::from_raw(boxed_fn) }
     /// }
+    ///
+    /// When Swift passes a closure to Rust:
+    ///
+    /// extern "C" {
+    ///     #\[link_name = "__swift_bridge__$SomeType$some_method$param1"]
+    ///     fn SomeType_some_method_param1(callback: *mut std::ffi::c_void, arg0: u8);
+    ///     #\[link_name = "__swift_bridge__$SomeType$some_method$_free$param1"]
+    ///     fn free_SomeType_some_method_param1(callback: *mut std::ffi::c_void);
+    /// }
     pub fn callbacks_support(
         &self,
         swift_bridge_path: &Path,
         types: &TypeDeclarations,
     ) -> TokenStream {
-        let sig = &self.func.sig;
-        let fn_name = &sig.ident;
+        let fn_name = &self.func.sig.ident;
 
         let mut boxed_fn_support = vec![];
         for (idx, boxed_fn) in self.args_filtered_to_boxed_fns(types) {
-            if boxed_fn.does_not_have_params_or_return() {
+            if self.host_lang.is_swift() && boxed_fn.uses_no_args_no_return_support() {
                 continue;
             }
 
-            let maybe_associated_ty = self
-                .associated_type
-                .as_ref()
-                .and_then(|t| t.as_opaque())
-                .map(|o| format!("{}_", o.ty.to_string()))
-                .unwrap_or("".to_string());
-
-            let boxed_fn_name = format!("{}{}_param{idx}", maybe_associated_ty, fn_name);
-            let boxed_fn_name = Ident::new(&boxed_fn_name, fn_name.span());
-
-            let boxed_fn_ffi_repr = boxed_fn.to_ffi_compatible_rust_type(types);
-
-            let free_boxed_fn_name = format!("free_{}{}_param{idx}", maybe_associated_ty, fn_name);
-            let free_boxed_fn_name = Ident::new(&free_boxed_fn_name, fn_name.span());
+            let boxed_fn_name = self.call_boxed_fn_ident(idx);
+            let free_boxed_fn_name = self.free_boxed_fn_ident(idx);
 
             let params = boxed_fn.params_to_ffi_compatible_rust_types(swift_bridge_path, types);
-            let call_args = boxed_fn.to_rust_call_args(swift_bridge_path, types);
 
             let call_boxed_fn_link_name = self.call_boxed_fn_link_name(idx);
             let free_boxed_fn_link_name = self.free_boxed_fn_link_name(idx);
@@ -390,9 +411,23 @@ impl ParsedExternFn {
             let arg_name = self.arg_name_tokens_at_idx(idx).unwrap();
             let arg_name = Ident::new(&format!("{}_{}", fn_name, arg_name), arg_name.span());
 
-            let call_boxed_fn = quote! {
-                unsafe { Box::from_raw(#arg_name)(#(#call_args),*) }
-            };
+            if self.host_lang.is_rust() {
+                boxed_fn_support.push(quote! {
+                    extern "C" {
+                        #[link_name = #call_boxed_fn_link_name]
+                        fn #boxed_fn_name(#arg_name: *mut std::ffi::c_void #maybe_params) #maybe_ret;
+
+                        #[link_name = #free_boxed_fn_link_name]
+                        fn #free_boxed_fn_name(#arg_name: *mut std::ffi::c_void);
+                    }
+                });
+                continue;
+            }
+
+            let boxed_fn_ffi_repr = boxed_fn.to_ffi_compatible_rust_type(types);
+            let call_args = boxed_fn.to_rust_call_args(swift_bridge_path, types);
+
+            let call_boxed_fn = boxed_fn.call_boxed_fn_pointer(&arg_name, &call_args);
             let call_boxed_fn = boxed_fn.ret.convert_rust_expression_to_ffi_type(
                 &call_boxed_fn,
                 swift_bridge_path,
@@ -50,12 +50,31 @@ impl ParsedExternFn {
                         todo!("Push to ParsedErrors")
                     };
 
+                    // The generated `@_cdecl` functions that Rust calls keep the Rust names.
+                    let param_name = if self.host_lang.is_rust() {
+                        self.swift_param_name(&arg_name)
+                    } else {
+                        arg_name.clone()
+                    };
+
+                    let maybe_default = self
+                        .argument_defaults
+                        .get(&format_ident!("{}", arg_name))
+                        .map(|default| format!(" = {}", default))
+                        .unwrap_or_default();
+
                     if let Some(argument_label) =
                         self.argument_labels.get(&format_ident!("{}", arg_name))
                     {
-                        format!("{} {}: {}", argument_label.value().as_str(), arg_name, ty)
+                        format!(
+                            "{} {}: {}{}",
+                            argument_label.value().as_str(),
+                            param_name,
+                            ty,
+                            maybe_default
+                        )
                     } else {
-                        format!("_ {}: {}", arg_name, ty)
+                        format!("_ {}: {}{}", param_name, ty, maybe_default)
                     }
                 }
             };
@@ -84,7 +103,7 @@ impl ParsedExternFn {
             match arg {
                 FnArg::Receiver(receiver) => {
                     if include_receiver_if_present {
-                        self.push_receiver_as_arg(&mut args, receiver.reference.is_some());
+                        self.push_receiver_as_arg(&mut args, receiver.reference.is_some(), types);
                     }
                 }
                 FnArg::Typed(pat_ty) => {
@@ -95,15 +114,19 @@ impl ParsedExternFn {
 
                     if pat_type_pat_is_self(pat_ty) {
                         if include_receiver_if_present {
-                            self.push_receiver_as_arg(&mut args, is_reference);
+                            self.push_receiver_as_arg(&mut args, is_reference, types);
                         }
 
                         continue;
                     }
 
                     let pat = &pat_ty.pat;
-                    let arg = pat.to_token_stream().to_string();
-                    let arg_name = arg.clone();
+                    let arg_name = pat.to_token_stream().to_string();
+                    let arg = if self.host_lang.is_rust() {
+                        self.swift_param_name(&arg_name)
+                    } else {
+                        arg_name.clone()
+                    };
 
                     let arg =
                         if let Some(bridged_ty) = BridgedType::new_with_type(&pat_ty.ty, types) {
@@ -146,7 +169,7 @@ impl ParsedExternFn {
                             }
                         } else {
                             // Default: use parameter name as label
-                            format!("{}: {}", arg_name, arg)
+                            format!("{}: {}", self.swift_param_name(&arg_name), arg)
                         }
                     } else {
                         arg
@@ -192,7 +215,31 @@ impl ParsedExternFn {
         }
     }
 
-    fn push_receiver_as_arg(&self, args: &mut Vec<String>, is_reference: bool) {
+    fn push_receiver_as_arg(
+        &self,
+        args: &mut Vec<String>,
+        is_reference: bool,
+        types: &TypeDeclarations,
+    ) {
+        if let Some(shared_ty) = self.associated_shared_type() {
+            if shared_ty.can_be_encoded_with_zero_bytes() {
+                return;
+            }
+
+            // Methods that write back `self` declare a `var this` that Rust writes the value to.
+            let arg = if self.writes_back_shared_self(types) {
+                "&this".to_string()
+            } else {
+                shared_ty.convert_swift_expression_to_ffi_type(
+                    "self",
+                    types,
+                    TypePosition::FnArg(self.host_lang, 0),
+                )
+            };
+            args.push(arg);
+            return;
+        }
+
         let arg = if self.is_copy_method_on_opaque_type() {
             "self.bytes"
         } else {
@@ -15,6 +15,14 @@ pub fn bridge(
             SwiftBridgeModuleAttr::SwiftBridgePath(path) => {
                 module.set_swift_bridge_path(path);
             }
+            SwiftBridgeModuleAttr::SwiftNaming(naming) => {
+                if let Err(error) = module.set_swift_naming(naming) {
+                    return error.to_compile_error().into();
+                }
+            }
+            SwiftBridgeModuleAttr::OptionalArgsDefaultNone => {
+                module.set_optional_args_default_none();
+            }
         }
     }
 
@@ -0,0 +1,15 @@
+//! # To Run
+//! cargo test -p swift-bridge-macro -- ui trybuild=boxed-fn-not-fn-arg.rs
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn some_function() -> Box<dyn Fn(u8)>;
+    }
+}
+
+fn some_function() -> Box<dyn Fn(u8)> {
+    Box::new(|_| {})
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+//! # To Run
+//! cargo test -p swift-bridge-macro -- ui trybuild=map-key-not-hashable.rs
+
+use std::collections::HashMap;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type SomeType;
+
+        fn some_function(arg: HashMap<SomeType, u8>);
+    }
+}
+
+#[derive(PartialEq, Eq, Hash)]
+pub struct SomeType;
+
+fn some_function(_arg: HashMap<SomeType, u8>) {}
+
+fn main() {}
@@ -0,0 +1,28 @@
+//! # To Run
+//! cargo test -p swift-bridge-macro -- ui trybuild=unsupported-trait-object.rs
+
+#[swift_bridge::bridge]
+mod ffi {
+    trait Animal {
+        fn name(&self) -> String;
+        fn on_speak(&self, callback: Box<dyn Fn(String)>);
+    }
+
+    extern "Rust" {
+        fn some_function() -> Box<dyn Animal>;
+        fn another_function(arg: Option<Box<dyn Animal>>);
+    }
+}
+
+trait Animal {
+    fn name(&self) -> String;
+    fn on_speak(&self, callback: Box<dyn Fn(String)>);
+}
+
+fn some_function() -> Box<dyn Animal> {
+    unimplemented!()
+}
+
+fn another_function(_arg: Option<Box<dyn Animal>>) {}
+
+fn main() {}
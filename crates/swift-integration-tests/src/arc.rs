@@ -0,0 +1,86 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type ArcSessionCache;
+
+        #[swift_bridge(init)]
+        fn new() -> ArcSessionCache;
+
+        fn session(&self) -> Arc<ArcSession>;
+        fn session_strong_count(&self) -> usize;
+        fn is_cached_session(&self, session: Arc<ArcSession>) -> bool;
+        fn maybe_session(&self, return_some: bool) -> Option<Arc<ArcSession>>;
+    }
+
+    extern "Rust" {
+        type ArcSession;
+
+        fn increment_requests(&self);
+        fn requests(&self) -> u32;
+    }
+
+    extern "Rust" {
+        fn rust_reflect_arc_session(arg: Arc<ArcSession>) -> Arc<ArcSession>;
+        fn rust_reflect_option_arc_session(arg: Option<Arc<ArcSession>>)
+            -> Option<Arc<ArcSession>>;
+    }
+}
+
+pub struct ArcSessionCache {
+    session: Arc<ArcSession>,
+}
+
+pub struct ArcSession {
+    requests: AtomicU32,
+}
+
+impl ArcSessionCache {
+    fn new() -> Self {
+        ArcSessionCache {
+            session: Arc::new(ArcSession {
+                requests: AtomicU32::new(0),
+            }),
+        }
+    }
+
+    fn session(&self) -> Arc<ArcSession> {
+        self.session.clone()
+    }
+
+    fn session_strong_count(&self) -> usize {
+        Arc::strong_count(&self.session)
+    }
+
+    fn is_cached_session(&self, session: Arc<ArcSession>) -> bool {
+        Arc::ptr_eq(&self.session, &session)
+    }
+
+    fn maybe_session(&self, return_some: bool) -> Option<Arc<ArcSession>> {
+        if return_some {
+            Some(self.session.clone())
+        } else {
+            None
+        }
+    }
+}
+
+impl ArcSession {
+    fn increment_requests(&self) {
+        self.requests.fetch_add(1, Ordering::SeqCst);
+    }
+
+    fn requests(&self) -> u32 {
+        self.requests.load(Ordering::SeqCst)
+    }
+}
+
+fn rust_reflect_arc_session(arg: Arc<ArcSession>) -> Arc<ArcSession> {
+    arg
+}
+
+fn rust_reflect_option_arc_session(arg: Option<Arc<ArcSession>>) -> Option<Arc<ArcSession>> {
+    arg
+}
@@ -0,0 +1,38 @@
+//! See also: crates/swift-bridge-ir/src/codegen/codegen_tests/argument_default.rs
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn test_argument_default(
+            value: u32,
+            #[swift_bridge(label = "times", default = "2")] multiplier: u32,
+            #[swift_bridge(label = "plus", default = "-1")] offset: i32,
+        ) -> i64;
+
+        fn test_argument_default_none(#[swift_bridge(default = None)] value: Option<u32>) -> u32;
+    }
+}
+
+#[swift_bridge::bridge(optional_args_default = None)]
+mod ffi_optional_args_default {
+    extern "Rust" {
+        fn test_optional_args_default(
+            value: u32,
+            minimum: Option<u32>,
+            #[swift_bridge(default = "100")] maximum: Option<u32>,
+        ) -> u32;
+    }
+}
+
+fn test_argument_default(value: u32, multiplier: u32, offset: i32) -> i64 {
+    (value * multiplier) as i64 + offset as i64
+}
+
+fn test_argument_default_none(value: Option<u32>) -> u32 {
+    value.unwrap_or(0)
+}
+
+fn test_optional_args_default(value: u32, minimum: Option<u32>, maximum: Option<u32>) -> u32 {
+    let value = value.max(minimum.unwrap_or(0));
+    maximum.map(|maximum| value.min(maximum)).unwrap_or(value)
+}
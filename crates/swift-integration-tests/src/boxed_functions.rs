@@ -34,6 +34,34 @@ mod ffi {
         ) -> u16;
     }
 
+    extern "Swift" {
+        fn swift_calls_rust_fn_callback_three_times(callback: Box<dyn Fn(u8) -> u8>) -> u8;
+        fn swift_calls_rust_fnmut_callback(callback: Box<dyn FnMut(u32)>, times: u32);
+        fn swift_stores_rust_fn_callback(callback: Box<dyn Fn()>);
+        fn swift_releases_stored_rust_fn_callback();
+        fn swift_calls_sendable_rust_callback_concurrently(
+            callback: Box<dyn Fn(u32) + Send + Sync>,
+            times: u32,
+        );
+    }
+
+    extern "Rust" {
+        fn rust_calls_swift_fn_callback_twice(callback: Box<dyn Fn(u8) -> u8>) -> u8;
+        fn rust_calls_sendable_swift_callback_on_another_thread(
+            callback: Box<dyn Fn(u32) -> u32 + Send + Sync>,
+        ) -> u32;
+    }
+
+    extern "Rust" {
+        type CallbackTestEventEmitter;
+
+        #[swift_bridge(init)]
+        fn new() -> CallbackTestEventEmitter;
+        fn set_listener(&mut self, listener: Box<dyn FnMut(u32) + Send>);
+        fn emit(&mut self, value: u32);
+        fn remove_listener(&mut self);
+    }
+
     // TODO
     // extern "Rust" {
     //     fn rust_takes_callback_fnonce_no_args_no_return(arg: Box<dyn FnOnce() -> ()>);
@@ -91,6 +119,39 @@ mod ffi {
 //     (arg)(123, CallbackTestOpaqueRustType { val: 222 })
 // }
 
+fn rust_calls_swift_fn_callback_twice(callback: Box<dyn Fn(u8) -> u8>) -> u8 {
+    callback(1) + callback(2)
+}
+
+fn rust_calls_sendable_swift_callback_on_another_thread(
+    callback: Box<dyn Fn(u32) -> u32 + Send + Sync>,
+) -> u32 {
+    std::thread::spawn(move || callback(21)).join().unwrap()
+}
+
+pub struct CallbackTestEventEmitter {
+    listener: Option<Box<dyn FnMut(u32) + Send>>,
+}
+impl CallbackTestEventEmitter {
+    fn new() -> Self {
+        Self { listener: None }
+    }
+
+    fn set_listener(&mut self, listener: Box<dyn FnMut(u32) + Send>) {
+        self.listener = Some(listener);
+    }
+
+    fn emit(&mut self, value: u32) {
+        if let Some(listener) = self.listener.as_mut() {
+            listener(value);
+        }
+    }
+
+    fn remove_listener(&mut self) {
+        self.listener = None;
+    }
+}
+
 pub struct CallbackTestOpaqueRustType {
     val: u32,
 }
@@ -136,4 +197,48 @@ fn test_callbacks_rust_calls_swift() {
     ffi::swift_func_takes_callback_with_result_arg(Box::new(|result| {
         assert_eq!(result.unwrap().val(), 555)
     }));
+
+    test_multi_call_callbacks_rust_calls_swift();
+}
+
+fn test_multi_call_callbacks_rust_calls_swift() {
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    let sum = ffi::swift_calls_rust_fn_callback_three_times(Box::new(|num| num * 2));
+    assert_eq!(sum, 2 + 4 + 6);
+
+    let calls = Arc::new(Mutex::new(vec![]));
+    let calls_clone = calls.clone();
+    let mut call_idx = 0;
+    ffi::swift_calls_rust_fnmut_callback(
+        Box::new(move |num| {
+            call_idx += 1;
+            calls_clone.lock().unwrap().push((call_idx, num));
+        }),
+        3,
+    );
+    assert_eq!(*calls.lock().unwrap(), vec![(1, 0), (2, 1), (3, 2)]);
+
+    // The boxed function is freed once Swift releases it.
+    let call_count = Arc::new(AtomicU32::new(0));
+    let call_count_clone = call_count.clone();
+    ffi::swift_stores_rust_fn_callback(Box::new(move || {
+        call_count_clone.fetch_add(1, Ordering::SeqCst);
+    }));
+    assert_eq!(call_count.load(Ordering::SeqCst), 1);
+    assert_eq!(Arc::strong_count(&call_count), 2);
+    ffi::swift_releases_stored_rust_fn_callback();
+    assert_eq!(Arc::strong_count(&call_count), 1);
+
+    let total = Arc::new(AtomicU32::new(0));
+    let total_clone = total.clone();
+    ffi::swift_calls_sendable_rust_callback_concurrently(
+        Box::new(move |num| {
+            total_clone.fetch_add(num, Ordering::SeqCst);
+        }),
+        10,
+    );
+    assert_eq!(total.load(Ordering::SeqCst), 10);
+    assert_eq!(Arc::strong_count(&total), 1);
 }
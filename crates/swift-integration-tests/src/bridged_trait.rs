@@ -0,0 +1,103 @@
+#[swift_bridge::bridge]
+mod ffi {
+    trait Animal {
+        fn name(&self) -> String;
+        fn speak(&self, times: u8) -> String;
+    }
+
+    impl Animal for RustDog {}
+
+    extern "Rust" {
+        type RustDog;
+
+        #[swift_bridge(init)]
+        fn new(name: String) -> RustDog;
+    }
+
+    extern "Rust" {
+        fn rust_animal_name(animal: &dyn Animal) -> String;
+        fn rust_animal_speak(animal: Box<dyn Animal>, times: u8) -> String;
+    }
+
+    trait Storage: Send + Sync {
+        fn get(&self, key: String) -> Option<String>;
+        fn set(&self, key: String, value: String);
+    }
+
+    trait Clock: Send + Sync {
+        fn now_millis(&self) -> u64;
+    }
+
+    extern "Rust" {
+        fn rust_storage_set_on_another_thread(
+            storage: Arc<dyn Storage + Send + Sync>,
+            key: String,
+            value: String,
+        ) -> Option<String>;
+        fn rust_clock_elapsed_millis(clock: Box<dyn Clock + Send>, since_millis: u64) -> u64;
+    }
+}
+
+use std::sync::Arc;
+
+trait Animal {
+    fn name(&self) -> String;
+    fn speak(&self, times: u8) -> String;
+}
+
+pub struct RustDog {
+    name: String,
+}
+
+impl RustDog {
+    fn new(name: String) -> Self {
+        RustDog { name }
+    }
+}
+
+impl Animal for RustDog {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn speak(&self, times: u8) -> String {
+        vec!["woof"; times as usize].join(" ")
+    }
+}
+
+fn rust_animal_name(animal: &dyn Animal) -> String {
+    animal.name()
+}
+
+fn rust_animal_speak(animal: Box<dyn Animal>, times: u8) -> String {
+    format!("{} says {}", animal.name(), animal.speak(times))
+}
+
+trait Storage: Send + Sync {
+    fn get(&self, key: String) -> Option<String>;
+    fn set(&self, key: String, value: String);
+}
+
+trait Clock: Send + Sync {
+    fn now_millis(&self) -> u64;
+}
+
+fn rust_storage_set_on_another_thread(
+    storage: Arc<dyn Storage + Send + Sync>,
+    key: String,
+    value: String,
+) -> Option<String> {
+    let thread_storage = storage.clone();
+    let thread_key = key.clone();
+    std::thread::spawn(move || thread_storage.set(thread_key, value))
+        .join()
+        .unwrap();
+
+    storage.get(key)
+}
+
+fn rust_clock_elapsed_millis(clock: Box<dyn Clock + Send>, since_millis: u64) -> u64 {
+    std::thread::spawn(move || clock.now_millis() - since_millis)
+        .join()
+        .unwrap()
+}
@@ -0,0 +1,42 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_reflect_char(arg: char) -> char;
+        fn rust_reflect_option_char(arg: Option<char>) -> Option<char>;
+        fn rust_string_to_vec_of_chars(string: &str) -> Vec<char>;
+        fn rust_vec_of_chars_to_string(chars: Vec<char>) -> String;
+    }
+
+    extern "Swift" {
+        fn swift_reflect_char(arg: char) -> char;
+        fn swift_reflect_option_char(arg: Option<char>) -> Option<char>;
+    }
+
+    extern "Rust" {
+        fn test_rust_calls_swift_char();
+    }
+}
+
+fn rust_reflect_char(arg: char) -> char {
+    arg
+}
+
+fn rust_reflect_option_char(arg: Option<char>) -> Option<char> {
+    arg
+}
+
+fn rust_string_to_vec_of_chars(string: &str) -> Vec<char> {
+    string.chars().collect()
+}
+
+fn rust_vec_of_chars_to_string(chars: Vec<char>) -> String {
+    chars.into_iter().collect()
+}
+
+fn test_rust_calls_swift_char() {
+    assert_eq!(ffi::swift_reflect_char('a'), 'a');
+    assert_eq!(ffi::swift_reflect_char('🦀'), '🦀');
+
+    assert_eq!(ffi::swift_reflect_option_char(Some('é')), Some('é'));
+    assert_eq!(ffi::swift_reflect_option_char(None), None);
+}
@@ -0,0 +1,52 @@
+//! See also: crates/swift-bridge-ir/src/codegen/codegen_tests/codable.rs
+
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct", Codable)]
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct CodableStruct {
+        #[serde(rename = "displayName")]
+        name: String,
+        count: u32,
+        nickname: Option<String>,
+    }
+
+    #[swift_bridge(Codable)]
+    #[derive(serde::Serialize, serde::Deserialize)]
+    enum CodableEnum {
+        #[serde(rename = "none")]
+        Empty,
+        Count(u32),
+        Pair(u8, String),
+        Moved {
+            #[serde(rename = "dx")]
+            x: i32,
+            y: i32,
+        },
+    }
+
+    extern "Rust" {
+        fn codable_struct_to_json(arg: CodableStruct) -> String;
+        fn codable_struct_from_json(json: &str) -> CodableStruct;
+        fn codable_enum_to_json(arg: CodableEnum) -> String;
+        fn codable_enum_from_json(json: &str) -> CodableEnum;
+    }
+}
+
+use ffi::{CodableEnum, CodableStruct};
+
+fn codable_struct_to_json(arg: CodableStruct) -> String {
+    serde_json::to_string(&arg).unwrap()
+}
+
+fn codable_struct_from_json(json: &str) -> CodableStruct {
+    serde_json::from_str(json).unwrap()
+}
+
+fn codable_enum_to_json(arg: CodableEnum) -> String {
+    serde_json::to_string(&arg).unwrap()
+}
+
+fn codable_enum_from_json(json: &str) -> CodableEnum {
+    serde_json::from_str(json).unwrap()
+}
@@ -1,3 +1,5 @@
 mod already_declared;
 mod derive;
+mod discriminant;
+mod raw_representable;
 mod swift_name;
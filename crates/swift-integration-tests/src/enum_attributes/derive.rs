@@ -4,4 +4,61 @@ mod ffi {
     enum DeriveDebugEnum {
         Variant,
     }
+
+    #[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    enum DeriveComparableEnum {
+        Low,
+        High,
+    }
+
+    #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+    enum DeriveComparableEnumWithDiscriminants {
+        High = 3,
+        Low = 1,
+    }
+
+    #[derive(Clone, PartialEq, Eq, Hash)]
+    enum DeriveHashableEnumWithData {
+        Named { name: String },
+        Number(i32),
+        Empty,
+    }
+
+    extern "Rust" {
+        fn reflect_derive_comparable_enum(arg: DeriveComparableEnum) -> DeriveComparableEnum;
+        fn reflect_derive_comparable_enum_with_discriminants(
+            arg: DeriveComparableEnumWithDiscriminants,
+        ) -> DeriveComparableEnumWithDiscriminants;
+        fn reflect_derive_hashable_enum_with_data(
+            arg: DeriveHashableEnumWithData,
+        ) -> DeriveHashableEnumWithData;
+    }
+}
+
+use ffi::{
+    DeriveComparableEnum, DeriveComparableEnumWithDiscriminants, DeriveHashableEnumWithData,
+};
+
+fn reflect_derive_comparable_enum(arg: DeriveComparableEnum) -> DeriveComparableEnum {
+    // Verify that the Rust derives work.
+    assert!(DeriveComparableEnum::Low < DeriveComparableEnum::High);
+    arg
+}
+
+fn reflect_derive_comparable_enum_with_discriminants(
+    arg: DeriveComparableEnumWithDiscriminants,
+) -> DeriveComparableEnumWithDiscriminants {
+    // Rust orders the variants by their discriminants, not by the order that they're declared in.
+    assert!(
+        DeriveComparableEnumWithDiscriminants::Low < DeriveComparableEnumWithDiscriminants::High
+    );
+    arg
+}
+
+fn reflect_derive_hashable_enum_with_data(
+    arg: DeriveHashableEnumWithData,
+) -> DeriveHashableEnumWithData {
+    // Verify that the Rust derives work.
+    assert!(arg.clone() == arg);
+    arg
 }
@@ -0,0 +1,36 @@
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/enum_discriminant.rs
+#[swift_bridge::bridge]
+mod ffi {
+    #[repr(u16)]
+    enum WireMessageKind {
+        Ping = 1,
+        Pong,
+        Error = 404,
+    }
+
+    #[repr(i32)]
+    enum WireMessage {
+        Data(u8) = 10,
+        Close,
+    }
+
+    extern "Rust" {
+        fn reflect_wire_message_kind(arg: WireMessageKind) -> WireMessageKind;
+        fn wire_message_kind_discriminant(arg: WireMessageKind) -> u16;
+        fn reflect_wire_message(arg: WireMessage) -> WireMessage;
+    }
+}
+
+use ffi::{WireMessage, WireMessageKind};
+
+fn reflect_wire_message_kind(arg: WireMessageKind) -> WireMessageKind {
+    arg
+}
+
+fn wire_message_kind_discriminant(arg: WireMessageKind) -> u16 {
+    arg as u16
+}
+
+fn reflect_wire_message(arg: WireMessage) -> WireMessage {
+    arg
+}
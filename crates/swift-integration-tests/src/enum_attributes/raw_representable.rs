@@ -0,0 +1,35 @@
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/case_iterable_and_raw_representable.rs
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(CaseIterable, RawRepresentable = "Int")]
+    #[repr(u16)]
+    enum HttpStatusCode {
+        Ok = 200,
+        Created,
+        NotFound = 404,
+    }
+
+    #[swift_bridge(CaseIterable, RawRepresentable = "String")]
+    enum StringRawValueEnum {
+        #[swift_bridge(rename = "first_variant")]
+        FirstVariant,
+        SecondVariant,
+    }
+
+    extern "Rust" {
+        fn reflect_http_status_code(arg: HttpStatusCode) -> HttpStatusCode;
+        fn reflect_string_raw_value_enum(arg: StringRawValueEnum) -> StringRawValueEnum;
+    }
+}
+
+use ffi::{HttpStatusCode, StringRawValueEnum};
+
+fn reflect_http_status_code(arg: HttpStatusCode) -> HttpStatusCode {
+    // Verify that the Rust enum keeps its `#[repr]` and discriminants.
+    assert_eq!(HttpStatusCode::Created as u16, 201);
+    arg
+}
+
+fn reflect_string_raw_value_enum(arg: StringRawValueEnum) -> StringRawValueEnum {
+    arg
+}
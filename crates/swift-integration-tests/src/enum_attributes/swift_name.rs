@@ -11,8 +11,21 @@ mod ffi {
         Variant2,
     }
 
+    enum EnumWithRenamedVariants {
+        #[swift_bridge(swift_name = "circle")]
+        Circle {
+            #[swift_bridge(swift_name = "radiusInMeters")]
+            radius: f64,
+        },
+        #[swift_bridge(swift_name = "empty")]
+        Nothing,
+    }
+
     extern "Rust" {
         fn extern_rust_enum_rename(arg: EnumName) -> EnumName;
+        fn double_enum_with_renamed_variants(
+            arg: EnumWithRenamedVariants,
+        ) -> EnumWithRenamedVariants;
     }
 
     extern "Swift" {
@@ -20,8 +33,17 @@ mod ffi {
     }
 }
 
-use ffi::EnumName;
+use ffi::{EnumName, EnumWithRenamedVariants};
 
 fn extern_rust_enum_rename(arg: EnumName) -> EnumName {
     arg
 }
+
+fn double_enum_with_renamed_variants(arg: EnumWithRenamedVariants) -> EnumWithRenamedVariants {
+    match arg {
+        EnumWithRenamedVariants::Circle { radius } => EnumWithRenamedVariants::Circle {
+            radius: radius * 2.,
+        },
+        EnumWithRenamedVariants::Nothing => EnumWithRenamedVariants::Nothing,
+    }
+}
@@ -0,0 +1,61 @@
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct")]
+    struct FixedSizeArrayStruct {
+        rgba: [u8; 4],
+        matrix: [[f32; 2]; 2],
+    }
+
+    extern "Rust" {
+        fn rust_reflect_array_u8(arg: [u8; 32]) -> [u8; 32];
+        fn rust_reflect_array_of_arrays(arg: [[f32; 4]; 4]) -> [[f32; 4]; 4];
+        fn rust_reflect_option_array(arg: Option<[u8; 4]>) -> Option<[u8; 4]>;
+        fn rust_reflect_vec_of_arrays(arg: Vec<[u8; 4]>) -> Vec<[u8; 4]>;
+        fn rust_reflect_fixed_size_array_struct(arg: FixedSizeArrayStruct) -> FixedSizeArrayStruct;
+    }
+
+    extern "Swift" {
+        fn swift_reflect_array_u8(arg: [u8; 32]) -> [u8; 32];
+        fn swift_reflect_option_array(arg: Option<[u8; 4]>) -> Option<[u8; 4]>;
+    }
+
+    extern "Rust" {
+        fn test_rust_calls_swift_fixed_size_array();
+    }
+}
+
+fn rust_reflect_array_u8(arg: [u8; 32]) -> [u8; 32] {
+    arg
+}
+
+fn rust_reflect_array_of_arrays(arg: [[f32; 4]; 4]) -> [[f32; 4]; 4] {
+    arg
+}
+
+fn rust_reflect_option_array(arg: Option<[u8; 4]>) -> Option<[u8; 4]> {
+    arg
+}
+
+fn rust_reflect_vec_of_arrays(arg: Vec<[u8; 4]>) -> Vec<[u8; 4]> {
+    arg
+}
+
+fn rust_reflect_fixed_size_array_struct(
+    arg: ffi::FixedSizeArrayStruct,
+) -> ffi::FixedSizeArrayStruct {
+    arg
+}
+
+fn test_rust_calls_swift_fixed_size_array() {
+    let mut hash = [0; 32];
+    for (idx, byte) in hash.iter_mut().enumerate() {
+        *byte = idx as u8;
+    }
+    assert_eq!(ffi::swift_reflect_array_u8(hash), hash);
+
+    assert_eq!(
+        ffi::swift_reflect_option_array(Some([1, 2, 3, 4])),
+        Some([1, 2, 3, 4])
+    );
+    assert_eq!(ffi::swift_reflect_option_array(None), None);
+}
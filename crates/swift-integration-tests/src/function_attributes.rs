@@ -2,6 +2,7 @@ mod args_into;
 mod get;
 mod get_with;
 mod identifiable;
+mod instantiate;
 mod return_into;
 mod return_with;
 mod rust_name;
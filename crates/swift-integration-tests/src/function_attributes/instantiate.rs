@@ -0,0 +1,52 @@
+//! See `crates/swift-bridge-ir/src/codegen/codegen_tests/generic_function.rs` for codegen tests.
+
+use std::str::FromStr;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type GenericFunctionCounter;
+
+        #[swift_bridge(init)]
+        fn new() -> GenericFunctionCounter;
+
+        #[swift_bridge(instantiate(T = u32), instantiate(T = String))]
+        fn rust_parse<T>(s: &str) -> T;
+
+        #[swift_bridge(instantiate(T = u8), instantiate(T = i64))]
+        fn rust_first_or_default<T>(items: Vec<T>) -> T;
+
+        #[swift_bridge(instantiate(T = u16), instantiate(T = bool))]
+        fn increment_and_reflect<T>(&mut self, arg: T) -> T;
+
+        fn count(&self) -> u32;
+    }
+}
+
+pub struct GenericFunctionCounter(u32);
+
+impl GenericFunctionCounter {
+    fn new() -> Self {
+        GenericFunctionCounter(0)
+    }
+
+    fn increment_and_reflect<T>(&mut self, arg: T) -> T {
+        self.0 += 1;
+        arg
+    }
+
+    fn count(&self) -> u32 {
+        self.0
+    }
+}
+
+fn rust_parse<T: FromStr>(s: &str) -> T {
+    match s.parse() {
+        Ok(val) => val,
+        Err(_) => panic!("Could not parse {}", s),
+    }
+}
+
+fn rust_first_or_default<T: Default + Copy>(items: Vec<T>) -> T {
+    items.first().copied().unwrap_or_default()
+}
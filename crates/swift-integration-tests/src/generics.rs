@@ -29,6 +29,29 @@ mod ffi {
             arg: GenericWithOpaqueRustInnerTy<InnerTy>,
         ) -> GenericWithOpaqueRustInnerTy<InnerTy>;
     }
+
+    extern "Rust" {
+        #[swift_bridge(declare_generic)]
+        type GenericWithNonPrimitiveTys<A, B>;
+        type GenericWithNonPrimitiveTys<String, InnerTy>;
+        #[swift_bridge(instantiate(A = Vec<u8>, B = Option<u32>))]
+        type GenericWithNonPrimitiveTys<A, B>;
+
+        fn new_generic_with_string_and_opaque_tys() -> GenericWithNonPrimitiveTys<String, InnerTy>;
+        fn new_generic_with_vec_and_option_tys() -> GenericWithNonPrimitiveTys<Vec<u8>, Option<u32>>;
+        fn generic_with_vec_and_option_tys_len(
+            arg: &GenericWithNonPrimitiveTys<Vec<u8>, Option<u32>>,
+        ) -> usize;
+    }
+
+    extern "Rust" {
+        #[swift_bridge(declare_generic)]
+        type GenericWithManyTys<A, B, C, D, E, F, G, H, I>;
+        type GenericWithManyTys<u8, u16, u32, u64, i8, i16, i32, i64, bool>;
+
+        fn new_generic_with_many_tys(
+        ) -> GenericWithManyTys<u8, u16, u32, u64, i8, i16, i32, i64, bool>;
+    }
 }
 
 pub struct SomeGenericType<T> {
@@ -48,6 +71,17 @@ pub struct GenericWithOpaqueRustInnerTy<T> {
 }
 pub struct InnerTy;
 
+pub struct GenericWithNonPrimitiveTys<A, B> {
+    first: A,
+    #[allow(unused)]
+    second: B,
+}
+
+pub struct GenericWithManyTys<A, B, C, D, E, F, G, H, I> {
+    #[allow(unused)]
+    fields: (A, B, C, D, E, F, G, H, I),
+}
+
 fn new_some_generic_type_u32() -> SomeGenericType<u32> {
     SomeGenericType { field: 123 }
 }
@@ -71,3 +105,27 @@ fn reflect_generic_with_inner_opaque_type(
 ) -> GenericWithOpaqueRustInnerTy<InnerTy> {
     arg
 }
+
+fn new_generic_with_string_and_opaque_tys() -> GenericWithNonPrimitiveTys<String, InnerTy> {
+    GenericWithNonPrimitiveTys {
+        first: "hello".to_string(),
+        second: InnerTy,
+    }
+}
+fn new_generic_with_vec_and_option_tys() -> GenericWithNonPrimitiveTys<Vec<u8>, Option<u32>> {
+    GenericWithNonPrimitiveTys {
+        first: vec![1, 2, 3],
+        second: Some(4),
+    }
+}
+fn generic_with_vec_and_option_tys_len(
+    arg: &GenericWithNonPrimitiveTys<Vec<u8>, Option<u32>>,
+) -> usize {
+    arg.first.len()
+}
+
+fn new_generic_with_many_tys() -> GenericWithManyTys<u8, u16, u32, u64, i8, i16, i32, i64, bool> {
+    GenericWithManyTys {
+        fields: (1, 2, 3, 4, 5, 6, 7, 8, true),
+    }
+}
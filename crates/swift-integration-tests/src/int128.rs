@@ -0,0 +1,60 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_reflect_i128(arg: i128) -> i128;
+        fn rust_reflect_u128(arg: u128) -> u128;
+        fn rust_reflect_option_i128(arg: Option<i128>) -> Option<i128>;
+        fn rust_reflect_option_u128(arg: Option<u128>) -> Option<u128>;
+        fn rust_reflect_vec_i128(arg: Vec<i128>) -> Vec<i128>;
+        fn rust_u128_max() -> u128;
+        fn rust_i128_min() -> i128;
+    }
+
+    extern "Swift" {
+        fn swift_reflect_i128(arg: i128) -> i128;
+        fn swift_reflect_u128(arg: u128) -> u128;
+        fn swift_reflect_option_u128(arg: Option<u128>) -> Option<u128>;
+    }
+
+    extern "Rust" {
+        fn test_rust_calls_swift_int128();
+    }
+}
+
+fn rust_reflect_i128(arg: i128) -> i128 {
+    arg
+}
+
+fn rust_reflect_u128(arg: u128) -> u128 {
+    arg
+}
+
+fn rust_reflect_option_i128(arg: Option<i128>) -> Option<i128> {
+    arg
+}
+
+fn rust_reflect_option_u128(arg: Option<u128>) -> Option<u128> {
+    arg
+}
+
+fn rust_reflect_vec_i128(arg: Vec<i128>) -> Vec<i128> {
+    arg
+}
+
+fn rust_u128_max() -> u128 {
+    u128::MAX
+}
+
+fn rust_i128_min() -> i128 {
+    i128::MIN
+}
+
+fn test_rust_calls_swift_int128() {
+    assert_eq!(ffi::swift_reflect_i128(i128::MIN), i128::MIN);
+    assert_eq!(ffi::swift_reflect_i128(-1), -1);
+    assert_eq!(ffi::swift_reflect_u128(u128::MAX), u128::MAX);
+    assert_eq!(ffi::swift_reflect_u128(1 << 64), 1 << 64);
+
+    assert_eq!(ffi::swift_reflect_option_u128(Some(123)), Some(123));
+    assert_eq!(ffi::swift_reflect_option_u128(None), None);
+}
@@ -1,21 +1,31 @@
 mod expose_opaque_rust_type;
 mod import_opaque_swift_class;
 
+mod arc;
 mod async_function;
 mod boxed_functions;
+mod bridged_trait;
+mod char;
+mod codable;
 mod conditional_compilation;
+mod fixed_size_array;
 mod generics;
+mod int128;
+mod map;
+mod nested_containers;
 mod option;
 mod pointer;
 mod primitive;
 mod result;
 mod rust_function_uses_opaque_swift_type;
+mod set;
 mod shared_types;
 mod single_representation_type_elision;
 mod slice;
 mod string;
 mod swift_function_uses_opaque_rust_type;
 mod swift_function_uses_opaque_swift_type;
+mod swift_naming;
 mod tuple;
 mod vec;
 
@@ -0,0 +1,102 @@
+use std::collections::{BTreeMap, HashMap};
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type MapTestOpaqueRustType;
+        #[swift_bridge(init)]
+        fn new(val: u32) -> MapTestOpaqueRustType;
+        fn val(&self) -> u32;
+    }
+
+    extern "Rust" {
+        #[swift_bridge(Equatable, Hashable)]
+        type MapTestHashableRustKey;
+        #[swift_bridge(init)]
+        fn new(val: u32) -> MapTestHashableRustKey;
+    }
+
+    extern "Rust" {
+        fn rust_reflect_hashmap_primitives(arg: HashMap<u16, i64>) -> HashMap<u16, i64>;
+        fn rust_reflect_hashmap_string_keys(arg: HashMap<String, u64>) -> HashMap<String, u64>;
+        fn rust_reflect_btreemap_opaque_rust_values(
+            arg: BTreeMap<u8, MapTestOpaqueRustType>,
+        ) -> BTreeMap<u8, MapTestOpaqueRustType>;
+        fn rust_reflect_hashmap_opaque_rust_keys(
+            arg: HashMap<MapTestHashableRustKey, u8>,
+        ) -> HashMap<MapTestHashableRustKey, u8>;
+        fn rust_reflect_hashmap_string_values(arg: HashMap<u8, String>) -> HashMap<u8, String>;
+        fn rust_reflect_option_hashmap(arg: Option<HashMap<u16, i64>>)
+            -> Option<HashMap<u16, i64>>;
+    }
+
+    extern "Swift" {
+        fn swift_reflect_hashmap_primitives(arg: HashMap<u32, bool>) -> HashMap<u32, bool>;
+        fn swift_reflect_hashmap_string_values(arg: HashMap<u8, String>) -> HashMap<u8, String>;
+    }
+
+    extern "Rust" {
+        fn test_rust_calls_swift_maps();
+    }
+}
+
+pub struct MapTestOpaqueRustType(u32);
+
+impl MapTestOpaqueRustType {
+    fn new(val: u32) -> Self {
+        MapTestOpaqueRustType(val)
+    }
+
+    fn val(&self) -> u32 {
+        self.0
+    }
+}
+
+#[derive(PartialEq, Eq, Hash)]
+pub struct MapTestHashableRustKey(u32);
+
+impl MapTestHashableRustKey {
+    fn new(val: u32) -> Self {
+        MapTestHashableRustKey(val)
+    }
+}
+
+fn rust_reflect_hashmap_primitives(arg: HashMap<u16, i64>) -> HashMap<u16, i64> {
+    arg
+}
+
+fn rust_reflect_hashmap_string_keys(arg: HashMap<String, u64>) -> HashMap<String, u64> {
+    arg
+}
+
+fn rust_reflect_btreemap_opaque_rust_values(
+    arg: BTreeMap<u8, MapTestOpaqueRustType>,
+) -> BTreeMap<u8, MapTestOpaqueRustType> {
+    arg
+}
+
+fn rust_reflect_hashmap_opaque_rust_keys(
+    arg: HashMap<MapTestHashableRustKey, u8>,
+) -> HashMap<MapTestHashableRustKey, u8> {
+    arg
+}
+
+fn rust_reflect_hashmap_string_values(arg: HashMap<u8, String>) -> HashMap<u8, String> {
+    arg
+}
+
+fn rust_reflect_option_hashmap(arg: Option<HashMap<u16, i64>>) -> Option<HashMap<u16, i64>> {
+    arg
+}
+
+fn test_rust_calls_swift_maps() {
+    let map: HashMap<u32, bool> = [(1, true), (2, false)].into_iter().collect();
+    let reflected = ffi::swift_reflect_hashmap_primitives(map.clone());
+    assert_eq!(reflected, map);
+
+    let map: HashMap<u8, String> = [(1, "one".to_string()), (2, "two".to_string())]
+        .into_iter()
+        .collect();
+    let reflected = ffi::swift_reflect_hashmap_string_values(map.clone());
+    assert_eq!(reflected, map);
+}
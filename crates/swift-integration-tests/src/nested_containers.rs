@@ -0,0 +1,106 @@
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct")]
+    struct NestedContainersStruct {
+        field: Vec<Option<u8>>,
+        optional_option: Option<Option<u8>>,
+    }
+
+    extern "Rust" {
+        fn rust_reflect_option_option_u8(arg: Option<Option<u8>>) -> Option<Option<u8>>;
+        fn rust_reflect_vec_option_string(arg: Vec<Option<String>>) -> Vec<Option<String>>;
+        fn rust_reflect_vec_vec_u8(arg: Vec<Vec<u8>>) -> Vec<Vec<u8>>;
+        fn rust_reflect_option_tuple(arg: Option<(u8, String)>) -> Option<(u8, String)>;
+        fn rust_reflect_option_result(
+            arg: Option<Result<u8, String>>,
+        ) -> Option<Result<u8, String>>;
+        fn rust_return_result_option_u32(succeed: bool) -> Result<Option<u32>, String>;
+        fn rust_return_result_vec_vec_u8(succeed: bool) -> Result<Vec<Vec<u8>>, String>;
+        fn rust_return_result_vec_option_u8(succeed: bool) -> Result<Vec<Option<u8>>, String>;
+        fn rust_reflect_option_const_ptr_u8(arg: Option<*const u8>) -> Option<*const u8>;
+        fn rust_reflect_option_slice_u8(arg: Option<&[u8]>) -> Option<&[u8]>;
+        fn rust_reflect_nested_containers_struct(
+            arg: NestedContainersStruct,
+        ) -> NestedContainersStruct;
+    }
+
+    extern "Swift" {
+        fn swift_reflect_option_option_u8(arg: Option<Option<u8>>) -> Option<Option<u8>>;
+        fn swift_reflect_vec_option_string(arg: Vec<Option<String>>) -> Vec<Option<String>>;
+    }
+
+    extern "Rust" {
+        fn test_rust_calls_swift_nested_containers();
+    }
+}
+
+fn rust_reflect_option_option_u8(arg: Option<Option<u8>>) -> Option<Option<u8>> {
+    arg
+}
+
+fn rust_reflect_vec_option_string(arg: Vec<Option<String>>) -> Vec<Option<String>> {
+    arg
+}
+
+fn rust_reflect_vec_vec_u8(arg: Vec<Vec<u8>>) -> Vec<Vec<u8>> {
+    arg
+}
+
+fn rust_reflect_option_tuple(arg: Option<(u8, String)>) -> Option<(u8, String)> {
+    arg
+}
+
+fn rust_reflect_option_result(arg: Option<Result<u8, String>>) -> Option<Result<u8, String>> {
+    arg
+}
+
+fn rust_reflect_option_const_ptr_u8(arg: Option<*const u8>) -> Option<*const u8> {
+    arg
+}
+
+fn rust_reflect_option_slice_u8(arg: Option<&[u8]>) -> Option<&[u8]> {
+    arg
+}
+
+fn rust_return_result_option_u32(succeed: bool) -> Result<Option<u32>, String> {
+    if succeed {
+        Ok(Some(123))
+    } else {
+        Err("failed".to_string())
+    }
+}
+
+fn rust_return_result_vec_vec_u8(succeed: bool) -> Result<Vec<Vec<u8>>, String> {
+    if succeed {
+        Ok(vec![vec![1, 2], vec![]])
+    } else {
+        Err("failed".to_string())
+    }
+}
+
+fn rust_return_result_vec_option_u8(succeed: bool) -> Result<Vec<Option<u8>>, String> {
+    if succeed {
+        Ok(vec![Some(1), None])
+    } else {
+        Err("failed".to_string())
+    }
+}
+
+fn rust_reflect_nested_containers_struct(
+    arg: ffi::NestedContainersStruct,
+) -> ffi::NestedContainersStruct {
+    arg
+}
+
+fn test_rust_calls_swift_nested_containers() {
+    assert_eq!(
+        ffi::swift_reflect_option_option_u8(Some(Some(5))),
+        Some(Some(5))
+    );
+    assert_eq!(ffi::swift_reflect_option_option_u8(Some(None)), Some(None));
+    assert_eq!(ffi::swift_reflect_option_option_u8(None), None);
+
+    let vec = vec![Some("hello".to_string()), None, Some("world".to_string())];
+    assert_eq!(ffi::swift_reflect_vec_option_string(vec.clone()), vec);
+    assert_eq!(ffi::swift_reflect_vec_option_string(vec![]), vec![]);
+}
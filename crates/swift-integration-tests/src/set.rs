@@ -0,0 +1,48 @@
+use std::collections::{BTreeSet, HashSet};
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_reflect_hashset_primitives(arg: HashSet<u16>) -> HashSet<u16>;
+        fn rust_reflect_hashset_strings(arg: HashSet<String>) -> HashSet<String>;
+        fn rust_reflect_btreeset_bools(arg: BTreeSet<bool>) -> BTreeSet<bool>;
+        fn rust_return_hashset_of_evens(below: i32) -> HashSet<i32>;
+    }
+
+    extern "Swift" {
+        fn swift_reflect_hashset_primitives(arg: HashSet<u64>) -> HashSet<u64>;
+        fn swift_reflect_btreeset_strings(arg: BTreeSet<String>) -> BTreeSet<String>;
+    }
+
+    extern "Rust" {
+        fn test_rust_calls_swift_sets();
+    }
+}
+
+fn rust_reflect_hashset_primitives(arg: HashSet<u16>) -> HashSet<u16> {
+    arg
+}
+
+fn rust_reflect_hashset_strings(arg: HashSet<String>) -> HashSet<String> {
+    arg
+}
+
+fn rust_reflect_btreeset_bools(arg: BTreeSet<bool>) -> BTreeSet<bool> {
+    arg
+}
+
+fn rust_return_hashset_of_evens(below: i32) -> HashSet<i32> {
+    (0..below).filter(|num| num % 2 == 0).collect()
+}
+
+fn test_rust_calls_swift_sets() {
+    let set: HashSet<u64> = [1, 2, 3].into_iter().collect();
+    let reflected = ffi::swift_reflect_hashset_primitives(set.clone());
+    assert_eq!(reflected, set);
+
+    let set: BTreeSet<String> = ["hello".to_string(), "world".to_string()]
+        .into_iter()
+        .collect();
+    let reflected = ffi::swift_reflect_btreeset_strings(set.clone());
+    assert_eq!(reflected, set);
+}
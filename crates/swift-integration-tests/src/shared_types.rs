@@ -1,2 +1,6 @@
+mod borrowed_struct_fields;
+mod generic_shared_type;
+mod recursive_shared_type;
 mod shared_enum;
 mod shared_struct;
+mod shared_type_method;
@@ -0,0 +1,81 @@
+use std::ffi::c_void;
+
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct")]
+    #[derive(Clone)]
+    struct BorrowedToken<'a> {
+        text: &'a str,
+        bytes: &'a [u8],
+        start: usize,
+    }
+
+    #[swift_bridge(swift_repr = "struct")]
+    struct StructWithPointerFields {
+        const_ptr: *const u8,
+        mut_void_ptr: *mut c_void,
+    }
+
+    extern "Rust" {
+        type TokenizedDocument;
+
+        #[swift_bridge(init)]
+        fn new(text: String) -> TokenizedDocument;
+
+        fn first_token(&self) -> Option<BorrowedToken<'_>>;
+        fn tokens(&self) -> Vec<BorrowedToken<'_>>;
+    }
+
+    extern "Rust" {
+        fn rust_reflect_borrowed_token(arg: BorrowedToken<'_>) -> BorrowedToken<'_>;
+        fn rust_reflect_struct_with_pointer_fields(
+            arg: StructWithPointerFields,
+        ) -> StructWithPointerFields;
+    }
+}
+
+use ffi::BorrowedToken;
+
+/// Owns the text that its tokens borrow from.
+pub struct TokenizedDocument {
+    text: String,
+}
+
+impl TokenizedDocument {
+    fn new(text: String) -> Self {
+        TokenizedDocument { text }
+    }
+
+    fn first_token(&self) -> Option<BorrowedToken<'_>> {
+        self.tokens().into_iter().next()
+    }
+
+    fn tokens(&self) -> Vec<BorrowedToken<'_>> {
+        self.text
+            .split(' ')
+            .filter(|word| !word.is_empty())
+            .map(|word| {
+                let start = word.as_ptr() as usize - self.text.as_ptr() as usize;
+                BorrowedToken {
+                    text: word,
+                    bytes: word.as_bytes(),
+                    start,
+                }
+            })
+            .collect()
+    }
+}
+
+fn rust_reflect_borrowed_token(arg: BorrowedToken<'_>) -> BorrowedToken<'_> {
+    arg
+}
+
+fn rust_reflect_struct_with_pointer_fields(
+    arg: ffi::StructWithPointerFields,
+) -> ffi::StructWithPointerFields {
+    // Make sure that the pointers made it across the boundary as the expected types.
+    let _: *const u8 = arg.const_ptr;
+    let _: *mut c_void = arg.mut_void_ptr;
+
+    arg
+}
@@ -0,0 +1,35 @@
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct", instantiate(T = u32), instantiate(T = String))]
+    struct GenericPage<T> {
+        items: Vec<T>,
+        next: Option<String>,
+    }
+
+    #[swift_bridge(instantiate(T = u8))]
+    enum GenericEvent<T> {
+        Data(T),
+        Named { value: Option<T> },
+        Closed,
+    }
+
+    extern "Rust" {
+        fn reflect_generic_page_u32(arg: GenericPage<u32>) -> GenericPage<u32>;
+        fn reflect_generic_page_string(arg: GenericPage<String>) -> GenericPage<String>;
+        fn reflect_generic_event_u8(arg: GenericEvent<u8>) -> GenericEvent<u8>;
+    }
+}
+
+use ffi::{GenericEvent, GenericPage};
+
+fn reflect_generic_page_u32(arg: GenericPage<u32>) -> GenericPage<u32> {
+    arg
+}
+
+fn reflect_generic_page_string(arg: GenericPage<String>) -> GenericPage<String> {
+    arg
+}
+
+fn reflect_generic_event_u8(arg: GenericEvent<u8>) -> GenericEvent<u8> {
+    arg
+}
@@ -0,0 +1,98 @@
+#[swift_bridge::bridge]
+mod ffi {
+    enum RecursiveSharedEnum {
+        Num(f64),
+        Add(Box<RecursiveSharedEnum>, Box<RecursiveSharedEnum>),
+        Neg { inner: Box<RecursiveSharedEnum> },
+    }
+
+    #[swift_bridge(swift_repr = "struct")]
+    struct RecursiveSharedStruct {
+        value: i32,
+        next: Option<Box<RecursiveSharedStruct>>,
+    }
+
+    #[swift_bridge(swift_repr = "struct")]
+    #[derive(Clone)]
+    struct SharedTreeNode {
+        value: u32,
+        children: Vec<SharedTreeNode>,
+    }
+
+    extern "Rust" {
+        fn rust_eval_recursive_shared_enum(expr: RecursiveSharedEnum) -> f64;
+        fn rust_negate_boxed_recursive_shared_enum(
+            expr: Box<RecursiveSharedEnum>,
+        ) -> Box<RecursiveSharedEnum>;
+
+        fn rust_make_recursive_shared_struct(len: i32) -> RecursiveSharedStruct;
+        fn rust_sum_recursive_shared_struct(list: RecursiveSharedStruct) -> i32;
+
+        fn rust_make_shared_tree() -> SharedTreeNode;
+        fn rust_sum_shared_tree(tree: SharedTreeNode) -> u32;
+    }
+}
+
+use ffi::{RecursiveSharedEnum, RecursiveSharedStruct, SharedTreeNode};
+
+fn rust_eval_recursive_shared_enum(expr: RecursiveSharedEnum) -> f64 {
+    match expr {
+        RecursiveSharedEnum::Num(num) => num,
+        RecursiveSharedEnum::Add(left, right) => {
+            rust_eval_recursive_shared_enum(*left) + rust_eval_recursive_shared_enum(*right)
+        }
+        RecursiveSharedEnum::Neg { inner } => -rust_eval_recursive_shared_enum(*inner),
+    }
+}
+
+fn rust_negate_boxed_recursive_shared_enum(
+    expr: Box<RecursiveSharedEnum>,
+) -> Box<RecursiveSharedEnum> {
+    Box::new(RecursiveSharedEnum::Neg { inner: expr })
+}
+
+/// Creates the list `1 -> 2 -> ... -> len`.
+fn rust_make_recursive_shared_struct(len: i32) -> RecursiveSharedStruct {
+    let mut list = RecursiveSharedStruct {
+        value: len,
+        next: None,
+    };
+    for value in (1..len).rev() {
+        list = RecursiveSharedStruct {
+            value,
+            next: Some(Box::new(list)),
+        };
+    }
+    list
+}
+
+fn rust_sum_recursive_shared_struct(list: RecursiveSharedStruct) -> i32 {
+    let mut sum = list.value;
+    let mut next = list.next;
+    while let Some(node) = next {
+        sum += node.value;
+        next = node.next;
+    }
+    sum
+}
+
+/// Creates a root node with the value `1`, and two leaf children with the values `2` and `3`.
+fn rust_make_shared_tree() -> SharedTreeNode {
+    let leaf = |value| SharedTreeNode {
+        value,
+        children: vec![],
+    };
+    SharedTreeNode {
+        value: 1,
+        children: vec![leaf(2), leaf(3)],
+    }
+}
+
+fn rust_sum_shared_tree(tree: SharedTreeNode) -> u32 {
+    tree.value
+        + tree
+            .children
+            .into_iter()
+            .map(rust_sum_shared_tree)
+            .sum::<u32>()
+}
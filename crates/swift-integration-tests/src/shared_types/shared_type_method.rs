@@ -0,0 +1,90 @@
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct")]
+    struct SharedStructWithMethods {
+        x: i32,
+        y: i32,
+    }
+
+    enum SharedEnumWithMethods {
+        Counter(u32),
+        Stopped,
+    }
+
+    extern "Rust" {
+        #[swift_bridge(init)]
+        fn new(x: i32, y: i32) -> SharedStructWithMethods;
+
+        #[swift_bridge(associated_to = SharedStructWithMethods)]
+        fn origin() -> SharedStructWithMethods;
+
+        fn is_origin(self: &SharedStructWithMethods) -> bool;
+        fn translate(self: &mut SharedStructWithMethods, dx: i32, dy: i32);
+        fn sum(self: SharedStructWithMethods) -> i32;
+    }
+
+    extern "Rust" {
+        #[swift_bridge(init)]
+        fn start(count: u32) -> Option<SharedEnumWithMethods>;
+
+        fn count(self: &SharedEnumWithMethods) -> u32;
+        fn increment(self: &mut SharedEnumWithMethods);
+        fn stop(self: &mut SharedEnumWithMethods) -> u32;
+    }
+}
+
+use ffi::{SharedEnumWithMethods, SharedStructWithMethods};
+
+impl SharedStructWithMethods {
+    fn new(x: i32, y: i32) -> Self {
+        SharedStructWithMethods { x, y }
+    }
+
+    fn origin() -> Self {
+        SharedStructWithMethods { x: 0, y: 0 }
+    }
+
+    fn is_origin(&self) -> bool {
+        self.x == 0 && self.y == 0
+    }
+
+    fn translate(&mut self, dx: i32, dy: i32) {
+        self.x += dx;
+        self.y += dy;
+    }
+
+    fn sum(self) -> i32 {
+        self.x + self.y
+    }
+}
+
+impl SharedEnumWithMethods {
+    /// Returns `None` for a count of zero.
+    fn start(count: u32) -> Option<Self> {
+        if count == 0 {
+            None
+        } else {
+            Some(SharedEnumWithMethods::Counter(count))
+        }
+    }
+
+    fn count(&self) -> u32 {
+        match self {
+            SharedEnumWithMethods::Counter(count) => *count,
+            SharedEnumWithMethods::Stopped => 0,
+        }
+    }
+
+    fn increment(&mut self) {
+        if let SharedEnumWithMethods::Counter(count) = self {
+            *count += 1;
+        }
+    }
+
+    /// Stops the counter and returns the count that it had.
+    fn stop(&mut self) -> u32 {
+        let count = self.count();
+        *self = SharedEnumWithMethods::Stopped;
+        count
+    }
+}
@@ -25,4 +25,39 @@ mod ffi {
     struct StructDeriveClone3 {
         field: String,
     }
+
+    #[swift_bridge(swift_repr = "struct")]
+    #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+    struct StructDeriveDebug {
+        field: u8,
+    }
+
+    #[swift_bridge(swift_repr = "struct")]
+    #[derive(Clone, PartialEq, Eq, Hash)]
+    struct StructDeriveHashable {
+        name: String,
+        count: u32,
+    }
+
+    extern "Rust" {
+        fn reflect_struct_derive_hashable(arg: StructDeriveHashable) -> StructDeriveHashable;
+    }
+}
+
+use ffi::StructDeriveHashable;
+
+// `StructDeriveHashable` owns a `String`, so it implements `Debug` outside of the bridge module.
+impl std::fmt::Debug for StructDeriveHashable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StructDeriveHashable")
+            .field("name", &self.name)
+            .field("count", &self.count)
+            .finish()
+    }
+}
+
+fn reflect_struct_derive_hashable(arg: StructDeriveHashable) -> StructDeriveHashable {
+    // Verify that the Rust derives work.
+    assert_eq!(arg.clone(), arg);
+    arg
 }
@@ -0,0 +1,70 @@
+//! See also: crates/swift-bridge-ir/src/codegen/codegen_tests/swift_naming.rs
+
+#[swift_bridge::bridge(swift_naming = "camelCase")]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct")]
+    struct CamelCaseRequestOptions {
+        max_retries: u8,
+        #[swift_bridge(swift_name = "timeout")]
+        timeout_in_seconds: u16,
+    }
+
+    enum CamelCaseRequestError {
+        NotFound,
+        TooManyRequests { retry_after: u16 },
+    }
+
+    extern "Rust" {
+        type CamelCaseCounter;
+
+        #[swift_bridge(init)]
+        fn new(start_value: u32) -> CamelCaseCounter;
+        fn add_amount(&mut self, amount_to_add: u32);
+        fn current_value(&self) -> u32;
+
+        fn double_max_retries(options: CamelCaseRequestOptions) -> CamelCaseRequestOptions;
+        fn retry_after_or_zero(error: CamelCaseRequestError) -> u16;
+    }
+
+    extern "Swift" {
+        fn camel_case_add_numbers(first_number: u32, second_number: u32) -> u32;
+    }
+
+    extern "Rust" {
+        fn test_rust_calls_camel_case_swift_function();
+    }
+}
+
+pub struct CamelCaseCounter(u32);
+
+impl CamelCaseCounter {
+    fn new(start_value: u32) -> Self {
+        CamelCaseCounter(start_value)
+    }
+
+    fn add_amount(&mut self, amount_to_add: u32) {
+        self.0 += amount_to_add;
+    }
+
+    fn current_value(&self) -> u32 {
+        self.0
+    }
+}
+
+fn double_max_retries(options: ffi::CamelCaseRequestOptions) -> ffi::CamelCaseRequestOptions {
+    ffi::CamelCaseRequestOptions {
+        max_retries: options.max_retries * 2,
+        timeout_in_seconds: options.timeout_in_seconds,
+    }
+}
+
+fn retry_after_or_zero(error: ffi::CamelCaseRequestError) -> u16 {
+    match error {
+        ffi::CamelCaseRequestError::NotFound => 0,
+        ffi::CamelCaseRequestError::TooManyRequests { retry_after } => retry_after,
+    }
+}
+
+fn test_rust_calls_camel_case_swift_function() {
+    assert_eq!(ffi::camel_case_add_numbers(2, 3), 5);
+}
@@ -5,6 +5,15 @@ mod ffi {
         VariantB,
     }
 
+    #[swift_bridge(swift_repr = "struct")]
+    #[derive(Clone)]
+    struct TransparentStructInsideVecT {
+        integer: u32,
+        string: String,
+        maybe_string: Option<String>,
+        maybe_integer: Option<i64>,
+    }
+
     extern "Rust" {
         type ARustTypeInsideVecT;
 
@@ -26,6 +35,14 @@ mod ffi {
         ) -> Vec<TransparentEnumInsideVecT>;
     }
 
+    extern "Rust" {
+        fn rust_reflect_vec_transparent_struct(
+            arg: Vec<TransparentStructInsideVecT>,
+        ) -> Vec<TransparentStructInsideVecT>;
+
+        fn rust_return_vec_transparent_struct() -> Vec<TransparentStructInsideVecT>;
+    }
+
     extern "Rust" {
         fn run_vec_tests();
     }
@@ -74,3 +91,26 @@ fn rust_reflect_vec_transparent_enum(
 ) -> Vec<ffi::TransparentEnumInsideVecT> {
     arg
 }
+
+fn rust_reflect_vec_transparent_struct(
+    arg: Vec<ffi::TransparentStructInsideVecT>,
+) -> Vec<ffi::TransparentStructInsideVecT> {
+    arg
+}
+
+fn rust_return_vec_transparent_struct() -> Vec<ffi::TransparentStructInsideVecT> {
+    vec![
+        ffi::TransparentStructInsideVecT {
+            integer: 1,
+            string: "first".to_string(),
+            maybe_string: Some("some".to_string()),
+            maybe_integer: None,
+        },
+        ffi::TransparentStructInsideVecT {
+            integer: 2,
+            string: "second".to_string(),
+            maybe_string: None,
+            maybe_integer: Some(-5),
+        },
+    ]
+}
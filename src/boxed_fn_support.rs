@@ -15,3 +15,62 @@ pub extern "C" fn __swift_bridge__free_boxed_fn_once_no_args_no_return(
         let _ = Box::from_raw(boxed_fn);
     }
 }
+
+/// A retained Swift closure that Swift passed to Rust as a `Box<dyn Fn>`, `Box<dyn FnMut>` or
+/// `Box<dyn FnOnce>`.
+///
+/// The Swift closure gets released when this is dropped.
+#[doc(hidden)]
+pub struct SwiftCallback {
+    ptr: *mut std::ffi::c_void,
+    free: unsafe extern "C" fn(*mut std::ffi::c_void),
+}
+
+impl SwiftCallback {
+    /// # Safety
+    ///
+    /// `ptr` must be a retained Swift closure that stays valid until `free` is called with it.
+    /// `free` must release the closure, and gets called exactly once when this is dropped.
+    pub unsafe fn new(
+        ptr: *mut std::ffi::c_void,
+        free: unsafe extern "C" fn(*mut std::ffi::c_void),
+    ) -> Self {
+        SwiftCallback { ptr, free }
+    }
+
+    pub fn as_ptr(&self) -> *mut std::ffi::c_void {
+        self.ptr
+    }
+}
+
+impl Drop for SwiftCallback {
+    fn drop(&mut self) {
+        unsafe { (self.free)(self.ptr) }
+    }
+}
+
+/// A [`SwiftCallback`] for an `@Sendable` Swift closure, which can be passed to Rust as a
+/// `Box<dyn Fn + Send>` or `Box<dyn Fn + Sync>`.
+#[doc(hidden)]
+pub struct SendableSwiftCallback(SwiftCallback);
+
+impl SendableSwiftCallback {
+    /// # Safety
+    ///
+    /// Same as [`SwiftCallback::new`]. The Swift closure must also be `@Sendable`, meaning that it
+    /// can be moved to, released on and called concurrently from any thread.
+    pub unsafe fn new(
+        ptr: *mut std::ffi::c_void,
+        free: unsafe extern "C" fn(*mut std::ffi::c_void),
+    ) -> Self {
+        SendableSwiftCallback(SwiftCallback::new(ptr, free))
+    }
+
+    pub fn as_ptr(&self) -> *mut std::ffi::c_void {
+        self.0.as_ptr()
+    }
+}
+
+// `@Sendable` Swift closures can be called from, and released on, any thread.
+unsafe impl Send for SendableSwiftCallback {}
+unsafe impl Sync for SendableSwiftCallback {}
@@ -6,7 +6,7 @@ pub use swift_bridge_macro::bridge;
 
 mod std_bridge;
 
-pub use self::std_bridge::{option, result, string};
+pub use self::std_bridge::{array, int128, map, option, result, string};
 
 #[doc(hidden)]
 #[cfg(feature = "async")]
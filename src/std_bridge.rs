@@ -2,7 +2,11 @@
 //! crates/swift-bridge-build/src/generate_core/*
 #![allow(missing_docs)]
 
+pub mod array;
+pub mod int128;
+pub mod map;
 pub mod option;
 pub mod result;
+mod rust_set;
 mod rust_vec;
 pub mod string;
@@ -0,0 +1,88 @@
+//! The corresponding C and Swift code can be found in
+//! crates/swift-bridge-build/src/generate_core/ffi_array_support.rs
+//!
+//! A `Vec<T>` whose elements are themselves containers, such as `Vec<Option<T>>` or
+//! `Vec<Vec<T>>`, can't be exposed to Swift as a `RustVec<T>` since there is no `Vectorizable`
+//! implementation for every possible nesting.
+//! Instead, each element is converted to its FFI representation and the elements are moved
+//! across the boundary in a single buffer, where they get converted into a Swift `Array`.
+
+use std::alloc::Layout;
+use std::ffi::c_void;
+
+/// A buffer of FFI compatible values.
+///
+/// The buffer is allocated by Rust's global allocator, so whichever side receives it is
+/// responsible for freeing it. Swift frees it using `__swift_bridge__$FfiArray$free`.
+#[doc(hidden)]
+#[repr(C)]
+pub struct FfiArray {
+    pub start: *mut c_void,
+    pub len: usize,
+}
+
+impl FfiArray {
+    /// Move the elements of a `Vec<T>` into an `FfiArray`.
+    pub fn from_vec<T>(vec: Vec<T>) -> Self {
+        let slice = vec.into_boxed_slice();
+        let len = slice.len();
+        let start = Box::into_raw(slice) as *mut T as *mut c_void;
+
+        FfiArray { start, len }
+    }
+
+    /// Move the elements out of an `FfiArray` and into a `Vec<T>`.
+    ///
+    /// # Safety
+    ///
+    /// The array must hold `len` initialized values of type `T` and must have been allocated
+    /// by `FfiArray::from_vec::<T>` or `__swift_bridge__$FfiArray$alloc`.
+    pub unsafe fn into_vec<T>(self) -> Vec<T> {
+        let slice = std::ptr::slice_from_raw_parts_mut(self.start as *mut T, self.len);
+        Box::from_raw(slice).into_vec()
+    }
+}
+
+#[export_name = "__swift_bridge__$FfiArray$alloc"]
+#[doc(hidden)]
+pub extern "C" fn _alloc(len: usize, size: usize, align: usize) -> FfiArray {
+    let layout = array_layout(len, size, align);
+
+    let start = if layout.size() == 0 {
+        // Same as `NonNull::dangling()`, which is what an empty `Box<[T]>` points to.
+        align as *mut c_void
+    } else {
+        let start = unsafe { std::alloc::alloc(layout) };
+        if start.is_null() {
+            std::alloc::handle_alloc_error(layout);
+        }
+        start as *mut c_void
+    };
+
+    FfiArray { start, len }
+}
+
+/// Free the buffer. The elements must have already been moved out of it.
+#[export_name = "__swift_bridge__$FfiArray$free"]
+#[doc(hidden)]
+pub extern "C" fn _free(array: FfiArray, size: usize, align: usize) {
+    let layout = array_layout(array.len, size, align);
+
+    if layout.size() != 0 {
+        unsafe { std::alloc::dealloc(array.start as *mut u8, layout) };
+    }
+}
+
+/// The same layout that `Layout::array::<T>(len)` gives for an element with the given size and
+/// alignment, which is the layout that `Box<[T]>` allocates and frees with.
+///
+/// Panics if the array's size overflows instead of allocating a buffer that is too small.
+fn array_layout(len: usize, size: usize, align: usize) -> Layout {
+    let element = Layout::from_size_align(size, align).unwrap().pad_to_align();
+    let size = element
+        .size()
+        .checked_mul(len)
+        .expect("FfiArray size overflows usize");
+
+    Layout::from_size_align(size, align).unwrap()
+}
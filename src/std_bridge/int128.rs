@@ -0,0 +1,55 @@
+//! The corresponding C and Swift code can be found in
+//! crates/swift-bridge-build/src/generate_core/int128_support.rs
+
+/// The FFI representation of an `i128`.
+///
+/// C has no standard 128-bit integer type, so we pass the low and high 64 bits separately.
+/// On the Swift side this becomes an `Int128`.
+#[doc(hidden)]
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct FfiI128 {
+    pub low: u64,
+    pub high: i64,
+}
+
+/// The FFI representation of a `u128`.
+///
+/// On the Swift side this becomes a `UInt128`.
+#[doc(hidden)]
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct FfiU128 {
+    pub low: u64,
+    pub high: u64,
+}
+
+impl From<i128> for FfiI128 {
+    fn from(val: i128) -> Self {
+        FfiI128 {
+            low: val as u64,
+            high: (val >> 64) as i64,
+        }
+    }
+}
+
+impl From<FfiI128> for i128 {
+    fn from(val: FfiI128) -> Self {
+        ((val.high as i128) << 64) | val.low as i128
+    }
+}
+
+impl From<u128> for FfiU128 {
+    fn from(val: u128) -> Self {
+        FfiU128 {
+            low: val as u64,
+            high: (val >> 64) as u64,
+        }
+    }
+}
+
+impl From<FfiU128> for u128 {
+    fn from(val: FfiU128) -> Self {
+        ((val.high as u128) << 64) | val.low as u128
+    }
+}
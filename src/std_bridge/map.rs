@@ -0,0 +1,39 @@
+//! The corresponding C and Swift code can be found in
+//! crates/swift-bridge-build/src/generate_core/rust_map.swift
+
+/// The FFI representation of a `HashMap<K, V>` or a `BTreeMap<K, V>`.
+///
+/// A map crosses the FFI boundary as two vectors of equal length, one holding the keys and the
+/// other holding the values.
+/// This lets the Swift side re-use the existing `RustVec<T>` support for every `Vectorizable`
+/// key and value type instead of needing to generate lookup functions for every `(K, V)` pair.
+///
+/// On the Swift side this becomes a `RustMap<K, V>`.
+#[doc(hidden)]
+#[repr(C)]
+pub struct FfiMap<K, V> {
+    pub keys: *mut Vec<K>,
+    pub values: *mut Vec<V>,
+}
+
+impl<K, V> FfiMap<K, V> {
+    /// Move the entries of a map into a pair of heap allocated vectors.
+    pub fn from_entries(entries: impl IntoIterator<Item = (K, V)>) -> Self {
+        let (keys, values): (Vec<K>, Vec<V>) = entries.into_iter().unzip();
+
+        FfiMap {
+            keys: Box::into_raw(Box::new(keys)),
+            values: Box::into_raw(Box::new(values)),
+        }
+    }
+
+    /// Take ownership of the keys and values.
+    ///
+    /// If the Swift side handed us vectors of different lengths, the extra entries are dropped.
+    pub fn into_entries(self) -> impl Iterator<Item = (K, V)> {
+        let keys = unsafe { *Box::from_raw(self.keys) };
+        let values = unsafe { *Box::from_raw(self.values) };
+
+        keys.into_iter().zip(values)
+    }
+}
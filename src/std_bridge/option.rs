@@ -88,3 +88,25 @@ pub struct OptionBool {
     pub val: bool,
     pub is_some: bool,
 }
+
+/// A `char` crosses the FFI boundary as a `u32`.
+#[repr(C)]
+#[doc(hidden)]
+pub struct OptionChar {
+    pub val: u32,
+    pub is_some: bool,
+}
+
+#[repr(C)]
+#[doc(hidden)]
+pub struct OptionI128 {
+    pub val: crate::int128::FfiI128,
+    pub is_some: bool,
+}
+
+#[repr(C)]
+#[doc(hidden)]
+pub struct OptionU128 {
+    pub val: crate::int128::FfiU128,
+    pub is_some: bool,
+}
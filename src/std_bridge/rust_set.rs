@@ -0,0 +1,193 @@
+//! The corresponding C and Swift code can be found in
+//! crates/swift-bridge-build/src/generate_core/rust_set.swift
+//!
+//! Both `HashSet<T>` and `BTreeSet<T>` cross the FFI boundary as a `*mut HashSet<T>`.
+//! A `BTreeSet<T>` gets collected into a `HashSet<T>` before being passed to Swift, and back
+//! into a `BTreeSet<T>` when it is passed to Rust, so Swift doesn't see a `BTreeSet<T>`'s
+//! elements in order.
+
+use crate::string::RustString;
+use std::collections::HashSet;
+
+use macro_::set_externs;
+
+set_externs!(u8, OptionU8, 123);
+set_externs!(u16, OptionU16, 123);
+set_externs!(u32, OptionU32, 123);
+set_externs!(u64, OptionU64, 123);
+set_externs!(usize, OptionUsize, 123);
+
+set_externs!(i8, OptionI8, 123);
+set_externs!(i16, OptionI16, 123);
+set_externs!(i32, OptionI32, 123);
+set_externs!(i64, OptionI64, 123);
+set_externs!(isize, OptionIsize, 123);
+
+set_externs!(bool, OptionBool, false);
+
+/// Iterates over a copy of a set's elements.
+///
+/// Swift can insert into or remove from the set while it is iterating over it, so the iterator
+/// can't borrow the set. The elements that it yields stay alive until the iterator is freed.
+#[doc(hidden)]
+pub struct SetIter<T> {
+    values: Vec<T>,
+    next_idx: usize,
+}
+
+impl<T> SetIter<T> {
+    fn new(values: Vec<T>) -> Self {
+        SetIter {
+            values,
+            next_idx: 0,
+        }
+    }
+
+    fn next(&mut self) -> Option<&T> {
+        let val = self.values.get(self.next_idx);
+        self.next_idx += 1;
+        val
+    }
+}
+
+const _: () = {
+    #[export_name = "__swift_bridge__$HashSet_RustString$new"]
+    #[doc(hidden)]
+    pub extern "C" fn _new() -> *mut HashSet<RustString> {
+        Box::into_raw(Box::new(HashSet::new()))
+    }
+
+    #[export_name = "__swift_bridge__$HashSet_RustString$_free"]
+    #[doc(hidden)]
+    pub extern "C" fn _drop(set: *mut HashSet<RustString>) {
+        let set = unsafe { Box::from_raw(set) };
+        drop(set)
+    }
+
+    #[export_name = "__swift_bridge__$HashSet_RustString$len"]
+    #[doc(hidden)]
+    pub extern "C" fn _len(set: *mut HashSet<RustString>) -> usize {
+        unsafe { &*set }.len()
+    }
+
+    #[export_name = "__swift_bridge__$HashSet_RustString$insert"]
+    #[doc(hidden)]
+    pub extern "C" fn _insert(set: *mut HashSet<RustString>, val: *mut RustString) -> bool {
+        let val = unsafe { *Box::from_raw(val) };
+        unsafe { &mut *set }.insert(val)
+    }
+
+    #[export_name = "__swift_bridge__$HashSet_RustString$remove"]
+    #[doc(hidden)]
+    pub extern "C" fn _remove(set: *mut HashSet<RustString>, val: *const RustString) -> bool {
+        unsafe { &mut *set }.remove(unsafe { &*val })
+    }
+
+    #[export_name = "__swift_bridge__$HashSet_RustString$contains"]
+    #[doc(hidden)]
+    pub extern "C" fn _contains(set: *mut HashSet<RustString>, val: *const RustString) -> bool {
+        unsafe { &*set }.contains(unsafe { &*val })
+    }
+
+    #[export_name = "__swift_bridge__$HashSet_RustString$iter"]
+    #[doc(hidden)]
+    pub extern "C" fn _iter(set: *mut HashSet<RustString>) -> *mut SetIter<RustString> {
+        let set = unsafe { &*set };
+        let values = set.iter().map(|val| RustString(val.0.clone())).collect();
+        Box::into_raw(Box::new(SetIter::new(values)))
+    }
+
+    #[export_name = "__swift_bridge__$HashSet_RustString$iter_next"]
+    #[doc(hidden)]
+    pub extern "C" fn _iter_next(iter: *mut SetIter<RustString>) -> *const RustString {
+        match unsafe { &mut *iter }.next() {
+            Some(val) => val as *const RustString,
+            None => std::ptr::null(),
+        }
+    }
+
+    #[export_name = "__swift_bridge__$HashSet_RustString$iter_free"]
+    #[doc(hidden)]
+    pub extern "C" fn _iter_free(iter: *mut SetIter<RustString>) {
+        let iter = unsafe { Box::from_raw(iter) };
+        drop(iter)
+    }
+};
+
+mod macro_ {
+    macro_rules! set_externs {
+        ($ty:ty, $option_ty:ident, $unused_none:expr) => {
+            const _: () = {
+                #[export_name = concat!("__swift_bridge__$HashSet_", stringify!($ty), "$new")]
+                #[doc(hidden)]
+                pub extern "C" fn _new() -> *mut HashSet<$ty> {
+                    Box::into_raw(Box::new(HashSet::new()))
+                }
+
+                #[export_name = concat!("__swift_bridge__$HashSet_", stringify!($ty), "$_free")]
+                #[doc(hidden)]
+                pub extern "C" fn _drop(set: *mut HashSet<$ty>) {
+                    let set = unsafe { Box::from_raw(set) };
+                    drop(set)
+                }
+
+                #[export_name = concat!("__swift_bridge__$HashSet_", stringify!($ty), "$len")]
+                #[doc(hidden)]
+                pub extern "C" fn _len(set: *mut HashSet<$ty>) -> usize {
+                    unsafe { &*set }.len()
+                }
+
+                #[export_name = concat!("__swift_bridge__$HashSet_", stringify!($ty), "$insert")]
+                #[doc(hidden)]
+                pub extern "C" fn _insert(set: *mut HashSet<$ty>, val: $ty) -> bool {
+                    unsafe { &mut *set }.insert(val)
+                }
+
+                #[export_name = concat!("__swift_bridge__$HashSet_", stringify!($ty), "$remove")]
+                #[doc(hidden)]
+                pub extern "C" fn _remove(set: *mut HashSet<$ty>, val: $ty) -> bool {
+                    unsafe { &mut *set }.remove(&val)
+                }
+
+                #[export_name = concat!("__swift_bridge__$HashSet_", stringify!($ty), "$contains")]
+                #[doc(hidden)]
+                pub extern "C" fn _contains(set: *mut HashSet<$ty>, val: $ty) -> bool {
+                    unsafe { &*set }.contains(&val)
+                }
+
+                #[export_name = concat!("__swift_bridge__$HashSet_", stringify!($ty), "$iter")]
+                #[doc(hidden)]
+                pub extern "C" fn _iter(set: *mut HashSet<$ty>) -> *mut SetIter<$ty> {
+                    let set = unsafe { &*set };
+                    let values = set.iter().copied().collect();
+                    Box::into_raw(Box::new(SetIter::new(values)))
+                }
+
+                #[export_name = concat!("__swift_bridge__$HashSet_", stringify!($ty), "$iter_next")]
+                #[doc(hidden)]
+                pub extern "C" fn _iter_next(iter: *mut SetIter<$ty>) -> crate::option::$option_ty {
+                    if let Some(val) = unsafe { &mut *iter }.next() {
+                        crate::option::$option_ty {
+                            val: *val,
+                            is_some: true,
+                        }
+                    } else {
+                        crate::option::$option_ty {
+                            val: $unused_none,
+                            is_some: false,
+                        }
+                    }
+                }
+
+                #[export_name = concat!("__swift_bridge__$HashSet_", stringify!($ty), "$iter_free")]
+                #[doc(hidden)]
+                pub extern "C" fn _iter_free(iter: *mut SetIter<$ty>) {
+                    let iter = unsafe { Box::from_raw(iter) };
+                    drop(iter)
+                }
+            };
+        };
+    }
+
+    pub(super) use set_externs;
+}
@@ -1,4 +1,4 @@
-use macro_::vec_externs;
+use macro_::{vec_externs, vec_externs_128};
 
 vec_externs!(u8, OptionU8, 123);
 vec_externs!(u16, OptionU16, 123);
@@ -17,6 +17,80 @@ vec_externs!(f64, OptionF64, 0.123);
 
 vec_externs!(bool, OptionBool, false);
 
+vec_externs_128!(i128, FfiI128, OptionI128);
+vec_externs_128!(u128, FfiU128, OptionU128);
+
+// `char` is not in `vec_externs!` since it crosses the FFI boundary as a `u32`.
+const _: () = {
+    #[export_name = "__swift_bridge__$Vec_char$new"]
+    #[doc(hidden)]
+    pub extern "C" fn _new() -> *mut Vec<char> {
+        Box::into_raw(Box::new(Vec::new()))
+    }
+
+    #[export_name = "__swift_bridge__$Vec_char$_free"]
+    #[doc(hidden)]
+    pub extern "C" fn _drop(vec: *mut Vec<char>) {
+        let vec = unsafe { Box::from_raw(vec) };
+        drop(vec)
+    }
+
+    #[export_name = "__swift_bridge__$Vec_char$len"]
+    #[doc(hidden)]
+    pub extern "C" fn _len(vec: *mut Vec<char>) -> usize {
+        let vec = unsafe { &*vec };
+        vec.len()
+    }
+
+    #[export_name = "__swift_bridge__$Vec_char$push"]
+    #[doc(hidden)]
+    pub extern "C" fn _push(vec: *mut Vec<char>, val: u32) {
+        let vec = unsafe { &mut *vec };
+        vec.push(char::from_u32(val).unwrap_or(char::REPLACEMENT_CHARACTER));
+    }
+
+    #[export_name = "__swift_bridge__$Vec_char$pop"]
+    #[doc(hidden)]
+    pub extern "C" fn _pop(vec: *mut Vec<char>) -> crate::option::OptionChar {
+        let vec = unsafe { &mut *vec };
+        option_char(vec.pop())
+    }
+
+    #[export_name = "__swift_bridge__$Vec_char$get"]
+    #[doc(hidden)]
+    pub extern "C" fn _get(vec: *mut Vec<char>, index: usize) -> crate::option::OptionChar {
+        let vec = unsafe { &*vec };
+        option_char(vec.get(index).copied())
+    }
+
+    #[export_name = "__swift_bridge__$Vec_char$get_mut"]
+    #[doc(hidden)]
+    pub extern "C" fn _get_mut(vec: *mut Vec<char>, index: usize) -> crate::option::OptionChar {
+        let vec = unsafe { &mut *vec };
+        option_char(vec.get(index).copied())
+    }
+
+    #[export_name = "__swift_bridge__$Vec_char$as_ptr"]
+    #[doc(hidden)]
+    pub extern "C" fn _as_ptr(vec: *mut Vec<char>) -> *const char {
+        let vec = unsafe { &*vec };
+        vec.as_ptr()
+    }
+
+    fn option_char(val: Option<char>) -> crate::option::OptionChar {
+        match val {
+            Some(val) => crate::option::OptionChar {
+                val: val as u32,
+                is_some: true,
+            },
+            None => crate::option::OptionChar {
+                val: 0,
+                is_some: false,
+            },
+        }
+    }
+};
+
 mod macro_ {
     macro_rules! vec_externs {
         ($ty:ty, $option_ty:ident, $unused_none:expr) => {
@@ -116,5 +190,88 @@ mod macro_ {
         };
     }
 
+    /// 128-bit integers cross the FFI boundary as a pair of 64-bit integers, so they can't use
+    /// `vec_externs!`.
+    macro_rules! vec_externs_128 {
+        ($ty:ty, $ffi_ty:ident, $option_ty:ident) => {
+            const _: () = {
+                #[export_name = concat!("__swift_bridge__$Vec_", stringify!($ty), "$new")]
+                #[doc(hidden)]
+                pub extern "C" fn _new() -> *mut Vec<$ty> {
+                    Box::into_raw(Box::new(Vec::new()))
+                }
+
+                #[export_name = concat!("__swift_bridge__$Vec_", stringify!($ty), "$_free")]
+                #[doc(hidden)]
+                pub extern "C" fn _drop(vec: *mut Vec<$ty>) {
+                    let vec = unsafe { Box::from_raw(vec) };
+                    drop(vec)
+                }
+
+                #[export_name = concat!("__swift_bridge__$Vec_", stringify!($ty), "$len")]
+                #[doc(hidden)]
+                pub extern "C" fn _len(vec: *mut Vec<$ty>) -> usize {
+                    let vec = unsafe { &*vec };
+                    vec.len()
+                }
+
+                #[export_name = concat!("__swift_bridge__$Vec_", stringify!($ty), "$push")]
+                #[doc(hidden)]
+                pub extern "C" fn _push(vec: *mut Vec<$ty>, val: crate::int128::$ffi_ty) {
+                    let vec = unsafe { &mut *vec };
+                    vec.push(<$ty>::from(val));
+                }
+
+                #[export_name = concat!("__swift_bridge__$Vec_", stringify!($ty), "$pop")]
+                #[doc(hidden)]
+                pub extern "C" fn _pop(vec: *mut Vec<$ty>) -> crate::option::$option_ty {
+                    let vec = unsafe { &mut *vec };
+                    to_ffi_option(vec.pop())
+                }
+
+                #[export_name = concat!("__swift_bridge__$Vec_", stringify!($ty), "$get")]
+                #[doc(hidden)]
+                pub extern "C" fn _get(
+                    vec: *mut Vec<$ty>,
+                    index: usize,
+                ) -> crate::option::$option_ty {
+                    let vec = unsafe { &*vec };
+                    to_ffi_option(vec.get(index).copied())
+                }
+
+                #[export_name = concat!("__swift_bridge__$Vec_", stringify!($ty), "$get_mut")]
+                #[doc(hidden)]
+                pub extern "C" fn _get_mut(
+                    vec: *mut Vec<$ty>,
+                    index: usize,
+                ) -> crate::option::$option_ty {
+                    let vec = unsafe { &mut *vec };
+                    to_ffi_option(vec.get(index).copied())
+                }
+
+                #[export_name = concat!("__swift_bridge__$Vec_", stringify!($ty), "$as_ptr")]
+                #[doc(hidden)]
+                pub extern "C" fn _as_ptr(vec: *mut Vec<$ty>) -> *const $ty {
+                    let vec = unsafe { &*vec };
+                    vec.as_ptr()
+                }
+
+                fn to_ffi_option(val: Option<$ty>) -> crate::option::$option_ty {
+                    match val {
+                        Some(val) => crate::option::$option_ty {
+                            val: crate::int128::$ffi_ty::from(val),
+                            is_some: true,
+                        },
+                        None => crate::option::$option_ty {
+                            val: crate::int128::$ffi_ty::from(0),
+                            is_some: false,
+                        },
+                    }
+                }
+            };
+        };
+    }
+
     pub(super) use vec_externs;
+    pub(super) use vec_externs_128;
 }
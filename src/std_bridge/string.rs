@@ -71,6 +71,7 @@ mod ffi {
 }
 
 #[doc(hidden)]
+#[derive(Hash, PartialEq, Eq)]
 pub struct RustString(pub String);
 
 #[doc(hidden)]